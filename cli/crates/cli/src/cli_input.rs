@@ -4,6 +4,7 @@ mod branch_ref;
 mod build;
 mod check;
 mod completions;
+mod compose;
 mod create;
 mod deploy;
 mod dev;
@@ -30,6 +31,7 @@ pub(crate) use branch::BranchSubCommand;
 pub(crate) use branch_ref::BranchRef;
 pub(crate) use build::BuildCommand;
 pub(crate) use completions::CompletionsCommand;
+pub(crate) use compose::ComposeCommand;
 pub(crate) use create::CreateCommand;
 pub(crate) use deploy::DeployCommand;
 pub(crate) use dev::DevCommand;
@@ -38,7 +40,7 @@ pub(crate) use graph_ref_no_branch::GraphRefNoBranch;
 pub(crate) use init::{GraphType, InitCommand};
 pub(crate) use introspect::IntrospectCommand;
 pub(crate) use link::LinkCommand;
-pub(crate) use lint::LintCommand;
+pub(crate) use lint::{LintCommand, LintOutputFormat};
 pub(crate) use log_level_filter::{LogLevelFilter, LogLevelFilters};
 pub(crate) use logs::LogsCommand;
 pub(crate) use project_ref::{ProjectRef, ProjectRefOrGraphRef};
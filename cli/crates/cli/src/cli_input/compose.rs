@@ -0,0 +1,14 @@
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Compose a federated schema from local subgraphs
+#[derive(Debug, Parser)]
+pub struct ComposeCommand {
+    /// The path of the configuration listing the subgraphs to compose
+    #[arg(short, long, default_value = "grafbase-compose.toml")]
+    pub config: PathBuf,
+    /// Write the federated schema to this file instead of stdout. The file can be passed to
+    /// `grafbase-gateway --schema`
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
@@ -1,9 +1,29 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::path::PathBuf;
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+#[clap(rename_all = "lowercase")]
+pub enum LintOutputFormat {
+    /// Human readable output
+    Text,
+    /// A JSON array of diagnostics
+    Json,
+    /// A SARIF log, supported by most code scanning tools
+    Sarif,
+}
+
 /// Lint a GraphQL schema
 #[derive(Debug, Parser)]
 pub struct LintCommand {
     /// The path of the schema to lint
     pub schema: Option<PathBuf>,
+    /// A TOML file enabling, disabling or changing the severity of rules
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// The output format
+    #[arg(long, value_enum, default_value_t = LintOutputFormat::Text)]
+    pub format: LintOutputFormat,
+    /// Fail if any warning is found, not only errors
+    #[arg(long)]
+    pub deny_warnings: bool,
 }
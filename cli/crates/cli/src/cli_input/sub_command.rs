@@ -13,8 +13,8 @@ use crate::{
 
 use super::{
     branch::BranchCommand, trust::TrustCommand, ArgumentNames, BuildCommand, CheckCommand, CompletionsCommand,
-    CreateCommand, DeployCommand, DevCommand, EnvironmentCommand, InitCommand, IntrospectCommand, LinkCommand,
-    LintCommand, LogsCommand, PublishCommand, SchemaCommand, StartCommand, SubgraphsCommand,
+    ComposeCommand, CreateCommand, DeployCommand, DevCommand, EnvironmentCommand, InitCommand, IntrospectCommand,
+    LinkCommand, LintCommand, LogsCommand, PublishCommand, SchemaCommand, StartCommand, SubgraphsCommand,
 };
 
 #[derive(Debug, Parser, strum::AsRefStr, strum::Display)]
@@ -71,6 +71,9 @@ pub enum SubCommand {
     Upgrade,
     /// Lint a GraphQL schema
     Lint(LintCommand),
+    /// Compose a federated schema from subgraph URLs or schema files, for use with the
+    /// self-hosted gateway
+    Compose(ComposeCommand),
 }
 
 impl SubCommand {
@@ -133,6 +136,7 @@ impl ArgumentNames for SubCommand {
             | SubCommand::Trust(_)
             | SubCommand::Upgrade
             | SubCommand::Lint(_)
+            | SubCommand::Compose(_)
             | SubCommand::Logs(_) => None,
         }
     }
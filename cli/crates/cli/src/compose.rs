@@ -0,0 +1,93 @@
+use crate::{cli_input::ComposeCommand, errors::CliError, output::report};
+use graphql_composition::{compose as compose_subgraphs, Subgraphs};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The configuration of `grafbase compose`:
+///
+/// ```toml
+/// [subgraphs.accounts]
+/// url = "http://localhost:4001/graphql"
+///
+/// [subgraphs.products]
+/// url = "http://localhost:4002/graphql"
+/// schema_path = "./products.graphql"
+/// ```
+#[derive(Debug, serde_derive::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ComposeConfig {
+    #[serde(default)]
+    subgraphs: BTreeMap<String, SubgraphConfig>,
+}
+
+#[derive(Debug, serde_derive::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SubgraphConfig {
+    /// The URL the gateway sends requests to.
+    url: String,
+    /// A file with the subgraph SDL, relative to the configuration file. The subgraph is
+    /// introspected if absent.
+    schema_path: Option<PathBuf>,
+    /// The URL to introspect, if different from `url`.
+    introspection_url: Option<String>,
+    /// Headers sent with the introspection request.
+    #[serde(default)]
+    introspection_headers: BTreeMap<String, String>,
+}
+
+#[tokio::main]
+pub(crate) async fn compose(ComposeCommand { config, output }: ComposeCommand) -> Result<(), CliError> {
+    let config_contents =
+        fs::read_to_string(&config).map_err(|error| CliError::ReadComposeConfig(config.clone(), error))?;
+    let compose_config: ComposeConfig =
+        toml::from_str(&config_contents).map_err(|error| CliError::ParseComposeConfig(config.clone(), error))?;
+    let config_directory = config.parent().unwrap_or(Path::new("."));
+
+    let mut subgraphs = Subgraphs::default();
+
+    for (name, subgraph) in &compose_config.subgraphs {
+        let sdl = match &subgraph.schema_path {
+            Some(schema_path) => {
+                let schema_path = config_directory.join(schema_path);
+                fs::read_to_string(&schema_path)
+                    .map_err(|error| CliError::ReadSubgraphSchema(name.clone(), schema_path, error))?
+            }
+            None => {
+                let url = subgraph.introspection_url.as_deref().unwrap_or(&subgraph.url);
+                let headers = subgraph.introspection_headers.iter().collect::<Vec<_>>();
+
+                grafbase_graphql_introspection::introspect(url, &headers)
+                    .await
+                    .map_err(|error| CliError::SubgraphIntrospection(name.clone(), error))?
+            }
+        };
+
+        subgraphs
+            .ingest_str(&sdl, name, &subgraph.url)
+            .map_err(|error| CliError::InvalidSubgraphSchema(name.clone(), error.to_string()))?;
+    }
+
+    let result = compose_subgraphs(&subgraphs);
+
+    for diagnostic in result.diagnostics().iter() {
+        report::compose_diagnostic(diagnostic);
+    }
+
+    // Only fatal diagnostics fail the composition.
+    let graph = result.into_result().map_err(|_| CliError::CompositionFailed)?;
+
+    let sdl = graph.into_federated_sdl();
+
+    match output {
+        Some(path) => {
+            fs::write(&path, sdl).map_err(|error| CliError::WriteFederatedSchema(path.clone(), error))?;
+            report::compose_success(compose_config.subgraphs.len(), &path);
+        }
+        None => print!("{sdl}"),
+    }
+
+    Ok(())
+}
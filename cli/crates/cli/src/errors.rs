@@ -85,8 +85,29 @@ pub enum CliError {
     /// returned if an unsupported extension is passed to lint
     #[error("attempted to lint a file with an unsupported extension: '{0}'")]
     LintUnsupportedFileExtension(String),
+    /// returned if the lint configuration could not be read
+    #[error("could not read the lint configuration '{0}'\nCaused by: {1}")]
+    ReadLintConfig(PathBuf, io::Error),
+    /// returned if the linted schema violates rules with the error severity, or warnings when denied
+    #[error("the schema did not pass the lint")]
+    LintFailed,
     #[error("failed to deploy a graph")]
     DeploymentFailed,
+    #[error("could not read the compose configuration '{0}'\nCaused by: {1}")]
+    ReadComposeConfig(PathBuf, io::Error),
+    #[error("could not parse the compose configuration '{0}'\nCaused by: {1}")]
+    ParseComposeConfig(PathBuf, toml::de::Error),
+    #[error("could not read the schema of the subgraph '{0}' at '{1}'\nCaused by: {2}")]
+    ReadSubgraphSchema(String, PathBuf, io::Error),
+    #[error("could not introspect the subgraph '{0}': {1}")]
+    SubgraphIntrospection(String, String),
+    #[error("the schema of the subgraph '{0}' is invalid: {1}")]
+    InvalidSubgraphSchema(String, String),
+    /// returned if composition produced fatal errors, which are reported separately
+    #[error("the subgraphs could not be composed")]
+    CompositionFailed,
+    #[error("could not write the federated schema to '{0}'\nCaused by: {1}")]
+    WriteFederatedSchema(PathBuf, io::Error),
 }
 
 #[cfg(target_family = "windows")]
@@ -131,6 +152,7 @@ impl CliError {
             Self::BackendApiError(ApiError::CorruptProjectMetadataFile | ApiError::UnlinkedProject) => Some("try running 'grafbase link'".to_owned()),
             Self::UpgradeError(UpgradeError::StartDownload | UpgradeError::StartGetLatestReleaseVersion) => Some("this may be caused by connection issues".to_owned()),
             Self::NotDirectInstall => Some("try upgrading via your original install method or installing grafbase directly".to_owned()),
+            Self::ReadComposeConfig(..) => Some("try passing the path of a compose configuration with --config".to_owned()),
             Self::LintUnsupportedFileExtension(_) | Self::LintNoExtension => Some("try passing a file with a supported extension: '.gql', '.graphql', '.graphqls' or '.sdl'".to_owned()),
             _ => None,
         }
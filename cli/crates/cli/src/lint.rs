@@ -1,16 +1,26 @@
-use crate::{errors::CliError, output::report};
-use graphql_lint::Severity;
+use crate::{
+    cli_input::{LintCommand, LintOutputFormat},
+    errors::CliError,
+    output::report,
+};
+use graphql_lint::{LintConfig, Severity};
 use std::{
     borrow::Borrow,
     fs,
     io::{IsTerminal, Read},
-    path::PathBuf,
 };
 
 const ALLOWED_EXTENSIONS: [&str; 4] = ["gql", "graphql", "graphqls", "sdl"];
 
-pub fn lint(schema_path: Option<PathBuf>) -> Result<(), CliError> {
-    let schema = match schema_path {
+pub fn lint(
+    LintCommand {
+        schema: schema_path,
+        config,
+        format,
+        deny_warnings,
+    }: LintCommand,
+) -> Result<(), CliError> {
+    let schema = match &schema_path {
         Some(schema_path) => {
             let extension = schema_path
                 .extension()
@@ -21,7 +31,7 @@ pub fn lint(schema_path: Option<PathBuf>) -> Result<(), CliError> {
                 return Err(CliError::LintUnsupportedFileExtension(extension.into_owned()));
             }
 
-            fs::read_to_string(&schema_path).map_err(|error| CliError::ReadLintSchema(schema_path, error))?
+            fs::read_to_string(schema_path).map_err(|error| CliError::ReadLintSchema(schema_path.clone(), error))?
         }
         None if std::io::stdin().is_terminal() => {
             return Err(CliError::MissingArgument("[schema] or a schema piped through stdin"));
@@ -37,17 +47,43 @@ pub fn lint(schema_path: Option<PathBuf>) -> Result<(), CliError> {
         }
     };
 
-    let diagnostics = graphql_lint::lint(&schema)?;
+    let config = match config {
+        Some(path) => {
+            let config = fs::read_to_string(&path).map_err(|error| CliError::ReadLintConfig(path, error))?;
+            LintConfig::from_toml(&config)?
+        }
+        None => LintConfig::default(),
+    };
 
-    if diagnostics.is_empty() {
-        report::lint_success();
-        return Ok(());
-    }
+    let diagnostics = graphql_lint::lint_with_config(&schema, &config)?;
+    let schema_name = schema_path
+        .as_ref()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|| String::from("stdin"));
 
-    for (message, severity) in diagnostics {
-        match severity {
-            Severity::Warning => report::lint_warning(message),
+    match format {
+        LintOutputFormat::Text if diagnostics.is_empty() => report::lint_success(),
+        LintOutputFormat::Text => {
+            for diagnostic in &diagnostics {
+                let location = format!("{schema_name}:{}", diagnostic.location);
+                let message = diagnostic.message.clone();
+                match diagnostic.severity {
+                    Severity::Warning => report::lint_warning(location, message, diagnostic.rule),
+                    Severity::Error => report::lint_error(location, message, diagnostic.rule),
+                }
+            }
         }
+        LintOutputFormat::Json => println!("{}", graphql_lint::output::to_json(&diagnostics)),
+        LintOutputFormat::Sarif => println!("{}", graphql_lint::output::to_sarif(&diagnostics, &schema_name)),
+    }
+
+    let failed = diagnostics.iter().any(|diagnostic| match diagnostic.severity {
+        Severity::Warning => deny_warnings,
+        Severity::Error => true,
+    });
+
+    if failed {
+        return Err(CliError::LintFailed);
     }
 
     Ok(())
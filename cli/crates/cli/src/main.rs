@@ -7,6 +7,7 @@ mod branch;
 mod build;
 mod check;
 mod cli_input;
+mod compose;
 mod create;
 mod deploy;
 mod dev;
@@ -205,7 +206,8 @@ fn try_main(args: Args) -> Result<(), CliError> {
             }
             upgrade::install_grafbase().map_err(Into::into)
         }
-        SubCommand::Lint(cmd) => lint::lint(cmd.schema),
+        SubCommand::Lint(cmd) => lint::lint(cmd),
+        SubCommand::Compose(cmd) => compose::compose(cmd),
         SubCommand::Branch(cmd) => match cmd.command {
             BranchSubCommand::List => branch::list(),
             BranchSubCommand::Delete(cmd) => branch::delete(cmd.branch_ref),
@@ -746,10 +746,42 @@ pub(crate) fn upgrade_up_to_date(version: &str) {
     watercolor::output!("✅ The locally installed version ({version}) is already up to date", @BrightGreen)
 }
 
+pub(crate) fn compose_success(subgraph_count: usize, path: &Path) {
+    let subgraphs = if subgraph_count == 1 { "subgraph" } else { "subgraphs" };
+    watercolor::output_error!("✨ Composed {subgraph_count} {subgraphs} into {}", path.display(), @BrightGreen);
+}
+
+pub(crate) fn compose_diagnostic(diagnostic: &graphql_composition::Diagnostic) {
+    let message = match (diagnostic.subgraph(), diagnostic.span()) {
+        (Some(subgraph), Some(span)) => format!(
+            "{} ({}, {subgraph}:{}:{})",
+            diagnostic.message(),
+            diagnostic.code(),
+            span.start.line,
+            span.start.column
+        ),
+        (Some(subgraph), None) => format!("{} ({}, {subgraph})", diagnostic.message(), diagnostic.code()),
+        _ => format!("{} ({})", diagnostic.message(), diagnostic.code()),
+    };
+
+    match diagnostic.severity() {
+        graphql_composition::Severity::Fatal => {
+            watercolor::output_error!("❌ [Error] {message}", @BrightRed);
+        }
+        graphql_composition::Severity::Warning => {
+            watercolor::output_error!("⚠️ [Warning] {message}", @BrightYellow);
+        }
+    }
+}
+
 pub(crate) fn lint_success() {
     watercolor::output!("✅ No issues found in your schema", @BrightGreen)
 }
 
-pub(crate) fn lint_warning(warning: String) {
-    watercolor::output!("⚠️ [Warning] {warning}", @BrightYellow);
+pub(crate) fn lint_warning(location: String, warning: String, rule: graphql_lint::Rule) {
+    watercolor::output!("⚠️ [Warning] {location}: {warning} ({rule})", @BrightYellow);
+}
+
+pub(crate) fn lint_error(location: String, error: String, rule: graphql_lint::Rule) {
+    watercolor::output!("❌ [Error] {location}: {error} ({rule})", @BrightRed);
 }
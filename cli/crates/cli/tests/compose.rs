@@ -0,0 +1,127 @@
+#![allow(unused_crate_dependencies)]
+
+mod utils;
+
+use serde_json::json;
+use utils::environment::Environment;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compose_introspected_and_file_subgraphs() {
+    let env = Environment::init();
+    let server = MockServer::start().await;
+
+    let response = ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "_service": {
+                "sdl": indoc::indoc! {r#"
+                    extend schema @link(url: "https://specs.apollo.dev/federation/v2.3", import: ["@key"])
+
+                    type Query {
+                      me: User
+                    }
+
+                    type User @key(fields: "id") {
+                      id: ID!
+                      name: String!
+                    }
+                "#}
+            }
+        }
+    }));
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(header("authorization", "Bearer secret"))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+
+    env.write_file(
+        "products.graphql",
+        indoc::indoc! {r#"
+            extend schema @link(url: "https://specs.apollo.dev/federation/v2.3", import: ["@key"])
+
+            type Query {
+              topProducts: [Product!]!
+            }
+
+            type Product {
+              upc: String!
+              seller: User
+            }
+
+            type User @key(fields: "id", resolvable: false) {
+              id: ID!
+            }
+        "#},
+    );
+
+    env.write_file(
+        "grafbase-compose.toml",
+        indoc::formatdoc! {r#"
+            [subgraphs.accounts]
+            url = "http://localhost:{port}/graphql"
+            introspection_headers = {{ authorization = "Bearer secret" }}
+
+            [subgraphs.products]
+            url = "http://products.example.com/graphql"
+            schema_path = "products.graphql"
+        "#,
+            port = server.address().port()
+        },
+    );
+
+    let output = env.grafbase_compose(&["--output", "federated.graphql"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Composed 2 subgraphs into federated.graphql"),
+        "{stderr}"
+    );
+
+    let federated_sdl = env.load_file_from_project("federated.graphql");
+
+    assert!(federated_sdl.contains("enum join__Graph"), "{federated_sdl}");
+    assert!(
+        federated_sdl.contains("http://products.example.com/graphql"),
+        "{federated_sdl}"
+    );
+    assert!(federated_sdl.contains("topProducts: [Product!]!"), "{federated_sdl}");
+    assert!(federated_sdl.contains("me: User"), "{federated_sdl}");
+}
+
+#[test]
+fn compose_errors_are_reported() {
+    let env = Environment::init();
+
+    env.write_file("a.graphql", "type Query { hello: String }");
+    env.write_file("b.graphql", "type Query { hello: Int }");
+    env.write_file(
+        "grafbase-compose.toml",
+        indoc::indoc! {r#"
+            [subgraphs.a]
+            url = "http://a.example.com/graphql"
+            schema_path = "a.graphql"
+
+            [subgraphs.b]
+            url = "http://b.example.com/graphql"
+            schema_path = "b.graphql"
+        "#},
+    );
+
+    let output = env.grafbase_compose(&[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        stderr.contains("The Query.hello field has conflicting types"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("the subgraphs could not be composed"), "{stderr}");
+}
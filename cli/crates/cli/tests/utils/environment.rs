@@ -246,6 +246,19 @@ impl Environment {
             .unwrap()
     }
 
+    pub fn grafbase_compose(&self, args: &[&str]) -> Output {
+        duct::cmd(
+            cargo_bin("grafbase"),
+            std::iter::once("compose").chain(args.iter().copied()),
+        )
+        .dir(&self.directory_path)
+        .stdout_capture()
+        .stderr_capture()
+        .unchecked()
+        .run()
+        .unwrap()
+    }
+
     #[track_caller]
     pub fn grafbase_init(&self, graph_type: GraphType) {
         let current_directory_path = self.schema_path.parent().expect("must be defined");
@@ -15,6 +15,7 @@ use self::{context::Context, directives::collect_composed_directives, input_obje
 use crate::{
     composition_ir as ir,
     subgraphs::{self, DefinitionKind, DefinitionWalker, FieldWalker, StringId},
+    DiagnosticCode,
 };
 use graphql_federated_graph as federated;
 use itertools::Itertools;
@@ -61,9 +62,14 @@ fn merge_object_definitions<'a>(
         let name = first.name().as_str();
         let first_subgraph = first.subgraph().name().as_str();
         let second_subgraph = incompatible.subgraph().name().as_str();
-        ctx.diagnostics.push_fatal(format!(
-            "Cannot merge {first_kind:?} with {second_kind:?} (`{name}` in `{first_subgraph}` and `{second_subgraph}`)",
-        ));
+        ctx.diagnostics.push_fatal(
+            DiagnosticCode::TypeKindMismatch,
+            format!(
+                "Cannot merge {first_kind:?} with {second_kind:?} (`{name}` in `{first_subgraph}` and `{second_subgraph}`)",
+            ),
+        )
+        .with_subgraph(second_subgraph)
+        .with_span(incompatible.directives().span());
         return;
     }
 
@@ -73,18 +79,25 @@ fn merge_object_definitions<'a>(
         let (entity_subgraphs, non_entity_subgraphs) = definitions
             .iter()
             .partition::<Vec<DefinitionWalker<'_>>, _>(|definition| definition.is_entity());
-
-        ctx.diagnostics.push_fatal(format!(
-            "The `{name}` object is an entity in subgraphs {} but not in subgraphs {}.",
-            entity_subgraphs
-                .into_iter()
-                .map(|d| d.subgraph().name().as_str())
-                .join(", "),
-            non_entity_subgraphs
-                .into_iter()
-                .map(|d| d.subgraph().name().as_str())
-                .join(", "),
-        ));
+        let inconsistent = non_entity_subgraphs[0];
+
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InconsistentEntity,
+                format!(
+                    "The `{name}` object is an entity in subgraphs {} but not in subgraphs {}.",
+                    entity_subgraphs
+                        .into_iter()
+                        .map(|d| d.subgraph().name().as_str())
+                        .join(", "),
+                    non_entity_subgraphs
+                        .into_iter()
+                        .map(|d| d.subgraph().name().as_str())
+                        .join(", "),
+                ),
+            )
+            .with_subgraph(inconsistent.subgraph().name().as_str())
+            .with_span(inconsistent.directives().span());
     }
 
     let description = definitions.iter().find_map(|def| def.description());
@@ -7,6 +7,7 @@ pub(super) fn collect_composed_directives<'a>(
     let mut tags: BTreeSet<StringId> = BTreeSet::new();
     let mut is_inaccessible = false;
     let mut authenticated = false;
+    let mut one_of = false;
     let mut extra_directives = Vec::new();
     let mut ids: Option<federated::Directives> = None;
     let mut push_directive = |ctx: &mut ComposeContext<'_>, directive: ir::Directive| {
@@ -31,6 +32,7 @@ pub(super) fn collect_composed_directives<'a>(
         // The directive is added whenever it's applied in any subgraph.
         is_inaccessible = is_inaccessible || site.inaccessible();
         authenticated = authenticated || site.authenticated();
+        one_of = one_of || site.one_of();
 
         for (name, arguments) in site.iter_composed_directives() {
             let name = ctx.insert_string(name);
@@ -51,6 +53,14 @@ pub(super) fn collect_composed_directives<'a>(
         push_directive(ctx, ir::Directive::Authenticated)
     }
 
+    if one_of {
+        let directive = ir::Directive::Other {
+            name: ctx.insert_static_str("oneOf"),
+            arguments: Vec::new(),
+        };
+        push_directive(ctx, directive);
+    }
+
     // @requiresScopes
     {
         let mut scopes: Vec<Vec<federated::StringId>> = Vec::new();
@@ -93,6 +103,45 @@ pub(super) fn collect_composed_directives<'a>(
         }
     }
 
+    // @semanticNonNull
+    //
+    // A level is only semantically non-null if every subgraph guarantees it.
+    {
+        let mut levels: Option<Vec<u8>> = None;
+
+        for site in sites.clone() {
+            let site_levels = site.semantic_non_null().unwrap_or_default();
+            match &mut levels {
+                Some(levels) => levels.retain(|level| site_levels.contains(level)),
+                None => levels = Some(site_levels.to_vec()),
+            }
+        }
+
+        if let Some(levels) = levels.filter(|levels| !levels.is_empty()) {
+            let directive = ir::Directive::Other {
+                name: ctx.insert_static_str("semanticNonNull"),
+                arguments: vec![(
+                    ctx.insert_static_str("levels"),
+                    subgraphs::Value::List(
+                        levels
+                            .into_iter()
+                            .map(|level| subgraphs::Value::Int(level.into()))
+                            .collect(),
+                    ),
+                )],
+            };
+            push_directive(ctx, directive);
+        }
+    }
+
+    if let Some(url) = sites.clone().find_map(|directives| directives.specified_by()) {
+        let directive = ir::Directive::Other {
+            name: ctx.insert_static_str("specifiedBy"),
+            arguments: vec![(ctx.insert_static_str("url"), subgraphs::Value::String(url))],
+        };
+        push_directive(ctx, directive);
+    }
+
     for tag in tags {
         let directive = ir::Directive::Other {
             name: ctx.insert_static_str("tag"),
@@ -13,10 +13,13 @@ pub(crate) fn merge_entity_interface_definitions<'a>(
     let mut interfaces = interface_defs();
 
     let Some(interface_def) = interfaces.next() else {
-        ctx.diagnostics.push_fatal(format!(
-            "The entity interface `{}` is not defined as an interface in any subgraph.",
-            first.name().as_str()
-        ));
+        ctx.diagnostics.push_fatal(
+            DiagnosticCode::InvalidEntityInterface,
+            format!(
+                "The entity interface `{}` is not defined as an interface in any subgraph.",
+                first.name().as_str()
+            ),
+        );
         return;
     };
 
@@ -46,17 +49,29 @@ pub(crate) fn merge_entity_interface_definitions<'a>(
                     .difference(&implementers)
                     .map(|id| ctx.subgraphs.walk(*id).as_str())
                     .join(", ");
-                ctx.diagnostics.push_fatal(format!(
-                r#"[{subgraph_name}]: Interface type "{interface_name}" has a resolvable key in subgraph "{subgraph_name}" but that subgraph is missing some of the supergraph implementation types of "{interface_name}". Subgraph "{subgraph_name}" should define types {implementer_names}."#
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidEntityInterface,
+                        format!(
+                            r#"[{subgraph_name}]: Interface type "{interface_name}" has a resolvable key in subgraph "{subgraph_name}" but that subgraph is missing some of the supergraph implementation types of "{interface_name}". Subgraph "{subgraph_name}" should define types {implementer_names}."#
+                        ),
+                    )
+                    .with_subgraph(subgraph_name)
+                    .with_span(interface.directives().span());
             }
 
             if interface.directives().interface_object() {
-                ctx.diagnostics.push_fatal(format!(
-                    "[{}] The @interfaceObject directive is not valid on interfaces (on `{}`).",
-                    interface.subgraph().name().as_str(),
-                    interface_name.as_str(),
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidEntityInterface,
+                        format!(
+                            "[{}] The @interfaceObject directive is not valid on interfaces (on `{}`).",
+                            interface.subgraph().name().as_str(),
+                            interface_name.as_str(),
+                        ),
+                    )
+                    .with_subgraph(interface.subgraph().name().as_str())
+                    .with_span(interface.directives().span());
             }
         }
     }
@@ -107,11 +122,17 @@ pub(crate) fn merge_entity_interface_definitions<'a>(
     }
 
     let Some(expected_key) = interface_def.entity_keys().next() else {
-        ctx.diagnostics.push_fatal(format!(
-            "The entity interface `{}` is missing a key in the `{}` subgraph.",
-            first.name().as_str(),
-            interface_def.subgraph().name().as_str(),
-        ));
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InvalidEntityInterface,
+                format!(
+                    "The entity interface `{}` is missing a key in the `{}` subgraph.",
+                    first.name().as_str(),
+                    interface_def.subgraph().name().as_str(),
+                ),
+            )
+            .with_subgraph(interface_def.subgraph().name().as_str())
+            .with_span(interface_def.directives().span());
         return;
     };
 
@@ -120,30 +141,48 @@ pub(crate) fn merge_entity_interface_definitions<'a>(
     // Each object in other subgraphs has to have @interfaceObject and the same key as the entity interface.
     for definition in definitions.iter().filter(|def| def.kind() == DefinitionKind::Object) {
         if !definition.directives().interface_object() {
-            ctx.diagnostics.push_fatal(format!(
-                "`{}` is an entity interface but the object type `{}` is missing the @interfaceObject directive in the `{}` subgraph.",
-                definition.name().as_str(),
-                definition.name().as_str(),
-                definition.subgraph().name().as_str(),
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InvalidEntityInterface,
+                    format!(
+                        "`{}` is an entity interface but the object type `{}` is missing the @interfaceObject directive in the `{}` subgraph.",
+                        definition.name().as_str(),
+                        definition.name().as_str(),
+                        definition.subgraph().name().as_str(),
+                    ),
+                )
+                .with_subgraph(definition.subgraph().name().as_str())
+                .with_span(definition.directives().span());
         }
 
         match definition.entity_keys().next() {
             None => {
-                ctx.diagnostics.push_fatal(format!(
-                    "The object type `{}` is annotated with @interfaceObject but missing a key in the `{}` subgraph.",
-                    first.name().as_str(),
-                    definition.subgraph().name().as_str(),
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidEntityInterface,
+                        format!(
+                            "The object type `{}` is annotated with @interfaceObject but missing a key in the `{}` subgraph.",
+                            first.name().as_str(),
+                            definition.subgraph().name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(definition.subgraph().name().as_str())
+                    .with_span(definition.directives().span());
             }
             Some(key) if key.fields() == expected_key.fields() => (),
             Some(_) => {
-                ctx.diagnostics.push_fatal(format!(
-                    "[{}] The object type `{}` is annotated with @interfaceObject but has a different key than the entity interface `{}`.",
-                    definition.subgraph().name().as_str(),
-                    definition.name().as_str(),
-                    interface_def.name().as_str(),
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidEntityInterface,
+                        format!(
+                            "[{}] The object type `{}` is annotated with @interfaceObject but has a different key than the entity interface `{}`.",
+                            definition.subgraph().name().as_str(),
+                            definition.name().as_str(),
+                            interface_def.name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(definition.subgraph().name().as_str())
+                    .with_span(definition.directives().span());
             }
         }
 
@@ -204,17 +243,33 @@ pub(crate) fn merge_entity_interface_definitions<'a>(
     for object in interface_def.subgraph().interface_implementers(first.name().id) {
         match object.entity_keys().next() {
             Some(key) if key.fields() == expected_key.fields() => (),
-            Some(_) => ctx.diagnostics.push_fatal(format!(
-                "[{}] The object type `{}` implements the entity interface `{}` but does not have the same key. The key must match exactly.",
-                object.subgraph().name().as_str(),
-                object.name().as_str(),
-                first.name().as_str(),
-            )),
-            None => ctx.diagnostics.push_fatal(format!(
-                "[{}] The object type `{}` is annotated with @interfaceObject but missing a key.",
-                object.subgraph().name().as_str(),
-                object.name().as_str(),
-            )),
+            Some(_) => {
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidEntityInterface,
+                        format!(
+                            "[{}] The object type `{}` implements the entity interface `{}` but does not have the same key. The key must match exactly.",
+                            object.subgraph().name().as_str(),
+                            object.name().as_str(),
+                            first.name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(object.subgraph().name().as_str())
+                    .with_span(object.directives().span());
+            }
+            None => {
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidEntityInterface,
+                        format!(
+                            "[{}] The object type `{}` is annotated with @interfaceObject but missing a key.",
+                            object.subgraph().name().as_str(),
+                            object.name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(object.subgraph().name().as_str())
+                    .with_span(object.directives().span());
+            }
         }
 
         let object_name = ctx.insert_string(object.name().id);
@@ -236,7 +291,10 @@ fn translate_arguments(
     ctx: &mut ComposeContext<'_>,
 ) -> federated::InputValueDefinitions {
     let mut ids: Option<federated::InputValueDefinitions> = None;
-    for arg in field.arguments() {
+    for arg in field
+        .arguments()
+        .filter(|arg| arg.directives().from_context().is_none())
+    {
         let directives = collect_composed_directives(std::iter::once(arg.directives()), ctx);
         let name = ctx.insert_string(arg.name().id);
         let id = ctx.insert_input_value_definition(ir::InputValueDefinitionIr {
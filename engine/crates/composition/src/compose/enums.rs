@@ -80,10 +80,13 @@ fn merge_intersection<'a>(
     }
 
     if intersection.is_empty() {
-        ctx.diagnostics.push_fatal(format!(
-            "Values for enum {} are empty (intersection)",
-            first.name().as_str(),
-        ));
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::EmptyEnum,
+                format!("Values for enum {} are empty (intersection)", first.name().as_str(),),
+            )
+            .with_subgraph(first.subgraph().name().as_str())
+            .with_span(first.directives().span());
     }
 
     for value in intersection {
@@ -133,10 +136,16 @@ fn merge_exactly_matching<'a>(
 
     for definition in definitions {
         if !is_slice_match(&expected, definition.enum_values().map(|v| v.name().id)) {
-            ctx.diagnostics.push_fatal(format!(
-                "The enum {} should match exactly in all subgraphs, but it does not",
-                first.name().as_str()
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::EnumMismatch,
+                    format!(
+                        "The enum {} should match exactly in all subgraphs, but it does not",
+                        first.name().as_str()
+                    ),
+                )
+                .with_subgraph(definition.subgraph().name().as_str())
+                .with_span(definition.directives().span());
             return;
         }
     }
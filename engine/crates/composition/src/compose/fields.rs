@@ -63,15 +63,21 @@ fn compose_field_types<'a>(
         }) {
         Ok((_, ty)) => Some(ty.id),
         Err((a_field, b_field)) => {
-            ctx.diagnostics.push_fatal(format!(
-                "The {}.{} field has conflicting types in different subgraphs: {} in {} but {} in {}",
-                first.parent_definition().name().as_str(),
-                first.name().as_str(),
-                a_field.r#type(),
-                a_field.parent_definition().subgraph().name().as_str(),
-                b_field.r#type(),
-                b_field.parent_definition().subgraph().name().as_str(),
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::FieldTypeMismatch,
+                    format!(
+                        "The {}.{} field has conflicting types in different subgraphs: {} in {} but {} in {}",
+                        first.parent_definition().name().as_str(),
+                        first.name().as_str(),
+                        a_field.r#type(),
+                        a_field.parent_definition().subgraph().name().as_str(),
+                        b_field.r#type(),
+                        b_field.parent_definition().subgraph().name().as_str(),
+                    ),
+                )
+                .with_subgraph(b_field.parent_definition().subgraph().name().as_str())
+                .with_span(b_field.directives().span());
             None
         }
     }
@@ -95,16 +101,22 @@ pub(super) fn compose_argument_types<'a>(
         }) {
         Ok((_, ty)) => Some(ty.id),
         Err((a_arg, b_arg)) => {
-            ctx.diagnostics.push_fatal(format!(
-                "The {}.{}({}:) argument has conflicting types in different subgraphs: {} in {} but {} in {}",
-                ctx.subgraphs.walk(parent_definition_name).as_str(),
-                ctx.subgraphs.walk(field_name).as_str(),
-                a_arg.name().as_str(),
-                a_arg.r#type(),
-                a_arg.field().parent_definition().subgraph().name().as_str(),
-                b_arg.r#type(),
-                b_arg.field().parent_definition().subgraph().name().as_str(),
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::ArgumentTypeMismatch,
+                    format!(
+                        "The {}.{}({}:) argument has conflicting types in different subgraphs: {} in {} but {} in {}",
+                        ctx.subgraphs.walk(parent_definition_name).as_str(),
+                        ctx.subgraphs.walk(field_name).as_str(),
+                        a_arg.name().as_str(),
+                        a_arg.r#type(),
+                        a_arg.field().parent_definition().subgraph().name().as_str(),
+                        b_arg.r#type(),
+                        b_arg.field().parent_definition().subgraph().name().as_str(),
+                    ),
+                )
+                .with_subgraph(b_arg.field().parent_definition().subgraph().name().as_str())
+                .with_span(b_arg.directives().span());
             None
         }
     }
@@ -11,6 +11,7 @@ pub(super) fn merge_input_object_definitions(
     let composed_directives = collect_composed_directives(definitions.iter().map(|def| def.directives()), ctx);
 
     let input_object_name = ctx.insert_string(first.name().id);
+    let is_one_of = definitions.iter().any(|def| def.directives().one_of());
 
     // We want to take the intersection of the field sets.
     let intersection: HashSet<StringId> = first
@@ -39,16 +40,44 @@ pub(super) fn merge_input_object_definitions(
         // Check that no required field was excluded.
         if !intersection.contains(&field_name) {
             if let Some((_, required_field)) = fields.iter().find(|(_, field)| field.r#type().is_required()) {
-                ctx.diagnostics.push_fatal(format!(
-                    "The {input_type_name}.{field_name} field is not defined in all subgraphs, but it is required in {bad_subgraph}",
-                    input_type_name = first.name().as_str(),
-                    field_name = required_field.name().as_str(),
-                    bad_subgraph = required_field.parent_definition().subgraph().name().as_str(),
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::RequiredInputFieldMissing,
+                        format!(
+                            "The {input_type_name}.{field_name} field is not defined in all subgraphs, but it is required in {bad_subgraph}",
+                            input_type_name = first.name().as_str(),
+                            field_name = required_field.name().as_str(),
+                            bad_subgraph = required_field.parent_definition().subgraph().name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(required_field.parent_definition().subgraph().name().as_str())
+                    .with_span(required_field.directives().span());
             }
             continue;
         }
 
+        // Fields of a @oneOf input object must all be nullable and without default value.
+        if is_one_of {
+            if let Some((_, invalid_field)) = fields
+                .iter()
+                .find(|(_, field)| field.r#type().is_required() || field.default_value().is_some())
+            {
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidOneOfField,
+                        format!(
+                            "The {input_type_name}.{field_name} field of the @oneOf input object must be nullable and cannot have a default value in {bad_subgraph}",
+                            input_type_name = first.name().as_str(),
+                            field_name = invalid_field.name().as_str(),
+                            bad_subgraph = invalid_field.parent_definition().subgraph().name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(invalid_field.parent_definition().subgraph().name().as_str())
+                    .with_span(invalid_field.directives().span());
+                continue;
+            }
+        }
+
         let directive_containers = fields.iter().map(|(_, field)| field.directives());
         let composed_directives = collect_composed_directives(directive_containers, ctx);
 
@@ -36,12 +36,18 @@ pub(super) fn merge_interface_definitions<'a>(
 
         let description = field.description().map(|description| ctx.insert_string(description.id));
 
-        if fields.iter().any(|field| field.1.directives().shareable()) {
-            ctx.diagnostics.push_fatal(format!(
-                "The field {}.{} is marked as shareable but this is not allowed on interfaces.",
-                first.name().as_str(),
-                field.name().as_str()
-            ));
+        if let Some((_, shareable)) = fields.iter().find(|field| field.1.directives().shareable()) {
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::ShareableInterfaceField,
+                    format!(
+                        "The field {}.{} is marked as shareable but this is not allowed on interfaces.",
+                        first.name().as_str(),
+                        field.name().as_str()
+                    ),
+                )
+                .with_subgraph(shareable.parent_definition().subgraph().name().as_str())
+                .with_span(shareable.directives().span());
         }
 
         let directive_containers = fields.iter().map(|(_, field)| field.directives());
@@ -91,12 +97,27 @@ fn check_implementers(
                 .iter_definitions_with_name(implementer_name)
                 .any(|(_, def)| ctx.subgraphs.walk(def).find_field(field_name).is_some())
             {
-                ctx.diagnostics.push_fatal(format!(
-                    "The `{}.{}` field is not implemented by `{}`, but it should be.",
-                    ctx.subgraphs.walk(interface_name).as_str(),
-                    ctx.subgraphs.walk(field_name).as_str(),
-                    ctx.subgraphs.walk(implementer_name).as_str(),
-                ));
+                let implementer = ctx
+                    .subgraphs
+                    .iter_definitions_with_name(implementer_name)
+                    .next()
+                    .map(|(_, def)| ctx.subgraphs.walk(def));
+
+                let diagnostic = ctx.diagnostics.push_fatal(
+                    DiagnosticCode::InterfaceFieldNotImplemented,
+                    format!(
+                        "The `{}.{}` field is not implemented by `{}`, but it should be.",
+                        ctx.subgraphs.walk(interface_name).as_str(),
+                        ctx.subgraphs.walk(field_name).as_str(),
+                        ctx.subgraphs.walk(implementer_name).as_str(),
+                    ),
+                );
+
+                if let Some(implementer) = implementer {
+                    diagnostic
+                        .with_subgraph(implementer.subgraph().name().as_str())
+                        .with_span(implementer.directives().span());
+                }
             }
         }
     }
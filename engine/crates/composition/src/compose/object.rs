@@ -1,7 +1,8 @@
 use super::*;
 
 /// The arguments of a federated graph's fields are the interseciton of the subgraph's arguments for
-/// that field. Returns (arg_name, arg_type, is_inaccessible).
+/// that field. Returns (arg_name, arg_type, is_inaccessible). Arguments with `@fromContext` are
+/// provided by the router, they are not part of the federated graph's field arguments.
 pub(super) fn merge_field_arguments<'a>(
     first: FieldWalker<'a>,
     fields: &[FieldWalker<'a>],
@@ -14,6 +15,7 @@ pub(super) fn merge_field_arguments<'a>(
     // We want to take the intersection of the field sets.
     let intersection: HashSet<StringId> = first
         .arguments()
+        .filter(|arg| arg.directives().from_context().is_none())
         .map(|arg| arg.name().id)
         .filter(|arg_name| fields[1..].iter().all(|def| def.argument_by_name(*arg_name).is_some()))
         .collect();
@@ -21,6 +23,7 @@ pub(super) fn merge_field_arguments<'a>(
     let mut all_arguments = fields
         .iter()
         .flat_map(|def| def.arguments())
+        .filter(|arg| arg.directives().from_context().is_none())
         .map(|arg| (arg.name().id, arg))
         .collect::<Vec<_>>();
 
@@ -65,20 +68,26 @@ pub(super) fn merge_field_arguments<'a>(
         };
 
         let argument_is_inaccessible = || arguments.iter().any(|(_, arg)| arg.directives().inaccessible());
-        let argument_type_is_inaccessible = arguments.iter().any(|(_, arg)| {
+        let argument_of_inaccessible_type = arguments.iter().find(|(_, arg)| {
             arg.r#type()
                 .definition(arg.field().parent_definition().subgraph_id())
                 .map(|def| def.directives().inaccessible())
                 .unwrap_or(false)
         });
 
-        if argument_type_is_inaccessible && !argument_is_inaccessible() {
-            ctx.diagnostics.push_fatal(format!(
-                "The argument `{}.{}({}:)` is of an @inaccessible type, but is itself not marked as @inaccessible.",
-                ctx.subgraphs.walk(parent_definition_name).as_str(),
-                ctx.subgraphs.walk(field_name).as_str(),
-                ctx.subgraphs.walk(argument_name).as_str(),
-            ));
+        if let Some((_, argument)) = argument_of_inaccessible_type.filter(|_| !argument_is_inaccessible()) {
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InaccessibleTypeReference,
+                    format!(
+                        "The argument `{}.{}({}:)` is of an @inaccessible type, but is itself not marked as @inaccessible.",
+                        ctx.subgraphs.walk(parent_definition_name).as_str(),
+                        ctx.subgraphs.walk(field_name).as_str(),
+                        ctx.subgraphs.walk(argument_name).as_str(),
+                    ),
+                )
+                .with_subgraph(argument.field().parent_definition().subgraph().name().as_str())
+                .with_span(argument.directives().span());
         }
 
         let name = ctx.insert_string(argument_name);
@@ -122,14 +131,22 @@ fn compose_field_argument_defaults<'a>(
                 default = Some((value, *argument));
             }
             Some((default, _)) if default == &value => (),
-            Some((_, other_argument)) => ctx.diagnostics.push_fatal(format!(
-                r#"The argument {type_name}.{field_name}.{argument_name} has incompatible defaults in subgraphs "{first_subgraph}" and "{second_subgraph}""#,
-                type_name = argument.field().parent_definition().name().as_str(),
-                field_name = argument.field().name().as_str(),
-                argument_name = argument.name().as_str(),
-                first_subgraph = other_argument.field().parent_definition().subgraph().name().as_str(),
-                second_subgraph = argument.field().parent_definition().subgraph().name().as_str(),
-            )),
+            Some((_, other_argument)) => {
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::ArgumentDefaultMismatch,
+                        format!(
+                            r#"The argument {type_name}.{field_name}.{argument_name} has incompatible defaults in subgraphs "{first_subgraph}" and "{second_subgraph}""#,
+                            type_name = argument.field().parent_definition().name().as_str(),
+                            field_name = argument.field().name().as_str(),
+                            argument_name = argument.name().as_str(),
+                            first_subgraph = other_argument.field().parent_definition().subgraph().name().as_str(),
+                            second_subgraph = argument.field().parent_definition().subgraph().name().as_str(),
+                        ),
+                    )
+                    .with_subgraph(argument.field().parent_definition().subgraph().name().as_str())
+                    .with_span(argument.directives().span());
+            }
         }
     }
 
@@ -150,14 +167,20 @@ fn required_argument_not_in_intersection_error(
         .filter(|field| field.argument_by_name(argument_name).is_none())
         .map(|field| field.parent_definition().subgraph().name().as_str())
         .collect::<Vec<_>>();
-    ctx.diagnostics.push_fatal(format!(
-        "The argument `{}.{}({}:)` is required in {} but missing in {}.",
-        ctx.subgraphs.walk(parent_definition_name).as_str(),
-        ctx.subgraphs.walk(field_name).as_str(),
-        ctx.subgraphs.walk(argument_name).as_str(),
-        subgraph_where_required,
-        subgraphs_where_missing.join(", "),
-    ));
+    ctx.diagnostics
+        .push_fatal(
+            DiagnosticCode::RequiredArgumentMissing,
+            format!(
+                "The argument `{}.{}({}:)` is required in {} but missing in {}.",
+                ctx.subgraphs.walk(parent_definition_name).as_str(),
+                ctx.subgraphs.walk(field_name).as_str(),
+                ctx.subgraphs.walk(argument_name).as_str(),
+                subgraph_where_required,
+                subgraphs_where_missing.join(", "),
+            ),
+        )
+        .with_subgraph(subgraph_where_required)
+        .with_span(required_arg.directives().span());
 }
 
 pub(super) fn compose_object_fields<'a>(
@@ -181,13 +204,19 @@ pub(super) fn compose_object_fields<'a>(
     {
         let next = &fields[1];
 
-        ctx.diagnostics.push_fatal(format!(
-            "The field `{}` on `{}` is defined in two subgraphs (`{}` and `{}`).",
-            first.name().as_str(),
-            first.parent_definition().name().as_str(),
-            first.parent_definition().subgraph().name().as_str(),
-            next.parent_definition().subgraph().name().as_str(),
-        ));
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::FieldNotShareable,
+                format!(
+                    "The field `{}` on `{}` is defined in two subgraphs (`{}` and `{}`).",
+                    first.name().as_str(),
+                    first.parent_definition().name().as_str(),
+                    first.parent_definition().subgraph().name().as_str(),
+                    next.parent_definition().subgraph().name().as_str(),
+                ),
+            )
+            .with_subgraph(next.parent_definition().subgraph().name().as_str())
+            .with_span(next.directives().span());
     }
 
     if fields.iter().any(|field| {
@@ -204,14 +233,21 @@ pub(super) fn compose_object_fields<'a>(
             first.name().as_str()
         );
         let non_marked_subgraphs = fields.iter().filter(|field| !field.directives().inaccessible());
-
-        ctx.diagnostics.push_fatal(format!(
-            "The field `{name}` is of an @inaccessible type, but is itself not marked as @inaccessible in subgraphs {}",
-            non_marked_subgraphs
-                .into_iter()
-                .map(|f| f.parent_definition().subgraph().name().as_str())
-                .join(", "),
-        ));
+        let non_marked = non_marked_subgraphs.clone().next().unwrap_or(&first);
+
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InaccessibleTypeReference,
+                format!(
+                    "The field `{name}` is of an @inaccessible type, but is itself not marked as @inaccessible in subgraphs {}",
+                    non_marked_subgraphs
+                        .into_iter()
+                        .map(|f| f.parent_definition().subgraph().name().as_str())
+                        .join(", "),
+                ),
+            )
+            .with_subgraph(non_marked.parent_definition().subgraph().name().as_str())
+            .with_span(non_marked.directives().span());
     }
 
     let arguments = object::merge_field_arguments(first, fields, ctx);
@@ -282,12 +318,18 @@ pub(super) fn collect_overrides(fields: &[FieldWalker<'_>], ctx: &mut Context<'_
         let field_subgraph = field.parent_definition().subgraph();
 
         if override_directive.from == field_subgraph.name().id {
-            ctx.diagnostics.push_fatal(format!(
-                r#"Source and destination subgraphs "{}" are the same for overridden field "{}.{}""#,
-                ctx.subgraphs.walk(override_directive.from).as_str(),
-                field.parent_definition().name().as_str(),
-                field.name().as_str()
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InvalidOverride,
+                    format!(
+                        r#"Source and destination subgraphs "{}" are the same for overridden field "{}.{}""#,
+                        ctx.subgraphs.walk(override_directive.from).as_str(),
+                        field.parent_definition().name().as_str(),
+                        field.name().as_str()
+                    ),
+                )
+                .with_subgraph(field_subgraph.name().as_str())
+                .with_span(field.directives().span());
             continue;
         }
 
@@ -297,11 +339,18 @@ pub(super) fn collect_overrides(fields: &[FieldWalker<'_>], ctx: &mut Context<'_
         {
             if override_source.directives().r#override().is_some() {
                 ctx.diagnostics
-                    .push_fatal(format!(r#"Field "{}.{}" on subgraph "{}" is also marked with directive @override in subgraph "{}". Only one @override directive is allowed per field."#,
-                        override_source.parent_definition().name().as_str(),
-                        override_source.name().as_str(),
-                        override_source.parent_definition().subgraph().name().as_str(),
-                        field.parent_definition().subgraph().name().as_str()));
+                    .push_fatal(
+                        DiagnosticCode::InvalidOverride,
+                        format!(
+                            r#"Field "{}.{}" on subgraph "{}" is also marked with directive @override in subgraph "{}". Only one @override directive is allowed per field."#,
+                            override_source.parent_definition().name().as_str(),
+                            override_source.name().as_str(),
+                            override_source.parent_definition().subgraph().name().as_str(),
+                            field.parent_definition().subgraph().name().as_str()
+                        ),
+                    )
+                    .with_subgraph(field_subgraph.name().as_str())
+                    .with_span(field.directives().span());
             }
         }
 
@@ -343,12 +392,18 @@ pub(crate) fn validate_shareable_object_fields_match(
     for definition in definitions {
         for field in all_fields.difference(&inaccessible_fields) {
             if definition.find_field(*field).is_none() {
-                ctx.diagnostics.push_fatal(format!(
-                    "[{}] The shareable object `{}` is missing the `{}` field defined in other subgraphs.",
-                    definition.subgraph().name().as_str(),
-                    definition.name().as_str(),
-                    definition.walk(*field).as_str(),
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::ShareableFieldMissing,
+                        format!(
+                            "[{}] The shareable object `{}` is missing the `{}` field defined in other subgraphs.",
+                            definition.subgraph().name().as_str(),
+                            definition.name().as_str(),
+                            definition.walk(*field).as_str(),
+                        ),
+                    )
+                    .with_subgraph(definition.subgraph().name().as_str())
+                    .with_span(definition.directives().span());
             }
         }
     }
@@ -20,8 +20,10 @@ pub(super) fn merge_root_fields(ctx: &mut Context<'_>) {
     }
 
     let Some(query_id) = merge_fields("Query", &query_types, ctx) else {
-        ctx.diagnostics
-            .push_fatal("The root `Query` object is not defined in any subgraph.".to_owned());
+        ctx.diagnostics.push_fatal(
+            DiagnosticCode::MissingQueryRoot,
+            "The root `Query` object is not defined in any subgraph.".to_owned(),
+        );
         return;
     };
 
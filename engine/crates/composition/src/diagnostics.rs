@@ -1,33 +1,131 @@
+mod code;
+
+pub use self::code::DiagnosticCode;
+
 /// Warnings and errors produced by composition.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, serde::Serialize)]
+#[serde(transparent)]
 pub struct Diagnostics(Vec<Diagnostic>);
 
 impl Diagnostics {
     pub(crate) fn any_fatal(&self) -> bool {
-        self.0.iter().any(|diagnostic| diagnostic.is_fatal)
+        self.0.iter().any(|diagnostic| diagnostic.severity == Severity::Fatal)
     }
 
     pub(crate) fn clone_all_from(&mut self, other: &Diagnostics) {
         self.0.extend(other.0.iter().cloned())
     }
 
+    /// Iterate over all diagnostics.
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.0.iter()
+    }
+
     /// Iterate over all diagnostic messages.
     pub fn iter_messages(&self) -> impl Iterator<Item = &str> {
         self.0.iter().map(|diagnostic| diagnostic.message.as_str())
     }
 
-    pub(crate) fn push_fatal(&mut self, message: String) {
+    pub(crate) fn push_fatal(&mut self, code: DiagnosticCode, message: String) -> &mut Diagnostic {
+        self.0.push(Diagnostic {
+            message,
+            code,
+            severity: Severity::Fatal,
+            subgraph: None,
+            span: None,
+        });
+
+        self.0.last_mut().unwrap()
+    }
+
+    pub(crate) fn push_warning(&mut self, code: DiagnosticCode, message: String) -> &mut Diagnostic {
         self.0.push(Diagnostic {
             message,
-            is_fatal: true,
+            code,
+            severity: Severity::Warning,
+            subgraph: None,
+            span: None,
         });
+
+        self.0.last_mut().unwrap()
     }
 }
 
 /// A composition diagnostic.
-#[derive(Debug, Clone)]
-pub(crate) struct Diagnostic {
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Diagnostic {
     message: String,
+    code: DiagnosticCode,
+    severity: Severity,
+    /// The subgraph the diagnostic is about, if it is about a single subgraph.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subgraph: Option<String>,
+    /// Where the definition, field or directive the diagnostic is about is in the subgraph SDL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    span: Option<Span>,
+}
+
+impl Diagnostic {
+    /// The human readable description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// A stable identifier for the kind of problem.
+    pub fn code(&self) -> DiagnosticCode {
+        self.code
+    }
+
     /// Should this diagnostic be interpreted as a composition failure?
-    is_fatal: bool,
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The name of the subgraph the diagnostic points to, if any.
+    pub fn subgraph(&self) -> Option<&str> {
+        self.subgraph.as_deref()
+    }
+
+    /// The location in the SDL of [`subgraph()`](Self::subgraph), if known.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    pub(crate) fn with_subgraph(&mut self, subgraph: &str) -> &mut Self {
+        self.subgraph = Some(subgraph.to_owned());
+        self
+    }
+
+    pub(crate) fn with_span(&mut self, span: Option<Span>) -> &mut Self {
+        self.span = span;
+        self
+    }
+}
+
+/// The severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Composition failed.
+    Fatal,
+    /// Composition succeeded, but the subgraphs should probably be fixed.
+    Warning,
+}
+
+/// A range in a subgraph SDL. The end is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Span {
+    /// Where the range starts.
+    pub start: Location,
+    /// Where the range ends.
+    pub end: Location,
+}
+
+/// A position in a subgraph SDL. Lines and columns start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Location {
+    /// The line number.
+    pub line: usize,
+    /// The column number, in characters.
+    pub column: usize,
 }
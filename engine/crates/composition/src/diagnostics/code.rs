@@ -0,0 +1,102 @@
+/// A stable identifier for each kind of composition diagnostic. The serialized form, for example
+/// `FIELD_TYPE_MISMATCH`, will not change between releases and can be relied upon by tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DiagnosticCode {
+    /// No subgraph to compose.
+    NoSubgraphs,
+    /// A subgraph name is empty or contains invalid characters.
+    InvalidSubgraphName,
+    /// Two subgraphs have the same name, ignoring case.
+    DuplicateSubgraphName,
+    /// None of the subgraphs defines a `Query` root type with fields.
+    MissingQueryRoot,
+    /// A type is named like a root type but is not used as a root.
+    RootTypeNameConflict,
+    /// A `@composeDirective` is invalid.
+    InvalidComposeDirective,
+    /// A field set in `@requires`, `@provides` or `@authorized` is invalid.
+    InvalidFieldSet,
+    /// An `@authorized` directive is invalid.
+    InvalidAuthorized,
+    /// An `@override` directive is invalid.
+    InvalidOverride,
+    /// A `@fromContext` directive is invalid.
+    InvalidFromContext,
+    /// Definitions with the same name are of different kinds, for example object and interface.
+    TypeKindMismatch,
+    /// An object is an entity in some subgraphs but not in others.
+    InconsistentEntity,
+    /// A field or argument of an `@inaccessible` type is not itself `@inaccessible`.
+    InaccessibleTypeReference,
+    /// A field is defined in multiple subgraphs without being shareable.
+    FieldNotShareable,
+    /// A shareable object does not define all the fields it has in other subgraphs.
+    ShareableFieldMissing,
+    /// A field has incompatible types in different subgraphs.
+    FieldTypeMismatch,
+    /// An argument has incompatible types in different subgraphs.
+    ArgumentTypeMismatch,
+    /// An argument has different default values in different subgraphs.
+    ArgumentDefaultMismatch,
+    /// A required argument is missing in some subgraphs.
+    RequiredArgumentMissing,
+    /// A required input field is missing in some subgraphs.
+    RequiredInputFieldMissing,
+    /// A field of a `@oneOf` input object is required or has a default value.
+    InvalidOneOfField,
+    /// The values of an enum have an empty intersection.
+    EmptyEnum,
+    /// An enum used in both inputs and outputs is not the same in all subgraphs.
+    EnumMismatch,
+    /// A field is marked as shareable on an interface.
+    ShareableInterfaceField,
+    /// An object does not implement a field of one of its interfaces.
+    InterfaceFieldNotImplemented,
+    /// An entity interface or one of its `@interfaceObject` is invalid.
+    InvalidEntityInterface,
+    /// A field of the composed graph can be queried, but cannot be resolved by any subgraph on some query path.
+    UnsatisfiableField,
+}
+
+impl DiagnosticCode {
+    /// The stable string representation of the code, for example `FIELD_TYPE_MISMATCH`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::NoSubgraphs => "NO_SUBGRAPHS",
+            DiagnosticCode::InvalidSubgraphName => "INVALID_SUBGRAPH_NAME",
+            DiagnosticCode::DuplicateSubgraphName => "DUPLICATE_SUBGRAPH_NAME",
+            DiagnosticCode::MissingQueryRoot => "MISSING_QUERY_ROOT",
+            DiagnosticCode::RootTypeNameConflict => "ROOT_TYPE_NAME_CONFLICT",
+            DiagnosticCode::InvalidComposeDirective => "INVALID_COMPOSE_DIRECTIVE",
+            DiagnosticCode::InvalidFieldSet => "INVALID_FIELD_SET",
+            DiagnosticCode::InvalidAuthorized => "INVALID_AUTHORIZED",
+            DiagnosticCode::InvalidOverride => "INVALID_OVERRIDE",
+            DiagnosticCode::InvalidFromContext => "INVALID_FROM_CONTEXT",
+            DiagnosticCode::TypeKindMismatch => "TYPE_KIND_MISMATCH",
+            DiagnosticCode::InconsistentEntity => "INCONSISTENT_ENTITY",
+            DiagnosticCode::InaccessibleTypeReference => "INACCESSIBLE_TYPE_REFERENCE",
+            DiagnosticCode::FieldNotShareable => "FIELD_NOT_SHAREABLE",
+            DiagnosticCode::ShareableFieldMissing => "SHAREABLE_FIELD_MISSING",
+            DiagnosticCode::FieldTypeMismatch => "FIELD_TYPE_MISMATCH",
+            DiagnosticCode::ArgumentTypeMismatch => "ARGUMENT_TYPE_MISMATCH",
+            DiagnosticCode::ArgumentDefaultMismatch => "ARGUMENT_DEFAULT_MISMATCH",
+            DiagnosticCode::RequiredArgumentMissing => "REQUIRED_ARGUMENT_MISSING",
+            DiagnosticCode::RequiredInputFieldMissing => "REQUIRED_INPUT_FIELD_MISSING",
+            DiagnosticCode::InvalidOneOfField => "INVALID_ONE_OF_FIELD",
+            DiagnosticCode::EmptyEnum => "EMPTY_ENUM",
+            DiagnosticCode::EnumMismatch => "ENUM_MISMATCH",
+            DiagnosticCode::ShareableInterfaceField => "SHAREABLE_INTERFACE_FIELD",
+            DiagnosticCode::InterfaceFieldNotImplemented => "INTERFACE_FIELD_NOT_IMPLEMENTED",
+            DiagnosticCode::InvalidEntityInterface => "INVALID_ENTITY_INTERFACE",
+            DiagnosticCode::UnsatisfiableField => "UNSATISFIABLE_FIELD",
+        }
+    }
+}
+
+impl std::fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -18,7 +18,7 @@ use std::{
 };
 
 /// This can't fail. All the relevant, correct information should already be in the CompositionIr.
-pub(crate) fn emit_federated_graph(mut ir: CompositionIr, subgraphs: &Subgraphs) -> federated::VersionedFederatedGraph {
+pub(crate) fn emit_federated_graph(mut ir: CompositionIr, subgraphs: &Subgraphs) -> federated::FederatedGraph {
     let __schema = ir.strings.insert("__schema");
     let __type = ir.strings.insert("__type");
 
@@ -44,6 +44,8 @@ pub(crate) fn emit_federated_graph(mut ir: CompositionIr, subgraphs: &Subgraphs)
         field_authorized_directives: vec![],
         object_authorized_directives: vec![],
         interface_authorized_directives: vec![],
+        contexts: vec![],
+        context_arguments: vec![],
     };
 
     let mut ctx = Context::new(&mut ir, subgraphs, &mut out);
@@ -61,11 +63,12 @@ pub(crate) fn emit_federated_graph(mut ir: CompositionIr, subgraphs: &Subgraphs)
     );
     emit_union_members(&ir.union_members, &ir.union_join_members, &mut ctx);
     emit_keys(&ir.keys, &mut ctx);
+    emit_contexts(&mut ctx);
     emit_authorized_directives(&ir, &mut ctx);
 
     drop(ctx);
 
-    federated::VersionedFederatedGraph::Sdl(graphql_federated_graph::render_federated_sdl(&out).unwrap())
+    out
 }
 
 fn emit_directives(ir: &mut Vec<ir::Directive>, ctx: &mut Context<'_>) {
@@ -437,6 +440,75 @@ fn emit_fields<'a>(
     }
 }
 
+/// `@context` on types and `@fromContext` on field arguments. The context names are prefixed with
+/// the subgraph name, since contexts are scoped to their subgraph.
+fn emit_contexts(ctx: &mut Context<'_>) {
+    for (definition, name) in ctx.subgraphs.iter_contexts() {
+        let definition_name = ctx.insert_string(definition.name());
+        let Some(federated_definition) = ctx.definitions.get(&definition_name).copied() else {
+            continue;
+        };
+        let name = ctx.insert_str(&format!("{}__{}", definition.subgraph().name().as_str(), name.as_str()));
+
+        ctx.out.contexts.push(federated::Context {
+            definition: federated_definition,
+            name,
+        });
+    }
+
+    for argument in ctx.subgraphs.iter_all_field_arguments() {
+        let Some(from_context) = argument.directives().from_context() else {
+            continue;
+        };
+
+        let field = argument.field();
+        let parent = field.parent_definition();
+        let parent_name = ctx.insert_string(parent.name());
+        let field_name = ctx.insert_string(field.name());
+        let Some(field_id) = ctx
+            .definitions
+            .get(&parent_name)
+            .and_then(|definition| ctx.selection_map.get(&(*definition, field_name)))
+            .copied()
+        else {
+            continue;
+        };
+
+        let context = ctx.insert_str(&format!(
+            "{}__{}",
+            parent.subgraph().name().as_str(),
+            ctx.subgraphs.walk(from_context.context).as_str()
+        ));
+
+        let provider_definitions = ctx
+            .out
+            .contexts
+            .iter()
+            .filter(|provider| provider.name == context)
+            .map(|provider| provider.definition)
+            .collect::<Vec<_>>();
+
+        let providers = provider_definitions
+            .into_iter()
+            .map(|definition| (definition, attach_selection(&from_context.selection, definition, ctx)))
+            .collect();
+
+        let context_argument = federated::ContextArgument {
+            field_id,
+            subgraph_id: federated::SubgraphId(parent.subgraph_id().idx()),
+            name: ctx.insert_string(argument.name()),
+            r#type: ctx.insert_str(&argument.r#type().to_string()),
+            context,
+            selection: ctx.insert_string(ctx.subgraphs.walk(from_context.selection_str)),
+            providers,
+        };
+
+        ctx.out.context_arguments.push(context_argument);
+    }
+
+    ctx.out.context_arguments.sort_by_key(|argument| argument.field_id);
+}
+
 fn emit_union_members(
     ir_members: &BTreeSet<(federated::StringId, federated::StringId)>,
     ir_join_members: &BTreeMap<(federated::StringId, federated::StringId), Vec<SubgraphId>>,
@@ -34,6 +34,10 @@ impl<'a> Context<'a> {
         self.strings_ir.insert(string.as_str())
     }
 
+    pub(crate) fn insert_str(&mut self, string: &str) -> federated::StringId {
+        self.strings_ir.insert(string)
+    }
+
     pub(crate) fn insert_value(&mut self, value: &subgraphs::Value) -> federated::Value {
         self.insert_value_with_type(value, None)
     }
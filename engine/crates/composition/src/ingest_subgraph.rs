@@ -10,7 +10,7 @@ mod schema_definitions;
 use self::{directives::*, nested_key_fields::ingest_nested_key_fields, schema_definitions::*};
 use crate::{
     subgraphs::{self, DefinitionId, DefinitionKind, DirectiveSiteId, SubgraphId},
-    Subgraphs,
+    DiagnosticCode, Location, Span, Subgraphs,
 };
 use async_graphql_parser::{types as ast, Positioned};
 use async_graphql_value::ConstValue;
@@ -27,7 +27,7 @@ pub(crate) fn ingest_subgraph(document: &ast::ServiceDocument, name: &str, url:
     let root_type_matcher = ingest_schema_definition(document);
 
     let directive_matcher = ingest_directive_definitions(document, |error| {
-        subgraphs.push_ingestion_diagnostic(subgraph_id, error);
+        subgraphs.push_ingestion_diagnostic(subgraph_id, DiagnosticCode::InvalidComposeDirective, error);
     });
 
     ingest_top_level_definitions(subgraph_id, document, subgraphs, &directive_matcher, &root_type_matcher);
@@ -58,6 +58,7 @@ fn ingest_top_level_definitions(
                     .map(|description| subgraphs.strings.intern(description.node.as_str()));
 
                 let directives = subgraphs.new_directive_site();
+                subgraphs.insert_span(directives, name_span(&type_definition.node.name));
 
                 let definition_id = match &type_definition.node.kind {
                     ast::TypeKind::Object(_) if type_name == SERVICE_TYPE_NAME => continue,
@@ -83,7 +84,13 @@ fn ingest_top_level_definitions(
                                 subgraphs.set_subscription_type(subgraph_id, definition_id);
                             }
                             RootTypeMatch::NotRootButHasDefaultRootName => {
-                                subgraphs.push_ingestion_diagnostic(subgraph_id, format!("The {type_name} type has the default name for a root but is itself not a root. This is not valid in a federation context."));
+                                subgraphs
+                                    .push_ingestion_diagnostic(
+                                        subgraph_id,
+                                        DiagnosticCode::RootTypeNameConflict,
+                                        format!("The {type_name} type has the default name for a root but is itself not a root. This is not valid in a federation context."),
+                                    )
+                                    .with_span(Some(name_span(&type_definition.node.name)));
                             }
                             RootTypeMatch::NotRoot => (),
                         }
@@ -148,6 +155,13 @@ fn ingest_top_level_definitions(
                     subgraphs,
                     directive_matcher,
                 );
+
+                directives::ingest_contexts(
+                    definition_id,
+                    &type_definition.node.directives,
+                    subgraphs,
+                    directive_matcher,
+                );
             }
             ast::TypeSystemDefinition::Schema(_) | ast::TypeSystemDefinition::Directive(_) => (),
         }
@@ -259,3 +273,31 @@ pub(super) fn ast_value_to_subgraph_value(value: &ConstValue, subgraphs: &mut Su
         ),
     }
 }
+
+/// The span of a name token, for diagnostics.
+fn name_span(name: &Positioned<async_graphql_value::Name>) -> Span {
+    let start = Location {
+        line: name.pos.line,
+        column: name.pos.column,
+    };
+    let end = Location {
+        line: start.line,
+        column: start.column + name.node.chars().count(),
+    };
+
+    Span { start, end }
+}
+
+/// The span of the `@name` part of a directive, for diagnostics.
+fn directive_span(directive: &Positioned<ast::ConstDirective>) -> Span {
+    let start = Location {
+        line: directive.pos.line,
+        column: directive.pos.column,
+    };
+    let end = Location {
+        line: start.line,
+        column: start.column + 1 + directive.node.name.node.chars().count(),
+    };
+
+    Span { start, end }
+}
@@ -60,13 +60,33 @@ pub(super) fn ingest_directives(
             continue;
         }
 
+        if directive_matcher.is_from_context(directive_name) {
+            let field_arg = directive.node.get_argument("field").map(|v| &v.node);
+            let Some(ConstValue::String(field_arg)) = field_arg else {
+                continue;
+            };
+            if let Err(err) = subgraphs.insert_from_context(directive_site_id, field_arg) {
+                let location = location(subgraphs);
+                subgraphs
+                    .push_ingestion_diagnostic(
+                        subgraph,
+                        DiagnosticCode::InvalidFromContext,
+                        format!("Error validating the @fromContext directive at {location}: {err}"),
+                    )
+                    .with_span(Some(directive_span(directive)));
+            }
+            continue;
+        }
+
         if directive_matcher.is_requires(directive_name) {
             let fields_arg = directive.node.get_argument("fields").map(|v| &v.node);
             let Some(ConstValue::String(fields_arg)) = fields_arg else {
                 continue;
             };
             if let Err(err) = subgraphs.insert_requires(directive_site_id, fields_arg) {
-                subgraphs.push_ingestion_diagnostic(subgraph, err.to_string());
+                subgraphs
+                    .push_ingestion_diagnostic(subgraph, DiagnosticCode::InvalidFieldSet, err.to_string())
+                    .with_span(Some(directive_span(directive)));
             };
             continue;
         }
@@ -77,7 +97,9 @@ pub(super) fn ingest_directives(
                 continue;
             };
             if let Err(err) = subgraphs.insert_provides(directive_site_id, fields_arg) {
-                subgraphs.push_ingestion_diagnostic(subgraph, err.to_string());
+                subgraphs
+                    .push_ingestion_diagnostic(subgraph, DiagnosticCode::InvalidFieldSet, err.to_string())
+                    .with_span(Some(directive_span(directive)));
             }
             continue;
         }
@@ -170,18 +192,53 @@ pub(super) fn ingest_directives(
             subgraphs.insert_deprecated(directive_site_id, reason);
         }
 
+        if directive_name == "specifiedBy" {
+            if let Some(ConstValue::String(url)) = directive.node.get_argument("url").map(|v| &v.node) {
+                subgraphs.insert_specified_by(directive_site_id, url);
+            }
+        }
+
+        if directive_name == "oneOf" {
+            subgraphs.set_one_of(directive_site_id);
+        }
+
+        if directive_name == "semanticNonNull" {
+            // `levels: [Int] = [0]`, a single value is coerced to a list.
+            let levels = match directive.node.get_argument("levels").map(|v| &v.node) {
+                None => vec![0],
+                Some(ConstValue::List(levels)) => levels.iter().filter_map(semantic_non_null_level).collect(),
+                Some(level) => semantic_non_null_level(level).into_iter().collect(),
+            };
+
+            subgraphs.insert_semantic_non_null(directive_site_id, levels);
+        }
+
         if directive_matcher.is_authorized(directive_name) {
             if let Err(err) = authorized::ingest(directive_site_id, &directive.node, subgraphs) {
                 let location = location(subgraphs);
-                subgraphs.push_ingestion_diagnostic(
-                    subgraph,
-                    format!("Error validating the @authorized directive at {location}: {err}",),
-                );
+                subgraphs
+                    .push_ingestion_diagnostic(
+                        subgraph,
+                        DiagnosticCode::InvalidAuthorized,
+                        format!("Error validating the @authorized directive at {location}: {err}",),
+                    )
+                    .with_span(Some(directive_span(directive)));
             };
         }
     }
 }
 
+/// List levels deeper than what the engine can represent are ignored.
+fn semantic_non_null_level(value: &ConstValue) -> Option<u8> {
+    match value {
+        ConstValue::Number(number) => number
+            .as_u64()
+            .and_then(|level| u8::try_from(level).ok())
+            .filter(|level| *level < 32),
+        _ => None,
+    }
+}
+
 pub(super) fn ingest_keys(
     definition_id: DefinitionId,
     directives_node: &[Positioned<ast::ConstDirective>],
@@ -209,6 +266,23 @@ pub(super) fn ingest_keys(
     }
 }
 
+pub(super) fn ingest_contexts(
+    definition_id: DefinitionId,
+    directives_node: &[Positioned<ast::ConstDirective>],
+    subgraphs: &mut Subgraphs,
+    directive_matcher: &DirectiveMatcher<'_>,
+) {
+    for directive in directives_node {
+        if !directive_matcher.is_context(&directive.node.name.node) {
+            continue;
+        }
+
+        if let Some(ConstValue::String(name)) = directive.node.get_argument("name").map(|v| &v.node) {
+            subgraphs.insert_context(definition_id, name);
+        }
+    }
+}
+
 pub(super) fn ingest_directive_definitions(
     document: &ast::ServiceDocument,
     mut push_error: impl FnMut(String),
@@ -284,6 +358,8 @@ pub(crate) struct DirectiveMatcher<'a> {
     interface_object: Cow<'a, str>,
     r#override: Cow<'a, str>,
     compose_directive: Cow<'a, str>,
+    context: Cow<'a, str>,
+    from_context: Cow<'a, str>,
     requires_scopes: Cow<'a, str>,
     authenticated: Cow<'a, str>,
     policy: Cow<'a, str>,
@@ -303,7 +379,9 @@ impl Default for DirectiveMatcher<'_> {
             authenticated: Cow::Borrowed(AUTHENTICATED),
             compose_directive: Cow::Borrowed(COMPOSE_DIRECTIVE),
             composed_directives: BTreeSet::new(),
+            context: Cow::Borrowed(CONTEXT),
             external: Cow::Borrowed(EXTERNAL),
+            from_context: Cow::Borrowed(FROM_CONTEXT),
             inaccessible: Cow::Borrowed(INACCESSIBLE),
             interface_object: Cow::Borrowed(INTERFACE_OBJECT),
             key: Cow::Borrowed(KEY),
@@ -361,7 +439,9 @@ impl<'a> DirectiveMatcher<'a> {
             authenticated: final_name(AUTHENTICATED),
             compose_directive: final_name(COMPOSE_DIRECTIVE),
             composed_directives: BTreeSet::new(),
+            context: final_name(CONTEXT),
             external: final_name(EXTERNAL),
+            from_context: final_name(FROM_CONTEXT),
             inaccessible: final_name(INACCESSIBLE),
             interface_object: final_name(INTERFACE_OBJECT),
             key: final_name(KEY),
@@ -392,6 +472,14 @@ impl<'a> DirectiveMatcher<'a> {
         self.composed_directives.iter().copied()
     }
 
+    pub(crate) fn is_context(&self, directive_name: &str) -> bool {
+        self.context == directive_name
+    }
+
+    pub(crate) fn is_from_context(&self, directive_name: &str) -> bool {
+        self.from_context == directive_name
+    }
+
     pub(crate) fn is_external(&self, directive_name: &str) -> bool {
         self.external == directive_name
     }
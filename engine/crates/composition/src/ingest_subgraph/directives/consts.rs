@@ -1,7 +1,9 @@
 pub(super) const AUTHENTICATED: &str = "authenticated";
 pub(super) const AUTHORIZED: &str = "authorized";
 pub(super) const COMPOSE_DIRECTIVE: &str = "composeDirective";
+pub(super) const CONTEXT: &str = "context";
 pub(super) const EXTERNAL: &str = "external";
+pub(super) const FROM_CONTEXT: &str = "fromContext";
 pub(super) const INACCESSIBLE: &str = "inaccessible";
 pub(super) const INTERFACE_OBJECT: &str = "interfaceObject";
 pub(super) const KEY: &str = "key";
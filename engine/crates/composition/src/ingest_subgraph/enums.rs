@@ -10,6 +10,7 @@ pub(super) fn ingest_enum(
     for value in &enum_type.values {
         let value_name = subgraphs.strings.intern(value.node.value.node.as_str());
         let value_directives = subgraphs.new_directive_site();
+        subgraphs.insert_span(value_directives, name_span(&value.node.value));
 
         subgraphs.push_enum_value(definition_id, value_name, value_directives);
 
@@ -11,6 +11,7 @@ pub(super) fn ingest_input_fields(
     for field in fields {
         let field_type = subgraphs.intern_field_type(&field.node.ty.node);
         let directives = subgraphs.new_directive_site();
+        subgraphs.insert_span(directives, name_span(&field.node.name));
         let field_name = field.node.name.node.as_str();
 
         directives::ingest_directives(
@@ -63,6 +64,7 @@ fn ingest_field_arguments(
         let name = subgraphs.strings.intern(argument.node.name.node.as_str());
 
         let argument_directives = subgraphs.new_directive_site();
+        subgraphs.insert_span(argument_directives, name_span(&argument.node.name));
 
         ingest_directives(
             argument_directives,
@@ -121,6 +123,7 @@ pub(super) fn ingest_fields(
 
         let field_type = subgraphs.intern_field_type(&field.ty.node);
         let directives = subgraphs.new_directive_site();
+        subgraphs.insert_span(directives, name_span(&field.name));
 
         let field_id = subgraphs.push_field(crate::subgraphs::FieldIngest {
             parent_definition_id: definition_id,
@@ -12,14 +12,20 @@ mod ingest_subgraph;
 mod result;
 mod subgraphs;
 mod validate;
+mod validate_satisfiability;
 
-pub use self::{diagnostics::Diagnostics, result::CompositionResult, subgraphs::Subgraphs};
-pub use graphql_federated_graph::{render_api_sdl, render_federated_sdl, VersionedFederatedGraph};
+pub use self::{
+    diagnostics::{Diagnostic, DiagnosticCode, Diagnostics, Location, Severity, Span},
+    result::CompositionResult,
+    subgraphs::Subgraphs,
+};
+pub use graphql_federated_graph::{render_api_sdl, render_federated_sdl, ContractFilter, VersionedFederatedGraph};
 
 use self::{
     compose::{compose_subgraphs, ComposeContext},
     emit_federated_graph::emit_federated_graph,
     ingest_subgraph::ast_value_to_subgraph_value,
+    validate_satisfiability::validate_satisfiability,
 };
 
 /// Compose subgraphs into a federated graph.
@@ -28,7 +34,7 @@ pub fn compose(subgraphs: &Subgraphs) -> CompositionResult {
 
     if subgraphs.iter_subgraphs().len() == 0 {
         let error = "No graphs found for composition build. You must have at least one active graph.";
-        diagnostics.push_fatal(error.to_owned());
+        diagnostics.push_fatal(DiagnosticCode::NoSubgraphs, error.to_owned());
 
         return CompositionResult {
             federated_graph: None,
@@ -57,8 +63,12 @@ pub fn compose(subgraphs: &Subgraphs) -> CompositionResult {
     } else {
         let federated_graph = emit_federated_graph(context.into_ir(), subgraphs);
 
+        validate_satisfiability(&federated_graph, &mut diagnostics);
+
         CompositionResult {
-            federated_graph: Some(federated_graph),
+            federated_graph: Some(VersionedFederatedGraph::Sdl(
+                render_federated_sdl(&federated_graph).unwrap(),
+            )),
             diagnostics,
         }
     }
@@ -1,15 +1,18 @@
+mod contexts;
 mod definitions;
 mod directives;
 mod enums;
 mod field_types;
 mod fields;
 mod keys;
+mod spans;
 mod strings;
 mod top;
 mod unions;
 mod walker;
 
 pub(crate) use self::{
+    contexts::FromContext,
     definitions::{DefinitionId, DefinitionKind, DefinitionWalker},
     directives::*,
     field_types::*,
@@ -27,12 +30,14 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 pub struct Subgraphs {
     pub(super) strings: strings::Strings,
     subgraphs: Vec<Subgraph>,
+    contexts: contexts::Contexts,
     definitions: definitions::Definitions,
     directives: directives::Directives,
     enums: enums::Enums,
     fields: fields::Fields,
     field_types: field_types::FieldTypes,
     keys: keys::Keys,
+    spans: spans::Spans,
     unions: unions::Unions,
 
     ingestion_diagnostics: crate::Diagnostics,
@@ -57,12 +62,14 @@ impl Default for Subgraphs {
         Self {
             strings,
             subgraphs: Default::default(),
+            contexts: Default::default(),
             definitions: Default::default(),
             directives: Default::default(),
             enums: Default::default(),
             fields: Default::default(),
             field_types: Default::default(),
             keys: Default::default(),
+            spans: Default::default(),
             unions: Default::default(),
             ingestion_diagnostics: Default::default(),
             definition_names: Default::default(),
@@ -121,9 +128,16 @@ impl Subgraphs {
         compose_fn(&buf)
     }
 
-    pub(crate) fn push_ingestion_diagnostic(&mut self, subgraph: SubgraphId, message: String) {
+    pub(crate) fn push_ingestion_diagnostic(
+        &mut self,
+        subgraph: SubgraphId,
+        code: crate::DiagnosticCode,
+        message: String,
+    ) -> &mut crate::Diagnostic {
+        let subgraph_name = self.walk_subgraph(subgraph).name().as_str().to_owned();
         self.ingestion_diagnostics
-            .push_fatal(format!("[{}]: {message}", self.walk_subgraph(subgraph).name().as_str()));
+            .push_fatal(code, format!("[{subgraph_name}]: {message}"))
+            .with_subgraph(&subgraph_name)
     }
 
     pub(crate) fn walk<Id>(&self, id: Id) -> Walker<'_, Id> {
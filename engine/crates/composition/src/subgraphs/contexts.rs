@@ -0,0 +1,100 @@
+use super::*;
+
+/// The `@context` and `@fromContext` directives in all subgraphs.
+#[derive(Default)]
+pub(crate) struct Contexts {
+    /// (definition, context name)
+    contexts: BTreeSet<(DefinitionId, StringId)>,
+    from_context: BTreeMap<DirectiveSiteId, FromContext>,
+}
+
+/// Corresponds to an `@fromContext` directive on a field argument.
+///
+/// ```graphql,ignore
+/// type Transaction @key(fields: "id") {
+///   id: ID!
+///   currency(locale: String @fromContext(field: "$userContext { locale }")): String
+///                           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// }
+/// ```
+#[derive(Debug)]
+pub(crate) struct FromContext {
+    /// The name of the context, without the `$` sigil.
+    pub(crate) context: StringId,
+    /// The selection in the context, as written in the subgraph.
+    pub(crate) selection_str: StringId,
+    pub(crate) selection: Vec<Selection>,
+}
+
+impl Subgraphs {
+    pub(crate) fn insert_context(&mut self, definition_id: DefinitionId, name: &str) {
+        let name = self.strings.intern(name);
+        self.contexts.contexts.insert((definition_id, name));
+    }
+
+    /// Parses the `field` argument of `@fromContext`: `$contextName { selection }`.
+    pub(crate) fn insert_from_context(&mut self, id: DirectiveSiteId, field: &str) -> Result<(), String> {
+        let Some(field) = field.trim().strip_prefix('$') else {
+            return Err(format!(
+                "the field argument must start with the name of a context, like `$myContext {{ myField }}`, found `{field}`"
+            ));
+        };
+
+        let name_end = field
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(field.len());
+        let (context, selection_str) = field.split_at(name_end);
+        let selection_str = selection_str.trim();
+
+        if context.is_empty() {
+            return Err("the name of the context is missing".to_owned());
+        }
+
+        let selection = self.selection_set_from_str(
+            selection_str
+                .strip_prefix('{')
+                .and_then(|selection| selection.strip_suffix('}'))
+                .unwrap_or(selection_str),
+        )?;
+
+        let from_context = FromContext {
+            context: self.strings.intern(context),
+            selection_str: self.strings.intern(selection_str),
+            selection,
+        };
+
+        self.contexts.from_context.insert(id, from_context);
+
+        Ok(())
+    }
+
+    /// All the `@context` directives, with the definition they are on.
+    pub(crate) fn iter_contexts(&self) -> impl Iterator<Item = (DefinitionWalker<'_>, StringWalker<'_>)> {
+        self.contexts
+            .contexts
+            .iter()
+            .map(|(definition_id, name)| (self.walk(*definition_id), self.walk(*name)))
+    }
+}
+
+impl<'a> DefinitionWalker<'a> {
+    /// ```graphql,ignore
+    /// type User @key(fields: "id") @context(name: "userContext") {
+    ///                                             ^^^^^^^^^^^^^
+    ///   id: ID!
+    /// }
+    /// ```
+    pub(crate) fn contexts(self) -> impl Iterator<Item = StringWalker<'a>> {
+        self.subgraphs
+            .contexts
+            .contexts
+            .range((self.id, StringId::MIN)..=(self.id, StringId::MAX))
+            .map(move |(_, name)| self.walk(*name))
+    }
+}
+
+impl<'a> DirectiveSiteWalker<'a> {
+    pub(crate) fn from_context(self) -> Option<&'a FromContext> {
+        self.subgraphs.contexts.from_context.get(&self.id)
+    }
+}
@@ -26,6 +26,10 @@ pub(super) struct Directives {
     provides: BTreeMap<DirectiveSiteId, Vec<Selection>>,
     requires: BTreeMap<DirectiveSiteId, Vec<Selection>>,
     authorized: BTreeMap<DirectiveSiteId, AuthorizedDirective>,
+    /// From @specifiedBy on scalars.
+    specified_by: BTreeMap<DirectiveSiteId, StringId>,
+    /// From @semanticNonNull on fields: the sorted list levels that are only null on error.
+    semantic_non_null: BTreeMap<DirectiveSiteId, Vec<u8>>,
 
     requires_scopes: BTreeSet<(DirectiveSiteId, Vec<StringId>)>,
     policies: BTreeSet<(DirectiveSiteId, Vec<StringId>)>,
@@ -35,6 +39,7 @@ pub(super) struct Directives {
     shareable: HashSet<DirectiveSiteId>,
     external: HashSet<DirectiveSiteId>,
     interface_object: HashSet<DirectiveSiteId>,
+    one_of: HashSet<DirectiveSiteId>,
 
     tags: BTreeSet<(DirectiveSiteId, StringId)>,
 
@@ -79,6 +84,17 @@ impl Subgraphs {
         self.directives.deprecated.insert(id, Deprecated { reason });
     }
 
+    pub(crate) fn insert_specified_by(&mut self, id: DirectiveSiteId, url: &str) {
+        let url = self.strings.intern(url);
+        self.directives.specified_by.insert(id, url);
+    }
+
+    pub(crate) fn insert_semantic_non_null(&mut self, id: DirectiveSiteId, mut levels: Vec<u8>) {
+        levels.sort_unstable();
+        levels.dedup();
+        self.directives.semantic_non_null.insert(id, levels);
+    }
+
     pub(crate) fn insert_provides(&mut self, id: DirectiveSiteId, fields: &str) -> Result<(), String> {
         let fields = self.selection_set_from_str(fields)?;
         self.directives.provides.insert(id, fields);
@@ -122,6 +138,10 @@ impl Subgraphs {
         self.directives.interface_object.insert(id);
     }
 
+    pub(crate) fn set_one_of(&mut self, id: DirectiveSiteId) {
+        self.directives.one_of.insert(id);
+    }
+
     pub(crate) fn set_override(&mut self, id: DirectiveSiteId, directive: OverrideDirective) {
         self.directives.r#override.insert(id, directive);
     }
@@ -162,6 +182,22 @@ impl<'a> DirectiveSiteWalker<'a> {
         self.subgraphs.directives.interface_object.contains(&self.id)
     }
 
+    pub(crate) fn specified_by(self) -> Option<StringId> {
+        self.subgraphs.directives.specified_by.get(&self.id).copied()
+    }
+
+    pub(crate) fn one_of(self) -> bool {
+        self.subgraphs.directives.one_of.contains(&self.id)
+    }
+
+    pub(crate) fn semantic_non_null(self) -> Option<&'a [u8]> {
+        self.subgraphs
+            .directives
+            .semantic_non_null
+            .get(&self.id)
+            .map(Vec::as_slice)
+    }
+
     pub(crate) fn iter_composed_directives(&self) -> impl Iterator<Item = (StringId, &Arguments)> {
         let instances = &self.subgraphs.directives.composed_directive_instances;
         let partition_point = instances.partition_point(|(id, _, _)| id < &self.id);
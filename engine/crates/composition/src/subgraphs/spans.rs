@@ -0,0 +1,20 @@
+use super::*;
+use crate::Span;
+
+/// The location of definitions, fields, arguments and enum values in their subgraph SDL, for
+/// diagnostics. They are keyed by directive site, since every one of them has exactly one.
+#[derive(Default)]
+pub(super) struct Spans(BTreeMap<DirectiveSiteId, Span>);
+
+impl Subgraphs {
+    pub(crate) fn insert_span(&mut self, id: DirectiveSiteId, span: Span) {
+        self.spans.0.insert(id, span);
+    }
+}
+
+impl DirectiveSiteWalker<'_> {
+    /// The span of the name of the definition, field, argument or enum value.
+    pub(crate) fn span(self) -> Option<Span> {
+        self.subgraphs.spans.0.get(&self.id).copied()
+    }
+}
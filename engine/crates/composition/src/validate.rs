@@ -1,5 +1,6 @@
-use crate::subgraphs;
+use crate::{subgraphs, DiagnosticCode, Span};
 
+mod context;
 mod input_selection;
 mod selection;
 mod subgraph_names;
@@ -11,6 +12,7 @@ pub(crate) fn validate(ctx: &mut ValidateContext<'_>) {
     subgraph_names::validate_subgraph_names(ctx);
     validate_query_nonempty(ctx);
     validate_fields(ctx);
+    validate_field_arguments(ctx);
 }
 
 fn validate_query_nonempty(ctx: &mut ValidateContext<'_>) {
@@ -20,8 +22,10 @@ fn validate_query_nonempty(ctx: &mut ValidateContext<'_>) {
         .filter_map(|subgraph| subgraph.query_type())
         .all(|query_type| query_type.fields().next().is_none())
     {
-        ctx.diagnostics
-            .push_fatal(String::from("None of the subgraphs defines root query fields."));
+        ctx.diagnostics.push_fatal(
+            DiagnosticCode::MissingQueryRoot,
+            String::from("None of the subgraphs defines root query fields."),
+        );
     }
 }
 
@@ -33,6 +37,12 @@ fn validate_fields(ctx: &mut ValidateContext<'_>) {
     }
 }
 
+fn validate_field_arguments(ctx: &mut ValidateContext<'_>) {
+    for argument in ctx.subgraphs.iter_all_field_arguments() {
+        context::validate_from_context(ctx, argument);
+    }
+}
+
 fn validate_override_labels(ctx: &mut ValidateContext<'_>, field: subgraphs::FieldWalker<'_>) {
     let Some(label) = field.directives().r#override().and_then(|directive| directive.label) else {
         return;
@@ -47,9 +57,15 @@ fn validate_override_labels(ctx: &mut ValidateContext<'_>, field: subgraphs::Fie
         return;
     };
 
-    ctx.diagnostics.push_fatal(format!(
-        "Invalid @override label argument on {ty}.{field}: {err}",
-        ty = field.parent_definition().name().as_str(),
-        field = field.name().as_str(),
-    ));
+    ctx.diagnostics
+        .push_fatal(
+            DiagnosticCode::InvalidOverride,
+            format!(
+                "Invalid @override label argument on {ty}.{field}: {err}",
+                ty = field.parent_definition().name().as_str(),
+                field = field.name().as_str(),
+            ),
+        )
+        .with_subgraph(field.parent_definition().subgraph().name().as_str())
+        .with_span(field.directives().span());
 }
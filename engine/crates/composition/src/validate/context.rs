@@ -0,0 +1,143 @@
+use super::*;
+use crate::subgraphs::DefinitionKind;
+
+/// Validate an argument with `@fromContext` against the types providing the context with `@context`.
+pub(super) fn validate_from_context(ctx: &mut ValidateContext<'_>, argument: subgraphs::FieldArgumentWalker<'_>) {
+    let Some(from_context) = argument.directives().from_context() else {
+        return;
+    };
+
+    let field = argument.field();
+    let parent = field.parent_definition();
+    let subgraph_name = parent.subgraph().name().as_str();
+    let argument_path = format!(
+        "{}.{}({}:)",
+        parent.name().as_str(),
+        field.name().as_str(),
+        argument.name().as_str()
+    );
+
+    // The value of contextual arguments is sent along with the representations when resolving
+    // the entity, so the parent must be an entity.
+    if parent.kind() != DefinitionKind::Object || !parent.entity_keys().any(|key| key.is_resolvable()) {
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InvalidFromContext,
+                format!(
+                    "[{subgraph_name}] The {argument_path} argument uses @fromContext, but {} is not an object with a resolvable @key.",
+                    parent.name().as_str(),
+                ),
+            )
+            .with_subgraph(subgraph_name)
+            .with_span(argument.directives().span());
+    }
+
+    if argument.default().is_some() {
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InvalidFromContext,
+                format!(
+                    "[{subgraph_name}] The {argument_path} argument uses @fromContext and cannot have a default value."
+                ),
+            )
+            .with_subgraph(subgraph_name)
+            .with_span(argument.directives().span());
+    }
+
+    let context_name = ctx.subgraphs.walk(from_context.context).as_str();
+    let providers = parent
+        .subgraph()
+        .definitions()
+        .filter(|definition| definition.contexts().any(|name| name.id == from_context.context))
+        .collect::<Vec<_>>();
+
+    if providers.is_empty() {
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InvalidFromContext,
+                format!(
+                    "[{subgraph_name}] The @fromContext directive on {argument_path} refers to the `{context_name}` context, which is not declared with @context in the subgraph."
+                ),
+            )
+            .with_subgraph(subgraph_name)
+            .with_span(argument.directives().span());
+        return;
+    }
+
+    for provider in providers {
+        if let Err(err) = validate_context_selection(&from_context.selection, provider, argument) {
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InvalidFromContext,
+                    format!(
+                        "[{subgraph_name}] The selection `{selection}` in @fromContext on {argument_path} is invalid on {provider}: {err}",
+                        selection = ctx.subgraphs.walk(from_context.selection_str).as_str(),
+                        provider = provider.name().as_str(),
+                    ),
+                )
+                .with_subgraph(subgraph_name)
+                .with_span(argument.directives().span());
+        }
+    }
+}
+
+/// The selection must be a path to a single field, whose value is the value of the argument.
+fn validate_context_selection(
+    selection: &[subgraphs::Selection],
+    on: subgraphs::DefinitionWalker<'_>,
+    argument: subgraphs::FieldArgumentWalker<'_>,
+) -> Result<(), String> {
+    let [subgraphs::Selection::Field(field_selection)] = selection else {
+        return Err("the selection must contain exactly one field, without type conditions".to_owned());
+    };
+
+    let Some(field) = on.find_field(field_selection.field) else {
+        return Err(format!(
+            "the {} field does not exist on {}",
+            on.walk(field_selection.field).as_str(),
+            on.name().as_str()
+        ));
+    };
+
+    if !field_selection.arguments.is_empty() {
+        return Err(format!(
+            "arguments are not supported in the selection, found them on {}.{}",
+            on.name().as_str(),
+            field.name().as_str()
+        ));
+    }
+
+    if field_selection.subselection.is_empty() {
+        let field_type = field.r#type();
+        let argument_type = argument.r#type();
+
+        if field_type.type_name().id != argument_type.type_name().id || field_type.is_list() != argument_type.is_list()
+        {
+            return Err(format!(
+                "the type of {}.{} is {field_type}, which is incompatible with the argument type {argument_type}",
+                on.name().as_str(),
+                field.name().as_str(),
+            ));
+        }
+
+        return Ok(());
+    }
+
+    if field.r#type().is_list() {
+        return Err(format!(
+            "{}.{} is a list, only the last field of the selection can be a list",
+            on.name().as_str(),
+            field.name().as_str()
+        ));
+    }
+
+    let Some(field_type) = field.r#type().definition(on.subgraph_id()) else {
+        return Err(format!(
+            "{}.{} is a leaf field, it cannot have a selection",
+            on.name().as_str(),
+            field.name().as_str()
+        ));
+    };
+
+    validate_context_selection(&field_selection.subselection, field_type, argument)
+}
@@ -33,10 +33,16 @@ fn validate_input_selection_on_arguments(
     directive_name: &str,
 ) {
     if field.argument_by_name(selection.field).is_none() {
-        ctx.diagnostics.push_fatal(format!(
-            "Error in @{directive_name}: the {field_in_selection} argument does not exist on {directive_path}. Did you use the `arguments` argument instead of `fields`?",
-            field_in_selection = ctx.subgraphs.walk(selection.field).as_str(),
-            directive_path = directive_path(),
-        ));
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InvalidFieldSet,
+                format!(
+                    "Error in @{directive_name}: the {field_in_selection} argument does not exist on {directive_path}. Did you use the `arguments` argument instead of `fields`?",
+                    field_in_selection = ctx.subgraphs.walk(selection.field).as_str(),
+                    directive_path = directive_path(),
+                ),
+            )
+            .with_subgraph(field.parent_definition().subgraph().name().as_str())
+            .with_span(field.directives().span());
     };
 }
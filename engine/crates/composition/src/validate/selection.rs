@@ -28,6 +28,7 @@ pub(super) fn validate_selections(ctx: &mut ValidateContext<'_>, field: subgraph
             selection,
             field.parent_definition(),
             &directive_path,
+            directives.span(),
             directive_name,
         );
     }
@@ -46,7 +47,14 @@ pub(super) fn validate_selections(ctx: &mut ValidateContext<'_>, field: subgraph
             .definition(field.parent_definition().subgraph_id())
             .unwrap();
 
-        validate_selection(ctx, selection, field_type, &directive_path, "provides");
+        validate_selection(
+            ctx,
+            selection,
+            field_type,
+            &directive_path,
+            directives.span(),
+            "provides",
+        );
     }
 }
 
@@ -55,26 +63,45 @@ fn validate_selection(
     selection: &subgraphs::Selection,
     on_definition: subgraphs::DefinitionWalker<'_>,
     directive_path: &dyn Fn() -> String,
+    directive_span: Option<Span>,
     directive_name: &str,
 ) {
     match selection {
-        subgraphs::Selection::Field(field_selection) => {
-            validate_field_selection(ctx, field_selection, on_definition, directive_path, directive_name)
-        }
+        subgraphs::Selection::Field(field_selection) => validate_field_selection(
+            ctx,
+            field_selection,
+            on_definition,
+            directive_path,
+            directive_span,
+            directive_name,
+        ),
         subgraphs::Selection::InlineFragment { on, subselection } => {
             let subgraph_id = on_definition.subgraph_id();
             let Some(on) = ctx.subgraphs.definition_by_name_id(*on, subgraph_id) else {
                 let directive_path = directive_path();
-                ctx.diagnostics.push_fatal(format!(
-                    "Error in {directive_name} at {directive_path}: type condition `... {on}` is invalid on {parent_definition}",
-                    on = ctx.subgraphs.walk(*on).as_str(),
-                    parent_definition = on_definition.name().as_str()
-                ));
+                ctx.diagnostics
+                    .push_fatal(
+                        DiagnosticCode::InvalidFieldSet,
+                        format!(
+                            "Error in {directive_name} at {directive_path}: type condition `... {on}` is invalid on {parent_definition}",
+                            on = ctx.subgraphs.walk(*on).as_str(),
+                            parent_definition = on_definition.name().as_str()
+                        ),
+                    )
+                    .with_subgraph(on_definition.subgraph().name().as_str())
+                .with_span(directive_span);
                 return;
             };
 
             for selection in subselection {
-                validate_selection(ctx, selection, ctx.subgraphs.walk(on), directive_path, directive_name);
+                validate_selection(
+                    ctx,
+                    selection,
+                    ctx.subgraphs.walk(on),
+                    directive_path,
+                    directive_span,
+                    directive_name,
+                );
             }
         }
     }
@@ -85,16 +112,24 @@ fn validate_field_selection(
     selection: &subgraphs::FieldSelection,
     on_definition: subgraphs::DefinitionWalker<'_>,
     directive_path: &dyn Fn() -> String,
+    directive_span: Option<Span>,
     directive_name: &str,
 ) {
     // The selected field must exist.
     let Some(field) = on_definition.find_field(selection.field) else {
-        return ctx.diagnostics.push_fatal(format!(
-            "Error in @{directive_name} at {directive_path}: the {field_in_selection} field does not exist on {definition_name}",
-            field_in_selection = ctx.subgraphs.walk(selection.field).as_str(),
-            directive_path = directive_path(),
-            definition_name = on_definition.name().as_str()
-        ));
+        ctx.diagnostics
+            .push_fatal(
+                DiagnosticCode::InvalidFieldSet,
+                format!(
+                    "Error in @{directive_name} at {directive_path}: the {field_in_selection} field does not exist on {definition_name}",
+                    field_in_selection = ctx.subgraphs.walk(selection.field).as_str(),
+                    directive_path = directive_path(),
+                    definition_name = on_definition.name().as_str()
+                ),
+            )
+            .with_subgraph(on_definition.subgraph().name().as_str())
+            .with_span(directive_span);
+        return;
     };
 
     for required_argument in field
@@ -103,35 +138,55 @@ fn validate_field_selection(
     {
         let arg_name = required_argument.name();
         if selection.arguments.iter().all(|(name, _)| *name != arg_name.id) {
-            ctx.diagnostics.push_fatal(format!(
-                "Error in @{directive_name} on {directive_path}: the {field_name}.{arg_name} argument is required but not provided.",
-                field_name = field.name().as_str(),
-                arg_name = arg_name.as_str(),
-                directive_path = directive_path(),
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InvalidFieldSet,
+                    format!(
+                        "Error in @{directive_name} on {directive_path}: the {field_name}.{arg_name} argument is required but not provided.",
+                        field_name = field.name().as_str(),
+                        arg_name = arg_name.as_str(),
+                        directive_path = directive_path(),
+                    ),
+                )
+                .with_subgraph(on_definition.subgraph().name().as_str())
+                .with_span(directive_span);
         }
     }
 
     // The arguments must exist on the field.
     for (argument_name, argument_value) in &selection.arguments {
         let Some(argument) = field.argument_by_name(*argument_name) else {
-            return ctx.diagnostics.push_fatal(format!(
-                "Error in @{directive_name} on {directive_path}: the {field_in_selection}.{argument_name} argument does not exist on {definition_name}",
-                argument_name = ctx.subgraphs.walk(*argument_name).as_str(),
-                field_in_selection = field.name().as_str(),
-                definition_name = on_definition.name().as_str(),
-                directive_path = directive_path(),
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InvalidFieldSet,
+                    format!(
+                        "Error in @{directive_name} on {directive_path}: the {field_in_selection}.{argument_name} argument does not exist on {definition_name}",
+                        argument_name = ctx.subgraphs.walk(*argument_name).as_str(),
+                        field_in_selection = field.name().as_str(),
+                        definition_name = on_definition.name().as_str(),
+                        directive_path = directive_path(),
+                    ),
+                )
+                .with_subgraph(on_definition.subgraph().name().as_str())
+                .with_span(directive_span);
+            return;
         };
 
         if !argument_type_matches(on_definition.subgraph_id(), argument.r#type(), argument_value) {
-            return ctx.diagnostics.push_fatal(format!(
-                "Error in @{directive_name} on {directive_path}: the {field_in_selection}.{argument_name} argument does not not match the expected type ({expected_type})",
-                argument_name = ctx.subgraphs.walk(*argument_name).as_str(),
-                field_in_selection = field.name().as_str(),
-                expected_type = argument.r#type(),
-                directive_path = directive_path(),
-            ));
+            ctx.diagnostics
+                .push_fatal(
+                    DiagnosticCode::InvalidFieldSet,
+                    format!(
+                        "Error in @{directive_name} on {directive_path}: the {field_in_selection}.{argument_name} argument does not not match the expected type ({expected_type})",
+                        argument_name = ctx.subgraphs.walk(*argument_name).as_str(),
+                        field_in_selection = field.name().as_str(),
+                        expected_type = argument.r#type(),
+                        directive_path = directive_path(),
+                    ),
+                )
+                .with_subgraph(on_definition.subgraph().name().as_str())
+                .with_span(directive_span);
+            return;
         }
     }
 
@@ -144,6 +199,7 @@ fn validate_field_selection(
                 .definition(on_definition.subgraph_id())
                 .expect("type is defined in subgraph"),
             directive_path,
+            directive_span,
             directive_name,
         );
     }
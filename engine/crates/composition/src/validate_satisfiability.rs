@@ -0,0 +1,428 @@
+use crate::{DiagnosticCode, Diagnostics};
+use graphql_federated_graph::{
+    self as federated, Definition, FederatedGraph, FieldId, InterfaceId, ObjectId, OverrideSource, Selection,
+    SubgraphId,
+};
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+/// Post-composition validation: simulate the paths a query can take through the subgraphs, starting from the
+/// root fields of every subgraph, and warn about fields that can be queried but that the planner would not be
+/// able to resolve, because of the `@key`, `@override`, `@shareable` and `@requires` constraints.
+///
+/// A field is only satisfiable at a given position in an operation: it may be resolvable under one parent field
+/// and not under another. Positions are identified by the field leading to the selection set (or the root of the
+/// operation), which keeps the search linear in the size of the graph while reporting every parent field under
+/// which a field cannot be resolved.
+pub(crate) fn validate_satisfiability(graph: &FederatedGraph, diagnostics: &mut Diagnostics) {
+    let mut ctx = Context::new(graph);
+
+    let roots = [
+        ("query", Some(graph.root_operation_types.query)),
+        ("mutation", graph.root_operation_types.mutation),
+        ("subscription", graph.root_operation_types.subscription),
+    ];
+
+    for (operation_type, root) in roots {
+        let Some(root) = root else { continue };
+
+        for subgraph_idx in 0..graph.subgraphs.len() {
+            ctx.enqueue(
+                Definition::Object(root),
+                SubgraphId(subgraph_idx),
+                None,
+                Path::new(operation_type),
+            );
+        }
+    }
+
+    while let Some(state) = ctx.queue.pop_front() {
+        ctx.visit(state);
+    }
+
+    for ((field_id, parent_field), unsatisfiable) in ctx.unsatisfiable {
+        if ctx.resolved.contains(&(parent_field, field_id)) {
+            continue;
+        }
+
+        let field = &graph[field_id];
+        let parent = graph.definition_name(ctx.parent_definitions[&field_id]);
+        let field_name = &graph[field.name];
+        let operation = unsatisfiable.path.render(graph, Some(field_id));
+        let subgraph_name = |id: SubgraphId| &graph[graph[id].name];
+
+        let message = match unsatisfiable.reason {
+            Reason::Unreachable { from } => format!(
+                "The field `{parent}.{field_name}` cannot be resolved in `{operation}`: it is only available in {}, which cannot be reached from \"{}\" through an entity key.",
+                ctx.field_subgraphs[field_id.0]
+                    .iter()
+                    .map(|id| format!("\"{}\"", subgraph_name(*id)))
+                    .join(", "),
+                subgraph_name(from),
+            ),
+            Reason::UnsatisfiedRequires { subgraph } => format!(
+                "The field `{parent}.{field_name}` cannot be resolved in `{operation}`: the fields it requires in \"{}\" cannot be resolved.",
+                subgraph_name(subgraph),
+            ),
+        };
+
+        diagnostics.push_warning(DiagnosticCode::UnsatisfiableField, message);
+    }
+}
+
+struct Context<'a> {
+    graph: &'a FederatedGraph,
+    /// The subgraphs each field can be resolved in, indexed by field id. Empty means it can be resolved in any
+    /// subgraph where its parent is available. This mirrors how the engine interprets the federated graph.
+    field_subgraphs: Vec<BTreeSet<SubgraphId>>,
+    /// Fields made available in a subgraph by a `@provides`.
+    provided: HashSet<(FieldId, SubgraphId)>,
+    parent_definitions: BTreeMap<FieldId, Definition>,
+    visited: HashSet<(Definition, SubgraphId, Option<FieldId>)>,
+    queue: VecDeque<State>,
+    /// Fields resolvable in at least one subgraph, keyed by the parent field they are selected under.
+    resolved: HashSet<(Option<FieldId>, FieldId)>,
+    /// The first path where each field could not be resolved under a given parent field.
+    unsatisfiable: BTreeMap<(FieldId, Option<FieldId>), Unsatisfiable>,
+}
+
+struct State {
+    definition: Definition,
+    subgraph_id: SubgraphId,
+    /// The field whose selection set we are in, `None` at the root of the operation.
+    parent_field: Option<FieldId>,
+    path: Path,
+}
+
+struct Unsatisfiable {
+    path: Path,
+    reason: Reason,
+}
+
+enum Reason {
+    Unreachable { from: SubgraphId },
+    UnsatisfiedRequires { subgraph: SubgraphId },
+}
+
+impl<'a> Context<'a> {
+    fn new(graph: &'a FederatedGraph) -> Self {
+        let mut field_subgraphs: Vec<BTreeSet<SubgraphId>> = graph
+            .fields
+            .iter()
+            .map(|field| {
+                let mut subgraphs: BTreeSet<_> = field.resolvable_in.iter().copied().collect();
+
+                subgraphs.extend(field.overrides.iter().map(|r#override| r#override.graph));
+
+                for r#override in &field.overrides {
+                    if let OverrideSource::Subgraph(from) = r#override.from {
+                        subgraphs.remove(&from);
+                    }
+                }
+
+                subgraphs
+            })
+            .collect();
+
+        let mut parent_definitions = BTreeMap::new();
+        let objects = graph
+            .objects
+            .iter()
+            .enumerate()
+            .map(|(idx, object)| (Definition::Object(ObjectId(idx)), &object.keys, object.fields.clone()));
+        let interfaces = graph.interfaces.iter().enumerate().map(|(idx, interface)| {
+            (
+                Definition::Interface(InterfaceId(idx)),
+                &interface.keys,
+                interface.fields.clone(),
+            )
+        });
+
+        for (definition, keys, fields) in objects.chain(interfaces) {
+            for field_idx in fields.start.0..fields.end.0 {
+                parent_definitions.insert(FieldId(field_idx), definition);
+            }
+
+            // Key fields are not part of `resolvable_in`, but they are resolvable in the subgraphs defining the key.
+            for key in keys {
+                for selection in &key.fields {
+                    if let Selection::Field { field, .. } = selection {
+                        field_subgraphs[field.0].insert(key.subgraph_id);
+                    }
+                }
+            }
+        }
+
+        let mut provided = HashSet::new();
+
+        for field in &graph.fields {
+            for provides in &field.provides {
+                collect_fields(&provides.fields, &mut |field_id| {
+                    provided.insert((field_id, provides.subgraph_id));
+                });
+            }
+        }
+
+        Context {
+            graph,
+            field_subgraphs,
+            provided,
+            parent_definitions,
+            visited: HashSet::new(),
+            queue: VecDeque::new(),
+            resolved: HashSet::new(),
+            unsatisfiable: BTreeMap::new(),
+        }
+    }
+
+    fn enqueue(&mut self, definition: Definition, subgraph_id: SubgraphId, parent_field: Option<FieldId>, path: Path) {
+        if self.visited.insert((definition, subgraph_id, parent_field)) {
+            self.queue.push_back(State {
+                definition,
+                subgraph_id,
+                parent_field,
+                path,
+            });
+        }
+    }
+
+    fn visit(&mut self, state: State) {
+        let graph = self.graph;
+
+        let (keys, fields): (Vec<&federated::Key>, _) = match state.definition {
+            Definition::Object(object_id) => {
+                let object = &graph[object_id];
+
+                // Objects implementing an entity interface can also be resolved through the `@interfaceObject`
+                // keys of the interface.
+                let interface_object_keys = object
+                    .implements_interfaces
+                    .iter()
+                    .flat_map(|interface_id| &graph[*interface_id].keys)
+                    .filter(|key| key.is_interface_object);
+
+                (
+                    object.keys.iter().chain(interface_object_keys).collect(),
+                    object.fields.clone(),
+                )
+            }
+            Definition::Interface(interface_id) => {
+                let interface = &graph[interface_id];
+                (interface.keys.iter().collect(), interface.fields.clone())
+            }
+            Definition::Union(union_id) => {
+                for (subgraph_id, object_id) in &graph[union_id].join_members {
+                    if *subgraph_id == state.subgraph_id {
+                        self.enqueue(
+                            Definition::Object(*object_id),
+                            state.subgraph_id,
+                            state.parent_field,
+                            state.path.with_fragment(*object_id),
+                        );
+                    }
+                }
+
+                return;
+            }
+            Definition::Scalar(_) | Definition::Enum(_) | Definition::InputObject(_) => return,
+        };
+
+        let reachable = self.reachable_subgraphs(&keys, state.subgraph_id);
+
+        if let Definition::Interface(interface_id) = state.definition {
+            for object in graph.iter_objects() {
+                for (subgraph_id, _) in object.join_implements.iter().filter(|(subgraph_id, implemented)| {
+                    *implemented == interface_id && reachable.contains(subgraph_id)
+                }) {
+                    self.enqueue(
+                        Definition::Object(object.id()),
+                        *subgraph_id,
+                        state.parent_field,
+                        state.path.with_fragment(object.id()),
+                    );
+                }
+            }
+        }
+
+        for field_idx in fields.start.0..fields.end.0 {
+            let field_id = FieldId(field_idx);
+            let field = &graph[field_id];
+
+            let candidates: Vec<SubgraphId> = if self.field_subgraphs[field_idx].is_empty()
+                || self.provided.contains(&(field_id, state.subgraph_id))
+            {
+                vec![state.subgraph_id]
+            } else {
+                self.field_subgraphs[field_idx]
+                    .intersection(&reachable)
+                    .copied()
+                    .collect()
+            };
+
+            let Some(first_candidate) = candidates.first().copied() else {
+                self.mark_unsatisfiable(
+                    field_id,
+                    &state,
+                    Reason::Unreachable {
+                        from: state.subgraph_id,
+                    },
+                );
+                continue;
+            };
+
+            let satisfied: Vec<SubgraphId> = candidates
+                .into_iter()
+                .filter(|candidate| {
+                    field
+                        .requires
+                        .iter()
+                        .filter(|requires| requires.subgraph_id == *candidate)
+                        .all(|requires| self.selection_set_is_resolvable(&requires.fields, &reachable))
+                })
+                .collect();
+
+            if satisfied.is_empty() {
+                self.mark_unsatisfiable(
+                    field_id,
+                    &state,
+                    Reason::UnsatisfiedRequires {
+                        subgraph: first_candidate,
+                    },
+                );
+                continue;
+            }
+
+            self.resolved.insert((state.parent_field, field_id));
+
+            for subgraph_id in satisfied {
+                self.enqueue(
+                    field.r#type.definition,
+                    subgraph_id,
+                    Some(field_id),
+                    state.path.with_field(field_id),
+                );
+            }
+        }
+    }
+
+    fn mark_unsatisfiable(&mut self, field_id: FieldId, state: &State, reason: Reason) {
+        self.unsatisfiable
+            .entry((field_id, state.parent_field))
+            .or_insert_with(|| Unsatisfiable {
+                path: state.path.clone(),
+                reason,
+            });
+    }
+
+    /// The subgraphs an entity can be resolved in, starting from `subgraph_id` and jumping from subgraph to
+    /// subgraph through the resolvable keys.
+    fn reachable_subgraphs(&self, keys: &[&federated::Key], subgraph_id: SubgraphId) -> BTreeSet<SubgraphId> {
+        let mut reachable = BTreeSet::from([subgraph_id]);
+
+        loop {
+            let next = keys.iter().find(|key| {
+                key.resolvable
+                    && !reachable.contains(&key.subgraph_id)
+                    && self.selection_set_is_resolvable(&key.fields, &reachable)
+            });
+
+            match next {
+                Some(key) => reachable.insert(key.subgraph_id),
+                None => return reachable,
+            };
+        }
+    }
+
+    fn selection_set_is_resolvable(&self, selection_set: &[Selection], subgraphs: &BTreeSet<SubgraphId>) -> bool {
+        selection_set.iter().all(|selection| match selection {
+            Selection::Field {
+                field, subselection, ..
+            } => {
+                let field_subgraphs = &self.field_subgraphs[field.0];
+
+                (field_subgraphs.is_empty()
+                    || !field_subgraphs.is_disjoint(subgraphs)
+                    || subgraphs.iter().any(|id| self.provided.contains(&(*field, *id))))
+                    && self.selection_set_is_resolvable(subselection, subgraphs)
+            }
+            Selection::InlineFragment { subselection, .. } => self.selection_set_is_resolvable(subselection, subgraphs),
+        })
+    }
+}
+
+fn collect_fields(selection_set: &[Selection], f: &mut impl FnMut(FieldId)) {
+    for selection in selection_set {
+        match selection {
+            Selection::Field {
+                field, subselection, ..
+            } => {
+                f(*field);
+                collect_fields(subselection, f);
+            }
+            Selection::InlineFragment { subselection, .. } => collect_fields(subselection, f),
+        }
+    }
+}
+
+/// An example operation leading to a field.
+#[derive(Clone)]
+struct Path {
+    operation_type: &'static str,
+    segments: Vec<PathSegment>,
+}
+
+#[derive(Clone, Copy)]
+enum PathSegment {
+    Field(FieldId),
+    Fragment(ObjectId),
+}
+
+impl Path {
+    fn new(operation_type: &'static str) -> Self {
+        Path {
+            operation_type,
+            segments: Vec::new(),
+        }
+    }
+
+    fn with_field(&self, field_id: FieldId) -> Self {
+        let mut path = self.clone();
+        path.segments.push(PathSegment::Field(field_id));
+        path
+    }
+
+    fn with_fragment(&self, object_id: ObjectId) -> Self {
+        let mut path = self.clone();
+        path.segments.push(PathSegment::Fragment(object_id));
+        path
+    }
+
+    /// Render the path as a GraphQL operation, for example `query { product { price } }`.
+    fn render(&self, graph: &FederatedGraph, leaf: Option<FieldId>) -> String {
+        let segments = self
+            .segments
+            .iter()
+            .copied()
+            .chain(leaf.map(PathSegment::Field))
+            .map(|segment| match segment {
+                PathSegment::Field(field_id) => graph[graph[field_id].name].clone(),
+                PathSegment::Fragment(object_id) => {
+                    format!("... on {}", graph.definition_name(Definition::Object(object_id)))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut out = String::from(self.operation_type);
+
+        for segment in &segments {
+            out.push_str(" { ");
+            out.push_str(segment);
+        }
+
+        for _ in &segments {
+            out.push_str(" }");
+        }
+
+        out
+    }
+}
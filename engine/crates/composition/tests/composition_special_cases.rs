@@ -22,3 +22,191 @@ fn subgraph_names_that_differ_only_by_case_are_not_allowed() {
         "Found two subgraphs named \"Valid\". Subgraph names are case insensitive."
     );
 }
+
+#[test]
+fn diagnostics_point_to_the_subgraph_sdl() {
+    let mut subgraphs = graphql_composition::Subgraphs::default();
+
+    subgraphs
+        .ingest_str(
+            "type Query { user: User }\n\ntype User @shareable {\n  name: String\n}\n",
+            "accounts",
+            "example.com",
+        )
+        .unwrap();
+
+    subgraphs
+        .ingest_str("type User @shareable {\n  name: Int\n}\n", "reviews", "example.com")
+        .unwrap();
+
+    let result = graphql_composition::compose(&subgraphs);
+    let diagnostic = result
+        .diagnostics()
+        .iter()
+        .find(|diagnostic| diagnostic.code() == graphql_composition::DiagnosticCode::FieldTypeMismatch)
+        .unwrap();
+
+    assert_eq!(diagnostic.severity(), graphql_composition::Severity::Fatal);
+    assert_eq!(diagnostic.subgraph(), Some("reviews"));
+
+    let span = diagnostic.span().unwrap();
+    assert_eq!((span.start.line, span.start.column), (2, 3));
+    assert_eq!((span.end.line, span.end.column), (2, 7));
+
+    let json = serde_json::to_value(result.diagnostics()).unwrap();
+    let json = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|diagnostic| diagnostic["code"] == "FIELD_TYPE_MISMATCH")
+        .unwrap();
+
+    assert_eq!(json["severity"], "fatal");
+    assert_eq!(json["subgraph"], "reviews");
+    assert_eq!(
+        json["span"],
+        serde_json::json!({ "start": { "line": 2, "column": 3 }, "end": { "line": 2, "column": 7 } })
+    );
+}
+
+#[test]
+fn type_kind_mismatch_points_to_the_subgraph_sdl() {
+    let mut subgraphs = graphql_composition::Subgraphs::default();
+
+    subgraphs
+        .ingest_str(
+            "type Query { user: User }\n\ntype User {\n  name: String\n}\n",
+            "accounts",
+            "example.com",
+        )
+        .unwrap();
+
+    subgraphs
+        .ingest_str("interface User {\n  name: String\n}\n", "reviews", "example.com")
+        .unwrap();
+
+    let result = graphql_composition::compose(&subgraphs);
+    let diagnostic = result
+        .diagnostics()
+        .iter()
+        .find(|diagnostic| diagnostic.code() == graphql_composition::DiagnosticCode::TypeKindMismatch)
+        .unwrap();
+
+    assert_eq!(diagnostic.severity(), graphql_composition::Severity::Fatal);
+    assert_eq!(diagnostic.subgraph(), Some("reviews"));
+
+    let span = diagnostic.span().unwrap();
+    assert_eq!((span.start.line, span.start.column), (1, 11));
+    assert_eq!((span.end.line, span.end.column), (1, 15));
+}
+
+#[test]
+fn unsatisfiable_fields_are_reported_with_an_example_operation() {
+    let mut subgraphs = graphql_composition::Subgraphs::default();
+
+    subgraphs
+        .ingest_str(
+            r#"type Query { product: Product } type Product @key(fields: "id") { id: ID! }"#,
+            "a",
+            "example.com",
+        )
+        .unwrap();
+
+    subgraphs
+        .ingest_str(
+            r#"type Product @key(fields: "upc") { upc: ID! price: Int }"#,
+            "b",
+            "example.com",
+        )
+        .unwrap();
+
+    let result = graphql_composition::compose(&subgraphs);
+    let warnings: Vec<_> = result
+        .diagnostics()
+        .iter()
+        .filter(|diagnostic| diagnostic.code() == graphql_composition::DiagnosticCode::UnsatisfiableField)
+        .collect();
+
+    assert!(warnings
+        .iter()
+        .all(|warning| warning.severity() == graphql_composition::Severity::Warning));
+    assert!(warnings.iter().any(|warning| warning.message()
+        == "The field `Product.price` cannot be resolved in `query { product { price } }`: it is only available in \"b\", which cannot be reached from \"a\" through an entity key."));
+    assert!(result.into_result().is_ok());
+}
+
+#[test]
+fn fields_reachable_through_a_shared_key_are_satisfiable() {
+    let mut subgraphs = graphql_composition::Subgraphs::default();
+
+    subgraphs
+        .ingest_str(
+            r#"type Query { product: Product } type Product @key(fields: "id") { id: ID! }"#,
+            "a",
+            "example.com",
+        )
+        .unwrap();
+
+    subgraphs
+        .ingest_str(
+            r#"type Product @key(fields: "id") { id: ID! price: Int }"#,
+            "b",
+            "example.com",
+        )
+        .unwrap();
+
+    let result = graphql_composition::compose(&subgraphs);
+
+    assert!(result.diagnostics().iter().next().is_none());
+}
+
+#[test]
+fn fields_are_reported_for_each_parent_field_they_cannot_be_resolved_under() {
+    let mut subgraphs = graphql_composition::Subgraphs::default();
+
+    subgraphs
+        .ingest_str(
+            r#"type Query { product: Product } type Product @key(fields: "id") { id: ID! }"#,
+            "a",
+            "example.com",
+        )
+        .unwrap();
+
+    subgraphs
+        .ingest_str(
+            r#"type Product @key(fields: "id") { id: ID! price: Int }"#,
+            "b",
+            "example.com",
+        )
+        .unwrap();
+
+    subgraphs
+        .ingest_str(
+            r#"type Query { legacyProduct: Product } type Product @key(fields: "upc") { upc: ID! }"#,
+            "c",
+            "example.com",
+        )
+        .unwrap();
+
+    let result = graphql_composition::compose(&subgraphs);
+    let messages: Vec<_> = result
+        .diagnostics()
+        .iter()
+        .filter(|diagnostic| diagnostic.code() == graphql_composition::DiagnosticCode::UnsatisfiableField)
+        .map(|diagnostic| diagnostic.message().to_owned())
+        .collect();
+
+    // `price` is reachable from "a" through the `id` key, but not from "c".
+    assert!(messages.iter().any(|message| message
+        == "The field `Product.price` cannot be resolved in `query { legacyProduct { price } }`: it is only available in \"b\", which cannot be reached from \"c\" through an entity key."));
+    assert!(!messages
+        .iter()
+        .any(|message| message.contains("`query { product { price } }`")));
+
+    // The other way around for `upc`.
+    assert!(messages.iter().any(|message| message
+        == "The field `Product.upc` cannot be resolved in `query { product { upc } }`: it is only available in \"c\", which cannot be reached from \"a\" through an entity key."));
+    assert!(!messages
+        .iter()
+        .any(|message| message.contains("`query { legacyProduct { upc } }`")));
+}
@@ -14,7 +14,7 @@ use engine_v2_config::{
 };
 use federated_graph::{FederatedGraph, FieldId, ObjectId, SubgraphId};
 use parser_sdl::federation::header::SubgraphHeaderRule;
-use parser_sdl::federation::{EntityCachingConfig, FederatedGraphConfig};
+use parser_sdl::federation::{CompressionAlgorithm, EntityCachingConfig, FederatedGraphConfig, LoadBalancingStrategy};
 use parser_sdl::{AuthV2Provider, GlobalCacheTarget};
 
 pub fn build_with_sdl_config(config: &FederatedGraphConfig, federated_graph: FederatedGraph) -> VersionedConfig {
@@ -51,6 +51,18 @@ pub fn build_with_sdl_config(config: &FederatedGraphConfig, federated_graph: Fed
             retry_percent: config.retry_percent,
             retry_mutations: config.retry_mutations,
         }),
+        scalars: config
+            .scalars
+            .iter()
+            .map(|(name, scalar)| {
+                let scalar = config::ScalarConfig {
+                    validate_input: scalar.validate_input,
+                    validate_output: scalar.validate_output,
+                };
+
+                (name.clone(), scalar)
+            })
+            .collect(),
     })
 }
 
@@ -178,6 +190,8 @@ impl<'a> BuildContext<'a> {
                 rate_limit,
                 timeout,
                 entity_caching,
+                multiplex_subscriptions,
+                deduplicate_requests,
                 ..
             } = config;
 
@@ -190,6 +204,22 @@ impl<'a> BuildContext<'a> {
                 duration: config.duration,
             });
 
+            let compression = config.compression.map(
+                |parser_sdl::federation::SubgraphCompressionConfig {
+                     accept_compressed_responses,
+                     entities_request,
+                     entities_request_min_size,
+                 }| config::SubgraphCompression {
+                    accept_compressed_responses,
+                    entities_request: entities_request.map(|algorithm| match algorithm {
+                        CompressionAlgorithm::Gzip => config::CompressionAlgorithm::Gzip,
+                        CompressionAlgorithm::Brotli => config::CompressionAlgorithm::Brotli,
+                        CompressionAlgorithm::Zstd => config::CompressionAlgorithm::Zstd,
+                    }),
+                    entities_request_min_size,
+                },
+            );
+
             let retry = config.retry.as_ref().map(
                 |parser_sdl::federation::RetryConfig {
                      min_per_second,
@@ -204,6 +234,48 @@ impl<'a> BuildContext<'a> {
                 },
             );
 
+            let hedging = config
+                .hedging
+                .map(
+                    |parser_sdl::federation::HedgingConfig { delay, percentile }| config::HedgingConfig {
+                        delay,
+                        percentile,
+                    },
+                );
+
+            let circuit_breaker = config.circuit_breaker.map(
+                |parser_sdl::federation::CircuitBreakerConfig {
+                     failure_threshold,
+                     error_rate,
+                     minimum_requests,
+                     window,
+                     open_duration,
+                     half_open_probes,
+                 }| config::CircuitBreakerConfig {
+                    failure_threshold,
+                    error_rate,
+                    minimum_requests,
+                    window,
+                    open_duration,
+                    half_open_probes,
+                },
+            );
+
+            let urls = config.urls.iter().map(|url| self.strings.intern(url)).collect();
+
+            let load_balancing = config.load_balancing.map(
+                |parser_sdl::federation::LoadBalancingConfig {
+                     strategy,
+                     ejection_duration,
+                 }| config::LoadBalancingConfig {
+                    strategy: match strategy {
+                        LoadBalancingStrategy::RoundRobin => config::LoadBalancingStrategy::RoundRobin,
+                        LoadBalancingStrategy::LeastInflight => config::LoadBalancingStrategy::LeastInflight,
+                    },
+                    ejection_duration,
+                },
+            );
+
             self.subgraph_configs.insert(
                 subgraph_id,
                 config::SubgraphConfig {
@@ -213,10 +285,17 @@ impl<'a> BuildContext<'a> {
                     rate_limit,
                     timeout: *timeout,
                     retry,
+                    hedging,
                     entity_caching: entity_caching.as_ref().map(|config| match config {
                         EntityCachingConfig::Disabled => EntityCaching::Disabled,
                         EntityCachingConfig::Enabled { ttl, .. } => EntityCaching::Enabled { ttl: *ttl },
                     }),
+                    compression,
+                    multiplex_subscriptions: *multiplex_subscriptions,
+                    deduplicate_requests: *deduplicate_requests,
+                    circuit_breaker,
+                    urls,
+                    load_balancing,
                 },
             );
         }
@@ -1,6 +1,6 @@
 use engine_v2_config::VersionedConfig;
 use federated_graph::FederatedGraph;
-use gateway_config::{Config, RetryConfig};
+use gateway_config::{CircuitBreakerConfig, Config, HedgingConfig, RetryConfig};
 use parser_sdl::federation::{header::SubgraphHeaderRule, FederatedGraphConfig};
 
 use crate::build_with_sdl_config;
@@ -30,6 +30,19 @@ pub fn build_with_toml_config(config: &Config, graph: FederatedGraph) -> Version
     graph_config.entity_caching = config.entity_caching.clone().into();
     graph_config.retry = retry_config(Some(config.gateway.retry));
 
+    graph_config.scalars = config
+        .scalars
+        .iter()
+        .map(|(name, scalar)| {
+            let scalar = parser_sdl::federation::ScalarConfig {
+                validate_input: scalar.validate_input,
+                validate_output: scalar.validate_output,
+            };
+
+            (name.clone(), scalar)
+        })
+        .collect();
+
     graph_config.subgraphs = config
         .subgraphs
         .clone()
@@ -50,6 +63,13 @@ pub fn build_with_toml_config(config: &Config, graph: FederatedGraph) -> Version
                 timeout: subgraph_config.timeout.or(config.gateway.subgraph_timeout),
                 entity_caching: subgraph_config.entity_caching.map(Into::into),
                 retry: retry_config(subgraph_config.retry),
+                hedging: hedging_config(subgraph_config.hedging),
+                compression: subgraph_config.compression.map(Into::into),
+                multiplex_subscriptions: subgraph_config.multiplex_subscriptions,
+                deduplicate_requests: subgraph_config.deduplicate_requests,
+                circuit_breaker: circuit_breaker_config(subgraph_config.circuit_breaker),
+                urls: subgraph_config.urls.iter().map(|url| url.to_string()).collect(),
+                load_balancing: subgraph_config.load_balancing.map(Into::into),
             };
 
             (name, config)
@@ -72,3 +92,27 @@ fn retry_config(retry_config: Option<RetryConfig>) -> Option<parser_sdl::federat
         retry_mutations: retry.retry_mutations,
     })
 }
+
+fn hedging_config(hedging: Option<HedgingConfig>) -> Option<parser_sdl::federation::HedgingConfig> {
+    hedging
+        .filter(|hedging| hedging.enabled)
+        .map(|hedging| parser_sdl::federation::HedgingConfig {
+            delay: hedging.delay,
+            percentile: hedging.percentile,
+        })
+}
+
+fn circuit_breaker_config(
+    circuit_breaker: Option<CircuitBreakerConfig>,
+) -> Option<parser_sdl::federation::CircuitBreakerConfig> {
+    circuit_breaker
+        .filter(|circuit_breaker| circuit_breaker.enabled)
+        .map(|circuit_breaker| parser_sdl::federation::CircuitBreakerConfig {
+            failure_threshold: circuit_breaker.failure_threshold,
+            error_rate: circuit_breaker.error_rate,
+            minimum_requests: circuit_breaker.minimum_requests,
+            window: circuit_breaker.window,
+            open_duration: circuit_breaker.open_duration,
+            half_open_probes: circuit_breaker.half_open_probes,
+        })
+}
@@ -85,7 +85,22 @@ async fn websocket_loop<R: Runtime>(socket: WebSocket, session: WebsocketSession
     let mut tasks = tokio::task::JoinSet::new();
     let mut subscriptions = HashMap::new();
 
-    while let Some(text) = receiver.recv_message().await {
+    let shutdown = session.graceful_shutdown();
+    pin_mut!(shutdown);
+
+    loop {
+        let text = tokio::select! {
+            text = receiver.recv_message() => text,
+            _ = &mut shutdown => {
+                // Going away: clients are expected to reconnect, to another instance.
+                sender.send(Message::close(1001, "Server is shutting down")).await.ok();
+                return;
+            }
+        };
+        let Some(text) = text else {
+            return;
+        };
+
         let response = handle_incoming_event(text, &session, &sender, &mut tasks, &mut subscriptions).await;
         match response {
             None => {}
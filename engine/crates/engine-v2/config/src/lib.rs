@@ -178,6 +178,7 @@ impl VersionedConfig {
                 timeout,
                 entity_caching,
                 retry,
+                scalars: Default::default(),
             },
             VersionedConfig::V6(latest) => latest,
         }
@@ -54,8 +54,59 @@ pub struct SubgraphConfig {
     pub timeout: Option<Duration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retry: Option<RetryConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hedging: Option<HedgingConfig>,
     #[serde(default)]
     pub entity_caching: Option<EntityCaching>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<SubgraphCompression>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multiplex_subscriptions: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deduplicate_requests: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<StringId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancing: Option<LoadBalancingConfig>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, Copy)]
+pub struct LoadBalancingConfig {
+    /// How the URL of each request is chosen.
+    #[serde(default)]
+    pub strategy: LoadBalancingStrategy,
+    /// How long a URL is left out after a connection error.
+    #[serde(default)]
+    pub ejection_duration: Option<Duration>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LoadBalancingStrategy {
+    #[default]
+    RoundRobin,
+    LeastInflight,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionAlgorithm {
+    Gzip,
+    Brotli,
+    Zstd,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, Copy)]
+pub struct SubgraphCompression {
+    /// Whether the subgraph may send compressed responses.
+    #[serde(default)]
+    pub accept_compressed_responses: bool,
+    /// Algorithm used to compress `_entities` request bodies, if any.
+    #[serde(default)]
+    pub entities_request: Option<CompressionAlgorithm>,
+    /// Minimum size in bytes of an `_entities` request body to be compressed.
+    #[serde(default)]
+    pub entities_request_min_size: u64,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, Copy)]
@@ -91,6 +142,38 @@ pub struct RetryConfig {
     pub retry_mutations: bool,
 }
 
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct HedgingConfig {
+    /// How long to wait for a response before sending the hedged request.
+    #[serde(default)]
+    pub delay: Option<Duration>,
+    /// Send the hedged request once the subgraph took longer than this percentile of its recent response times.
+    #[serde(default)]
+    pub percentile: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Open the circuit after this many consecutive failed requests.
+    #[serde(default)]
+    pub failure_threshold: Option<u32>,
+    /// Open the circuit when the fraction of failed requests within the window reaches this value.
+    #[serde(default)]
+    pub error_rate: Option<f32>,
+    /// How many requests must be seen within the window before the error rate is considered.
+    #[serde(default)]
+    pub minimum_requests: Option<u32>,
+    /// The window over which the error rate is computed.
+    #[serde(default)]
+    pub window: Option<Duration>,
+    /// How long the circuit stays open before probe requests are let through.
+    #[serde(default)]
+    pub open_duration: Option<Duration>,
+    /// How many successful probe requests are needed to close the circuit again.
+    #[serde(default)]
+    pub half_open_probes: Option<u32>,
+}
+
 /// A header that should be sent to a subgraph
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Header {
@@ -6,6 +6,7 @@ use std::{
 
 use federated_graph::{FederatedGraph, SubgraphId};
 
+pub use super::v2::{CompressionAlgorithm, SubgraphCompression};
 use crate::v5::{RateLimitConfigRef, RateLimitRedisConfigRef, RateLimitRedisTlsConfigRef};
 
 pub use super::v5::{
@@ -56,6 +57,29 @@ pub struct Config {
 
     #[serde(default)]
     pub retry: Option<RetryConfig>,
+
+    /// Validation settings of built-in scalars, by scalar name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scalars: BTreeMap<String, ScalarConfig>,
+}
+
+/// Controls how the engine validates the values of a built-in scalar such as `DateTime` or `UUID`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ScalarConfig {
+    /// Reject arguments and variables that do not match the scalar format. True by default.
+    pub validate_input: bool,
+    /// Treat subgraph values that do not match the scalar format as errors. False by default.
+    pub validate_output: bool,
+}
+
+impl Default for ScalarConfig {
+    fn default() -> Self {
+        ScalarConfig {
+            validate_input: true,
+            validate_output: false,
+        }
+    }
 }
 
 impl Config {
@@ -75,6 +99,7 @@ impl Config {
             timeout: None,
             entity_caching: EntityCaching::Disabled,
             retry: None,
+            scalars: Default::default(),
         }
     }
 
@@ -204,6 +204,23 @@ impl<'a> InputValueCoercer<'a> {
                 _ => None,
             }
             .map(SchemaInputValueRecord::String),
+            ty @ (ScalarType::Date | ScalarType::DateTime | ScalarType::UUID | ScalarType::Email | ScalarType::URL) => {
+                match value {
+                    Value::String(id) => {
+                        let id = StringId::from(id);
+                        if !ty.is_valid_string(&self.ctx.strings[id]) {
+                            return Err(InputValueError::IncorrectScalarValue {
+                                actual: format!("{:?}", self.ctx.strings[id]),
+                                expected: self.ctx.strings[self.graph[scalar_id].name_id].to_string(),
+                                path: self.path(),
+                            });
+                        }
+                        Some(id)
+                    }
+                    _ => None,
+                }
+                .map(SchemaInputValueRecord::String)
+            }
             ScalarType::Float => match value {
                 Value::Int(n) => Some(n as f64),
                 Value::Float(f) => Some(f),
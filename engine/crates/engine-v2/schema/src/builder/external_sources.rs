@@ -24,20 +24,47 @@ impl ExternalDataSources {
                         headers,
                         timeout,
                         retry,
+                        hedging,
                         entity_caching,
+                        compression,
+                        multiplex_subscriptions,
+                        deduplicate_requests,
+                        circuit_breaker,
+                        urls,
+                        load_balancing,
                         ..
-                    }) => GraphqlEndpointRecord {
-                        subgraph_name_id,
-                        url_id,
-                        websocket_url_id: websocket_url
-                            .map(|url| ctx.urls.insert(url::Url::parse(&config[url]).expect("valid url"))),
-                        header_rule_ids: headers.into_iter().map(Into::into).collect(),
-                        config: super::SubgraphConfig {
-                            timeout: timeout.unwrap_or(DEFAULT_SUBGRAPH_TIMEOUT),
-                            retry: retry.map(Into::into),
-                            cache_ttl: entity_caching.as_ref().unwrap_or(&config.entity_caching).ttl(),
-                        },
-                    },
+                    }) => {
+                        let url_ids: Vec<_> = urls
+                            .into_iter()
+                            .map(|url| ctx.urls.insert(url::Url::parse(&config[url]).expect("valid url")))
+                            .collect();
+
+                        GraphqlEndpointRecord {
+                            subgraph_name_id,
+                            url_id: url_ids.first().copied().unwrap_or(url_id),
+                            websocket_url_id: websocket_url
+                                .map(|url| ctx.urls.insert(url::Url::parse(&config[url]).expect("valid url"))),
+                            header_rule_ids: headers.into_iter().map(Into::into).collect(),
+                            config: super::SubgraphConfig {
+                                timeout: timeout.unwrap_or(DEFAULT_SUBGRAPH_TIMEOUT),
+                                retry: retry.map(Into::into),
+                                hedging: hedging.map(Into::into),
+                                cache_ttl: entity_caching.as_ref().unwrap_or(&config.entity_caching).ttl(),
+                                compression: compression.map(Into::into).unwrap_or_default(),
+                                multiplex_subscriptions,
+                                deduplicate_requests,
+                                circuit_breaker: circuit_breaker.map(Into::into),
+                                // A single URL only replaces the one from the federated graph.
+                                load_balancing: (url_ids.len() > 1).then(|| super::LoadBalancingConfig {
+                                    strategy: load_balancing.unwrap_or_default().strategy.into(),
+                                    ejection_duration: load_balancing
+                                        .and_then(|config| config.ejection_duration)
+                                        .unwrap_or(DEFAULT_EJECTION_DURATION),
+                                    url_ids,
+                                }),
+                            },
+                        }
+                    }
 
                     None => GraphqlEndpointRecord {
                         subgraph_name_id,
@@ -47,7 +74,13 @@ impl ExternalDataSources {
                         config: super::SubgraphConfig {
                             timeout: DEFAULT_SUBGRAPH_TIMEOUT,
                             retry: None,
+                            hedging: None,
                             cache_ttl: config.entity_caching.ttl(),
+                            compression: Default::default(),
+                            multiplex_subscriptions: false,
+                            deduplicate_requests: false,
+                            circuit_breaker: None,
+                            load_balancing: None,
                         },
                     },
                 }
@@ -58,3 +91,4 @@ impl ExternalDataSources {
 }
 
 const DEFAULT_SUBGRAPH_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_EJECTION_DURATION: Duration = Duration::from_secs(10);
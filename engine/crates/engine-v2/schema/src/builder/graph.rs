@@ -40,6 +40,7 @@ impl<'a> GraphBuilder<'a> {
                 input_object_definitions: Vec::new(),
                 input_value_definitions: Vec::new(),
                 field_definitions: Vec::new(),
+                inaccessible_field_definition_ids: Vec::new(),
                 resolver_definitions: Vec::new(),
                 type_definitions_ordered_by_name: Vec::new(),
                 required_field_sets: Vec::new(),
@@ -274,11 +275,33 @@ impl<'a> GraphBuilder<'a> {
             .iter_scalars()
             .map(|scalar| {
                 let name = StringId::from(scalar.name);
+                let specified_by_url_id = self.find_specified_by_url(config, scalar.directives);
+
+                let name_str = &self.ctx.strings[name];
+                let ty = specified_by_url_id
+                    .and_then(|id| ScalarType::from_specified_by_url(&self.ctx.strings[id]))
+                    .unwrap_or_else(|| ScalarType::from_scalar_name(name_str));
+
+                // Formatted scalars are validated unless the configuration opts out, in which case
+                // they're accepted as is like any custom scalar.
+                let scalar_config = config.scalars.get(name_str.as_str()).copied().unwrap_or_default();
+                let input_ty = if !ty.is_formatted_string() || scalar_config.validate_input {
+                    ty
+                } else {
+                    ScalarType::JSON
+                };
+                let output_ty = if !ty.is_formatted_string() || scalar_config.validate_output {
+                    ty
+                } else {
+                    ScalarType::JSON
+                };
+
                 ScalarDefinitionRecord {
                     name_id: name,
-                    ty: ScalarType::from_scalar_name(&self.ctx.strings[name]),
+                    ty: input_ty,
+                    output_ty,
                     description_id: None,
-                    specified_by_url_id: None,
+                    specified_by_url_id,
                     directive_ids: self.push_directives(
                         config,
                         Directives {
@@ -291,6 +314,22 @@ impl<'a> GraphBuilder<'a> {
             .collect();
     }
 
+    fn find_specified_by_url(&self, config: &Config, directives: federated_graph::Directives) -> Option<StringId> {
+        config.graph[directives].iter().find_map(|directive| match directive {
+            federated_graph::Directive::Other { name, arguments }
+                if &self.ctx.strings[StringId::from(*name)] == "specifiedBy" =>
+            {
+                arguments.iter().find_map(|(name, value)| match value {
+                    federated_graph::Value::String(url) if &self.ctx.strings[StringId::from(*name)] == "url" => {
+                        Some(StringId::from(*url))
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        })
+    }
+
     fn ingest_objects(&mut self, config: &mut Config) -> ObjectMetadata {
         let mut entities_metadata = ObjectMetadata {
             entities: Default::default(),
@@ -609,6 +648,31 @@ impl<'a> GraphBuilder<'a> {
                         }
                     })
                     .collect(),
+                context_argument_records: config
+                    .graph
+                    .field_context_arguments(federated_id)
+                    .flat_map(|argument| {
+                        argument
+                            .providers
+                            .iter()
+                            .map(move |(definition, selection)| (argument, *definition, selection))
+                    })
+                    .filter_map(|(argument, definition, selection)| {
+                        let entity_id = match definition {
+                            federated_graph::Definition::Object(id) => EntityDefinitionId::Object(id.into()),
+                            federated_graph::Definition::Interface(id) => EntityDefinitionId::Interface(id.into()),
+                            _ => return None,
+                        };
+                        let field_set_id = self.required_field_sets_buffer.push(schema_location, selection.clone());
+                        Some(FieldContextArgumentRecord {
+                            subgraph_id: SubgraphId::GraphqlEndpoint(GraphqlEndpointId::from(argument.subgraph_id)),
+                            name_id: argument.name.into(),
+                            argument_type_id: argument.r#type.into(),
+                            entity_id,
+                            field_set_id,
+                        })
+                    })
+                    .collect(),
                 argument_ids: self.ctx.idmaps.input_value.get_range(field.arguments),
                 directive_ids: directives,
             })
@@ -624,6 +688,12 @@ impl<'a> GraphBuilder<'a> {
         } = self;
 
         graph.required_scopes = required_scopes.into();
+        graph.inaccessible_field_definition_ids = ctx
+            .idmaps
+            .inaccessible_planner_fields
+            .iter()
+            .filter_map(|id| ctx.idmaps.field.get(*id))
+            .collect();
         required_field_sets_buffer.try_insert_into(ctx, &mut graph)?;
 
         let introspection = IntrospectionBuilder::create_data_source_and_insert_fields(ctx, &mut graph);
@@ -770,6 +840,31 @@ impl<'a> GraphBuilder<'a> {
                         reason_id: reason.map(Into::into),
                     })
                 }
+                federated_graph::Directive::Other { name, .. }
+                    if &self.ctx.strings[StringId::from(*name)] == "oneOf" =>
+                {
+                    TypeSystemDirectiveId::OneOf
+                }
+                federated_graph::Directive::Other { name, arguments }
+                    if &self.ctx.strings[StringId::from(*name)] == "semanticNonNull" =>
+                {
+                    let levels = arguments
+                        .iter()
+                        .find(|(name, _)| &self.ctx.strings[StringId::from(*name)] == "levels")
+                        .map(|(_, value)| match value {
+                            federated_graph::Value::List(levels) => levels
+                                .iter()
+                                .filter_map(|level| match level {
+                                    federated_graph::Value::Int(level) => usize::try_from(*level).ok(),
+                                    _ => None,
+                                })
+                                .collect(),
+                            federated_graph::Value::Int(level) => usize::try_from(*level).ok().into_iter().collect(),
+                            _ => Vec::new(),
+                        })
+                        .unwrap_or_else(|| vec![0]);
+                    TypeSystemDirectiveId::SemanticNonNull(SemanticNonNullDirective::new(levels))
+                }
                 federated_graph::Directive::Other { .. }
                 | federated_graph::Directive::Inaccessible
                 | federated_graph::Directive::Policy(_) => continue,
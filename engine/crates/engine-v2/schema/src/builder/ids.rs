@@ -2,7 +2,7 @@
 //! schema when the mapping is not 1:1.
 //!
 //! As of 3b56f12c95d334ce6cb46f4f8654ce531a69f975, this only happens when @inaccessible items
-//! are removed.
+//! are removed. Inaccessible fields needed by the query planner are kept.
 
 use std::marker::PhantomData;
 
@@ -37,6 +37,9 @@ pub(crate) struct IdMaps {
     pub(crate) field: IdMap<federated_graph::FieldId, FieldDefinitionId>,
     pub(crate) input_value: IdMap<federated_graph::InputValueDefinitionId, InputValueDefinitionId>,
     pub(crate) enum_values: IdMap<federated_graph::EnumValueId, EnumValueId>,
+    /// Inaccessible fields kept because they're part of a `@key`, `@requires`, `@provides` or
+    /// `@fromContext` selection. Sorted.
+    pub(crate) inaccessible_planner_fields: Vec<federated_graph::FieldId>,
     /// The index in that vector is the id in the graph being built.
     scalar_ids: Vec<federated_graph::TypeDefinitionId>,
     enum_ids: Vec<federated_graph::TypeDefinitionId>,
@@ -48,13 +51,20 @@ impl IdMaps {
             field: Default::default(),
             input_value: Default::default(),
             enum_values: IdMap::default(),
+            inaccessible_planner_fields: Vec::new(),
             scalar_ids: graph.iter_scalars().map(|s| s.id()).collect(),
             enum_ids: graph.iter_enums().map(|e| e.id()).collect(),
         };
 
+        let planner_fields = graph.planner_field_ids();
         for (i, field) in graph.fields.iter().enumerate() {
+            let id = federated_graph::FieldId(i);
             if is_inaccessible(graph, field.composed_directives) {
-                idmaps.field.skip(federated_graph::FieldId(i))
+                if planner_fields.contains(&id) {
+                    idmaps.inaccessible_planner_fields.push(id);
+                } else {
+                    idmaps.field.skip(id)
+                }
             }
         }
         for (i, input_value) in graph.input_value_definitions.iter().enumerate() {
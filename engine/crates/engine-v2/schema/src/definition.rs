@@ -26,9 +26,10 @@ impl<'a> Definition<'a> {
         directive_ids.walk(schema)
     }
 
-    pub fn scalar_type(&self) -> Option<ScalarType> {
+    /// Scalar type used to read values of this definition from subgraph responses.
+    pub fn output_scalar_type(&self) -> Option<ScalarType> {
         match self {
-            Definition::Scalar(scalar) => Some(scalar.ty),
+            Definition::Scalar(scalar) => Some(scalar.output_ty),
             _ => None,
         }
     }
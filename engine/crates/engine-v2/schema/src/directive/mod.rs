@@ -1,3 +1,5 @@
 mod requires_scopes;
+mod semantic_non_null;
 
 pub use requires_scopes::*;
+pub use semantic_non_null::*;
@@ -0,0 +1,37 @@
+/// `@semanticNonNull(levels: [Int] = [0])` on a field definition.
+///
+/// The levels are positions in the field type that are nullable but are only null when an error
+/// was raised. Level 0 is the field itself, level 1 the items of its list and so on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+pub struct SemanticNonNullDirective {
+    levels: u32,
+}
+
+impl SemanticNonNullDirective {
+    /// Levels beyond this one are ignored.
+    pub const MAX_LEVEL: usize = 31;
+
+    pub fn new(levels: impl IntoIterator<Item = usize>) -> Self {
+        let levels = levels
+            .into_iter()
+            .filter(|level| *level <= Self::MAX_LEVEL)
+            .fold(0, |levels, level| levels | (1 << level));
+        Self { levels }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.levels == 0
+    }
+
+    pub fn contains(self, level: usize) -> bool {
+        level <= Self::MAX_LEVEL && self.levels & (1 << level) != 0
+    }
+
+    /// Levels relative to the items of the list at level 0.
+    #[must_use]
+    pub fn list_items(self) -> Self {
+        Self {
+            levels: self.levels >> 1,
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
 use crate::{
-    FieldDefinition, InputValueDefinition, ProvidableFieldSet, RequiredFieldSetRecord, SubgraphId, TypeSystemDirective,
+    FieldContextArgument, FieldDefinition, InputValueDefinition, ProvidableFieldSet, RequiredFieldSetRecord,
+    SemanticNonNullDirective, SubgraphId, TypeSystemDirective,
 };
 
 impl<'a> FieldDefinition<'a> {
@@ -28,7 +29,9 @@ impl<'a> FieldDefinition<'a> {
             .filter_map(|directive| match directive {
                 TypeSystemDirective::Authenticated
                 | TypeSystemDirective::Deprecated(_)
-                | TypeSystemDirective::RequiresScopes(_) => None,
+                | TypeSystemDirective::OneOf
+                | TypeSystemDirective::RequiresScopes(_)
+                | TypeSystemDirective::SemanticNonNull(_) => None,
                 TypeSystemDirective::Authorized(directive) => {
                     directive.fields().map(|fields| Cow::Borrowed(fields.as_ref()))
                 }
@@ -44,6 +47,32 @@ impl<'a> FieldDefinition<'a> {
             .unwrap_or(Cow::Borrowed(RequiredFieldSetRecord::empty()))
     }
 
+    /// Arguments populated with `@fromContext`, one item per argument and type providing the context.
+    pub fn context_arguments_for_subgraph(
+        &self,
+        subgraph_id: SubgraphId,
+    ) -> impl Iterator<Item = FieldContextArgument<'a>> {
+        self.context_arguments()
+            .filter(move |argument| argument.as_ref().subgraph_id == subgraph_id)
+    }
+
+    pub fn has_context_arguments_for_subgraph(&self, subgraph_id: SubgraphId) -> bool {
+        self.as_ref()
+            .context_argument_records
+            .iter()
+            .any(|argument| argument.subgraph_id == subgraph_id)
+    }
+
+    /// Levels of the field type that are only null alongside an error, empty without `@semanticNonNull`.
+    pub fn semantic_non_null(&self) -> SemanticNonNullDirective {
+        self.directives()
+            .find_map(|directive| match directive {
+                TypeSystemDirective::SemanticNonNull(directive) => Some(directive),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
     pub fn is_resolvable_in(&self, subgraph_id: SubgraphId) -> bool {
         self.only_resolvable_in_ids.is_empty() || self.only_resolvable_in_ids.contains(&subgraph_id)
     }
@@ -56,7 +85,9 @@ impl<'a> FieldDefinition<'a> {
             || self.directives().any(|directive| match directive {
                 TypeSystemDirective::Authenticated
                 | TypeSystemDirective::Deprecated(_)
-                | TypeSystemDirective::RequiresScopes(_) => false,
+                | TypeSystemDirective::OneOf
+                | TypeSystemDirective::RequiresScopes(_)
+                | TypeSystemDirective::SemanticNonNull(_) => false,
                 TypeSystemDirective::Authorized(directive) => directive.fields().is_some(),
             })
     }
@@ -6,7 +6,7 @@
 mod authorized;
 mod deprecated;
 
-use crate::{prelude::*, RequiresScopesDirective, RequiresScopesDirectiveId};
+use crate::{prelude::*, RequiresScopesDirective, RequiresScopesDirectiveId, SemanticNonNullDirective};
 pub use authorized::*;
 pub use deprecated::*;
 use walker::Walk;
@@ -17,17 +17,20 @@ use walker::Walk;
 /// union TypeSystemDirective
 ///   @id
 ///   @meta(module: "directive")
-///   @variants(empty: ["Authenticated"], remove_suffix: "Directive") =
+///   @variants(empty: ["Authenticated", "OneOf"], remove_suffix: "Directive") =
 ///   | DeprecatedDirective
 ///   | RequiresScopesDirective
 ///   | AuthorizedDirective
+///   | SemanticNonNullDirective
 /// ```
 #[derive(serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TypeSystemDirectiveId {
     Authenticated,
     Authorized(AuthorizedDirectiveId),
     Deprecated(DeprecatedDirectiveRecord),
+    OneOf,
     RequiresScopes(RequiresScopesDirectiveId),
+    SemanticNonNull(SemanticNonNullDirective),
 }
 
 impl std::fmt::Debug for TypeSystemDirectiveId {
@@ -36,7 +39,9 @@ impl std::fmt::Debug for TypeSystemDirectiveId {
             TypeSystemDirectiveId::Authenticated => write!(f, "Authenticated"),
             TypeSystemDirectiveId::Authorized(variant) => variant.fmt(f),
             TypeSystemDirectiveId::Deprecated(variant) => variant.fmt(f),
+            TypeSystemDirectiveId::OneOf => write!(f, "OneOf"),
             TypeSystemDirectiveId::RequiresScopes(variant) => variant.fmt(f),
+            TypeSystemDirectiveId::SemanticNonNull(variant) => variant.fmt(f),
         }
     }
 }
@@ -56,13 +61,20 @@ impl From<RequiresScopesDirectiveId> for TypeSystemDirectiveId {
         TypeSystemDirectiveId::RequiresScopes(value)
     }
 }
+impl From<SemanticNonNullDirective> for TypeSystemDirectiveId {
+    fn from(value: SemanticNonNullDirective) -> Self {
+        TypeSystemDirectiveId::SemanticNonNull(value)
+    }
+}
 
 #[derive(Clone, Copy)]
 pub enum TypeSystemDirective<'a> {
     Authenticated,
     Authorized(AuthorizedDirective<'a>),
     Deprecated(DeprecatedDirective<'a>),
+    OneOf,
     RequiresScopes(RequiresScopesDirective<'a>),
+    SemanticNonNull(SemanticNonNullDirective),
 }
 
 impl std::fmt::Debug for TypeSystemDirective<'_> {
@@ -71,7 +83,9 @@ impl std::fmt::Debug for TypeSystemDirective<'_> {
             TypeSystemDirective::Authenticated => write!(f, "Authenticated"),
             TypeSystemDirective::Authorized(variant) => variant.fmt(f),
             TypeSystemDirective::Deprecated(variant) => variant.fmt(f),
+            TypeSystemDirective::OneOf => write!(f, "OneOf"),
             TypeSystemDirective::RequiresScopes(variant) => variant.fmt(f),
+            TypeSystemDirective::SemanticNonNull(variant) => variant.fmt(f),
         }
     }
 }
@@ -86,7 +100,9 @@ impl Walk<Schema> for TypeSystemDirectiveId {
             TypeSystemDirectiveId::Authenticated => TypeSystemDirective::Authenticated,
             TypeSystemDirectiveId::Authorized(id) => TypeSystemDirective::Authorized(id.walk(schema)),
             TypeSystemDirectiveId::Deprecated(item) => TypeSystemDirective::Deprecated(item.walk(schema)),
+            TypeSystemDirectiveId::OneOf => TypeSystemDirective::OneOf,
             TypeSystemDirectiveId::RequiresScopes(id) => TypeSystemDirective::RequiresScopes(id.walk(schema)),
+            TypeSystemDirectiveId::SemanticNonNull(item) => TypeSystemDirective::SemanticNonNull(item),
         }
     }
 }
@@ -97,7 +113,9 @@ impl TypeSystemDirective<'_> {
             TypeSystemDirective::Authenticated => TypeSystemDirectiveId::Authenticated,
             TypeSystemDirective::Authorized(walker) => TypeSystemDirectiveId::Authorized(walker.id),
             TypeSystemDirective::Deprecated(walker) => TypeSystemDirectiveId::Deprecated(walker.item),
+            TypeSystemDirective::OneOf => TypeSystemDirectiveId::OneOf,
             TypeSystemDirective::RequiresScopes(walker) => TypeSystemDirectiveId::RequiresScopes(walker.id),
+            TypeSystemDirective::SemanticNonNull(item) => TypeSystemDirectiveId::SemanticNonNull(*item),
         }
     }
 }
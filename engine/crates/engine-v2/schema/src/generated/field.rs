@@ -3,6 +3,7 @@
 //! ===================
 //! Generated with: `cargo run -p engine-v2-codegen`
 //! Source file: <engine-v2-codegen dir>/domain/schema.graphql
+mod context_argument;
 mod provides;
 mod requires;
 
@@ -14,6 +15,7 @@ use crate::{
     prelude::*,
     StringId,
 };
+pub use context_argument::*;
 pub use provides::*;
 pub use requires::*;
 use walker::{Iter, Walk};
@@ -35,6 +37,8 @@ use walker::{Iter, Walk};
 ///   only_resolvable_in: [Subgraph!]!
 ///   requires: [FieldRequires!]! @field(record_field_name: "requires_records")
 ///   provides: [FieldProvides!]! @field(record_field_name: "provides_records")
+///   "Arguments populated by the subgraph with @fromContext, they're not part of the public schema."
+///   context_arguments: [FieldContextArgument!]! @field(record_field_name: "context_argument_records")
 ///   "The arguments referenced by this range are sorted by their name (string)"
 ///   arguments: [InputValueDefinition!]!
 ///   directives: [TypeSystemDirective!]!
@@ -53,6 +57,8 @@ pub struct FieldDefinitionRecord {
     pub only_resolvable_in_ids: Vec<SubgraphId>,
     pub requires_records: Vec<FieldRequiresRecord>,
     pub provides_records: Vec<FieldProvidesRecord>,
+    /// Arguments populated by the subgraph with @fromContext, they're not part of the public schema.
+    pub context_argument_records: Vec<FieldContextArgumentRecord>,
     /// The arguments referenced by this range are sorted by their name (string)
     pub argument_ids: IdRange<InputValueDefinitionId>,
     pub directive_ids: Vec<TypeSystemDirectiveId>,
@@ -111,6 +117,10 @@ impl<'a> FieldDefinition<'a> {
     pub fn provides(&self) -> impl Iter<Item = FieldProvides<'a>> + 'a {
         self.as_ref().provides_records.walk(self.schema)
     }
+    /// Arguments populated by the subgraph with @fromContext, they're not part of the public schema.
+    pub fn context_arguments(&self) -> impl Iter<Item = FieldContextArgument<'a>> + 'a {
+        self.as_ref().context_argument_records.walk(self.schema)
+    }
     /// The arguments referenced by this range are sorted by their name (string)
     pub fn arguments(&self) -> impl Iter<Item = InputValueDefinition<'a>> + 'a {
         self.argument_ids.walk(self.schema)
@@ -140,6 +150,7 @@ impl std::fmt::Debug for FieldDefinition<'_> {
             .field("only_resolvable_in", &self.only_resolvable_in())
             .field("requires", &self.requires())
             .field("provides", &self.provides())
+            .field("context_arguments", &self.context_arguments())
             .field("arguments", &self.arguments())
             .field("directives", &self.directives())
             .finish()
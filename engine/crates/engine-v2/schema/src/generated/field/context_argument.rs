@@ -0,0 +1,97 @@
+//! ===================
+//! !!! DO NOT EDIT !!!
+//! ===================
+//! Generated with: `cargo run -p engine-v2-codegen`
+//! Source file: <engine-v2-codegen dir>/domain/schema.graphql
+use crate::{
+    generated::{EntityDefinition, EntityDefinitionId, Subgraph, SubgraphId},
+    prelude::*,
+    RequiredFieldSet, RequiredFieldSetId, StringId,
+};
+use walker::Walk;
+
+/// Generated from:
+///
+/// ```custom,{.language-graphql}
+/// type FieldContextArgument @meta(module: "field/context_argument") @copy {
+///   subgraph: Subgraph!
+///   name: String!
+///   "Type of the argument in the subgraph, like `String!`."
+///   argument_type: String!
+///   "Entity providing the context with @context."
+///   entity: EntityDefinition!
+///   "Path to the value within the entity, a single field per level."
+///   field_set: RequiredFieldSet!
+/// }
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy)]
+pub struct FieldContextArgumentRecord {
+    pub subgraph_id: SubgraphId,
+    pub name_id: StringId,
+    /// Type of the argument in the subgraph, like `String!`.
+    pub argument_type_id: StringId,
+    /// Entity providing the context with @context.
+    pub entity_id: EntityDefinitionId,
+    /// Path to the value within the entity, a single field per level.
+    pub field_set_id: RequiredFieldSetId,
+}
+
+#[derive(Clone, Copy)]
+pub struct FieldContextArgument<'a> {
+    pub(crate) schema: &'a Schema,
+    pub(crate) item: FieldContextArgumentRecord,
+}
+
+impl std::ops::Deref for FieldContextArgument<'_> {
+    type Target = FieldContextArgumentRecord;
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+
+impl<'a> FieldContextArgument<'a> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &FieldContextArgumentRecord {
+        &self.item
+    }
+    pub fn subgraph(&self) -> Subgraph<'a> {
+        self.subgraph_id.walk(self.schema)
+    }
+    pub fn name(&self) -> &'a str {
+        self.name_id.walk(self.schema)
+    }
+    /// Type of the argument in the subgraph, like `String!`.
+    pub fn argument_type(&self) -> &'a str {
+        self.argument_type_id.walk(self.schema)
+    }
+    /// Entity providing the context with @context.
+    pub fn entity(&self) -> EntityDefinition<'a> {
+        self.entity_id.walk(self.schema)
+    }
+    /// Path to the value within the entity, a single field per level.
+    pub fn field_set(&self) -> RequiredFieldSet<'a> {
+        self.field_set_id.walk(self.schema)
+    }
+}
+
+impl Walk<Schema> for FieldContextArgumentRecord {
+    type Walker<'a> = FieldContextArgument<'a>;
+    fn walk<'a>(self, schema: &'a Schema) -> Self::Walker<'a>
+    where
+        Self: 'a,
+    {
+        FieldContextArgument { schema, item: self }
+    }
+}
+
+impl std::fmt::Debug for FieldContextArgument<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldContextArgument")
+            .field("subgraph", &self.subgraph())
+            .field("name", &self.name())
+            .field("argument_type", &self.argument_type())
+            .field("entity", &self.entity())
+            .field("field_set", &self.field_set())
+            .finish()
+    }
+}
@@ -16,6 +16,7 @@ use walker::{Iter, Walk};
 /// type ScalarDefinition @meta(module: "scalar") @indexed(id_size: "u32", max_id: "MAX_ID") {
 ///   name: String!
 ///   ty: ScalarType!
+///   output_ty: ScalarType!
 ///   description: String
 ///   specified_by_url: String
 ///   directives: [TypeSystemDirective!]!
@@ -25,6 +26,7 @@ use walker::{Iter, Walk};
 pub struct ScalarDefinitionRecord {
     pub name_id: StringId,
     pub ty: ScalarType,
+    pub output_ty: ScalarType,
     pub description_id: Option<StringId>,
     pub specified_by_url_id: Option<StringId>,
     pub directive_ids: Vec<TypeSystemDirectiveId>,
@@ -85,6 +87,7 @@ impl std::fmt::Debug for ScalarDefinition<'_> {
         f.debug_struct("ScalarDefinition")
             .field("name", &self.name())
             .field("ty", &self.ty)
+            .field("output_ty", &self.output_ty)
             .field("description", &self.description())
             .field("specified_by_url", &self.specified_by_url())
             .field("directives", &self.directives())
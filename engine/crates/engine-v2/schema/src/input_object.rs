@@ -0,0 +1,8 @@
+use crate::{InputObjectDefinition, TypeSystemDirective};
+
+impl InputObjectDefinition<'_> {
+    pub fn is_one_of(&self) -> bool {
+        self.directives()
+            .any(|directive| matches!(directive, TypeSystemDirective::OneOf))
+    }
+}
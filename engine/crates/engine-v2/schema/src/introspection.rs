@@ -26,6 +26,7 @@ pub enum __Schema {
     MutationType,
     SubscriptionType,
     Directives,
+    DefaultErrorBehavior,
 }
 
 #[derive(
@@ -52,6 +53,7 @@ pub enum __Type {
     InputFields,
     OfType,
     SpecifiedByURL,
+    IsOneOf,
 }
 
 #[derive(
@@ -145,6 +147,7 @@ pub struct IntrospectionMetadata {
     pub meta_fields: [FieldDefinitionId; 2],
     pub type_kind: TypeKind,
     pub directive_location: DirectiveLocation,
+    pub error_behavior: ErrorBehavior,
     pub __schema: IntrospectionObject<__Schema, { __Schema::COUNT }>,
     pub __type: IntrospectionObject<__Type, { __Type::COUNT }>,
     pub __enum_value: IntrospectionObject<__EnumValue, { __EnumValue::COUNT }>,
@@ -198,6 +201,12 @@ pub struct TypeKind {
     pub non_null: StringId,
 }
 
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ErrorBehavior {
+    pub null: StringId,
+    pub propagate: StringId,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct DirectiveLocation {
     pub query: StringId,
@@ -359,6 +368,18 @@ impl<'a> IntrospectionBuilder<'a> {
             input_field_definition: self.get_or_intern("INPUT_FIELD_DEFINITION"),
         };
 
+        /*
+        enum __ErrorBehavior {
+          NULL
+          PROPAGATE
+        }
+        */
+        let __error_behavior = self.insert_enum("__ErrorBehavior", &["NULL", "PROPAGATE"]);
+        let error_behavior = ErrorBehavior {
+            null: self.get_or_intern("NULL"),
+            propagate: self.get_or_intern("PROPAGATE"),
+        };
+
         /*
         type __EnumValue {
           name: String!
@@ -491,6 +512,7 @@ impl<'a> IntrospectionBuilder<'a> {
                 ("description", nullable_string, __Type::Description),
                 ("inputFields", input_fields, __Type::InputFields),
                 ("specifiedByURL", nullable_string, __Type::SpecifiedByURL),
+                ("isOneOf", nullable_boolean, __Type::IsOneOf),
                 ("fields", nullable__field_list, __Type::Fields),
                 ("enumValues", nullable__enum_value_list, __Type::EnumValues),
                 ("ofType", nullable__type, __Type::OfType),
@@ -547,12 +569,17 @@ impl<'a> IntrospectionBuilder<'a> {
           mutationType: __Type
           subscriptionType: __Type
           directives: [__Directive!]!
+          defaultErrorBehavior: __ErrorBehavior!
         }
         */
         let required__directive_list = TypeRecord {
             definition_id: __directive.id.into(),
             wrapping: Wrapping::required().wrapped_by_required_list(),
         };
+        let required__error_behavior = TypeRecord {
+            definition_id: __error_behavior.into(),
+            wrapping: Wrapping::required(),
+        };
         let __schema = self.insert_object("__Schema");
 
         let __schema = self.insert_object_fields(
@@ -564,6 +591,11 @@ impl<'a> IntrospectionBuilder<'a> {
                 ("mutationType", nullable__type, __Schema::MutationType),
                 ("subscriptionType", nullable__type, __Schema::SubscriptionType),
                 ("directives", required__directive_list, __Schema::Directives),
+                (
+                    "defaultErrorBehavior",
+                    required__error_behavior,
+                    __Schema::DefaultErrorBehavior,
+                ),
             ],
         );
 
@@ -613,6 +645,7 @@ impl<'a> IntrospectionBuilder<'a> {
             meta_fields: [__type_field_id, __schema_field_id],
             type_kind,
             directive_location,
+            error_behavior,
             __schema,
             __type,
             __enum_value,
@@ -689,6 +722,7 @@ impl<'a> IntrospectionBuilder<'a> {
                 only_resolvable_in_ids: vec![SubgraphId::Introspection],
                 requires_records: Vec::new(),
                 provides_records: Vec::new(),
+                context_argument_records: Vec::new(),
                 directive_ids: Vec::new(),
                 resolver_ids: Vec::new(),
                 argument_ids: IdRange::empty(),
@@ -773,6 +807,7 @@ impl<'a> IntrospectionBuilder<'a> {
                 self.scalar_definitions.push(crate::ScalarDefinitionRecord {
                     name_id,
                     ty: scalar_type,
+                    output_ty: scalar_type,
                     description_id: None,
                     specified_by_url_id: None,
                     directive_ids: Vec::new(),
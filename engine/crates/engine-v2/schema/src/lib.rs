@@ -1,4 +1,4 @@
-use std::{str::FromStr, sync::OnceLock};
+use std::sync::OnceLock;
 
 mod builder;
 mod definition;
@@ -9,12 +9,14 @@ mod field;
 mod field_set;
 mod generated;
 mod ids;
+mod input_object;
 mod input_value;
 mod interface;
 pub mod introspection;
 mod object;
 mod prelude;
 mod resolver;
+mod scalar;
 mod subgraph;
 mod ty;
 mod union;
@@ -139,6 +141,9 @@ pub struct Graph {
     interface_definitions: Vec<InterfaceDefinitionRecord>,
     #[indexed_by(FieldDefinitionId)]
     field_definitions: Vec<FieldDefinitionRecord>,
+    /// Fields absent from the API schema, kept only for the query planner as they're used in
+    /// `@key`, `@requires`, `@provides` or `@fromContext` selections. Sorted.
+    inaccessible_field_definition_ids: Vec<FieldDefinitionId>,
     #[indexed_by(EnumDefinitionId)]
     enum_definitions: Vec<EnumDefinitionRecord>,
     #[indexed_by(UnionDefinitionId)]
@@ -198,6 +203,7 @@ impl Schema {
             .iter()
             .position(|field| self[field.name_id] == name)
             .map(|pos| FieldDefinitionId::from(usize::from(fields.start) + pos))
+            .filter(|id| !self.is_inaccessible(*id))
     }
 
     pub fn interface_field_by_name(
@@ -210,6 +216,15 @@ impl Schema {
             .iter()
             .position(|field| self[field.name_id] == name)
             .map(|pos| FieldDefinitionId::from(usize::from(fields.start) + pos))
+            .filter(|id| !self.is_inaccessible(*id))
+    }
+
+    /// Whether the field is only known to the query planner and absent from the API schema.
+    pub fn is_inaccessible(&self, field_id: FieldDefinitionId) -> bool {
+        self.graph
+            .inaccessible_field_definition_ids
+            .binary_search(&field_id)
+            .is_ok()
     }
 
     pub fn default_header_rules(&self) -> impl Iter<Item = HeaderRule<'_>> + '_ {
@@ -268,13 +283,9 @@ pub enum ScalarType {
     BigInt,
     JSON,
     Boolean,
-}
-
-impl ScalarType {
-    pub fn from_scalar_name(name: &str) -> ScalarType {
-        ScalarType::from_str(name).ok().unwrap_or(match name {
-            "ID" => ScalarType::String,
-            _ => ScalarType::JSON,
-        })
-    }
+    Date,
+    DateTime,
+    UUID,
+    Email,
+    URL,
 }
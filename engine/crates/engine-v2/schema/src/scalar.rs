@@ -0,0 +1,101 @@
+use std::str::FromStr;
+
+use crate::ScalarType;
+
+impl ScalarType {
+    pub fn from_scalar_name(name: &str) -> ScalarType {
+        ScalarType::from_str(name).ok().unwrap_or(match name {
+            "ID" => ScalarType::String,
+            "Uuid" => ScalarType::UUID,
+            "Url" => ScalarType::URL,
+            _ => ScalarType::JSON,
+        })
+    }
+
+    /// Built-in scalar type matching a well-known `@specifiedBy(url:)` specification, if any.
+    pub fn from_specified_by_url(url: &str) -> Option<ScalarType> {
+        let url = url.trim_end_matches('/');
+        let url = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .unwrap_or(url);
+
+        match url {
+            "scalars.graphql.org/andimarek/date-time" | "datatracker.ietf.org/doc/html/rfc3339" => {
+                Some(ScalarType::DateTime)
+            }
+            "scalars.graphql.org/andimarek/local-date" | "datatracker.ietf.org/doc/html/rfc3339#appendix-A" => {
+                Some(ScalarType::Date)
+            }
+            "tools.ietf.org/html/rfc4122"
+            | "datatracker.ietf.org/doc/html/rfc4122"
+            | "www.rfc-editor.org/rfc/rfc4122"
+            | "en.wikipedia.org/wiki/Universally_unique_identifier" => Some(ScalarType::UUID),
+            "html.spec.whatwg.org/multipage/input.html#valid-e-mail-address" => Some(ScalarType::Email),
+            "url.spec.whatwg.org" => Some(ScalarType::URL),
+            _ => None,
+        }
+    }
+
+    /// Whether values of this scalar are strings with a format the engine validates.
+    pub fn is_formatted_string(self) -> bool {
+        matches!(
+            self,
+            ScalarType::Date | ScalarType::DateTime | ScalarType::UUID | ScalarType::Email | ScalarType::URL
+        )
+    }
+
+    /// Validates a string value against the format of this scalar. Scalars without any specific
+    /// format accept any string.
+    pub fn is_valid_string(self, value: &str) -> bool {
+        match self {
+            ScalarType::Date => value.parse::<chrono::NaiveDate>().is_ok(),
+            ScalarType::DateTime => chrono::DateTime::parse_from_rfc3339(value).is_ok(),
+            ScalarType::UUID => value.parse::<uuid::Uuid>().is_ok(),
+            ScalarType::Email => fast_chemail::parse_email(value).is_ok(),
+            ScalarType::URL => url::Url::parse(value).is_ok(),
+            ScalarType::String
+            | ScalarType::Float
+            | ScalarType::Int
+            | ScalarType::BigInt
+            | ScalarType::JSON
+            | ScalarType::Boolean => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_scalars_are_detected_by_name_and_specified_by_url() {
+        assert_eq!(ScalarType::from_scalar_name("DateTime"), ScalarType::DateTime);
+        assert_eq!(ScalarType::from_scalar_name("Uuid"), ScalarType::UUID);
+        assert_eq!(ScalarType::from_scalar_name("Timestamp"), ScalarType::JSON);
+        assert_eq!(
+            ScalarType::from_specified_by_url("https://scalars.graphql.org/andimarek/date-time"),
+            Some(ScalarType::DateTime)
+        );
+        assert_eq!(
+            ScalarType::from_specified_by_url("http://url.spec.whatwg.org/"),
+            Some(ScalarType::URL)
+        );
+        assert_eq!(ScalarType::from_specified_by_url("https://example.com"), None);
+    }
+
+    #[test]
+    fn formatted_strings_are_validated() {
+        assert!(ScalarType::Date.is_valid_string("2024-02-29"));
+        assert!(!ScalarType::Date.is_valid_string("2023-02-29"));
+        assert!(ScalarType::DateTime.is_valid_string("2007-12-03T10:15:30+01:00"));
+        assert!(!ScalarType::DateTime.is_valid_string("2007-12-03"));
+        assert!(ScalarType::UUID.is_valid_string("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert!(!ScalarType::UUID.is_valid_string("67e55044"));
+        assert!(ScalarType::Email.is_valid_string("user@example.com"));
+        assert!(!ScalarType::Email.is_valid_string("user.example.com"));
+        assert!(ScalarType::URL.is_valid_string("https://grafbase.com/docs"));
+        assert!(!ScalarType::URL.is_valid_string("grafbase"));
+        assert!(ScalarType::String.is_valid_string("anything"));
+    }
+}
@@ -1,12 +1,86 @@
 use std::time::Duration;
 
+use crate::UrlId;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct SubgraphConfig {
     pub timeout: Duration,
     pub retry: Option<RetryConfig>,
+    pub hedging: Option<HedgingConfig>,
     // The ttl to use for caching for this subgraph.
     // If None then caching is disabled for this subgraph
     pub cache_ttl: Option<Duration>,
+    pub compression: CompressionConfig,
+    /// Whether identical subscriptions share a single upstream stream.
+    pub multiplex_subscriptions: bool,
+    /// Whether identical in-flight queries share a single subgraph request.
+    pub deduplicate_requests: bool,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// Set when requests are balanced across several replicas of the subgraph.
+    pub load_balancing: Option<LoadBalancingConfig>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct LoadBalancingConfig {
+    pub strategy: LoadBalancingStrategy,
+    /// How long a URL is left out after a connection error.
+    pub ejection_duration: Duration,
+    /// Replicas of the subgraph, the first one is also the endpoint url.
+    pub url_ids: Vec<UrlId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LoadBalancingStrategy {
+    RoundRobin,
+    LeastInflight,
+}
+
+impl From<config::latest::LoadBalancingStrategy> for LoadBalancingStrategy {
+    fn from(strategy: config::latest::LoadBalancingStrategy) -> Self {
+        match strategy {
+            config::latest::LoadBalancingStrategy::RoundRobin => LoadBalancingStrategy::RoundRobin,
+            config::latest::LoadBalancingStrategy::LeastInflight => LoadBalancingStrategy::LeastInflight,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct CompressionConfig {
+    /// Whether the subgraph may send compressed responses.
+    pub accept_compressed_responses: bool,
+    /// Algorithm used to compress `_entities` request bodies, if any.
+    pub entities_request: Option<CompressionAlgorithm>,
+    /// Minimum size in bytes of an `_entities` request body to be compressed.
+    pub entities_request_min_size: usize,
+}
+
+impl CompressionConfig {
+    /// The algorithm to compress an `_entities` request body of the given size with, if any.
+    pub fn entities_request_compression(&self, body_size: usize) -> Option<CompressionAlgorithm> {
+        self.entities_request
+            .filter(|_| body_size >= self.entities_request_min_size)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum CompressionAlgorithm {
+    Gzip,
+    Brotli,
+    Zstd,
+}
+
+impl From<config::latest::SubgraphCompression> for CompressionConfig {
+    fn from(config: config::latest::SubgraphCompression) -> Self {
+        Self {
+            accept_compressed_responses: config.accept_compressed_responses,
+            entities_request: config.entities_request.map(|algorithm| match algorithm {
+                config::latest::CompressionAlgorithm::Gzip => CompressionAlgorithm::Gzip,
+                config::latest::CompressionAlgorithm::Brotli => CompressionAlgorithm::Brotli,
+                config::latest::CompressionAlgorithm::Zstd => CompressionAlgorithm::Zstd,
+            }),
+            entities_request_min_size: usize::try_from(config.entities_request_min_size).unwrap_or(usize::MAX),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
@@ -31,3 +105,49 @@ impl From<config::latest::RetryConfig> for RetryConfig {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct HedgingConfig {
+    /// How long to wait for a response before sending the hedged request.
+    pub delay: Option<Duration>,
+    /// Send the hedged request once the subgraph took longer than this percentile of its recent response times.
+    pub percentile: Option<f32>,
+}
+
+impl From<config::latest::HedgingConfig> for HedgingConfig {
+    fn from(config: config::latest::HedgingConfig) -> Self {
+        Self {
+            delay: config.delay,
+            percentile: config.percentile,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Open the circuit after this many consecutive failed requests.
+    pub failure_threshold: Option<u32>,
+    /// Open the circuit when the fraction of failed requests within the window reaches this value.
+    pub error_rate: Option<f32>,
+    /// How many requests must be seen within the window before the error rate is considered.
+    pub minimum_requests: Option<u32>,
+    /// The window over which the error rate is computed.
+    pub window: Option<Duration>,
+    /// How long the circuit stays open before probe requests are let through.
+    pub open_duration: Option<Duration>,
+    /// How many successful probe requests are needed to close the circuit again.
+    pub half_open_probes: Option<u32>,
+}
+
+impl From<config::latest::CircuitBreakerConfig> for CircuitBreakerConfig {
+    fn from(config: config::latest::CircuitBreakerConfig) -> Self {
+        Self {
+            failure_threshold: config.failure_threshold,
+            error_rate: config.error_rate,
+            minimum_requests: config.minimum_requests,
+            window: config.window,
+            open_duration: config.open_duration,
+            half_open_probes: config.half_open_probes,
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use ::runtime::operation_cache::OperationCacheFactory;
 use bytes::Bytes;
+use circuit_breaker::CircuitBreakers;
 use futures::{StreamExt, TryFutureExt};
 use futures_util::Stream;
 use gateway_v2_auth::AuthService;
+use hedging::Hedgers;
+use load_balancer::LoadBalancers;
 use retry_budget::RetryBudgets;
 use schema::Schema;
 use std::{borrow::Cow, future::Future, sync::Arc};
@@ -13,17 +16,29 @@ use crate::{
     response::Response,
     websocket, Body,
 };
+pub(crate) use circuit_breaker::{CircuitBreaker, CircuitBreakerPermit, CircuitBreakerTransition};
 pub(crate) use execute::*;
+pub(crate) use hedging::Hedger;
+pub(crate) use load_balancer::LoadBalancer;
+pub(crate) use request_deduplicator::*;
 pub(crate) use runtime::*;
+pub(crate) use subscription_multiplexer::*;
 
 mod cache;
+mod circuit_breaker;
 mod error_responses;
 mod execute;
+mod hedging;
+mod load_balancer;
+mod request_deduplicator;
 mod retry_budget;
 mod runtime;
+mod subscription_multiplexer;
 mod trusted_documents;
+mod warming;
 
 pub use runtime::Runtime;
+pub use warming::WarmingOperation;
 
 pub struct Engine<R: Runtime> {
     // We use an Arc for the schema to have a self-contained response which may still
@@ -32,7 +47,13 @@ pub struct Engine<R: Runtime> {
     pub(crate) runtime: R,
     auth: AuthService,
     retry_budgets: RetryBudgets,
+    hedgers: Hedgers,
+    circuit_breakers: CircuitBreakers,
+    load_balancers: LoadBalancers,
     operation_cache: <R::OperationCacheFactory as OperationCacheFactory>::Cache<Arc<PreparedOperation>>,
+    operation_usage: warming::OperationUsage,
+    pub(crate) subscription_multiplexers: SubscriptionMultiplexers,
+    pub(crate) request_deduplicator: RequestDeduplicator,
     default_response_format: ResponseFormat,
 }
 
@@ -48,7 +69,13 @@ impl<R: Runtime> Engine<R> {
         Self {
             auth,
             retry_budgets: RetryBudgets::build(&schema),
+            hedgers: Hedgers::build(&schema),
+            circuit_breakers: CircuitBreakers::build(&schema),
+            load_balancers: LoadBalancers::build(&schema),
             operation_cache: runtime.operation_cache_factory().create().await,
+            operation_usage: Default::default(),
+            subscription_multiplexers: Default::default(),
+            request_deduplicator: Default::default(),
             schema,
             runtime,
             // Could be coming from configuration one day
@@ -56,6 +83,17 @@ impl<R: Runtime> Engine<R> {
         }
     }
 
+    pub fn runtime(&self) -> &R {
+        &self.runtime
+    }
+
+    /// Name and URL of each GraphQL subgraph.
+    pub fn subgraph_urls(&self) -> impl Iterator<Item = (&str, &url::Url)> + '_ {
+        self.schema
+            .graphql_endpoints()
+            .map(|endpoint| (endpoint.subgraph_name(), endpoint.url()))
+    }
+
     pub async fn execute<F>(self: &Arc<Self>, request: http::Request<F>) -> http::Response<Body>
     where
         F: Future<Output = Result<Bytes, (http::StatusCode, String)>> + Send,
@@ -71,12 +109,14 @@ impl<R: Runtime> Engine<R> {
             Err(response) => return response,
         };
 
+        let multipart_boundary = multipart_boundary(&headers);
+
         let request_context_fut = self
             .create_request_context(!method.is_safe(), headers, response_format)
             .map_err(|response| Http::error(response_format, response));
 
         let graphql_request_fut =
-            self.extract_well_formed_graphql_over_http_request(method, uri, response_format, body);
+            self.extract_well_formed_graphql_over_http_request(method, uri, multipart_boundary, response_format, body);
 
         // Retrieve the request body while processing the headers
         match futures::try_join!(request_context_fut, graphql_request_fut) {
@@ -150,4 +190,9 @@ impl<R: Runtime> WebsocketSession<R> {
             },
         })
     }
+
+    /// Resolves once the server starts shutting down and the connection should be closed.
+    pub fn graceful_shutdown(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.engine.runtime.graceful_shutdown()
+    }
 }
@@ -18,6 +18,7 @@ pub(super) enum Key<'a> {
     },
 }
 
+#[derive(Clone)]
 pub(super) enum Document<'a> {
     AutomaticallyPersistedQuery(&'a PersistedQueryRequestExtension),
     TrustedDocumentId { client_name: &'a str, doc_id: Cow<'a, str> },
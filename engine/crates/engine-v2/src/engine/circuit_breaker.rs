@@ -0,0 +1,391 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use schema::{CircuitBreakerConfig, GraphqlEndpointId, Schema};
+use web_time::Instant;
+
+use super::Runtime;
+
+#[derive(id_derives::IndexedFields)]
+pub(super) struct CircuitBreakers {
+    #[indexed_by(GraphqlEndpointId)]
+    by_graphql_endpoints: Vec<Option<CircuitBreaker>>,
+}
+
+impl CircuitBreakers {
+    pub fn build(schema: &Schema) -> Self {
+        Self {
+            by_graphql_endpoints: schema
+                .graphql_endpoints()
+                .map(|endpoint| endpoint.config.circuit_breaker.as_ref().map(CircuitBreaker::new))
+                .collect(),
+        }
+    }
+}
+
+impl<R: Runtime> super::Engine<R> {
+    pub(crate) fn get_circuit_breaker(&self, endpoint_id: GraphqlEndpointId) -> Option<&CircuitBreaker> {
+        self.circuit_breakers[endpoint_id].as_ref()
+    }
+}
+
+/// State change of a circuit breaker worth reporting in metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CircuitBreakerTransition {
+    /// The circuit went from closed to open.
+    Opened,
+    /// The circuit closed again after successful probes.
+    Closed,
+}
+
+pub(crate) struct CircuitBreaker {
+    failure_threshold: u32,
+    error_rate: Option<f32>,
+    minimum_requests: u32,
+    window: Duration,
+    open_duration: Duration,
+    half_open_probes: u32,
+    /// Number of times the circuit went half-open, identifies the probes of each round.
+    half_open_rounds: AtomicU64,
+    state: Mutex<State>,
+}
+
+enum State {
+    Closed {
+        consecutive_failures: u32,
+        window_start: Instant,
+        requests: u32,
+        failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    HalfOpen {
+        round: u64,
+        probes_in_flight: u32,
+        successes: u32,
+    },
+}
+
+impl State {
+    fn closed(now: Instant) -> Self {
+        State::Closed {
+            consecutive_failures: 0,
+            window_start: now,
+            requests: 0,
+            failures: 0,
+        }
+    }
+}
+
+/// Allows a single request to be sent to the subgraph. Its outcome must be reported with
+/// [`CircuitBreakerPermit::record`]. A half-open probe dropped without an outcome, because the
+/// rate limiter rejected it or the request was cancelled, gives its slot back.
+pub(crate) struct CircuitBreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    /// Half-open round this permit is a probe for.
+    probe: Option<u64>,
+}
+
+impl CircuitBreakerPermit<'_> {
+    /// Records the outcome of the request sent to the subgraph.
+    pub fn record(mut self, success: bool) -> Option<CircuitBreakerTransition> {
+        let probe = self.probe.take();
+        self.breaker.record(probe, success)
+    }
+}
+
+impl Drop for CircuitBreakerPermit<'_> {
+    fn drop(&mut self) {
+        if let Some(round) = self.probe.take() {
+            self.breaker.release_probe(round);
+        }
+    }
+}
+
+impl CircuitBreaker {
+    fn new(config: &CircuitBreakerConfig) -> Self {
+        Self {
+            failure_threshold: config.failure_threshold.unwrap_or(5).max(1),
+            error_rate: config.error_rate,
+            minimum_requests: config.minimum_requests.unwrap_or(10).max(1),
+            window: config.window.unwrap_or(Duration::from_secs(10)),
+            open_duration: config.open_duration.unwrap_or(Duration::from_secs(30)),
+            half_open_probes: config.half_open_probes.unwrap_or(1).max(1),
+            half_open_rounds: AtomicU64::new(0),
+            state: Mutex::new(State::closed(Instant::now())),
+        }
+    }
+
+    /// Returns a permit if a request may be sent to the subgraph. Once the open duration has
+    /// elapsed, a limited number of probe requests are let through.
+    pub fn try_acquire(&self) -> Option<CircuitBreakerPermit<'_>> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        let probe = match &mut *state {
+            State::Closed { .. } => None,
+            State::Open { until } if now < *until => return None,
+            State::Open { .. } => {
+                let round = self.half_open_rounds.fetch_add(1, Ordering::Relaxed) + 1;
+                *state = State::HalfOpen {
+                    round,
+                    probes_in_flight: 1,
+                    successes: 0,
+                };
+                Some(round)
+            }
+            State::HalfOpen {
+                round,
+                probes_in_flight,
+                ..
+            } => {
+                if *probes_in_flight < self.half_open_probes {
+                    *probes_in_flight += 1;
+                    Some(*round)
+                } else {
+                    return None;
+                }
+            }
+        };
+
+        Some(CircuitBreakerPermit { breaker: self, probe })
+    }
+
+    fn release_probe(&self, probe: u64) {
+        let mut state = self.state.lock().unwrap();
+
+        if let State::HalfOpen {
+            round,
+            probes_in_flight,
+            ..
+        } = &mut *state
+        {
+            if *round == probe {
+                *probes_in_flight = probes_in_flight.saturating_sub(1);
+            }
+        }
+    }
+
+    fn record(&self, probe: Option<u64>, success: bool) -> Option<CircuitBreakerTransition> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        match &mut *state {
+            State::Closed {
+                consecutive_failures,
+                window_start,
+                requests,
+                failures,
+            } => {
+                if now.duration_since(*window_start) > self.window {
+                    *window_start = now;
+                    *requests = 0;
+                    *failures = 0;
+                }
+
+                *requests += 1;
+                if success {
+                    *consecutive_failures = 0;
+                    return None;
+                }
+                *consecutive_failures += 1;
+                *failures += 1;
+
+                let error_rate_exceeded = self.error_rate.is_some_and(|error_rate| {
+                    *requests >= self.minimum_requests && (*failures as f32 / *requests as f32) >= error_rate
+                });
+
+                if *consecutive_failures >= self.failure_threshold || error_rate_exceeded {
+                    *state = State::Open {
+                        until: now + self.open_duration,
+                    };
+                    Some(CircuitBreakerTransition::Opened)
+                } else {
+                    None
+                }
+            }
+            // Late responses of requests sent before the circuit opened.
+            State::Open { .. } => None,
+            State::HalfOpen {
+                round,
+                probes_in_flight,
+                successes,
+            } => {
+                // Only the probes of the current round decide whether the circuit closes, late
+                // responses of requests sent before it opened are ignored.
+                if probe != Some(*round) {
+                    return None;
+                }
+
+                *probes_in_flight = probes_in_flight.saturating_sub(1);
+                if !success {
+                    // Still counted as open in metrics, the circuit never closed.
+                    *state = State::Open {
+                        until: now + self.open_duration,
+                    };
+                    return None;
+                }
+
+                *successes += 1;
+                if *successes >= self.half_open_probes {
+                    *state = State::closed(now);
+                    Some(CircuitBreakerTransition::Closed)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(config: CircuitBreakerConfig) -> CircuitBreaker {
+        CircuitBreaker::new(&config)
+    }
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: Some(3),
+            error_rate: None,
+            minimum_requests: None,
+            window: None,
+            open_duration: Some(Duration::from_secs(60)),
+            half_open_probes: None,
+        }
+    }
+
+    /// Sends a request through the breaker, returning `None` if it was rejected.
+    fn request(breaker: &CircuitBreaker, success: bool) -> Option<Option<CircuitBreakerTransition>> {
+        breaker.try_acquire().map(|permit| permit.record(success))
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let breaker = breaker(config());
+
+        assert_eq!(request(&breaker, false), Some(None));
+        assert_eq!(request(&breaker, false), Some(None));
+        assert_eq!(request(&breaker, false), Some(Some(CircuitBreakerTransition::Opened)));
+        assert!(breaker.try_acquire().is_none());
+    }
+
+    #[test]
+    fn success_resets_consecutive_failures() {
+        let breaker = breaker(config());
+
+        request(&breaker, false);
+        request(&breaker, false);
+        request(&breaker, true);
+        request(&breaker, false);
+        request(&breaker, false);
+        assert!(breaker.try_acquire().is_some());
+    }
+
+    #[test]
+    fn opens_on_error_rate() {
+        let breaker = breaker(CircuitBreakerConfig {
+            failure_threshold: Some(100),
+            error_rate: Some(0.5),
+            minimum_requests: Some(4),
+            window: Some(Duration::from_secs(60)),
+            ..config()
+        });
+
+        assert_eq!(request(&breaker, false), Some(None));
+        assert_eq!(request(&breaker, true), Some(None));
+        assert_eq!(request(&breaker, false), Some(None));
+        assert_eq!(request(&breaker, true), Some(None));
+        assert_eq!(request(&breaker, false), Some(Some(CircuitBreakerTransition::Opened)));
+        assert!(breaker.try_acquire().is_none());
+    }
+
+    #[test]
+    fn half_open_probes() {
+        let breaker = breaker(CircuitBreakerConfig {
+            failure_threshold: Some(1),
+            open_duration: Some(Duration::ZERO),
+            half_open_probes: Some(2),
+            ..config()
+        });
+
+        assert_eq!(request(&breaker, false), Some(Some(CircuitBreakerTransition::Opened)));
+
+        // Only two probes are let through.
+        let first = breaker.try_acquire().unwrap();
+        let second = breaker.try_acquire().unwrap();
+        assert!(breaker.try_acquire().is_none());
+
+        assert_eq!(first.record(true), None);
+        assert_eq!(second.record(true), Some(CircuitBreakerTransition::Closed));
+        assert!(breaker.try_acquire().is_some());
+    }
+
+    #[test]
+    fn failed_probe_reopens() {
+        let breaker = breaker(CircuitBreakerConfig {
+            failure_threshold: Some(1),
+            open_duration: Some(Duration::ZERO),
+            ..config()
+        });
+
+        assert_eq!(request(&breaker, false), Some(Some(CircuitBreakerTransition::Opened)));
+        assert_eq!(request(&breaker, false), Some(None));
+        // Open duration is zero, so the next request is a probe again.
+        let _probe = breaker.try_acquire().unwrap();
+        assert!(breaker.try_acquire().is_none());
+    }
+
+    #[test]
+    fn dropped_probe_releases_its_slot() {
+        let breaker = breaker(CircuitBreakerConfig {
+            failure_threshold: Some(1),
+            open_duration: Some(Duration::ZERO),
+            ..config()
+        });
+
+        assert_eq!(request(&breaker, false), Some(Some(CircuitBreakerTransition::Opened)));
+
+        let probe = breaker.try_acquire().unwrap();
+        assert!(breaker.try_acquire().is_none());
+        drop(probe);
+
+        assert_eq!(request(&breaker, true), Some(Some(CircuitBreakerTransition::Closed)));
+    }
+
+    #[test]
+    fn late_responses_do_not_close_a_half_open_circuit() {
+        let breaker = breaker(CircuitBreakerConfig {
+            failure_threshold: Some(1),
+            open_duration: Some(Duration::ZERO),
+            half_open_probes: Some(2),
+            ..config()
+        });
+
+        let late = breaker.try_acquire().unwrap();
+        assert_eq!(request(&breaker, false), Some(Some(CircuitBreakerTransition::Opened)));
+
+        let failing = breaker.try_acquire().unwrap();
+        let stale = breaker.try_acquire().unwrap();
+        assert_eq!(late.record(true), None);
+        assert!(breaker.try_acquire().is_none());
+
+        // The second probe outlives its half-open round and must not count for the next one.
+        assert_eq!(failing.record(false), None);
+        let first = breaker.try_acquire().unwrap();
+        let second = breaker.try_acquire().unwrap();
+        assert_eq!(stale.record(true), None);
+        assert!(breaker.try_acquire().is_none());
+
+        assert_eq!(first.record(true), None);
+        assert_eq!(second.record(true), Some(CircuitBreakerTransition::Closed));
+    }
+}
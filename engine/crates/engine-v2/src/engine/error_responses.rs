@@ -23,7 +23,7 @@ impl Response {
         Response::refuse_request_with(
             http::StatusCode::UNSUPPORTED_MEDIA_TYPE,
             GraphqlError::new(
-                "Missing or invalid Content-Type header. Only 'application/json' is supported.",
+                "Missing or invalid Content-Type header. Only 'application/json' and 'multipart/form-data' are supported.",
                 ErrorCode::BadRequest,
             ),
         )
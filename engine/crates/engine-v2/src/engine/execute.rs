@@ -1,4 +1,4 @@
-use ::runtime::{error::ErrorResponse, hooks::Hooks, rate_limiting::RateLimitKey};
+use ::runtime::{error::ErrorResponse, hooks::Hooks, rate_limiting::GlobalRateLimitContext};
 use bytes::Bytes;
 use futures::StreamExt;
 use grafbase_telemetry::grafbase_client::Client;
@@ -7,7 +7,7 @@ use std::{future::Future, sync::Arc};
 
 use crate::{
     graphql_over_http::{Http, ResponseFormat},
-    request::{BatchRequest, QueryParamsRequest, Request},
+    request::{multipart, BatchRequest, QueryParamsRequest, Request},
     response::{ErrorCode, GraphqlError, Response},
     Body,
 };
@@ -49,9 +49,23 @@ impl<R: Runtime> Engine<R> {
             // GraphQL-over-HTTP spec:
             //   If the client does not supply a Content-Type header with a POST request,
             //   the server SHOULD reject the request using the appropriate 4xx status code.
-            if !content_type_is_application_json(&parts.headers) {
+            if !content_type_is_application_json(&parts.headers) && multipart_boundary(&parts.headers).is_none() {
                 return Err(Http::error(format, Response::unsupported_media_type()));
             }
+            // Browsers send multipart/form-data requests cross-origin without a CORS preflight,
+            // so we require a header they can't set without one.
+            if multipart_boundary(&parts.headers).is_some() && !has_csrf_prevention_header(&parts.headers) {
+                return Err(Http::error(
+                    format,
+                    Response::refuse_request_with(
+                        http::StatusCode::BAD_REQUEST,
+                        GraphqlError::new(
+                            "Multipart requests must set a non-empty apollo-require-preflight, x-apollo-operation-name or x-grafbase-csrf-protection header",
+                            ErrorCode::BadRequest,
+                        ),
+                    ),
+                ));
+            }
         } else if parts.method != http::Method::GET {
             return Err(Http::error(
                 format,
@@ -82,7 +96,8 @@ impl<R: Runtime> Engine<R> {
         };
 
         // Currently it doesn't rely on authentication, but likely will at some point.
-        if self.runtime.rate_limiter().limit(&RateLimitKey::Global).await.is_err() {
+        let rate_limit_context = GlobalRateLimitContext::new(&headers);
+        if self.runtime.rate_limiter().limit(&rate_limit_context).await.is_err() {
             return Err(Response::gateway_rate_limited());
         }
 
@@ -102,6 +117,7 @@ impl<R: Runtime> Engine<R> {
         &self,
         method: http::method::Method,
         uri: http::Uri,
+        multipart_boundary: Option<String>,
         response_format: ResponseFormat,
         body: F,
     ) -> Result<BatchRequest, http::Response<Body>>
@@ -118,6 +134,17 @@ impl<R: Runtime> Engine<R> {
 
             self.runtime.metrics().record_request_body_size(body.len());
 
+            if let Some(boundary) = multipart_boundary {
+                return multipart::parse(body, boundary).await.map_err(|err| {
+                    Http::error(
+                        response_format,
+                        Response::not_well_formed_graphql_over_http_request(format_args!(
+                            "Multipart request deserialization failure: {err}",
+                        )),
+                    )
+                });
+            }
+
             serde_json::from_slice(&body).map_err(|err| {
                 Http::error(
                     response_format,
@@ -151,12 +178,11 @@ impl<R: Runtime> Engine<R> {
         match request {
             BatchRequest::Single(request) => match request_context.response_format {
                 ResponseFormat::Streaming(format) => {
-                    Http::stream(
-                        format,
-                        hooks_context.clone(),
-                        self.execute_stream(Arc::new(request_context), hooks_context, request),
-                    )
-                    .await
+                    let mut response = self.execute_stream(Arc::new(request_context), hooks_context.clone(), request);
+                    // Ending the stream early still sends the final event of the streaming format.
+                    response.stream = response.stream.take_until(self.runtime.graceful_shutdown()).boxed();
+
+                    Http::stream(format, hooks_context, response).await
                 }
                 ResponseFormat::Complete(format) => {
                     let Some(response) = self
@@ -225,3 +251,20 @@ fn content_type_is_application_json(headers: &http::HeaderMap) -> bool {
 
     without_parameters == APPLICATION_JSON
 }
+
+fn has_csrf_prevention_header(headers: &http::HeaderMap) -> bool {
+    [
+        "apollo-require-preflight",
+        "x-apollo-operation-name",
+        "x-grafbase-csrf-protection",
+    ]
+    .into_iter()
+    .any(|name| headers.get(name).is_some_and(|value| !value.is_empty()))
+}
+
+pub(super) fn multipart_boundary(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|header| header.to_str().ok())
+        .and_then(multipart::extract_boundary)
+}
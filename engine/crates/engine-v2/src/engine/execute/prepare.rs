@@ -3,7 +3,7 @@ use futures::FutureExt;
 use std::sync::Arc;
 
 use crate::{
-    engine::trusted_documents::OperationDocument,
+    engine::{cache::Document, trusted_documents::OperationDocument},
     execution::{ExecutableOperation, PreExecutionContext},
     operation::{Operation, Variables},
     request::Request,
@@ -35,7 +35,11 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
 
     async fn prepare_operation_inner(&mut self, request: Request) -> Result<ExecutableOperation, Response> {
         let result = {
-            let OperationDocument { cache_key, load_fut } = match self.determine_operation_document(&request) {
+            let OperationDocument {
+                cache_key,
+                document,
+                load_fut,
+            } = match self.determine_operation_document(&request) {
                 Ok(doc) => doc,
                 // If we have an error a this stage, it means we couldn't determine what document
                 // to load, so we don't consider it a well-formed GraphQL-over-HTTP request.
@@ -46,11 +50,24 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
                 self.executed_operation_builder.set_cached_plan();
                 self.metrics().record_operation_cache_hit();
 
+                // Operations prepared by another instance sharing the cache are only tracked if we
+                // have their text at hand.
+                if !self.engine.operation_usage.record_use(&cache_key) {
+                    if let Document::Text(text) = &document {
+                        self.engine.operation_usage.track(
+                            cache_key,
+                            request.operation_name.as_deref(),
+                            &document,
+                            text,
+                        );
+                    }
+                }
+
                 Ok(operation)
             } else {
                 self.metrics().record_operation_cache_miss();
                 match load_fut.await {
-                    Ok(document) => Err((cache_key, document)),
+                    Ok(text) => Err((cache_key, document, text)),
                     Err(err) => return Err(Response::request_error(None, [err])),
                 }
             }
@@ -58,14 +75,21 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
 
         let operation = match result {
             Ok(operation) => operation,
-            Err((cache_key, document)) => {
-                let operation = Operation::prepare(self.schema(), &request, &document)
+            Err((cache_key, document, text)) => {
+                let operation = Operation::prepare(self.schema(), &request, &text)
                     .map(Arc::new)
                     .map_err(|mut err| {
                         let attributes = err.take_operation_attributes();
                         Response::request_error(attributes, [err])
                     })?;
 
+                self.engine.operation_usage.track(
+                    cache_key.clone(),
+                    request.operation_name.as_deref(),
+                    &document,
+                    &text,
+                );
+
                 let cache_fut = self.engine.operation_cache.insert(cache_key, operation.clone());
                 self.push_background_future(cache_fut.boxed());
 
@@ -89,10 +113,10 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
             ));
         }
 
-        let variables = Variables::build(self.schema(), &operation, request.variables)
+        let variables = Variables::build(self.schema(), &operation, request.variables, request.uploads)
             .map_err(|errors| Response::request_error(Some(operation.attributes.clone()), errors))?;
 
-        self.finalize_operation(Arc::clone(&operation), variables)
+        self.finalize_operation(Arc::clone(&operation), variables, request.on_error)
             .await
             .map_err(|err| Response::request_error(Some(operation.attributes.clone()), [err]))
     }
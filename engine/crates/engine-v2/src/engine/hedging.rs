@@ -0,0 +1,129 @@
+use std::{collections::VecDeque, sync::Mutex, time::Duration};
+
+use schema::{GraphqlEndpointId, HedgingConfig, Schema};
+
+use super::Runtime;
+
+/// Number of recent response times kept to compute the percentile.
+const LATENCY_SAMPLES: usize = 100;
+/// Below this number of response times, the fixed delay is used.
+const MIN_LATENCY_SAMPLES: usize = 20;
+
+#[derive(id_derives::IndexedFields)]
+pub(super) struct Hedgers {
+    #[indexed_by(GraphqlEndpointId)]
+    by_graphql_endpoints: Vec<Option<Hedger>>,
+}
+
+impl Hedgers {
+    pub fn build(schema: &Schema) -> Self {
+        Self {
+            by_graphql_endpoints: schema
+                .graphql_endpoints()
+                .map(|endpoint| {
+                    let config = endpoint.config.hedging.as_ref()?;
+
+                    // Hedged requests are withdrawn from the retry budget.
+                    if endpoint.config.retry.is_none() && schema.settings.retry.is_none() {
+                        tracing::warn!(
+                            "Hedging is ignored for subgraph '{}', it requires retries to be enabled",
+                            endpoint.subgraph_name()
+                        );
+                        return None;
+                    }
+
+                    Some(Hedger::new(config))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<R: Runtime> super::Engine<R> {
+    pub(crate) fn get_hedger_for_query(&self, endpoint_id: GraphqlEndpointId) -> Option<&Hedger> {
+        self.hedgers[endpoint_id].as_ref()
+    }
+}
+
+pub(crate) struct Hedger {
+    delay: Duration,
+    percentile: Option<f32>,
+    latencies: Mutex<VecDeque<Duration>>,
+}
+
+impl Hedger {
+    fn new(config: &HedgingConfig) -> Self {
+        Self {
+            delay: config.delay.unwrap_or(Duration::from_millis(100)),
+            percentile: config.percentile.map(|percentile| percentile.clamp(0.0, 1.0)),
+            latencies: Mutex::new(VecDeque::with_capacity(LATENCY_SAMPLES)),
+        }
+    }
+
+    /// How long to wait for the first request before sending the hedged one.
+    pub fn delay(&self) -> Duration {
+        let Some(percentile) = self.percentile else {
+            return self.delay;
+        };
+
+        let mut latencies = {
+            let latencies = self.latencies.lock().unwrap();
+            if latencies.len() < MIN_LATENCY_SAMPLES {
+                return self.delay;
+            }
+            latencies.iter().copied().collect::<Vec<_>>()
+        };
+
+        latencies.sort_unstable();
+        let index = ((latencies.len() - 1) as f32 * percentile).round() as usize;
+        latencies[index]
+    }
+
+    pub fn record_latency(&self, latency: Duration) {
+        if self.percentile.is_none() {
+            return;
+        }
+
+        let mut latencies = self.latencies.lock().unwrap();
+        if latencies.len() == LATENCY_SAMPLES {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_delay() {
+        let hedger = Hedger::new(&HedgingConfig {
+            delay: Some(Duration::from_millis(30)),
+            percentile: None,
+        });
+
+        hedger.record_latency(Duration::from_secs(1));
+        assert_eq!(hedger.delay(), Duration::from_millis(30));
+    }
+
+    #[test]
+    fn percentile_delay() {
+        let hedger = Hedger::new(&HedgingConfig {
+            delay: None,
+            percentile: Some(0.9),
+        });
+
+        for ms in 1..=10 {
+            hedger.record_latency(Duration::from_millis(ms));
+        }
+        // Not enough samples yet.
+        assert_eq!(hedger.delay(), Duration::from_millis(100));
+
+        for ms in 11..=150 {
+            hedger.record_latency(Duration::from_millis(ms));
+        }
+        // Only the last 100 samples, 51ms to 150ms, are kept.
+        assert_eq!(hedger.delay(), Duration::from_millis(140));
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use schema::{GraphqlEndpointId, LoadBalancingConfig, LoadBalancingStrategy, Schema};
+use url::Url;
+use web_time::Instant;
+
+use super::Runtime;
+
+#[derive(id_derives::IndexedFields)]
+pub(super) struct LoadBalancers {
+    #[indexed_by(GraphqlEndpointId)]
+    by_graphql_endpoints: Vec<Option<LoadBalancer>>,
+}
+
+impl LoadBalancers {
+    pub fn build(schema: &Schema) -> Self {
+        Self {
+            by_graphql_endpoints: schema
+                .graphql_endpoints()
+                .map(|endpoint| {
+                    let config = endpoint.config.load_balancing.as_ref()?;
+                    Some(LoadBalancer::new(
+                        config,
+                        config.url_ids.iter().map(|id| schema.walk(*id).clone()).collect(),
+                    ))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<R: Runtime> super::Engine<R> {
+    pub(crate) fn get_load_balancer(&self, endpoint_id: GraphqlEndpointId) -> Option<&LoadBalancer> {
+        self.load_balancers[endpoint_id].as_ref()
+    }
+}
+
+/// Spreads the requests of a subgraph across its replicas. Replicas failing to connect are
+/// left out for a while.
+pub(crate) struct LoadBalancer {
+    strategy: LoadBalancingStrategy,
+    ejection_duration: Duration,
+    upstreams: Vec<Upstream>,
+    next: AtomicUsize,
+}
+
+struct Upstream {
+    url: Url,
+    inflight: AtomicUsize,
+    ejected_until: Mutex<Option<Instant>>,
+}
+
+impl Upstream {
+    fn is_ejected(&self, now: Instant) -> bool {
+        self.ejected_until.lock().unwrap().is_some_and(|until| now < until)
+    }
+}
+
+impl LoadBalancer {
+    fn new(config: &LoadBalancingConfig, urls: Vec<Url>) -> Self {
+        Self {
+            strategy: config.strategy,
+            ejection_duration: config.ejection_duration,
+            upstreams: urls
+                .into_iter()
+                .map(|url| Upstream {
+                    url,
+                    inflight: AtomicUsize::new(0),
+                    ejected_until: Mutex::new(None),
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.upstreams.len()
+    }
+
+    /// Chooses the upstream for the next request, skipping those already tried for it. If every
+    /// remaining upstream is ejected, one of them is chosen anyway rather than failing the request.
+    pub fn select(&self, tried: &[usize]) -> Option<UpstreamGuard<'_>> {
+        let now = Instant::now();
+        let candidates = (0..self.upstreams.len()).filter(|index| !tried.contains(index));
+        let mut pool = candidates
+            .clone()
+            .filter(|index| !self.upstreams[*index].is_ejected(now))
+            .collect::<Vec<_>>();
+        if pool.is_empty() {
+            pool = candidates.collect();
+        }
+        if pool.is_empty() {
+            return None;
+        }
+
+        // Rotating the starting point also spreads ties between equally loaded upstreams.
+        let offset = self.next.fetch_add(1, Ordering::Relaxed);
+        let mut rotated = (0..pool.len()).map(|i| pool[(offset + i) % pool.len()]);
+        let index = match self.strategy {
+            LoadBalancingStrategy::RoundRobin => rotated.next()?,
+            LoadBalancingStrategy::LeastInflight => {
+                rotated.min_by_key(|index| self.upstreams[*index].inflight.load(Ordering::Relaxed))?
+            }
+        };
+
+        self.upstreams[index].inflight.fetch_add(1, Ordering::Relaxed);
+        Some(UpstreamGuard { balancer: self, index })
+    }
+}
+
+/// An upstream chosen for a request, counted as in flight until dropped.
+pub(crate) struct UpstreamGuard<'a> {
+    balancer: &'a LoadBalancer,
+    index: usize,
+}
+
+impl<'a> UpstreamGuard<'a> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn url(&self) -> &'a Url {
+        &self.balancer.upstreams[self.index].url
+    }
+
+    /// Leaves the upstream out of the selection for the ejection duration.
+    pub fn eject(&self) {
+        tracing::warn!(
+            "ejecting subgraph upstream {} for {:?}",
+            self.url(),
+            self.balancer.ejection_duration
+        );
+        *self.balancer.upstreams[self.index].ejected_until.lock().unwrap() =
+            Some(Instant::now() + self.balancer.ejection_duration);
+    }
+}
+
+impl Drop for UpstreamGuard<'_> {
+    fn drop(&mut self) {
+        self.balancer.upstreams[self.index]
+            .inflight
+            .fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balancer(strategy: LoadBalancingStrategy) -> LoadBalancer {
+        LoadBalancer::new(
+            &LoadBalancingConfig {
+                strategy,
+                ejection_duration: Duration::from_secs(60),
+                url_ids: Vec::new(),
+            },
+            ["http://a/graphql", "http://b/graphql", "http://c/graphql"]
+                .into_iter()
+                .map(|url| url.parse().unwrap())
+                .collect(),
+        )
+    }
+
+    fn select(balancer: &LoadBalancer, tried: &[usize]) -> usize {
+        balancer.select(tried).unwrap().index()
+    }
+
+    #[test]
+    fn round_robin() {
+        let balancer = balancer(LoadBalancingStrategy::RoundRobin);
+
+        let selected = (0..6).map(|_| select(&balancer, &[])).collect::<Vec<_>>();
+        assert_eq!(selected, vec![0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn least_inflight() {
+        let balancer = balancer(LoadBalancingStrategy::LeastInflight);
+
+        let first = balancer.select(&[]).unwrap();
+        let second = balancer.select(&[]).unwrap();
+        assert_eq!((first.index(), second.index()), (0, 1));
+        assert_eq!(select(&balancer, &[]), 2);
+
+        drop(first);
+        assert_eq!(select(&balancer, &[]), 0);
+    }
+
+    #[test]
+    fn ejected_upstreams_are_skipped() {
+        let balancer = balancer(LoadBalancingStrategy::RoundRobin);
+
+        balancer.select(&[]).unwrap().eject();
+        let selected = (0..4).map(|_| select(&balancer, &[])).collect::<Vec<_>>();
+        assert!(!selected.contains(&0));
+    }
+
+    #[test]
+    fn tried_upstreams_are_skipped() {
+        let balancer = balancer(LoadBalancingStrategy::RoundRobin);
+
+        assert_eq!(select(&balancer, &[0, 1]), 2);
+        assert!(balancer.select(&[0, 1, 2]).is_none());
+    }
+
+    #[test]
+    fn all_ejected_falls_back_to_ejected_upstreams() {
+        let balancer = balancer(LoadBalancingStrategy::RoundRobin);
+
+        for _ in 0..3 {
+            balancer.select(&[]).unwrap().eject();
+        }
+        assert!(balancer.select(&[]).is_some());
+    }
+}
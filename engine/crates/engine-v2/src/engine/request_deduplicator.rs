@@ -0,0 +1,253 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use futures::channel::oneshot;
+use runtime::bytes::OwnedOrSharedBytes;
+
+use crate::execution::ExecutionResult;
+
+/// Identifies identical subgraph requests: same subgraph, URL, body and headers.
+pub(crate) type RequestKey = [u8; 32];
+
+type InFlight = Arc<Mutex<HashMap<RequestKey, Waiters>>>;
+
+type Waiters = VecDeque<oneshot::Sender<Outcome>>;
+
+type SubgraphResult = ExecutionResult<http::Response<OwnedOrSharedBytes>>;
+
+/// Shares the outcome of an in-flight subgraph request with all the identical requests made
+/// while it's running, whether they come from the same operation or from concurrent ones.
+///
+/// Errors are shared as well, so a failing subgraph isn't hit by every waiting request. If the
+/// request is cancelled instead, a single waiting request is sent in its place.
+#[derive(Default)]
+pub(crate) struct RequestDeduplicator {
+    in_flight: InFlight,
+}
+
+pub(crate) enum Deduplicated<T> {
+    /// The outcome of another identical request.
+    Shared(SubgraphResult),
+    /// The result of our own request.
+    Sent(T),
+}
+
+enum Outcome {
+    Shared(SubgraphResult),
+    /// The request we were waiting for was cancelled, we're sending it instead.
+    Lead(Leader),
+}
+
+impl RequestDeduplicator {
+    /// Runs `fetch` unless an identical request is already in flight, in which case its outcome
+    /// is used instead.
+    pub async fn run<F>(&self, key: RequestKey, fetch: F) -> Deduplicated<SubgraphResult>
+    where
+        F: Future<Output = SubgraphResult>,
+    {
+        let waiting = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+
+            match in_flight.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    let (sender, receiver) = oneshot::channel();
+                    entry.get_mut().push_back(sender);
+                    Some(receiver)
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(VecDeque::new());
+                    None
+                }
+            }
+        };
+
+        let leader = match waiting {
+            Some(receiver) => match receiver.await {
+                Ok(Outcome::Shared(result)) => return Deduplicated::Shared(result),
+                Ok(Outcome::Lead(leader)) => leader,
+                // Waiters are always notified, but sending our own request is the safe choice.
+                Err(_) => return Deduplicated::Sent(fetch.await),
+            },
+            None => Leader {
+                in_flight: self.in_flight.clone(),
+                key: Some(key),
+            },
+        };
+
+        let result = fetch.await.map(share_body);
+
+        for waiter in leader.finish() {
+            waiter.send(Outcome::Shared(copy_result(&result))).ok();
+        }
+
+        Deduplicated::Sent(result)
+    }
+}
+
+/// Removes the in-flight entry once the request is done. If it's dropped before, the request is
+/// handed over to the first waiter still around, or the entry removed if there are none.
+struct Leader {
+    in_flight: InFlight,
+    key: Option<RequestKey>,
+}
+
+impl Leader {
+    fn finish(mut self) -> Waiters {
+        let Some(key) = self.key.take() else {
+            return Waiters::new();
+        };
+
+        self.in_flight.lock().unwrap().remove(&key).unwrap_or_default()
+    }
+}
+
+impl Drop for Leader {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+
+        let Entry::Occupied(mut entry) = in_flight.entry(key) else {
+            return;
+        };
+
+        while let Some(waiter) = entry.get_mut().pop_front() {
+            let leader = Leader {
+                in_flight: self.in_flight.clone(),
+                key: Some(key),
+            };
+
+            match waiter.send(Outcome::Lead(leader)) {
+                Ok(()) => return,
+                // The waiter is gone. Defusing the returned leader, as we're holding the lock.
+                Err(Outcome::Lead(mut leader)) => leader.key = None,
+                Err(Outcome::Shared(_)) => (),
+            }
+        }
+
+        entry.remove();
+    }
+}
+
+fn share_body(response: http::Response<OwnedOrSharedBytes>) -> http::Response<OwnedOrSharedBytes> {
+    response.map(|body| match body {
+        OwnedOrSharedBytes::Owned(bytes) => OwnedOrSharedBytes::Shared(Bytes::from(bytes)),
+        shared => shared,
+    })
+}
+
+fn copy_result(result: &SubgraphResult) -> SubgraphResult {
+    let response = match result {
+        Ok(response) => response,
+        Err(err) => return Err(err.clone()),
+    };
+
+    let mut copy = http::Response::new(response.body().clone());
+    *copy.status_mut() = response.status();
+    *copy.version_mut() = response.version();
+    *copy.headers_mut() = response.headers().clone();
+
+    Ok(copy)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::{executor::block_on, future};
+
+    use super::*;
+    use crate::execution::ExecutionError;
+
+    fn response(body: &'static [u8]) -> SubgraphResult {
+        Ok(http::Response::new(OwnedOrSharedBytes::Owned(body.to_vec())))
+    }
+
+    fn body(response: &http::Response<OwnedOrSharedBytes>) -> &[u8] {
+        match response.body() {
+            OwnedOrSharedBytes::Owned(bytes) => bytes,
+            OwnedOrSharedBytes::Shared(bytes) => bytes,
+        }
+    }
+
+    #[test]
+    fn shares_in_flight_response() {
+        let deduplicator = RequestDeduplicator::default();
+        let (release, released) = oneshot::channel::<()>();
+
+        let leader = deduplicator.run([0; 32], async move {
+            released.await.ok();
+            response(b"leader")
+        });
+        let follower = deduplicator.run([0; 32], async { response(b"follower") });
+        let other = deduplicator.run([1; 32], async { response(b"other") });
+
+        let (leader, follower, other, _) =
+            block_on(async { futures::join!(leader, follower, other, async move { release.send(()).ok() }) });
+
+        assert!(matches!(leader, Deduplicated::Sent(Ok(ref response)) if body(response) == b"leader"));
+        assert!(matches!(follower, Deduplicated::Shared(Ok(ref response)) if body(response) == b"leader"));
+        assert!(matches!(other, Deduplicated::Sent(Ok(ref response)) if body(response) == b"other"));
+
+        // Nothing in flight anymore.
+        let again = block_on(deduplicator.run([0; 32], async { response(b"again") }));
+        assert!(matches!(again, Deduplicated::Sent(Ok(ref response)) if body(response) == b"again"));
+    }
+
+    #[test]
+    fn errors_are_shared() {
+        let deduplicator = RequestDeduplicator::default();
+        let (release, released) = oneshot::channel::<()>();
+
+        let leader = deduplicator.run([0; 32], async move {
+            released.await.ok();
+            Err(ExecutionError::Internal("failed".into()))
+        });
+        let follower = deduplicator.run([0; 32], async { response(b"follower") });
+
+        let (leader, follower, _) =
+            block_on(async { futures::join!(leader, follower, async move { release.send(()).ok() }) });
+
+        assert!(matches!(leader, Deduplicated::Sent(Err(_))));
+        assert!(
+            matches!(follower, Deduplicated::Shared(Err(ExecutionError::Internal(ref message))) if message == "failed")
+        );
+    }
+
+    #[test]
+    fn cancelled_request_is_sent_by_a_single_waiter() {
+        let deduplicator = RequestDeduplicator::default();
+        let sent = AtomicUsize::new(0);
+        let fetch = |body: &'static [u8]| {
+            let sent = &sent;
+            async move {
+                sent.fetch_add(1, Ordering::Relaxed);
+                response(body)
+            }
+        };
+
+        let (first, second) = block_on(async {
+            let mut leader = Box::pin(deduplicator.run([0; 32], future::pending()));
+            assert!(futures::poll!(leader.as_mut()).is_pending());
+
+            let first = deduplicator.run([0; 32], fetch(b"first"));
+            let second = deduplicator.run([0; 32], fetch(b"second"));
+
+            let (first, second, ()) = futures::join!(first, second, async move { drop(leader) });
+            (first, second)
+        });
+
+        assert!(matches!(first, Deduplicated::Sent(Ok(ref response)) if body(response) == b"first"));
+        assert!(matches!(second, Deduplicated::Shared(Ok(ref response)) if body(response) == b"first"));
+        assert_eq!(sent.load(Ordering::Relaxed), 1);
+
+        // Nothing in flight anymore.
+        assert!(deduplicator.in_flight.lock().unwrap().is_empty());
+    }
+}
@@ -19,6 +19,12 @@ pub trait Runtime: Send + Sync + 'static {
     fn rate_limiter(&self) -> &RateLimiter;
     fn sleep(&self, duration: std::time::Duration) -> impl Future<Output = ()> + Send;
     fn entity_cache(&self) -> &dyn EntityCache;
+
+    /// Resolves once the server starts shutting down. Streaming responses are completed at that
+    /// point and websocket connections closed, so that clients reconnect elsewhere.
+    fn graceful_shutdown(&self) -> impl Future<Output = ()> + Send + 'static {
+        std::future::pending()
+    }
 }
 
 pub(crate) trait RuntimeExt: Runtime {
@@ -0,0 +1,238 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{
+    channel::{mpsc, oneshot},
+    stream::BoxStream,
+    Stream, StreamExt,
+};
+use runtime::{
+    bytes::OwnedOrSharedBytes,
+    fetch::{FetchError, FetchResult},
+};
+
+use crate::execution::ExecutionResult;
+
+/// How many events can be buffered for a single subscriber before it's considered too slow and
+/// disconnected from the shared upstream stream.
+const SUBSCRIBER_BUFFER_SIZE: usize = 64;
+
+/// Identifies identical upstream subscriptions: same subgraph, URL, query, variables and headers.
+pub(crate) type SubscriptionKey = [u8; 32];
+
+#[derive(Default)]
+pub(crate) struct SubscriptionMultiplexers {
+    pub websocket: SubscriptionMultiplexer<Arc<serde_json::Value>>,
+    pub sse: SubscriptionMultiplexer<OwnedOrSharedBytes>,
+}
+
+/// Shares a single upstream subscription stream between all the local subscribers with the same
+/// key. The upstream stream is closed as soon as its last subscriber leaves.
+pub(crate) struct SubscriptionMultiplexer<T> {
+    upstreams: Upstreams<T>,
+}
+
+type Upstreams<T> = Arc<Mutex<HashMap<SubscriptionKey, Arc<Upstream<T>>>>>;
+
+impl<T> Default for SubscriptionMultiplexer<T> {
+    fn default() -> Self {
+        Self {
+            upstreams: Default::default(),
+        }
+    }
+}
+
+struct Upstream<T> {
+    subscribers: Mutex<Vec<mpsc::Sender<FetchResult<T>>>>,
+    /// Dropped when the last subscriber leaves, which stops the forwarding of the upstream stream.
+    stop: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> SubscriptionMultiplexer<T> {
+    /// Subscribes to the upstream stream identified by the key, using `connect` to open it if
+    /// there is none yet.
+    pub async fn subscribe<S>(
+        &self,
+        key: SubscriptionKey,
+        connect: impl Future<Output = ExecutionResult<S>> + Send,
+    ) -> ExecutionResult<BoxStream<'static, FetchResult<T>>>
+    where
+        S: Stream<Item = FetchResult<T>> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(SUBSCRIBER_BUFFER_SIZE);
+
+        let (upstream, stop) = {
+            let mut upstreams = self.upstreams.lock().unwrap();
+
+            if let Some(upstream) = upstreams.get(&key) {
+                upstream.subscribers.lock().unwrap().push(sender);
+                return Ok(Subscriber::new(&self.upstreams, key, upstream, receiver).boxed());
+            }
+
+            let (stop_sender, stop) = oneshot::channel();
+            let upstream = Arc::new(Upstream {
+                subscribers: Mutex::new(vec![sender]),
+                stop: Mutex::new(Some(stop_sender)),
+            });
+            upstreams.insert(key, Arc::clone(&upstream));
+
+            (upstream, stop)
+        };
+
+        let subscriber = Subscriber::new(&self.upstreams, key, &upstream, receiver);
+
+        // If we're dropped while connecting, the subscribers which joined in the meantime must not
+        // wait forever for a stream that will never be forwarded.
+        let mut connecting = Connecting {
+            upstreams: &self.upstreams,
+            key,
+            upstream: &upstream,
+            done: false,
+        };
+
+        let result = connect.await;
+        connecting.done = true;
+
+        match result {
+            Ok(stream) => {
+                async_runtime::spawn(forward(
+                    Arc::clone(&self.upstreams),
+                    key,
+                    Arc::clone(&upstream),
+                    stream.take_until(stop),
+                ));
+                Ok(subscriber.boxed())
+            }
+            Err(err) => {
+                fail(&self.upstreams, &key, &upstream, FetchError::any(&err));
+                Err(err)
+            }
+        }
+    }
+}
+
+struct Connecting<'a, T> {
+    upstreams: &'a Upstreams<T>,
+    key: SubscriptionKey,
+    upstream: &'a Arc<Upstream<T>>,
+    done: bool,
+}
+
+impl<T> Drop for Connecting<'_, T> {
+    fn drop(&mut self) {
+        if !self.done {
+            let error = FetchError::any("The subscription shared with this request was cancelled while connecting");
+            fail(self.upstreams, &self.key, self.upstream, error);
+        }
+    }
+}
+
+/// Removes an upstream which could not be opened, its subscribers get the error as their only event.
+fn fail<T>(upstreams: &Upstreams<T>, key: &SubscriptionKey, upstream: &Arc<Upstream<T>>, error: FetchError) {
+    remove(&mut upstreams.lock().unwrap(), key, upstream);
+
+    for mut subscriber in upstream.subscribers.lock().unwrap().drain(..) {
+        subscriber.try_send(Err(error.clone())).ok();
+    }
+}
+
+/// The stream of a single subscriber, which leaves the upstream when dropped.
+struct Subscriber<T> {
+    receiver: mpsc::Receiver<FetchResult<T>>,
+    upstreams: Upstreams<T>,
+    key: SubscriptionKey,
+    upstream: Arc<Upstream<T>>,
+}
+
+impl<T> Subscriber<T> {
+    fn new(
+        upstreams: &Upstreams<T>,
+        key: SubscriptionKey,
+        upstream: &Arc<Upstream<T>>,
+        receiver: mpsc::Receiver<FetchResult<T>>,
+    ) -> Self {
+        Subscriber {
+            receiver,
+            upstreams: Arc::clone(upstreams),
+            key,
+            upstream: Arc::clone(upstream),
+        }
+    }
+}
+
+impl<T> Stream for Subscriber<T> {
+    type Item = FetchResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl<T> Drop for Subscriber<T> {
+    fn drop(&mut self) {
+        self.receiver.close();
+
+        // Same lock order as `subscribe`, so that nobody can join while we're closing the upstream.
+        let mut upstreams = self.upstreams.lock().unwrap();
+        let mut subscribers = self.upstream.subscribers.lock().unwrap();
+        subscribers.retain(|subscriber| !subscriber.is_closed());
+
+        if subscribers.is_empty() {
+            drop(subscribers);
+            remove(&mut upstreams, &self.key, &self.upstream);
+            self.upstream.stop.lock().unwrap().take();
+        }
+    }
+}
+
+async fn forward<T, S>(upstreams: Upstreams<T>, key: SubscriptionKey, upstream: Arc<Upstream<T>>, stream: S)
+where
+    T: Clone + Send + Sync + 'static,
+    S: Stream<Item = FetchResult<T>> + Send + 'static,
+{
+    let mut stream = std::pin::pin!(stream);
+
+    while let Some(event) = stream.next().await {
+        let has_subscribers = {
+            let mut subscribers = upstream.subscribers.lock().unwrap();
+
+            subscribers.retain_mut(|subscriber| match subscriber.try_send(event.clone()) {
+                Ok(()) => true,
+                Err(err) => {
+                    if err.is_full() {
+                        tracing::warn!("Disconnecting a subscriber lagging behind a shared subscription");
+                    }
+                    false
+                }
+            });
+
+            !subscribers.is_empty()
+        };
+
+        if !has_subscribers {
+            // Checking again while holding the map lock, so that nobody can join in the meantime.
+            let mut current_upstreams = upstreams.lock().unwrap();
+            if upstream.subscribers.lock().unwrap().is_empty() {
+                remove(&mut current_upstreams, &key, &upstream);
+                return;
+            }
+        }
+    }
+
+    remove(&mut upstreams.lock().unwrap(), &key, &upstream);
+}
+
+fn remove<T>(
+    upstreams: &mut HashMap<SubscriptionKey, Arc<Upstream<T>>>,
+    key: &SubscriptionKey,
+    upstream: &Arc<Upstream<T>>,
+) {
+    if upstreams.get(key).is_some_and(|current| Arc::ptr_eq(current, upstream)) {
+        upstreams.remove(key);
+    }
+}
@@ -10,7 +10,7 @@ use engine::PersistedQueryRequestExtension;
 use futures::{future::BoxFuture, FutureExt};
 use grafbase_telemetry::grafbase_client::X_GRAFBASE_CLIENT_NAME;
 use runtime::trusted_documents_client::TrustedDocumentsError;
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 use super::{
     cache::{Document, Key},
@@ -21,6 +21,7 @@ type DocumentFuture<'a> = BoxFuture<'a, Result<Cow<'a, str>, GraphqlError>>;
 
 pub(crate) struct OperationDocument<'a> {
     pub cache_key: String,
+    pub document: Document<'a>,
     pub load_fut: DocumentFuture<'a>,
 }
 
@@ -61,6 +62,7 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
                             document: Document::Text(document),
                         }
                         .to_string(),
+                        document: Document::Text(document),
                         load_fut: Box::pin(std::future::ready(Ok(Cow::Borrowed(document)))),
                     })
                 } else {
@@ -91,16 +93,19 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
                     unreachable!()
                 };
 
+                let document = Document::TrustedDocumentId {
+                    client_name,
+                    doc_id: doc_id.clone(),
+                };
+
                 Ok(OperationDocument {
                     cache_key: Key::Operation {
                         name,
                         schema,
-                        document: Document::TrustedDocumentId {
-                            client_name,
-                            doc_id: doc_id.clone(),
-                        },
+                        document: document.clone(),
                     }
                     .to_string(),
+                    document,
                     load_fut: handle_trusted_document_query(self.engine, client_name, doc_id).boxed(),
                 })
             }
@@ -117,7 +122,8 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
                         document: Document::AutomaticallyPersistedQuery(ext),
                     }
                     .to_string(),
-                    load_fut: handle_apq(query, ext).boxed(),
+                    document: Document::AutomaticallyPersistedQuery(ext),
+                    load_fut: handle_apq(self.engine, query, ext).boxed(),
                 })
             }
             (false, None, _) => {
@@ -133,6 +139,7 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
                         document: Document::Text(document),
                     }
                     .to_string(),
+                    document: Document::Text(document),
                     load_fut: Box::pin(std::future::ready(Ok(Cow::Borrowed(document)))),
                 })
             }
@@ -165,10 +172,11 @@ async fn handle_trusted_document_query<'ctx, 'r, R: Runtime>(
 }
 
 /// Handle a request using Automatic Persisted Queries.
-/// We don't cache anything here, we only rely on the operation cache. We might want to use an
-/// external cache for this one day, but not another in-memory cache.
+/// Documents are stored in the KV store, so that a query registered on one gateway instance can
+/// be used on the others when the store is shared. The operation cache is still checked first.
 #[tracing::instrument(skip_all)]
-async fn handle_apq<'r, 'f>(
+async fn handle_apq<'ctx, 'r, R: Runtime>(
+    engine: &'ctx Engine<R>,
     query: &'r str,
     ext: &'r PersistedQueryRequestExtension,
 ) -> Result<Cow<'r, str>, GraphqlError> {
@@ -179,6 +187,8 @@ async fn handle_apq<'r, 'f>(
         ));
     }
 
+    let key = apq_key(ext);
+
     if !query.is_empty() {
         use sha2::{Digest, Sha256};
         let digest = <Sha256 as Digest>::digest(query.as_bytes()).to_vec();
@@ -188,11 +198,37 @@ async fn handle_apq<'r, 'f>(
                 ErrorCode::PersistedQueryError,
             ));
         }
+
+        if let Err(err) = engine
+            .runtime
+            .kv()
+            .put(&key, Cow::Borrowed(query.as_bytes()), Some(APQ_TTL))
+            .await
+        {
+            tracing::error!("Failed to store persisted query: {err}");
+        }
+
         return Ok(Cow::Borrowed(query));
     }
 
+    match engine.runtime.kv().get(&key, None).await {
+        Ok(Some(bytes)) => match String::from_utf8(bytes) {
+            Ok(query) => return Ok(Cow::Owned(query)),
+            Err(err) => tracing::error!("Invalid persisted query in the KV store: {err}"),
+        },
+        Ok(None) => {}
+        Err(err) => tracing::error!("Failed to fetch persisted query: {err}"),
+    }
+
     Err(GraphqlError::new(
         "Persisted query not found",
         ErrorCode::PersistedQueryNotFound,
     ))
 }
+
+/// How long automatic persisted queries are kept in the KV store.
+const APQ_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn apq_key(ext: &PersistedQueryRequestExtension) -> String {
+    format!("apq.v{}.sha256.{}", ext.version, hex::encode(&ext.sha256_hash))
+}
@@ -0,0 +1,208 @@
+//! Tracking of the most used operations, so that a new engine can prepare them before serving any
+//! request, for example after a graph update.
+
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::Poll,
+};
+
+use ::runtime::operation_cache::OperationCache;
+use engine::PersistedQueryRequestExtension;
+
+use crate::{operation::Operation, request::Request, Runtime};
+
+use super::{
+    cache::{Document, Key},
+    Engine,
+};
+
+/// Maximum number of distinct operations for which we keep track of the usage.
+const TRACKED_OPERATIONS_LIMIT: u64 = 1000;
+
+/// An operation served by an engine, which can be prepared ahead of time by another one with
+/// [Engine::warm].
+#[derive(Clone)]
+pub struct WarmingOperation {
+    name: Option<String>,
+    source: DocumentSource,
+    document: Arc<str>,
+    count: u64,
+}
+
+#[derive(Clone)]
+enum DocumentSource {
+    Text,
+    AutomaticallyPersistedQuery(PersistedQueryRequestExtension),
+    TrustedDocumentId { client_name: String, doc_id: String },
+}
+
+impl WarmingOperation {
+    fn new(name: Option<&str>, document: &Document<'_>, text: &str) -> Self {
+        let source = match document {
+            Document::Text(_) => DocumentSource::Text,
+            Document::AutomaticallyPersistedQuery(ext) => DocumentSource::AutomaticallyPersistedQuery((*ext).clone()),
+            Document::TrustedDocumentId { client_name, doc_id } => DocumentSource::TrustedDocumentId {
+                client_name: client_name.to_string(),
+                doc_id: doc_id.to_string(),
+            },
+        };
+
+        WarmingOperation {
+            name: name.map(str::to_string),
+            source,
+            document: text.into(),
+            count: 0,
+        }
+    }
+
+    fn cache_document(&self) -> Document<'_> {
+        match &self.source {
+            DocumentSource::Text => Document::Text(&self.document),
+            DocumentSource::AutomaticallyPersistedQuery(ext) => Document::AutomaticallyPersistedQuery(ext),
+            DocumentSource::TrustedDocumentId { client_name, doc_id } => Document::TrustedDocumentId {
+                client_name,
+                doc_id: Cow::Borrowed(doc_id),
+            },
+        }
+    }
+}
+
+struct TrackedOperation {
+    operation: WarmingOperation,
+    count: AtomicU64,
+}
+
+/// Usage count of the operations served by an engine, keyed by operation cache key.
+pub(super) struct OperationUsage {
+    operations: mini_moka::sync::Cache<String, Arc<TrackedOperation>>,
+}
+
+impl Default for OperationUsage {
+    fn default() -> Self {
+        OperationUsage {
+            operations: mini_moka::sync::Cache::builder()
+                .max_capacity(TRACKED_OPERATIONS_LIMIT)
+                .build(),
+        }
+    }
+}
+
+impl OperationUsage {
+    /// Increments the usage of an operation, returns false if it isn't tracked yet.
+    // moka-cache does require a &String rather than a &str
+    #[allow(clippy::ptr_arg)]
+    pub fn record_use(&self, cache_key: &String) -> bool {
+        match self.operations.get(cache_key) {
+            Some(tracked) => {
+                tracked.count.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn track(&self, cache_key: String, name: Option<&str>, document: &Document<'_>, text: &str) {
+        self.insert(cache_key, WarmingOperation::new(name, document, text), 1);
+    }
+
+    fn insert(&self, cache_key: String, operation: WarmingOperation, count: u64) {
+        self.operations.insert(
+            cache_key,
+            Arc::new(TrackedOperation {
+                operation,
+                count: AtomicU64::new(count),
+            }),
+        );
+    }
+
+    fn most_used(&self, limit: usize) -> Vec<WarmingOperation> {
+        let mut operations = self
+            .operations
+            .iter()
+            .map(|entry| {
+                let mut operation = entry.value().operation.clone();
+                operation.count = entry.value().count.load(Ordering::Relaxed);
+                operation
+            })
+            .collect::<Vec<_>>();
+
+        operations.sort_unstable_by_key(|operation| std::cmp::Reverse(operation.count));
+        operations.truncate(limit);
+        operations
+    }
+}
+
+impl<R: Runtime> Engine<R> {
+    /// The operations most used by this engine, in decreasing order of usage.
+    pub fn most_used_operations(&self, limit: usize) -> Vec<WarmingOperation> {
+        self.operation_usage.most_used(limit)
+    }
+
+    /// Prepares the given operations against the schema of this engine and stores them in the
+    /// operation cache. Operations which aren't valid anymore are skipped. Returns the number of
+    /// operations which are ready to be served from the cache.
+    ///
+    /// Preparing an operation is CPU-bound, so we yield back to the executor after each one to
+    /// avoid starving the requests served in the meantime.
+    pub async fn warm(&self, operations: Vec<WarmingOperation>) -> usize {
+        let mut warmed = 0;
+
+        for operation in operations {
+            let cache_key = Key::Operation {
+                name: operation.name.as_deref(),
+                schema: &self.schema,
+                document: operation.cache_document(),
+            }
+            .to_string();
+
+            if self.operation_cache.get(&cache_key).await.is_none() {
+                let request = Request {
+                    query: None,
+                    operation_name: operation.name.clone(),
+                    doc_id: None,
+                    variables: Default::default(),
+                    extensions: Default::default(),
+                    on_error: Default::default(),
+                    uploads: Vec::new(),
+                };
+
+                let prepared = Operation::prepare(&self.schema, &request, &operation.document);
+                yield_now().await;
+
+                match prepared {
+                    Ok(prepared) => self.operation_cache.insert(cache_key.clone(), Arc::new(prepared)).await,
+                    Err(err) => {
+                        tracing::debug!("Skipping the warm-up of an operation: {err}");
+                        continue;
+                    }
+                }
+            }
+
+            let count = operation.count;
+            self.operation_usage.insert(cache_key, operation, count);
+            warmed += 1;
+        }
+
+        warmed
+    }
+}
+
+/// Lets the executor run other tasks before resuming. The engine is runtime agnostic, so we can't
+/// rely on `tokio::task::yield_now`.
+async fn yield_now() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
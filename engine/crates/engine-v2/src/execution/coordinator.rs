@@ -130,7 +130,7 @@ impl<'ctx, R: Runtime> ExecutionContext<'ctx, R> {
             futures: ExecutionPlanFutureSet::new(),
             state: self.new_execution_state(),
             executed_operation_builder,
-            response: ResponseBuilder::new(self.operation.root_object_id),
+            response: ResponseBuilder::new(self.operation.root_object_id, self.operation.error_behavior),
             ctx: self,
         }
         .run()
@@ -181,7 +181,7 @@ impl<'ctx, R: Runtime> ExecutionContext<'ctx, R> {
     }
 
     fn new_subscription_response(&self, subscription_plan_id: ExecutionPlanId) -> SubscriptionResponse {
-        let mut response = ResponseBuilder::new(self.operation.root_object_id);
+        let mut response = ResponseBuilder::new(self.operation.root_object_id, self.operation.error_behavior);
 
         let tracked_response_object_set_ids = self
             .plan_walker(subscription_plan_id)
@@ -440,8 +440,9 @@ where
                 return (first_edge, None);
             }
         }
+        let propagates_null = self.operation.error_behavior.propagates_null();
         for field_shape in &shapes[shape.field_shape_ids] {
-            if field_shape.wrapping.is_required() {
+            if propagates_null && field_shape.wrapping.is_required() {
                 return (first_edge, None);
             }
             fields.push(ResponseObjectField {
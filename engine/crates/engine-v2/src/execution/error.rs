@@ -30,7 +30,7 @@ impl From<&str> for PlanningError {
     }
 }
 
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, Clone)]
 pub enum ExecutionError {
     #[error("Internal error: {0}")]
     Internal(Cow<'static, str>),
@@ -12,6 +12,7 @@ use std::sync::Arc;
 
 use crate::{
     operation::{LogicalPlanId, PreparedOperation, QueryModifications, ResponseModifierRule, Variables},
+    request::ErrorBehavior,
     response::{ResponseKey, ResponseObjectSetId, ResponseViewSelectionSet, ResponseViews},
     sources::Resolver,
     Runtime,
@@ -28,9 +29,10 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
         &self,
         operation: Arc<PreparedOperation>,
         variables: Variables,
+        error_behavior: ErrorBehavior,
     ) -> PlanningResult<ExecutableOperation> {
         tracing::trace!("Execution Planning");
-        planner::plan(self, operation, variables).await
+        planner::plan(self, operation, variables, error_behavior).await
     }
 }
 
@@ -39,6 +41,7 @@ impl<'ctx, R: Runtime> PreExecutionContext<'ctx, R> {
 pub(crate) struct ExecutableOperation {
     pub(crate) prepared: Arc<PreparedOperation>,
     pub(crate) variables: Variables,
+    pub(crate) error_behavior: ErrorBehavior,
     pub(crate) subgraph_default_headers: http::HeaderMap,
     pub(crate) query_modifications: QueryModifications,
     #[indexed_by(ExecutionPlanId)]
@@ -210,6 +210,14 @@ where
         for (selection_set_id, required_fields) in required_fields_by_selection_set_id {
             self.collect_dependencies(selection_set_id, &required_fields, &mut dependencies)
         }
+        // Contextual arguments are read from an ancestor, outside of the plan input.
+        let operation = self.operation;
+        for field_id in field_ids {
+            for requirement in operation.context_requirements_for(*field_id) {
+                let required_fields = self.ctx.schema().walk(requirement.argument.field_set_id).as_ref();
+                self.collect_dependencies(requirement.selection_set_id, required_fields, &mut dependencies);
+            }
+        }
         let view = self.build_view(&required_fields);
         (view, dependencies)
     }
@@ -13,6 +13,7 @@ use crate::{
         ResponseModifierExecutorId,
     },
     operation::{FieldId, LogicalPlanId, PreparedOperation, Variables},
+    request::ErrorBehavior,
     response::{ResponseViewSelection, ResponseViews},
     utils::BufferPool,
     Runtime,
@@ -73,11 +74,13 @@ pub(super) async fn plan<'ctx, R: Runtime>(
     ctx: &PreExecutionContext<'ctx, R>,
     prepared: Arc<PreparedOperation>,
     variables: Variables,
+    error_behavior: ErrorBehavior,
 ) -> PlanningResult<ExecutableOperation> {
     let operation = ExecutableOperation {
         query_modifications: QueryModifications::build(ctx, &prepared, &variables).await?,
         prepared,
         variables,
+        error_behavior,
         subgraph_default_headers: create_subgraph_headers_with_rules(
             ctx.request_context,
             ctx.schema().default_header_rules(),
@@ -13,7 +13,7 @@ mod sources;
 mod utils;
 pub mod websocket;
 
-pub use engine::{Engine, Runtime, WebsocketSession};
+pub use engine::{Engine, Runtime, WarmingOperation, WebsocketSession};
 pub use graphql_over_http::{Body, ErrorCode, HooksExtension, TelemetryExtension};
 pub use schema::{BuildError, Schema, Version as SchemaVersion};
 
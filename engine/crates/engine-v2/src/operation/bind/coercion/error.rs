@@ -69,6 +69,29 @@ pub enum InputValueError {
         location: Location,
         path: String,
     },
+    #[error(
+        "Exactly one field must be provided for the @oneOf input object {input_object}, but {count} were provided{path}"
+    )]
+    IncorrectOneOfFieldCount {
+        input_object: String,
+        count: usize,
+        location: Location,
+        path: String,
+    },
+    #[error("Field '{name}' of the @oneOf input object {input_object} cannot be null{path}")]
+    NullOneOfField {
+        input_object: String,
+        name: String,
+        location: Location,
+        path: String,
+    },
+    #[error("Variable ${name} must be non-nullable to be used in the @oneOf input object {input_object}{path}")]
+    NullableVariableInOneOfField {
+        name: String,
+        input_object: String,
+        location: Location,
+        path: String,
+    },
     #[error("Unknown variable ${name}{path}")]
     UnknownVariable {
         name: String,
@@ -95,6 +118,9 @@ impl InputValueError {
             | InputValueError::UnknownVariable { location, .. }
             | InputValueError::IncorrectVariableType { location, .. }
             | InputValueError::UnknownInputField { location, .. }
+            | InputValueError::IncorrectOneOfFieldCount { location, .. }
+            | InputValueError::NullOneOfField { location, .. }
+            | InputValueError::NullableVariableInOneOfField { location, .. }
             | InputValueError::VariableDefaultValueReliesOnAnotherVariable { location, .. }
             | InputValueError::UnknownEnumValue { location, .. } => *location,
         }
@@ -111,6 +137,8 @@ pub enum ValueKind {
     Boolean,
     List,
     Null,
+    /// A file of a multipart request.
+    Upload,
 }
 
 impl From<ConstValue> for ValueKind {
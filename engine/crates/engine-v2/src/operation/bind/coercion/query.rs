@@ -1,4 +1,4 @@
-use engine_value::{ConstValue, Name, Value};
+use engine_value::{indexmap::IndexMap, ConstValue, Name, Value};
 use id_newtypes::IdRange;
 use schema::{
     DefinitionId, EnumDefinition, InputObjectDefinition, InputValueDefinitionId, ListWrapping, ScalarDefinition,
@@ -190,6 +190,10 @@ impl<'binder, 'schema, 'parsed> QueryValueCoercionContext<'binder, 'schema, 'par
             });
         };
 
+        if input_object.is_one_of() {
+            self.validate_one_of(input_object, &fields)?;
+        }
+
         let mut fields_buffer = self.input_fields_buffer_pool.pop().unwrap_or_default();
         for input_field in input_object.input_fields() {
             match fields.swap_remove(input_field.name()) {
@@ -225,6 +229,49 @@ impl<'binder, 'schema, 'parsed> QueryValueCoercionContext<'binder, 'schema, 'par
         Ok(QueryInputValue::InputObject(ids))
     }
 
+    fn validate_one_of(
+        &self,
+        input_object: InputObjectDefinition<'_>,
+        fields: &IndexMap<Name, Value>,
+    ) -> Result<(), InputValueError> {
+        if fields.len() != 1 {
+            return Err(InputValueError::IncorrectOneOfFieldCount {
+                input_object: input_object.name().to_string(),
+                count: fields.len(),
+                path: self.path(),
+                location: self.location,
+            });
+        }
+        match fields.first() {
+            Some((name, Value::Null)) => Err(InputValueError::NullOneOfField {
+                input_object: input_object.name().to_string(),
+                name: name.to_string(),
+                path: self.path(),
+                location: self.location,
+            }),
+            Some((_, Value::Variable(name))) => {
+                let is_required = self
+                    .variable_definitions
+                    .iter()
+                    .find(|variable| variable.name == *name)
+                    .map(|variable| variable.ty.wrapping.is_required())
+                    // Unknown variables are reported with a better error during field coercion.
+                    .unwrap_or(true);
+                if is_required {
+                    Ok(())
+                } else {
+                    Err(InputValueError::NullableVariableInOneOfField {
+                        name: name.to_string(),
+                        input_object: input_object.name().to_string(),
+                        path: self.path(),
+                        location: self.location,
+                    })
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn coerce_enum(&mut self, r#enum: EnumDefinition<'_>, value: Value) -> Result<QueryInputValue, InputValueError> {
         let name = match &value {
             Value::Enum(value) => value.as_str(),
@@ -327,6 +374,17 @@ impl<'binder, 'schema, 'parsed> QueryValueCoercionContext<'binder, 'schema, 'par
                 Ok(QueryInputValue::Float(value))
             }
             (Value::String(value), ScalarType::String) => Ok(QueryInputValue::String(value)),
+            (Value::String(value), ty) if ty.is_formatted_string() => {
+                if !ty.is_valid_string(&value) {
+                    return Err(InputValueError::IncorrectScalarValue {
+                        actual: format!("{value:?}"),
+                        expected: scalar.name().to_string(),
+                        path: self.path(),
+                        location: self.location,
+                    });
+                }
+                Ok(QueryInputValue::String(value))
+            }
             (Value::Boolean(value), ScalarType::Boolean) => Ok(QueryInputValue::Boolean(value)),
             (Value::Binary(_), _) => unreachable!("Parser doesn't generate bytes, nor do variables."),
             (Value::Variable(name), _) => self.variable_ref(
@@ -5,22 +5,30 @@ use schema::{
     ScalarType, Schema, TypeRecord,
 };
 
-use crate::operation::{Location, VariableDefinition, VariableInputValue, VariableInputValueId, VariableInputValues};
+use crate::{
+    operation::{
+        Location, UploadId, VariableDefinition, VariableInputValue, VariableInputValueId, VariableInputValues,
+    },
+    request::Upload,
+};
 
 use super::{
-    error::InputValueError,
+    error::{InputValueError, ValueKind},
     path::{value_path_to_string, ValuePathSegment},
 };
 
 pub fn coerce_variable(
     schema: &Schema,
     input_values: &mut VariableInputValues,
+    uploads: &[Upload],
     definition: &VariableDefinition,
     value: ConstValue,
 ) -> Result<VariableInputValueId, InputValueError> {
     let mut ctx = VariableCoercionContext {
         schema,
         input_values,
+        uploads,
+        variable_name: &definition.name,
         location: definition.name_location,
         value_path: Vec::new(),
         input_fields_buffer_pool: Vec::new(),
@@ -32,6 +40,8 @@ pub fn coerce_variable(
 struct VariableCoercionContext<'a> {
     schema: &'a Schema,
     input_values: &'a mut VariableInputValues,
+    uploads: &'a [Upload],
+    variable_name: &'a str,
     location: Location,
     value_path: Vec<ValuePathSegment>,
     input_fields_buffer_pool: Vec<Vec<(InputValueDefinitionId, VariableInputValue)>>,
@@ -82,6 +92,20 @@ impl<'a> VariableCoercionContext<'a> {
 
     fn coerce_named_type(&mut self, ty: TypeRecord, value: ConstValue) -> Result<VariableInputValue, InputValueError> {
         if value.is_null() {
+            // Files of a multipart request are sent as `null` in the variables.
+            if let Some(id) = self.upload_at_current_path() {
+                return match ty.definition_id {
+                    DefinitionId::Scalar(scalar) if self.schema.walk(scalar).name() == "Upload" => {
+                        Ok(VariableInputValue::Upload(id))
+                    }
+                    definition_id => Err(InputValueError::IncorrectScalarType {
+                        actual: ValueKind::Upload,
+                        expected: self.schema.walk(definition_id).name().to_string(),
+                        path: self.path(),
+                        location: self.location,
+                    }),
+                };
+            }
             if ty.wrapping.is_required() {
                 return Err(InputValueError::UnexpectedNull {
                     expected: self.schema.walk(ty).to_string(),
@@ -100,6 +124,23 @@ impl<'a> VariableCoercionContext<'a> {
         }
     }
 
+    fn upload_at_current_path(&self) -> Option<UploadId> {
+        self.uploads
+            .iter()
+            .position(|upload| {
+                let Some((name, path)) = upload.variable_path.split_first() else {
+                    return false;
+                };
+                name == self.variable_name
+                    && path.len() == self.value_path.len()
+                    && path.iter().zip(&self.value_path).all(|(key, segment)| match segment {
+                        ValuePathSegment::Field(id) => key == &self.schema[*id],
+                        ValuePathSegment::Index(idx) => key.parse::<usize>().ok() == Some(*idx),
+                    })
+            })
+            .map(UploadId::from)
+    }
+
     fn coerce_input_objet(
         &mut self,
         input_object: InputObjectDefinition<'_>,
@@ -114,6 +155,25 @@ impl<'a> VariableCoercionContext<'a> {
             });
         };
 
+        if input_object.is_one_of() {
+            if fields.len() != 1 {
+                return Err(InputValueError::IncorrectOneOfFieldCount {
+                    input_object: input_object.name().to_string(),
+                    count: fields.len(),
+                    path: self.path(),
+                    location: self.location,
+                });
+            }
+            if let Some((name, ConstValue::Null)) = fields.first() {
+                return Err(InputValueError::NullOneOfField {
+                    input_object: input_object.name().to_string(),
+                    name: name.to_string(),
+                    path: self.path(),
+                    location: self.location,
+                });
+            }
+        }
+
         let mut fields_buffer = self.input_fields_buffer_pool.pop().unwrap_or_default();
         for input_field in input_object.input_fields() {
             match fields.swap_remove(input_field.name()) {
@@ -257,6 +317,17 @@ impl<'a> VariableCoercionContext<'a> {
                 Ok(VariableInputValue::Float(value))
             }
             (ConstValue::String(value), ScalarType::String) => Ok(VariableInputValue::String(value)),
+            (ConstValue::String(value), ty) if ty.is_formatted_string() => {
+                if !ty.is_valid_string(&value) {
+                    return Err(InputValueError::IncorrectScalarValue {
+                        actual: format!("{value:?}"),
+                        expected: scalar.name().to_string(),
+                        path: self.path(),
+                        location: self.location,
+                    });
+                }
+                Ok(VariableInputValue::String(value))
+            }
             (ConstValue::Boolean(value), ScalarType::Boolean) => Ok(VariableInputValue::Boolean(value)),
             (ConstValue::Binary(_), _) => unreachable!("Parser doesn't generate bytes, nor do variables."),
             (actual, _) => Err(InputValueError::IncorrectScalarType {
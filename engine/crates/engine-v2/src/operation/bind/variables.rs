@@ -5,6 +5,7 @@ use schema::{DefinitionId, Schema, Wrapping};
 
 use crate::{
     operation::{Location, Operation, VariableDefinition, VariableInputValues, VariableValue, Variables},
+    request::Upload,
     response::{ErrorCode, GraphqlError},
 };
 
@@ -35,18 +36,26 @@ pub fn bind_variables(
     schema: &Schema,
     operation: &Operation,
     mut request_variables: engine::Variables,
+    uploads: Vec<Upload>,
 ) -> Result<Variables, Vec<VariableError>> {
     let mut errors = Vec::new();
     let mut variables = Variables {
         input_values: VariableInputValues::default(),
         definition_to_value: vec![VariableValue::Undefined; operation.variable_definitions.len()],
+        uploads,
     };
 
     for (variable_id, definition) in operation.variable_definitions.iter().enumerate() {
         match request_variables.entry(engine_value::Name::new(&definition.name)) {
             Entry::Occupied(mut entry) => {
                 let value = std::mem::take(entry.get_mut());
-                match coerce_variable(schema, &mut variables.input_values, definition, value) {
+                match coerce_variable(
+                    schema,
+                    &mut variables.input_values,
+                    &variables.uploads,
+                    definition,
+                    value,
+                ) {
                     Ok(id) => variables.definition_to_value[variable_id] = VariableValue::InputValue(id),
                     Err(err) => {
                         errors.push(VariableError::InvalidValue {
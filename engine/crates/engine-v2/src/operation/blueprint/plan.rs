@@ -332,7 +332,7 @@ where
         let ty = definition.ty();
         let ty_definition = ty.definition();
 
-        let shape = match ty_definition.scalar_type() {
+        let shape = match ty_definition.output_scalar_type() {
             Some(scalar) => Shape::Scalar(scalar),
             None => {
                 if let Definition::Enum(enum_definition) = ty_definition {
@@ -360,6 +360,7 @@ where
             definition_id: definition.id(),
             shape,
             wrapping: ty.wrapping,
+            semantic_non_null: definition.semantic_non_null(),
         }
     }
 
@@ -136,7 +136,10 @@ fn add_directive_scopes<'a>(
     let mut scopes_added = 0;
     for directive in directives {
         match directive {
-            schema::TypeSystemDirective::Deprecated(_) | schema::TypeSystemDirective::Authorized(_) => {}
+            schema::TypeSystemDirective::Deprecated(_)
+            | schema::TypeSystemDirective::Authorized(_)
+            | schema::TypeSystemDirective::OneOf
+            | schema::TypeSystemDirective::SemanticNonNull(_) => {}
 
             schema::TypeSystemDirective::Authenticated => {
                 scopes_added += 1;
@@ -2,6 +2,8 @@ mod de;
 mod ser;
 mod view;
 
+use std::fmt::Write;
+
 use id_derives::{Id, IndexedFields};
 use id_newtypes::IdRange;
 use schema::{
@@ -9,7 +11,7 @@ use schema::{
 };
 use walker::Walk;
 
-use crate::operation::{OperationWalker, PreparedOperationWalker, VariableDefinitionId};
+use crate::operation::{OperationWalker, PreparedOperationWalker, UploadId, VariableDefinitionId, VariableValueWalker};
 
 pub(crate) use view::*;
 
@@ -143,6 +145,47 @@ impl<'a> QueryInputValueWalker<'a> {
             selection_set,
         }
     }
+
+    /// Collects the files referenced by this value with their position, `path` being the
+    /// position of the value itself. The position uses the dot-separated format of the GraphQL
+    /// multipart request spec.
+    pub fn collect_uploads(&self, path: &mut String, uploads: &mut Vec<(UploadId, String)>) {
+        let input_values = &self.operation.query_input_values;
+        match self.item {
+            QueryInputValue::InputObject(ids) => {
+                for (input_value_definition_id, value) in &input_values[*ids] {
+                    let len = path.len();
+                    path.push('.');
+                    path.push_str(self.schema.walk(*input_value_definition_id).name());
+                    self.walk(value).collect_uploads(path, uploads);
+                    path.truncate(len);
+                }
+            }
+            QueryInputValue::List(ids) => {
+                for (i, value) in input_values[*ids].iter().enumerate() {
+                    let len = path.len();
+                    write!(path, ".{i}").unwrap();
+                    self.walk(value).collect_uploads(path, uploads);
+                    path.truncate(len);
+                }
+            }
+            QueryInputValue::Map(ids) => {
+                for (key, value) in &input_values[*ids] {
+                    let len = path.len();
+                    path.push('.');
+                    path.push_str(key);
+                    self.walk(value).collect_uploads(path, uploads);
+                    path.truncate(len);
+                }
+            }
+            QueryInputValue::Variable(id) => {
+                if let VariableValueWalker::VariableInputValue(value) = self.walk(*id).as_value() {
+                    value.collect_uploads(path, uploads);
+                }
+            }
+            _ => (),
+        }
+    }
 }
 
 impl<'a> From<QueryInputValueWalker<'a>> for InputValue<'a> {
@@ -18,7 +18,7 @@ impl<'de> serde::Deserializer<'de> for VariableInputValueWalker<'de> {
         V: Visitor<'de>,
     {
         match self.item {
-            VariableInputValue::Null => visitor.visit_none(),
+            VariableInputValue::Null | VariableInputValue::Upload(_) => visitor.visit_none(),
             VariableInputValue::String(s) => visitor.visit_borrowed_str(s),
             VariableInputValue::EnumValue(id) => visitor.visit_borrowed_str(self.schema.walk(*id).name()),
             VariableInputValue::Int(n) => visitor.visit_i32(*n),
@@ -1,12 +1,14 @@
 mod de;
 mod ser;
 
+use std::fmt::Write;
+
 use id_derives::{Id, IndexedFields};
 use id_newtypes::IdRange;
 use schema::{EnumValueId, InputValue, InputValueDefinitionId, SchemaInputValueId, SchemaInputValueRecord};
 use walker::Walk;
 
-use crate::operation::PreparedOperationWalker;
+use crate::operation::{PreparedOperationWalker, UploadId};
 
 #[derive(Default, IndexedFields)]
 pub struct VariableInputValues {
@@ -52,6 +54,10 @@ pub enum VariableInputValue {
     /// Used to reference default values for operation input values. It's tricky without as default
     /// values also need to be taken into account for nested input object fields.
     DefaultValue(SchemaInputValueId),
+
+    /// File received with a multipart request. Sent as `null` to subgraphs, the file itself being
+    /// a separate part of the request.
+    Upload(UploadId),
 }
 
 impl VariableInputValues {
@@ -94,6 +100,43 @@ impl VariableInputValues {
 
 pub type VariableInputValueWalker<'a> = PreparedOperationWalker<'a, &'a VariableInputValue>;
 
+impl VariableInputValueWalker<'_> {
+    /// Collects the files within this value with their position, `path` being the position of
+    /// the value itself.
+    pub fn collect_uploads(&self, path: &mut String, uploads: &mut Vec<(UploadId, String)>) {
+        match self.item {
+            VariableInputValue::Upload(id) => uploads.push((*id, path.clone())),
+            VariableInputValue::InputObject(ids) => {
+                for (input_value_definition_id, value) in &self.variables[*ids] {
+                    let len = path.len();
+                    path.push('.');
+                    path.push_str(self.schema.walk(*input_value_definition_id).name());
+                    self.walk(value).collect_uploads(path, uploads);
+                    path.truncate(len);
+                }
+            }
+            VariableInputValue::List(ids) => {
+                for (i, value) in self.variables[*ids].iter().enumerate() {
+                    let len = path.len();
+                    write!(path, ".{i}").unwrap();
+                    self.walk(value).collect_uploads(path, uploads);
+                    path.truncate(len);
+                }
+            }
+            VariableInputValue::Map(ids) => {
+                for (key, value) in &self.variables[*ids] {
+                    let len = path.len();
+                    path.push('.');
+                    path.push_str(key);
+                    self.walk(value).collect_uploads(path, uploads);
+                    path.truncate(len);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
 impl<'a> From<VariableInputValueWalker<'a>> for InputValue<'a> {
     fn from(walker: VariableInputValueWalker<'a>) -> Self {
         match walker.item {
@@ -127,6 +170,7 @@ impl<'a> From<VariableInputValueWalker<'a>> for InputValue<'a> {
             }
             VariableInputValue::U64(n) => InputValue::U64(*n),
             VariableInputValue::DefaultValue(id) => id.walk(walker.schema).into(),
+            VariableInputValue::Upload(_) => InputValue::Null,
         }
     }
 }
@@ -206,6 +250,7 @@ impl PartialEq<SchemaInputValueRecord> for VariableInputValueWalker<'_> {
             (VariableInputValue::InputObject(_), _) => false,
             (VariableInputValue::List(_), _) => false,
             (VariableInputValue::Map(_), _) => false,
+            (VariableInputValue::Upload(_), _) => false,
         }
     }
 }
@@ -245,6 +290,7 @@ impl std::fmt::Debug for VariableInputValueWalker<'_> {
                 map.finish()
             }
             VariableInputValue::DefaultValue(id) => f.debug_tuple("DefaultValue").field(&id.walk(self.schema)).finish(),
+            VariableInputValue::Upload(id) => f.debug_tuple("Upload").field(&self.variables[*id].filename).finish(),
         }
     }
 }
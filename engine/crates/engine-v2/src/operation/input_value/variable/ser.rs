@@ -9,7 +9,8 @@ impl<'ctx> serde::Serialize for VariableInputValueWalker<'ctx> {
         S: serde::Serializer,
     {
         match self.item {
-            VariableInputValue::Null => serializer.serialize_none(),
+            // Files are sent as separate parts of a multipart request.
+            VariableInputValue::Null | VariableInputValue::Upload(_) => serializer.serialize_none(),
             VariableInputValue::String(s) => s.serialize(serializer),
             VariableInputValue::EnumValue(id) => self.schema.walk(*id).name().serialize(serializer),
             VariableInputValue::Int(n) => n.serialize(serializer),
@@ -1,21 +1,21 @@
 mod logic;
 mod selection_set;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
 use engine_parser::types::OperationType;
 use id_derives::IndexedFields;
 use id_newtypes::{BitSet, IdToMany};
 use itertools::Itertools;
 use schema::{
-    EntityDefinitionId, FieldDefinitionId, RequiredFieldId, RequiredFieldSetRecord, ResolverDefinitionId, Schema,
-    TypeSystemDirective,
+    EntityDefinitionId, FieldContextArgumentRecord, FieldDefinitionId, RequiredFieldId, RequiredFieldSetRecord,
+    ResolverDefinitionId, Schema, TypeSystemDirective,
 };
 
 use crate::{
     operation::{
-        FieldId, LogicalPlan, LogicalPlanId, Operation, OperationWalker, QueryPath, ResponseModifierRule,
-        SelectionSetId, SolvedRequiredFieldSet,
+        ContextRequirement, FieldId, LogicalPlan, LogicalPlanId, Operation, OperationWalker, QueryPath,
+        ResponseModifierRule, SelectionSetId, SolvedRequiredFieldSet,
     },
     response::{ErrorCode, GraphqlError},
 };
@@ -80,6 +80,13 @@ pub(super) struct LogicalPlanner<'a> {
 
     /// A list of solved requirements associated with selection sets.
     solved_requirements: Vec<(SelectionSetId, SolvedRequiredFieldSet)>,
+
+    /// Fields with `@fromContext` arguments, grouped by the closest ancestor selection set
+    /// providing the context. Consumed when planning the ancestor.
+    context_requirements: HashMap<SelectionSetId, Vec<(FieldId, FieldContextArgumentRecord)>>,
+
+    /// Context requirements for which the extra fields were added to the ancestor.
+    solved_context_requirements: Vec<ContextRequirement>,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -98,6 +105,8 @@ impl<'a> LogicalPlanner<'a> {
             operation,
             logical_plans: Vec::new(),
             solved_requirements: Vec::new(),
+            context_requirements: HashMap::new(),
+            solved_context_requirements: Vec::new(),
             dependents_builder: Vec::new(),
             mutation_fields_plan_order: Vec::new(),
         }
@@ -132,6 +141,7 @@ impl<'a> LogicalPlanner<'a> {
             mutation_fields_plan_order,
             selection_set_to_objects_must_be_tracked,
             mut solved_requirements,
+            mut solved_context_requirements,
             mut dependents_builder,
             ..
         } = self;
@@ -154,11 +164,14 @@ impl<'a> LogicalPlanner<'a> {
         );
         tracing::trace!("Field to solved requirements: {:?}", field_to_solved_requirement);
 
+        solved_context_requirements.sort_unstable_by_key(|requirement| requirement.field_id);
+
         dependents_builder.sort_unstable();
         let children = IdToMany::from_sorted_vec(dependents_builder.into_iter().dedup().collect());
 
         let mut plan = OperationPlan {
             solved_requirements,
+            context_requirements: solved_context_requirements,
             mutation_fields_plan_order,
             field_to_solved_requirement,
             selection_set_to_objects_must_be_tracked,
@@ -210,6 +223,8 @@ impl<'a> LogicalPlanner<'a> {
                 }
             });
 
+        self.collect_context_requirements(self.operation.root_selection_set_id, &mut Vec::new());
+
         if !introspection_field_ids.is_empty() {
             self.push_plan(
                 QueryPath::default(),
@@ -230,6 +245,48 @@ impl<'a> LogicalPlanner<'a> {
         Ok(())
     }
 
+    /// A field with `@fromContext` arguments retrieves its value from the closest ancestor
+    /// providing the context. As the deepest selection sets are planned first, we need to know
+    /// upfront which selection sets must provide extra fields for their descendants.
+    fn collect_context_requirements(
+        &mut self,
+        selection_set_id: SelectionSetId,
+        ancestors: &mut Vec<(SelectionSetId, EntityDefinitionId)>,
+    ) {
+        let field_ids = self.operation[selection_set_id]
+            .field_ids_ordered_by_parent_entity_id_then_position
+            .clone();
+
+        for field_id in field_ids {
+            let Some(definition_id) = self.operation[field_id].definition_id() else {
+                continue;
+            };
+            let definition = self.schema.walk(definition_id);
+
+            for argument in definition.context_arguments() {
+                let closest_ancestor = ancestors
+                    .iter()
+                    .rev()
+                    .find(|(_, entity_id)| entity_provides_context(self.schema, *entity_id, argument.entity_id));
+
+                if let Some((ancestor_id, _)) = closest_ancestor {
+                    self.context_requirements
+                        .entry(*ancestor_id)
+                        .or_default()
+                        .push((field_id, *argument.as_ref()));
+                }
+            }
+
+            if let Some(subselection_id) = self.operation[field_id].selection_set_id() {
+                // Within interfaces and unions, the fields are grouped by their parent entity, so
+                // it's more precise than the type of the selection set.
+                ancestors.push((selection_set_id, definition.as_ref().parent_entity_id));
+                self.collect_context_requirements(subselection_id, ancestors);
+                ancestors.pop();
+            }
+        }
+    }
+
     /// A query is simply treated as a plan boundary with no parent.
     fn plan_query(&mut self, field_ids: Vec<FieldId>) -> LogicalPlanningResult<()> {
         let id = self.operation.root_selection_set_id;
@@ -320,6 +377,7 @@ impl<'a> LogicalPlanner<'a> {
                 if let Some(definition) = walker.walk(*field_id).definition() {
                     logic.is_providable(definition.id())
                         && !definition.has_required_fields_for_subgraph(logic.resolver().subgraph_id())
+                        && !definition.has_context_arguments_for_subgraph(logic.resolver().subgraph_id())
                 } else {
                     true
                 }
@@ -343,7 +401,10 @@ impl<'a> LogicalPlanner<'a> {
                 }
             });
 
-        if !unplanned_field_ids.is_empty() || !parent_extra_requirements.is_empty() {
+        if !unplanned_field_ids.is_empty()
+            || !parent_extra_requirements.is_empty()
+            || self.context_requirements.contains_key(&selection_set_id)
+        {
             SelectionSetLogicalPlanner::new(self, path, Some(logic)).solve(
                 selection_set_id,
                 Some((parent_field_id, parent_extra_requirements)),
@@ -405,6 +466,21 @@ impl<'a> LogicalPlanner<'a> {
     }
 }
 
+/// Whether an object of type `entity_id` may provide the context declared on `context_entity_id`.
+fn entity_provides_context(
+    schema: &Schema,
+    entity_id: EntityDefinitionId,
+    context_entity_id: EntityDefinitionId,
+) -> bool {
+    match (entity_id, context_entity_id) {
+        (EntityDefinitionId::Object(object_id), EntityDefinitionId::Interface(interface_id))
+        | (EntityDefinitionId::Interface(interface_id), EntityDefinitionId::Object(object_id)) => {
+            schema[interface_id].possible_type_ids.binary_search(&object_id).is_ok()
+        }
+        (a, b) => a == b,
+    }
+}
+
 fn sorted_plan_ids_by_topological_order(plan: &OperationPlan) -> Vec<LogicalPlanId> {
     let mut parent_count = plan.parent_count.clone();
     let mut out = parent_count
@@ -15,8 +15,8 @@ use walker::Walk;
 use super::{logic::PlanningLogic, LogicalPlanner, LogicalPlanningError, LogicalPlanningResult, ParentToChildEdge};
 use crate::{
     operation::{
-        ExtraField, Field, FieldArgument, FieldArgumentId, FieldId, LogicalPlanId, QueryInputValue, QueryPath,
-        SelectionSet, SelectionSetId, SolvedRequiredField, SolvedRequiredFieldSet,
+        ContextRequirement, ExtraField, Field, FieldArgument, FieldArgumentId, FieldId, LogicalPlanId, QueryInputValue,
+        QueryPath, SelectionSet, SelectionSetId, SolvedRequiredField, SolvedRequiredFieldSet,
     },
     response::{SafeResponseKey, UnpackedResponseEdge},
 };
@@ -69,6 +69,7 @@ impl<'schema, 'a> SelectionSetLogicalPlanner<'schema, 'a> {
         let missing = self.build_unplanned_fields(unplanned_field_ids);
 
         self.plan_selection_set(&mut planned_selection_set, parent_field_requirements, missing)?;
+        self.plan_context_requirements(selection_set_id, &mut planned_selection_set)?;
 
         // During the planning we add extra fields as necessary but we don't add them in the
         // children plan root fields.
@@ -258,9 +259,12 @@ impl<'schema, 'a> SelectionSetLogicalPlanner<'schema, 'a> {
             let mut planned_field_ids = vec![];
 
             for (&id, definition) in &unplanned_fields {
-                // If the parent plan can provide the field, we don't need to plan it.
-                let required_fields = definition.requires_for_subgraph(parent_logic.resolver().as_ref().subgraph_id());
+                // If the parent plan can provide the field, we don't need to plan it. Fields with
+                // contextual arguments are always retrieved through their entity.
+                let subgraph_id = parent_logic.resolver().as_ref().subgraph_id();
+                let required_fields = definition.requires_for_subgraph(subgraph_id);
                 if parent_logic.is_providable(definition.id())
+                    && !definition.has_context_arguments_for_subgraph(subgraph_id)
                     && self.could_plan_requirements(planned_selection_set, id, &required_fields)?
                 {
                     requires = RequiredFieldSetRecord::union_cow(requires, required_fields);
@@ -390,6 +394,49 @@ impl<'schema, 'a> SelectionSetLogicalPlanner<'schema, 'a> {
         Ok(())
     }
 
+    /// Adds the fields providing the context of `@fromContext` arguments within this selection
+    /// set. All nested selection sets are planned at this stage, so we know which plan needs them.
+    fn plan_context_requirements(
+        &mut self,
+        selection_set_id: SelectionSetId,
+        planned_selection_set: &mut PlannedSelectionSet,
+    ) -> LogicalPlanningResult<()> {
+        let Some(requirements) = self.planner.context_requirements.remove(&selection_set_id) else {
+            return Ok(());
+        };
+
+        for (field_id, argument) in requirements {
+            // Fields which are never planned use an out of bounds plan id.
+            let Some(plan_id) = self.planner[field_id].filter(|id| usize::from(*id) < self.logical_plans.len()) else {
+                continue;
+            };
+            if self.schema.walk(self[plan_id].resolver_id).as_ref().subgraph_id() != argument.subgraph_id {
+                continue;
+            }
+
+            let requires = self.schema.walk(argument.field_set_id).as_ref();
+            if !self.could_plan_requirements(planned_selection_set, field_id, requires)? {
+                let walker = self.walker();
+                return Err(LogicalPlanningError::CouldNotPlanAnyField {
+                    missing: requires
+                        .iter()
+                        .map(|item| self.schema.walk(item).field().definition().name().to_string())
+                        .collect(),
+                    query_path: walker.walk(self.query_path).iter().map(|s| s.to_string()).collect(),
+                });
+            }
+
+            self.register_necessary_extra_fields(Some(plan_id), planned_selection_set, requires);
+            self.planner.solved_context_requirements.push(ContextRequirement {
+                field_id,
+                selection_set_id,
+                argument,
+            });
+        }
+
+        Ok(())
+    }
+
     fn push_child(
         &mut self,
         planned_selection_set: &mut PlannedSelectionSet,
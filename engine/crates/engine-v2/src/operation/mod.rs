@@ -28,7 +28,9 @@ pub(crate) use location::Location;
 pub(crate) use modifier::*;
 pub(crate) use parse::{parse_operation, ParsedOperation};
 pub(crate) use path::QueryPath;
-use schema::{EntityDefinitionId, ObjectDefinitionId, RequiredFieldId, ResolverDefinitionId, Schema};
+use schema::{
+    EntityDefinitionId, FieldContextArgumentRecord, ObjectDefinitionId, RequiredFieldId, ResolverDefinitionId, Schema,
+};
 pub(crate) use selection_set::*;
 pub(crate) use variables::*;
 pub(crate) use walkers::*;
@@ -114,6 +116,8 @@ pub(crate) struct OperationPlan {
     pub in_topological_order: Vec<LogicalPlanId>,
     // Sorted
     pub solved_requirements: Vec<(SelectionSetId, SolvedRequiredFieldSet)>,
+    // Sorted by FieldId
+    pub context_requirements: Vec<ContextRequirement>,
 }
 
 impl OperationPlan {
@@ -129,6 +133,15 @@ pub(crate) struct LogicalPlan {
     pub root_field_ids_ordered_by_parent_entity_id_then_position: Vec<FieldId>,
 }
 
+/// A field with a `@fromContext` argument and the ancestor selection set in which the context
+/// fields were added.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy)]
+pub(crate) struct ContextRequirement {
+    pub field_id: FieldId,
+    pub selection_set_id: SelectionSetId,
+    pub argument: FieldContextArgumentRecord,
+}
+
 pub(crate) type SolvedRequiredFieldSet = Vec<SolvedRequiredField>;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
@@ -191,6 +204,13 @@ impl PreparedOperation {
             .map(|ix| &self.plan.solved_requirements[ix].1)
             .ok()
     }
+
+    pub fn context_requirements_for(&self, id: FieldId) -> &[ContextRequirement] {
+        let requirements = &self.plan.context_requirements;
+        let start = requirements.partition_point(|requirement| requirement.field_id < id);
+        let end = start + requirements[start..].partition_point(|requirement| requirement.field_id == id);
+        &requirements[start..end]
+    }
 }
 
 impl Operation {
@@ -1,5 +1,8 @@
+use id_derives::Id;
 use schema::Schema;
 
+use crate::request::Upload;
+
 use super::{
     bind::{bind_variables, VariableError},
     Location, Operation, QueryInputValueId, VariableDefinitionId, VariableInputValueId, VariableInputValues,
@@ -17,8 +20,13 @@ pub struct VariableDefinition {
 pub struct Variables {
     pub input_values: VariableInputValues,
     pub definition_to_value: Vec<VariableValue>,
+    /// Files received with a multipart request, referenced by [super::VariableInputValue::Upload].
+    pub uploads: Vec<Upload>,
 }
 
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, serde::Serialize, serde::Deserialize, Id)]
+pub struct UploadId(std::num::NonZero<u32>);
+
 #[derive(Clone)]
 pub enum VariableValue {
     Undefined,
@@ -33,6 +41,14 @@ impl std::ops::Index<VariableDefinitionId> for Variables {
     }
 }
 
+impl std::ops::Index<UploadId> for Variables {
+    type Output = Upload;
+
+    fn index(&self, index: UploadId) -> &Self::Output {
+        &self.uploads[usize::from(index)]
+    }
+}
+
 impl<T> std::ops::Index<T> for Variables
 where
     VariableInputValues: std::ops::Index<T>,
@@ -49,7 +65,8 @@ impl Variables {
         schema: &Schema,
         operation: &Operation,
         request_variables: engine::Variables,
+        uploads: Vec<Upload>,
     ) -> Result<Self, Vec<VariableError>> {
-        bind_variables(schema, operation, request_variables)
+        bind_variables(schema, operation, request_variables, uploads)
     }
 }
@@ -1,7 +1,7 @@
 use schema::FieldDefinition;
 
 use crate::{
-    operation::{FieldArgumentsWalker, FieldId, QueryInputValueWalker},
+    operation::{ContextRequirement, FieldArgumentsWalker, FieldId, QueryInputValueWalker},
     response::ResponseKey,
 };
 
@@ -28,6 +28,11 @@ impl<'a> PlanField<'a> {
         self.operation.response_keys.try_resolve(self.response_key()).unwrap()
     }
 
+    /// `@fromContext` arguments for which the planner added the context fields to an ancestor.
+    pub fn context_requirements(&self) -> &'a [ContextRequirement] {
+        self.operation.context_requirements_for(self.item)
+    }
+
     pub fn arguments(self) -> FieldArgumentsWalker<'a> {
         self.prepared_walk_with(self.as_ref().argument_ids())
     }
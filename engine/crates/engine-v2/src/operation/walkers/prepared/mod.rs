@@ -5,6 +5,7 @@ mod variable;
 
 pub(crate) use argument::*;
 use schema::Schema;
+pub(crate) use variable::VariableValueWalker;
 
 #[derive(Clone, Copy)]
 pub(crate) struct PreparedOperationWalker<'a, Item = ()> {
@@ -1,4 +1,8 @@
+pub(crate) mod multipart;
+
+use bytes::Bytes;
 use engine::{RequestExtensions, Variables};
+use engine_value::ConstValue;
 use serde::Deserializer;
 
 #[derive(serde::Deserialize)]
@@ -20,6 +24,75 @@ pub(crate) struct Request {
     pub variables: Variables,
     #[serde(default)]
     pub extensions: RequestExtensions,
+    #[serde(default, rename = "onError")]
+    pub on_error: ErrorBehavior,
+    #[serde(skip)]
+    pub uploads: Vec<Upload>,
+}
+
+/// What happens to the response data when a field fails, chosen by the client with the `onError`
+/// request parameter from the GraphQL error behavior proposal.
+#[derive(serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum ErrorBehavior {
+    /// Only the failing field is set to null, even if it's non-null in the schema. Its error is
+    /// still added to the response errors.
+    Null,
+    /// The null propagates up to the nearest nullable parent as required by the GraphQL spec.
+    #[default]
+    Propagate,
+}
+
+impl ErrorBehavior {
+    pub(crate) fn propagates_null(self) -> bool {
+        matches!(self, ErrorBehavior::Propagate)
+    }
+}
+
+/// A file received through a multipart request.
+#[derive(Debug, Clone)]
+pub(crate) struct Upload {
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub content: Bytes,
+    /// Position of the file within the variables, for example `["files", "2"]` for
+    /// `variables.files.2`. The variable itself is `null` at this position.
+    pub variable_path: Vec<String>,
+}
+
+impl Request {
+    /// Records that the file is used at `path`, for example `variables.files.2`. Returns false
+    /// if there is no `null` variable value at the path.
+    pub(crate) fn add_upload(&mut self, path: &str, mut upload: Upload) -> bool {
+        let Some(variable_path) = path.strip_prefix("variables.").map(|path| path.split('.')) else {
+            return false;
+        };
+        let variable_path: Vec<String> = variable_path.map(str::to_string).collect();
+
+        let Some(mut value) = variable_path.first().and_then(|name| self.variables.get(name.as_str())) else {
+            return false;
+        };
+
+        for part in &variable_path[1..] {
+            let next = match value {
+                ConstValue::List(list) => part.parse::<usize>().ok().and_then(|idx| list.get(idx)),
+                ConstValue::Object(fields) => fields.get(part.as_str()),
+                _ => None,
+            };
+            let Some(next) = next else {
+                return false;
+            };
+            value = next;
+        }
+
+        if !value.is_null() {
+            return false;
+        }
+
+        upload.variable_path = variable_path;
+        self.uploads.push(upload);
+        true
+    }
 }
 
 pub(crate) struct QueryParamsRequest(Request);
@@ -41,6 +114,7 @@ impl<'de> serde::Deserialize<'de> for QueryParamsRequest {
             variables,
             operation_name,
             extensions,
+            on_error,
         } = QueryParams::deserialize(deserializer)?;
         Ok(QueryParamsRequest(Request {
             query,
@@ -58,6 +132,8 @@ impl<'de> serde::Deserialize<'de> for QueryParamsRequest {
                 .transpose()
                 .map_err(serde::de::Error::custom)?
                 .unwrap_or_default(),
+            on_error: on_error.unwrap_or_default(),
+            uploads: Vec::new(),
         }))
     }
 }
@@ -74,4 +150,6 @@ struct QueryParams {
     operation_name: Option<String>,
     #[serde(default)]
     extensions: Option<String>,
+    #[serde(default, rename = "onError")]
+    on_error: Option<ErrorBehavior>,
 }
@@ -0,0 +1,106 @@
+//! GraphQL multipart request spec:
+//!
+//! https://github.com/jaydenseric/graphql-multipart-request-spec
+//!
+use std::collections::HashMap;
+
+use bytes::Bytes;
+
+use super::{BatchRequest, Upload};
+
+pub(crate) const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum MultipartError {
+    #[error(transparent)]
+    Multer(#[from] multer::Error),
+    #[error("Invalid 'operations' part: {0}")]
+    InvalidOperations(serde_json::Error),
+    #[error("Invalid 'map' part: {0}")]
+    InvalidMap(serde_json::Error),
+    #[error("Missing 'operations' part")]
+    MissingOperations,
+    #[error("Missing 'map' part")]
+    MissingMap,
+    #[error("File '{0}' referenced in 'map' is missing")]
+    MissingFile(String),
+    #[error("Invalid file path '{0}' in 'map'")]
+    InvalidPath(String),
+}
+
+/// Returns the multipart boundary if the Content-Type is `multipart/form-data`.
+pub(crate) fn extract_boundary(content_type: &str) -> Option<String> {
+    let (without_parameters, _) = content_type.split_once(';').unwrap_or((content_type, ""));
+    if !without_parameters.trim().eq_ignore_ascii_case(MULTIPART_FORM_DATA) {
+        return None;
+    }
+    multer::parse_boundary(content_type).ok()
+}
+
+/// Parses a multipart request. The body has already been fully read within the
+/// request body limit, so files are kept in memory until they're forwarded to the subgraph.
+pub(crate) async fn parse(body: Bytes, boundary: String) -> Result<BatchRequest, MultipartError> {
+    let mut multipart = multer::Multipart::new(
+        futures_util::stream::once(async move { Ok::<_, std::io::Error>(body) }),
+        boundary,
+    );
+
+    let mut request = None;
+    let mut map = None;
+    let mut files = HashMap::new();
+
+    while let Some(field) = multipart.next_field().await? {
+        match field.name() {
+            Some("operations") => {
+                let bytes = field.bytes().await?;
+                request =
+                    Some(serde_json::from_slice::<BatchRequest>(&bytes).map_err(MultipartError::InvalidOperations)?);
+            }
+            Some("map") => {
+                let bytes = field.bytes().await?;
+                map = Some(
+                    serde_json::from_slice::<HashMap<String, Vec<String>>>(&bytes)
+                        .map_err(MultipartError::InvalidMap)?,
+                );
+            }
+            Some(name) => {
+                let name = name.to_string();
+                let filename = field.file_name().unwrap_or_default().to_string();
+                let content_type = field.content_type().map(ToString::to_string);
+                let content = field.bytes().await?;
+                files.insert(
+                    name,
+                    Upload {
+                        filename,
+                        content_type,
+                        content,
+                        variable_path: Vec::new(),
+                    },
+                );
+            }
+            None => (),
+        }
+    }
+
+    let mut request = request.ok_or(MultipartError::MissingOperations)?;
+    let map = map.ok_or(MultipartError::MissingMap)?;
+
+    for (name, paths) in map {
+        let upload = files.remove(&name).ok_or(MultipartError::MissingFile(name))?;
+        for path in paths {
+            let found = match &mut request {
+                BatchRequest::Single(request) => request.add_upload(&path, upload.clone()),
+                BatchRequest::Batch(requests) => path
+                    .split_once('.')
+                    .and_then(|(idx, path)| Some((idx.parse::<usize>().ok()?, path)))
+                    .and_then(|(idx, path)| requests.get_mut(idx).map(|request| (request, path)))
+                    .is_some_and(|(request, path)| request.add_upload(path, upload.clone())),
+            };
+            if !found {
+                return Err(MultipartError::InvalidPath(path));
+            }
+        }
+    }
+
+    Ok(request)
+}
@@ -0,0 +1,122 @@
+use itertools::Either;
+use schema::RequiredFieldSetRecord;
+
+use super::{ResponseObjectsView, ResponseValueWalker};
+use crate::response::{
+    ResponseListId, ResponseObjectId, ResponseObjectRef, ResponseValue, ResponseViewSelectionSet, UnpackedResponseEdge,
+};
+
+impl<'a> ResponseObjectsView<'a> {
+    /// For each response object, reads the value of each item in `paths` from its closest ancestor
+    /// providing it. Each path is a list of alternatives: a single field per level leading to the
+    /// value in one of the possible ancestor types. Used for `@fromContext` arguments.
+    pub fn ancestor_values(&self, paths: &[Vec<&RequiredFieldSetRecord>]) -> Vec<Vec<serde_json::Value>> {
+        self.response_object_set
+            .iter()
+            .map(|object_ref| {
+                let ancestors = self.ancestors(object_ref);
+                paths
+                    .iter()
+                    .map(|alternatives| {
+                        ancestors
+                            .iter()
+                            .rev()
+                            .find_map(|&object_id| {
+                                alternatives
+                                    .iter()
+                                    .find_map(|field_set| self.read_field_path(object_id, field_set))
+                            })
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// All objects from the root down to the parent of the response object.
+    fn ancestors(&self, object_ref: &ResponseObjectRef) -> Vec<ResponseObjectId> {
+        let response = self.ctx.response;
+        let Some((root, _)) = response.root else {
+            return Vec::new();
+        };
+
+        let mut ancestors = vec![root];
+        let mut previous: Either<ResponseObjectId, ResponseListId> = Either::Left(root);
+        for &edge in object_ref.path.iter() {
+            let value = match (previous, edge.unpack()) {
+                (
+                    Either::Left(object_id),
+                    UnpackedResponseEdge::BoundResponseKey(_) | UnpackedResponseEdge::ExtraFieldResponseKey(_),
+                ) => {
+                    let Some(field_position) = response[object_id].field_position(edge) else {
+                        break;
+                    };
+                    &response[object_id][field_position]
+                }
+                (Either::Right(list_id), UnpackedResponseEdge::Index(index)) => {
+                    let Some(value) = response[list_id].get(index) else {
+                        break;
+                    };
+                    value
+                }
+                _ => break,
+            };
+            match *value {
+                ResponseValue::Object { part_id, index, .. } => {
+                    let object_id = ResponseObjectId { part_id, index };
+                    ancestors.push(object_id);
+                    previous = Either::Left(object_id);
+                }
+                ResponseValue::List {
+                    part_id,
+                    offset,
+                    length,
+                    ..
+                } => {
+                    previous = Either::Right(ResponseListId {
+                        part_id,
+                        offset,
+                        length,
+                    });
+                }
+                _ => break,
+            }
+        }
+
+        // The path leads to the response object itself.
+        if ancestors.last() == Some(&object_ref.id) {
+            ancestors.pop();
+        }
+        ancestors
+    }
+
+    /// Returns `None` if the object doesn't have the first field, otherwise the value at the end of
+    /// the path which may be null.
+    fn read_field_path(
+        &self,
+        object_id: ResponseObjectId,
+        field_set: &RequiredFieldSetRecord,
+    ) -> Option<serde_json::Value> {
+        let item = field_set.iter().next()?;
+        let value = self.ctx.response[object_id].find_required_field(item.field_id)?;
+
+        if item.subselection.is_empty() {
+            return Some(
+                serde_json::to_value(ResponseValueWalker {
+                    ctx: self.ctx,
+                    value,
+                    selection_set: ResponseViewSelectionSet::empty(),
+                })
+                .unwrap_or_default(),
+            );
+        }
+
+        match *value {
+            ResponseValue::Object { part_id, index, .. } => Some(
+                self.read_field_path(ResponseObjectId { part_id, index }, &item.subselection)
+                    .unwrap_or_default(),
+            ),
+            _ => Some(serde_json::Value::Null),
+        }
+    }
+}
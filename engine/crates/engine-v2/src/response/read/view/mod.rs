@@ -1,3 +1,4 @@
+mod ancestors;
 mod de;
 mod ser;
 
@@ -3,7 +3,7 @@ use std::num::NonZero;
 use id_newtypes::IdRange;
 use schema::{
     EnumDefinitionId, FieldDefinitionId, InterfaceDefinitionId, ObjectDefinitionId, RequiredFieldId, ScalarType,
-    UnionDefinitionId, Wrapping,
+    SemanticNonNullDirective, UnionDefinitionId, Wrapping,
 };
 
 use crate::operation::FieldId;
@@ -38,6 +38,7 @@ pub(crate) struct FieldShape {
     pub definition_id: FieldDefinitionId,
     pub shape: Shape,
     pub wrapping: Wrapping,
+    pub semantic_non_null: SemanticNonNullDirective,
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
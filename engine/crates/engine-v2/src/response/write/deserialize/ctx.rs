@@ -34,6 +34,12 @@ impl<'ctx> SeedContext<'ctx> {
         }
     }
 
+    /// Whether a failing non-null field makes its parent null, or is set to null itself because
+    /// the client disabled null propagation.
+    pub(super) fn propagates_null(&self) -> bool {
+        self.operation.error_behavior.propagates_null()
+    }
+
     pub(super) fn push_edge(&self, edge: ResponseEdge) {
         self.path.borrow_mut().push(edge);
     }
@@ -1,4 +1,4 @@
-use schema::{ListWrapping, Wrapping};
+use schema::{ListWrapping, SemanticNonNullDirective, Wrapping};
 use serde::de::DeserializeSeed;
 
 use super::{
@@ -12,6 +12,8 @@ pub(super) struct FieldSeed<'ctx, 'parent> {
     pub ctx: &'parent SeedContext<'ctx>,
     pub field: &'parent FieldShape,
     pub wrapping: Wrapping,
+    /// Levels, relative to the current one, at which a null must be explained by an error.
+    pub semantic_non_null: SemanticNonNullDirective,
 }
 
 impl<'de, 'ctx, 'parent> DeserializeSeed<'de> for FieldSeed<'ctx, 'parent> {
@@ -20,22 +22,36 @@ impl<'de, 'ctx, 'parent> DeserializeSeed<'de> for FieldSeed<'ctx, 'parent> {
     where
         D: serde::Deserializer<'de>,
     {
-        let result = if let Some(list_wrapping) = self.wrapping.pop_list_wrapping() {
+        let list_wrapping = self.wrapping.pop_list_wrapping();
+        let mut is_required = match list_wrapping {
+            Some(list_wrapping) => list_wrapping == ListWrapping::RequiredList,
+            None => self.wrapping.inner_is_required(),
+        };
+        let mut is_semantic_non_null = self.semantic_non_null.contains(0);
+        // Without null propagation a non-null value behaves like a semantically non-null one.
+        if !self.ctx.propagates_null() {
+            is_semantic_non_null |= is_required;
+            is_required = false;
+        }
+        self.semantic_non_null = self.semantic_non_null.list_items();
+
+        let result = if list_wrapping.is_some() {
             let list_seed = ListSeed {
                 ctx: self.ctx,
                 field_id: self.field.id,
                 seed: &self,
             };
-            match list_wrapping {
-                ListWrapping::RequiredList => list_seed.deserialize(deserializer),
-                ListWrapping::NullableList => NullableSeed {
+            if is_required {
+                list_seed.deserialize(deserializer)
+            } else {
+                NullableSeed {
                     ctx: self.ctx,
                     field_id: self.field.id,
                     seed: list_seed,
                 }
-                .deserialize(deserializer),
+                .deserialize(deserializer)
             }
-        } else if self.wrapping.inner_is_required() {
+        } else if is_required {
             match self.field.shape {
                 Shape::Scalar(ty) => ScalarTypeSeed(ty).deserialize(deserializer),
                 Shape::Enum(id) => EnumValueSeed(self.ctx, id).deserialize(deserializer),
@@ -75,6 +91,17 @@ impl<'de, 'ctx, 'parent> DeserializeSeed<'de> for FieldSeed<'ctx, 'parent> {
             }
         };
 
+        if is_semantic_non_null && matches!(result, Ok(ResponseValue::Null)) {
+            self.ctx.writer.push_unexplained_null_error(
+                GraphqlError::new(
+                    "Error decoding response from upstream: Unexpected null without any error for a non-null value",
+                    ErrorCode::SubgraphInvalidResponseError,
+                )
+                .with_location(self.ctx.operation[self.field.id].location())
+                .with_path(self.ctx.response_path()),
+            );
+        }
+
         result.inspect_err(move |err| {
             if self.ctx.should_create_new_graphql_error() {
                 self.ctx.writer.push_error(
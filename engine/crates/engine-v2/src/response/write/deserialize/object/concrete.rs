@@ -6,8 +6,8 @@ use std::fmt;
 use crate::response::{
     value::ResponseObjectField,
     write::deserialize::{field::FieldSeed, key::Key, SeedContext},
-    ConcreteObjectShapeId, FieldShape, FieldShapeId, GraphqlError, ObjectIdentifier, ResponseEdge, ResponseObject,
-    ResponseObjectRef, ResponseObjectSetId, ResponseValue,
+    ConcreteObjectShapeId, ErrorCode, FieldShape, FieldShapeId, GraphqlError, ObjectIdentifier, ResponseEdge,
+    ResponseObject, ResponseObjectRef, ResponseObjectSetId, ResponseValue,
 };
 
 pub(crate) struct ConcreteObjectSeed<'ctx, 'seed> {
@@ -212,7 +212,7 @@ impl<'de, 'ctx, 'seed> ConcreteObjectFieldsSeed<'ctx, 'seed> {
                         ..self.ctx.operation.query_modifications[error_id].clone()
                     });
 
-                    if field_shape.wrapping.is_required() {
+                    if self.ctx.propagates_null() && field_shape.wrapping.is_required() {
                         required_field_error = true;
                     } else {
                         response_fields.push(ResponseObjectField {
@@ -229,17 +229,38 @@ impl<'de, 'ctx, 'seed> ConcreteObjectFieldsSeed<'ctx, 'seed> {
         }
 
         if response_fields.len() < self.field_shape_ids.len() - self.skipped_id_count {
-            let field_shapes = &self.ctx.operation.response_blueprint[self.field_shape_ids];
-            let n = response_fields.len();
-            for field_shape in field_shapes {
-                if response_fields[0..n]
-                    .binary_search_by(|field| field.edge.cmp(&field.edge))
-                    .is_err()
-                {
+            let mut present_edges = response_fields.iter().map(|field| field.edge).collect::<Vec<_>>();
+            present_edges.sort_unstable();
+            for id in self.field_shape_ids {
+                if self.ctx.operation.query_modifications.skipped_field_shape_ids[id] {
+                    continue;
+                }
+                let field_shape = &self.ctx.operation.response_blueprint[id];
+                if present_edges.binary_search(&field_shape.edge).is_err() {
                     if field_shape.wrapping.is_required() {
-                        return Err(serde::de::Error::custom(
-                            self.ctx.missing_field_error_message(field_shape),
-                        ));
+                        let message = self.ctx.missing_field_error_message(field_shape);
+                        if self.ctx.propagates_null() {
+                            return Err(serde::de::Error::custom(message));
+                        }
+
+                        let mut path = self.ctx.response_path();
+                        path.push(field_shape.edge);
+                        self.ctx.writer.push_error(
+                            GraphqlError::new(message, ErrorCode::SubgraphInvalidResponseError)
+                                .with_location(self.ctx.operation[field_shape.id].location())
+                                .with_path(path),
+                        );
+                    } else if field_shape.semantic_non_null.contains(0) {
+                        let mut path = self.ctx.response_path();
+                        path.push(field_shape.edge);
+                        self.ctx.writer.push_unexplained_null_error(
+                            GraphqlError::new(
+                                self.ctx.missing_field_error_message(field_shape),
+                                ErrorCode::SubgraphInvalidResponseError,
+                            )
+                            .with_location(self.ctx.operation[field_shape.id].location())
+                            .with_path(path),
+                        );
                     }
                     response_fields.push(ResponseObjectField {
                         edge: field_shape.edge,
@@ -345,6 +366,7 @@ impl<'de, 'ctx, 'seed> ConcreteObjectFieldsSeed<'ctx, 'seed> {
                 ctx: self.ctx,
                 field,
                 wrapping: field.wrapping,
+                semantic_non_null: field.semantic_non_null,
             });
             self.ctx.pop_edge();
             response_fields.push(ResponseObjectField {
@@ -362,6 +384,7 @@ impl<'de, 'ctx, 'seed> ConcreteObjectFieldsSeed<'ctx, 'seed> {
                     ctx: self.ctx,
                     field,
                     wrapping: field.wrapping,
+                    semantic_non_null: field.semantic_non_null,
                 }
                 .deserialize(serde_value::ValueDeserializer::new(stored_value.clone()));
                 self.ctx.pop_edge();
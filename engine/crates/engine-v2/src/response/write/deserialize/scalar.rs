@@ -21,6 +21,13 @@ impl<'de> DeserializeSeed<'de> for ScalarTypeSeed {
             ScalarType::BigInt => i64::deserialize(deserializer).map(Into::into),
             ScalarType::JSON => Box::<serde_json::Value>::deserialize(deserializer).map(Into::into),
             ScalarType::Boolean => bool::deserialize(deserializer).map(Into::into),
+            ScalarType::Date | ScalarType::DateTime | ScalarType::UUID | ScalarType::Email | ScalarType::URL => {
+                let value = String::deserialize(deserializer)?;
+                if !ty.is_valid_string(&value) {
+                    return Err(serde::de::Error::custom(format!("invalid {ty} value: {value:?}")));
+                }
+                Ok(value.into())
+            }
         }
     }
 }
@@ -23,6 +23,7 @@ use super::{
 use crate::{
     execution::{ExecutionContext, ExecutionError},
     operation::{LogicalPlanId, PreparedOperation},
+    request::ErrorBehavior,
     utils::BufferPool,
     Runtime,
 };
@@ -52,6 +53,7 @@ pub(crate) struct ResponseBuilder {
     pub(super) root: Option<(ResponseObjectId, ObjectDefinitionId)>,
     parts: Vec<ResponseDataPart>,
     errors: Vec<GraphqlError>,
+    error_behavior: ErrorBehavior,
 }
 
 // Only supporting additions for the current graph. Deletion are... tricky
@@ -60,7 +62,7 @@ pub(crate) struct ResponseBuilder {
 // least wait until we face actual problems. We're focused on OLTP workloads, so might never
 // happen.
 impl ResponseBuilder {
-    pub fn new(root_object_id: ObjectDefinitionId) -> Self {
+    pub fn new(root_object_id: ObjectDefinitionId, error_behavior: ErrorBehavior) -> Self {
         let mut initial_part = ResponseDataPart {
             id: ResponseDataPartId::from(0),
             objects: Vec::new(),
@@ -73,6 +75,7 @@ impl ResponseBuilder {
             root: Some((root_id, root_object_id)),
             parts: vec![initial_part],
             errors: Vec::new(),
+            error_behavior,
         }
     }
 
@@ -207,7 +210,12 @@ impl ResponseBuilder {
                     self[obj_ref.id].extend(fields);
                 }
                 UpdateSlot::Error => {
-                    if !invalidated_paths.iter().any(|path| obj_ref.path.starts_with(path)) {
+                    if let Some(fields) = default_fields
+                        .as_ref()
+                        .filter(|_| !self.error_behavior.propagates_null())
+                    {
+                        self[obj_ref.id].extend(fields.clone());
+                    } else if !invalidated_paths.iter().any(|path| obj_ref.path.starts_with(path)) {
                         if let Some(invalidated_path) = self.propagate_error(&obj_ref.path) {
                             invalidated_paths.push(invalidated_path);
                         }
@@ -215,6 +223,27 @@ impl ResponseBuilder {
                 }
             }
         }
+        // A null at a semantically non-null position is only valid if the subgraph explained it
+        // with an error at or below it. Errors without any path are execution errors which are
+        // also enough.
+        for error in subgraph_response.unexplained_null_errors {
+            let Some(path) = error.path.as_ref() else {
+                continue;
+            };
+            if invalidated_paths
+                .iter()
+                .any(|invalidated| path.starts_with(invalidated))
+            {
+                continue;
+            }
+            if !subgraph_response
+                .errors
+                .iter()
+                .any(|error| error.path.as_ref().map(|p| p.starts_with(path)).unwrap_or(true))
+            {
+                self.errors.push(error);
+            }
+        }
         self.errors.extend(subgraph_response.errors);
 
         let mut boundaries = subgraph_response.tracked_response_object_sets;
@@ -269,8 +298,11 @@ impl ResponseBuilder {
     // was in a different part (provided by a parent plan).
     // To correctly propagate error we're finding the last nullable element in the path and make it
     // nullable. If there's nothing, then root will be null.
+    // If the client opted out of null propagation, every element is treated as nullable so only
+    // the last one is set to null.
     fn propagate_error<'p>(&mut self, path: &'p ResponsePath) -> Option<&'p [ResponseEdge]> {
         let (root, _) = self.root?;
+        let propagates_null = self.error_behavior.propagates_null();
 
         let mut last_nullable_path_end = 0;
         let mut last_nullable: Option<ResponseValueId> = None;
@@ -311,7 +343,7 @@ impl ResponseBuilder {
                     part_id,
                     index,
                 } => {
-                    if nullable {
+                    if nullable || !propagates_null {
                         last_nullable_path_end = i;
                         last_nullable = Some(id);
                     }
@@ -323,7 +355,7 @@ impl ResponseBuilder {
                     offset,
                     length,
                 } => {
-                    if nullable {
+                    if nullable || !propagates_null {
                         last_nullable_path_end = i;
                         last_nullable = Some(id);
                     }
@@ -333,7 +365,13 @@ impl ResponseBuilder {
                         length,
                     });
                 }
-                _ => break,
+                _ => {
+                    if !propagates_null {
+                        last_nullable_path_end = i;
+                        last_nullable = Some(id);
+                    }
+                    break;
+                }
             }
         }
         if let Some(last_nullable) = last_nullable {
@@ -371,6 +409,9 @@ pub(crate) struct SubgraphResponse {
     logical_plan_id: LogicalPlanId,
     root_response_object_set: Arc<InputResponseObjectSet>,
     errors: Vec<GraphqlError>,
+    /// Errors for nulls at semantically non-null positions, only kept if the subgraph errors
+    /// don't explain them.
+    unexplained_null_errors: Vec<GraphqlError>,
     updates: Vec<UpdateSlot>,
     tracked_response_object_set_ids: IdRange<ResponseObjectSetId>,
     tracked_response_object_sets: Vec<ResponseObjectSet>,
@@ -389,6 +430,7 @@ impl SubgraphResponse {
             logical_plan_id,
             root_response_object_set,
             errors: Vec::new(),
+            unexplained_null_errors: Vec::new(),
             updates: Vec::new(),
             tracked_response_object_set_ids,
             tracked_response_object_sets: tracked_response_object_set_ids
@@ -508,6 +550,10 @@ impl<'resp> ResponseWriter<'resp> {
         self.part().errors.push(error.into());
     }
 
+    pub fn push_unexplained_null_error(&self, error: impl Into<GraphqlError>) {
+        self.part().unexplained_null_errors.push(error.into());
+    }
+
     pub fn push_response_object(&self, set_id: ResponseObjectSetId, obj: ResponseObjectRef) {
         let mut part = self.part();
         let i = part
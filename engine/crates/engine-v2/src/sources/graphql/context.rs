@@ -17,13 +17,14 @@ use web_time::Instant;
 use grafbase_telemetry::{
     graphql::SubgraphResponseStatus,
     metrics::{
-        SubgraphCacheHitAttributes, SubgraphCacheMissAttributes, SubgraphInFlightRequestAttributes,
-        SubgraphRequestBodySizeAttributes, SubgraphRequestDurationAttributes, SubgraphRequestRetryAttributes,
-        SubgraphResponseBodySizeAttributes,
+        SubgraphCacheHitAttributes, SubgraphCacheMissAttributes, SubgraphCircuitBreakerAttributes,
+        SubgraphInFlightRequestAttributes, SubgraphRequestBodySizeAttributes, SubgraphRequestDeduplicatedAttributes,
+        SubgraphRequestDurationAttributes, SubgraphRequestRetryAttributes, SubgraphResponseBodySizeAttributes,
     },
 };
 
 use crate::{
+    engine::{CircuitBreaker, CircuitBreakerPermit, CircuitBreakerTransition, Hedger, LoadBalancer},
     execution::{ExecutionContext, ExecutionError, ExecutionResult, RequestHooks},
     response::SubgraphResponse,
     sources::ResolverResult,
@@ -35,6 +36,10 @@ pub(crate) struct SubgraphContext<'ctx, R: Runtime> {
     pub(super) ctx: ExecutionContext<'ctx, R>,
     pub(super) endpoint: GraphqlEndpoint<'ctx>,
     pub(super) retry_budget: Option<&'ctx TpsBudget>,
+    hedger: Option<&'ctx Hedger>,
+    circuit_breaker: Option<&'ctx CircuitBreaker>,
+    load_balancer: Option<&'ctx LoadBalancer>,
+    deduplicate_requests: bool,
     span: SubgraphGraphqlRequestSpan,
     start: Instant,
     executed_request_builder: ExecutedSubgraphRequestBuilder<'ctx>,
@@ -64,6 +69,16 @@ impl<'ctx, R: Runtime> SubgraphContext<'ctx, R> {
             "mutation" => ctx.engine.get_retry_budget_for_mutation(endpoint.id()),
             _ => ctx.engine.get_retry_budget_for_non_mutation(endpoint.id()),
         };
+        // Only queries are hedged, mutations must never be sent twice concurrently and there is
+        // no response to wait for with subscriptions.
+        let hedger = match span.operation_type {
+            "query" => ctx.engine.get_hedger_for_query(endpoint.id()),
+            _ => None,
+        };
+        // Same for deduplication, a mutation must be executed as many times as it's requested.
+        let deduplicate_requests = span.operation_type == "query" && endpoint.config.deduplicate_requests;
+        let circuit_breaker = ctx.engine.get_circuit_breaker(endpoint.id());
+        let load_balancer = ctx.engine.get_load_balancer(endpoint.id());
         let span = span.build();
 
         Self {
@@ -73,6 +88,10 @@ impl<'ctx, R: Runtime> SubgraphContext<'ctx, R> {
             span,
             start: Instant::now(),
             retry_budget,
+            hedger,
+            circuit_breaker,
+            load_balancer,
+            deduplicate_requests,
             status: None,
             http_status_code: None,
             send_count: 0,
@@ -99,10 +118,26 @@ impl<'ctx, R: Runtime> SubgraphContext<'ctx, R> {
         self.execution_context().hooks()
     }
 
-    pub fn retry_budget(&self) -> Option<&TpsBudget> {
+    pub fn retry_budget(&self) -> Option<&'ctx TpsBudget> {
         self.retry_budget
     }
 
+    pub fn hedger(&self) -> Option<&'ctx Hedger> {
+        self.hedger
+    }
+
+    pub fn circuit_breaker(&self) -> Option<&'ctx CircuitBreaker> {
+        self.circuit_breaker
+    }
+
+    pub fn load_balancer(&self) -> Option<&'ctx LoadBalancer> {
+        self.load_balancer
+    }
+
+    pub fn deduplicate_requests(&self) -> bool {
+        self.deduplicate_requests
+    }
+
     pub fn send_count(&self) -> Option<usize> {
         self.send_count.checked_sub(1)
     }
@@ -207,6 +242,49 @@ impl<'ctx, R: Runtime> SubgraphContext<'ctx, R> {
         });
     }
 
+    pub(super) fn record_circuit_breaker_rejection(&mut self) {
+        self.push_request_execution(SubgraphRequestExecutionKind::CircuitOpen);
+        self.metrics()
+            .record_subgraph_circuit_breaker_rejection(SubgraphCircuitBreakerAttributes {
+                name: self.endpoint.subgraph_name().to_string(),
+            });
+    }
+
+    pub(super) fn record_request_deduplicated(&mut self) {
+        self.push_request_execution(SubgraphRequestExecutionKind::Deduplicated);
+        self.metrics()
+            .record_subgraph_request_deduplicated(SubgraphRequestDeduplicatedAttributes {
+                name: self.endpoint.subgraph_name().to_string(),
+            });
+    }
+
+    pub(super) fn record_circuit_breaker_outcome(&self, permit: Option<CircuitBreakerPermit<'_>>, success: bool) {
+        let Some(transition) = permit.and_then(|permit| permit.record(success)) else {
+            return;
+        };
+
+        let attributes = SubgraphCircuitBreakerAttributes {
+            name: self.endpoint.subgraph_name().to_string(),
+        };
+
+        match transition {
+            CircuitBreakerTransition::Opened => {
+                tracing::warn!(
+                    "circuit breaker opened for subgraph '{}'",
+                    self.endpoint.subgraph_name()
+                );
+                self.metrics().record_subgraph_circuit_breaker_opened(attributes);
+            }
+            CircuitBreakerTransition::Closed => {
+                tracing::info!(
+                    "circuit breaker closed for subgraph '{}'",
+                    self.endpoint.subgraph_name()
+                );
+                self.metrics().record_subgraph_circuit_breaker_closed(attributes);
+            }
+        }
+    }
+
     pub(super) fn push_request_execution(&mut self, kind: SubgraphRequestExecutionKind) {
         self.executed_request_builder.push_execution(kind)
     }
@@ -2,17 +2,17 @@ use bytes::Bytes;
 use futures::future::join_all;
 use grafbase_telemetry::{graphql::GraphqlResponseStatus, span::subgraph::SubgraphRequestSpanBuilder};
 use http::HeaderMap;
-use runtime::bytes::OwnedOrSharedBytes;
-use schema::{GraphqlEndpoint, GraphqlEndpointId, GraphqlFederationEntityResolverDefinition};
+use runtime::{bytes::OwnedOrSharedBytes, fetch::ContentEncoding};
+use schema::{CompressionAlgorithm, GraphqlEndpoint, GraphqlEndpointId, GraphqlFederationEntityResolverDefinition};
 use serde::{de::DeserializeSeed, Deserialize};
 use serde_json::value::RawValue;
-use std::{borrow::Cow, time::Duration};
+use std::{borrow::Cow, collections::HashMap, time::Duration};
 use tracing::Instrument;
 use walker::Walk;
 
 use crate::{
     execution::{ExecutionContext, ExecutionError, PlanningResult},
-    operation::{CacheScope, OperationType, PlanWalker},
+    operation::{CacheScope, OperationType, PlanWalker, UploadId},
     response::{ResponseObjectsView, SubgraphResponse},
     sources::{
         graphql::{
@@ -72,6 +72,7 @@ impl FederationEntityResolver {
         subgraph_response: SubgraphResponse,
     ) -> ExecutionResult<FederationEntityRequest<'ctx>> {
         ctx.span().in_scope(|| {
+            let context_groups = self.context_groups(ctx, &root_response_objects);
             let root_response_objects = root_response_objects.with_extra_constant_fields(vec![(
                 "__typename".to_string(),
                 serde_json::Value::String(entity_name(ctx, plan)),
@@ -87,9 +88,56 @@ impl FederationEntityResolver {
                 plan,
                 subgraph_response,
                 representations,
+                context_groups,
             })
         })
     }
+
+    /// Groups the representations by the values of their `@fromContext` arguments.
+    fn context_groups<R: Runtime>(
+        &self,
+        ctx: &SubgraphContext<'_, R>,
+        root_response_objects: &ResponseObjectsView<'_>,
+    ) -> Vec<ContextGroup> {
+        if self.operation.context_variables.is_empty() {
+            return Vec::new();
+        }
+
+        let schema = ctx.schema();
+        let paths = self
+            .operation
+            .context_variables
+            .iter()
+            .map(|variable| {
+                variable
+                    .field_set_ids
+                    .iter()
+                    .map(|id| id.walk(schema).as_ref())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut groups = Vec::<ContextGroup>::new();
+        let mut group_by_values = HashMap::<String, usize>::new();
+        for (i, values) in root_response_objects.ancestor_values(&paths).into_iter().enumerate() {
+            let key = serde_json::to_string(&values).unwrap_or_default();
+            let group = *group_by_values.entry(key).or_insert_with(|| {
+                groups.push(ContextGroup {
+                    values,
+                    representation_indices: Vec::new(),
+                });
+                groups.len() - 1
+            });
+            groups[group].representation_indices.push(i);
+        }
+
+        groups
+    }
+}
+
+struct ContextGroup {
+    values: Vec<serde_json::Value>,
+    representation_indices: Vec<usize>,
 }
 
 pub(crate) struct FederationEntityRequest<'ctx> {
@@ -97,6 +145,8 @@ pub(crate) struct FederationEntityRequest<'ctx> {
     plan: PlanWalker<'ctx>,
     subgraph_response: SubgraphResponse,
     representations: Vec<Box<RawValue>>,
+    /// Empty if the plan has no `@fromContext` arguments.
+    context_groups: Vec<ContextGroup>,
 }
 
 impl<'ctx> FederationEntityRequest<'ctx> {
@@ -106,6 +156,7 @@ impl<'ctx> FederationEntityRequest<'ctx> {
             plan,
             subgraph_response,
             mut representations,
+            context_groups,
         } = self;
         let span = ctx.span();
 
@@ -136,7 +187,8 @@ impl<'ctx> FederationEntityRequest<'ctx> {
                 })
                 .collect::<Vec<_>>();
 
-            if cache_ttl.is_some() {
+            // Context values aren't part of the representations, so we can't rely on the cache.
+            if cache_ttl.is_some() && context_groups.is_empty() {
                 match cache_fetches(ctx, &headers, representations, &additional_scopes).await {
                     CacheFetchOutcome::FullyCached { cache_entries } => {
                         ctx.record_cache_hit();
@@ -170,32 +222,189 @@ impl<'ctx> FederationEntityRequest<'ctx> {
                 }
             }
 
-            let variables = SubgraphVariables {
-                plan,
-                variables: &operation.variables,
-                extra_variables: vec![(&operation.entities_variable_name, representations)],
+            let (body, uploads) = if context_groups.is_empty() {
+                let variables = SubgraphVariables {
+                    plan,
+                    variables: &operation.variables,
+                    extra_variables: vec![(&operation.entities_variable_name, representations)],
+                };
+
+                tracing::debug!(
+                    "Executing request to subgraph named '{}' with query and variables:\n{}\n{}",
+                    ctx.endpoint().subgraph_name(),
+                    self.resolver.operation.query,
+                    serde_json::to_string_pretty(&variables).unwrap_or_default()
+                );
+
+                let uploads = variables.uploads();
+                let body = serde_json::to_vec(&SubgraphGraphqlRequest {
+                    query: &operation.query,
+                    variables,
+                })
+                .map_err(|err| format!("Failed to serialize query: {err}"))?;
+                (body, uploads)
+            } else {
+                contextual_request_body(ctx, operation, plan, representations, &context_groups)?
             };
 
-            tracing::debug!(
-                "Executing request to subgraph named '{}' with query and variables:\n{}\n{}",
-                ctx.endpoint().subgraph_name(),
-                self.resolver.operation.query,
-                serde_json::to_string_pretty(&variables).unwrap_or_default()
-            );
-
-            let body = serde_json::to_vec(&SubgraphGraphqlRequest {
-                query: &operation.query,
-                variables,
-            })
-            .map_err(|err| format!("Failed to serialize query: {err}"))?;
-
-            execute_subgraph_request(ctx, headers, Bytes::from(body), ingester).await
+            // Representations can be quite large, so those requests may be compressed.
+            let compression = ctx
+                .endpoint()
+                .config
+                .compression
+                .entities_request_compression(body.len())
+                .map(|algorithm| match algorithm {
+                    CompressionAlgorithm::Gzip => ContentEncoding::Gzip,
+                    CompressionAlgorithm::Brotli => ContentEncoding::Brotli,
+                    CompressionAlgorithm::Zstd => ContentEncoding::Zstd,
+                });
+
+            if context_groups.len() > 1 {
+                let ingester = ContextGroupsIngester {
+                    inner: ingester,
+                    groups: context_groups
+                        .into_iter()
+                        .map(|group| group.representation_indices)
+                        .collect(),
+                };
+                execute_subgraph_request(ctx, headers, Bytes::from(body), compression, uploads, ingester).await
+            } else {
+                execute_subgraph_request(ctx, headers, Bytes::from(body), compression, uploads, ingester).await
+            }
         }
         .instrument(span)
         .await
     }
 }
 
+/// Builds the request for a plan with `@fromContext` arguments. If all representations share the
+/// same context values, it's the usual `_entities` query, otherwise each group of representations
+/// has its own `_{group}: _entities` field. The files used by the request are returned with the body.
+fn contextual_request_body<R: Runtime>(
+    ctx: &SubgraphContext<'_, R>,
+    operation: &PreparedFederationEntityOperation,
+    plan: PlanWalker<'_>,
+    representations: Vec<Box<RawValue>>,
+    context_groups: &[ContextGroup],
+) -> ExecutionResult<(Vec<u8>, Vec<(UploadId, String)>)> {
+    let (query, names, values) = if let [group] = context_groups {
+        let mut names = vec![operation.entities_variable_name.clone()];
+        let mut values = vec![serde_json::value::to_raw_value(&representations)?];
+        for (idx, value) in group.values.iter().enumerate() {
+            names.push(PreparedFederationEntityOperation::context_variable_name(idx, None));
+            values.push(serde_json::value::to_raw_value(value)?);
+        }
+        (Cow::Borrowed(operation.query.as_str()), names, values)
+    } else {
+        let query = operation
+            .query_for_context_groups(context_groups.len())
+            .map_err(|err| format!("Failed to build query: {err}"))?;
+        let mut names = Vec::new();
+        let mut values = Vec::new();
+        for (group_idx, group) in context_groups.iter().enumerate() {
+            names.push(format!("{}_{group_idx}", operation.entities_variable_name));
+            values.push(serde_json::value::to_raw_value(
+                &group
+                    .representation_indices
+                    .iter()
+                    .map(|&i| &representations[i])
+                    .collect::<Vec<_>>(),
+            )?);
+            for (idx, value) in group.values.iter().enumerate() {
+                names.push(PreparedFederationEntityOperation::context_variable_name(
+                    idx,
+                    Some(group_idx),
+                ));
+                values.push(serde_json::value::to_raw_value(value)?);
+            }
+        }
+        (Cow::Owned(query), names, values)
+    };
+
+    let variables = SubgraphVariables {
+        plan,
+        variables: &operation.variables,
+        extra_variables: names.iter().map(String::as_str).zip(values).collect(),
+    };
+
+    tracing::debug!(
+        "Executing request to subgraph named '{}' with query and variables:\n{}\n{}",
+        ctx.endpoint().subgraph_name(),
+        query,
+        serde_json::to_string_pretty(&variables).unwrap_or_default()
+    );
+
+    let uploads = variables.uploads();
+    let body = serde_json::to_vec(&SubgraphGraphqlRequest {
+        query: &query,
+        variables,
+    })
+    .map_err(|err| format!("Failed to serialize query: {err}"))?;
+
+    Ok((body, uploads))
+}
+
+/// Merges the aliased `_entities` fields of each context group back into a single `_entities`
+/// list in the original order of the representations, so that the usual ingestion applies.
+struct ContextGroupsIngester<I> {
+    inner: I,
+    groups: Vec<Vec<usize>>,
+}
+
+impl<I: ResponseIngester> ResponseIngester for ContextGroupsIngester<I> {
+    async fn ingest(
+        self,
+        http_response: http::Response<OwnedOrSharedBytes>,
+    ) -> Result<(GraphqlResponseStatus, SubgraphResponse), ExecutionError> {
+        let (parts, body) = http_response.into_parts();
+        // If the response isn't valid JSON, the inner ingester will report it.
+        let body = match merge_context_groups(&body, &self.groups) {
+            Some(merged) => OwnedOrSharedBytes::from(merged),
+            None => body,
+        };
+        self.inner.ingest(http::Response::from_parts(parts, body)).await
+    }
+}
+
+fn merge_context_groups(body: &[u8], groups: &[Vec<usize>]) -> Option<Vec<u8>> {
+    let mut response = serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(body).ok()?;
+
+    if let Some(serde_json::Value::Object(data)) = response.get_mut("data") {
+        let mut entities = vec![serde_json::Value::Null; groups.iter().map(Vec::len).sum()];
+        for (group, indices) in groups.iter().enumerate() {
+            if let Some(serde_json::Value::Array(items)) = data.remove(&format!("_{group}")) {
+                for (&i, item) in indices.iter().zip(items) {
+                    entities[i] = item;
+                }
+            }
+        }
+        data.clear();
+        data.insert("_entities".to_string(), serde_json::Value::Array(entities));
+    }
+
+    if let Some(serde_json::Value::Array(errors)) = response.get_mut("errors") {
+        for error in errors {
+            let Some(serde_json::Value::Array(path)) = error.get_mut("path") else {
+                continue;
+            };
+            let original_index = path
+                .first()
+                .and_then(serde_json::Value::as_str)
+                .and_then(|key| key.strip_prefix('_'))
+                .and_then(|group| group.parse::<usize>().ok())
+                .and_then(|group| groups.get(group))
+                .zip(path.get(1).and_then(serde_json::Value::as_u64))
+                .and_then(|(indices, i)| indices.get(i as usize).copied());
+            if let Some(i) = original_index {
+                path[0] = serde_json::Value::String("_entities".to_string());
+                path[1] = serde_json::Value::from(i);
+            }
+        }
+    }
+
+    serde_json::to_vec(&response).ok()
+}
+
 async fn cache_fetches<'ctx, R: Runtime>(
     ctx: &mut SubgraphContext<'ctx, R>,
     headers: &http::HeaderMap,
@@ -1,24 +1,33 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, pin::pin};
 
 use bytes::Bytes;
-use futures::Future;
-use grafbase_telemetry::{graphql::GraphqlResponseStatus, otel::tracing_opentelemetry::OpenTelemetrySpanExt as _};
+use futures::{
+    future::{self, Either},
+    Future,
+};
+use grafbase_telemetry::{
+    graphql::GraphqlResponseStatus, otel::tracing_opentelemetry::OpenTelemetrySpanExt as _,
+    span::subgraph::SubgraphHttpRequestSpan,
+};
 use headers::HeaderMapExt;
 use runtime::{
     bytes::OwnedOrSharedBytes,
-    fetch::{FetchError, FetchRequest, FetchResult, Fetcher},
+    fetch::{ContentEncoding, FetchError, FetchRequest, FetchResult, Fetcher},
     hooks::{ResponseInfo, SubgraphRequestExecutionKind},
     rate_limiting::RateLimitKey,
 };
-use tower::retry::budget::Budget;
+use tower::retry::budget::{Budget, TpsBudget};
 use tracing::Instrument;
-use web_time::Duration;
+use web_time::{Duration, Instant};
 
+use super::MultipartSubgraphRequest;
 use crate::{
+    engine::{CircuitBreakerPermit, Deduplicated, Hedger, LoadBalancer},
     execution::{ExecutionError, ExecutionResult},
+    operation::UploadId,
     response::{ErrorCode, GraphqlError, SubgraphResponse},
     sources::graphql::SubgraphContext,
-    Runtime,
+    Engine, Runtime,
 };
 
 pub trait ResponseIngester: Send {
@@ -32,11 +41,13 @@ pub(crate) async fn execute_subgraph_request<'ctx, 'a, R: Runtime>(
     ctx: &mut SubgraphContext<'ctx, R>,
     headers: http::HeaderMap,
     body: Bytes,
+    compression: Option<ContentEncoding>,
+    uploads: Vec<(UploadId, String)>,
     ingester: impl ResponseIngester,
 ) -> ExecutionResult<SubgraphResponse> {
     let endpoint = ctx.endpoint();
 
-    let request = {
+    let (request, deduplication_key) = {
         let mut headers = ctx
             .hooks()
             .on_subgraph_request(endpoint.subgraph_name(), http::Method::POST, endpoint.url(), headers)
@@ -46,8 +57,33 @@ pub(crate) async fn execute_subgraph_request<'ctx, 'a, R: Runtime>(
                 ctx.push_request_execution(SubgraphRequestExecutionKind::HookError);
             })?;
 
-        headers.typed_insert(headers::ContentType::json());
+        let variables = &ctx.execution_context().operation.variables;
+        let (body, compression) = match MultipartSubgraphRequest::build(&body, &uploads, variables)
+            .map_err(|err| format!("Failed to build multipart request: {err}"))?
+        {
+            Some(multipart) => {
+                headers.insert(http::header::CONTENT_TYPE, multipart.content_type());
+                // Subgraphs with CSRF prevention, such as Apollo Server, reject multipart requests
+                // without this header.
+                headers.insert(
+                    http::HeaderName::from_static("apollo-require-preflight"),
+                    http::HeaderValue::from_static("true"),
+                );
+                // Files are usually compressed already.
+                (multipart.body, None)
+            }
+            None => {
+                headers.typed_insert(headers::ContentType::json());
+                (body, compression)
+            }
+        };
         headers.typed_insert(headers::ContentLength(body.len() as u64));
+        if endpoint.config.compression.accept_compressed_responses {
+            headers.insert(
+                http::header::ACCEPT_ENCODING,
+                http::HeaderValue::from_static("gzip, br, zstd"),
+            );
+        }
         headers.insert(
             http::header::ACCEPT,
             http::HeaderValue::from_static(
@@ -55,27 +91,40 @@ pub(crate) async fn execute_subgraph_request<'ctx, 'a, R: Runtime>(
             ),
         );
 
+        // Computed before injecting the trace context, which differs for every request.
+        let deduplication_key = (ctx.deduplicate_requests() && uploads.is_empty())
+            .then(|| super::request_key(endpoint, endpoint.url(), &headers, &body));
+
         grafbase_telemetry::otel::opentelemetry::global::get_text_map_propagator(|propagator| {
             let context = tracing::Span::current().context();
 
             propagator.inject_context(&context, &mut grafbase_telemetry::http::HeaderInjector(&mut headers));
         });
 
-        FetchRequest {
+        let request = FetchRequest {
+            subgraph_name: endpoint.subgraph_name(),
             url: Cow::Borrowed(endpoint.url()),
             headers,
             method: http::Method::POST,
             body,
             timeout: endpoint.config.timeout,
-        }
+            compression,
+        };
+
+        (request, deduplication_key)
     };
 
     ctx.record_request_size(&request);
 
     let fetcher = ctx.engine.runtime.fetcher();
     let http_span = ctx.create_subgraph_request_span(&request);
-    let fetch_result = retrying_fetch(ctx, || async {
-        let (fetch_result, info) = fetcher.fetch(request.clone()).instrument(http_span.span()).await;
+    let load_balancer = ctx.load_balancer();
+    let engine = ctx.execution_context().engine;
+    let fetch = retrying_fetch(ctx, || async {
+        let (fetch_result, info) = match load_balancer {
+            Some(load_balancer) => balanced_fetch(fetcher, load_balancer, &request, &http_span).await,
+            None => fetcher.fetch(request.clone()).instrument(http_span.span()).await,
+        };
 
         let fetch_result = fetch_result.and_then(|response| {
             tracing::debug!("Received response:\n{}", String::from_utf8_lossy(response.body()));
@@ -90,8 +139,19 @@ pub(crate) async fn execute_subgraph_request<'ctx, 'a, R: Runtime>(
         });
 
         (fetch_result, info)
-    })
-    .await;
+    });
+
+    let fetch_result = match deduplication_key {
+        Some(key) => match engine.request_deduplicator.run(key, fetch).await {
+            Deduplicated::Shared(result) => {
+                tracing::debug!("Using the outcome of an identical in-flight subgraph request");
+                ctx.record_request_deduplicated();
+                result
+            }
+            Deduplicated::Sent(result) => result,
+        },
+        None => fetch.await,
+    };
 
     if let Some(count) = ctx.send_count() {
         http_span.record_resend_count(count);
@@ -138,6 +198,35 @@ pub(crate) async fn execute_subgraph_request<'ctx, 'a, R: Runtime>(
     }
 }
 
+/// Sends the request to one of the subgraph replicas, failing over to the next one when the
+/// connection can't be established.
+async fn balanced_fetch<'a>(
+    fetcher: &impl Fetcher,
+    load_balancer: &'a LoadBalancer,
+    request: &FetchRequest<'a, Bytes>,
+    http_span: &SubgraphHttpRequestSpan,
+) -> (FetchResult<http::Response<OwnedOrSharedBytes>>, Option<ResponseInfo>) {
+    let mut tried = Vec::with_capacity(load_balancer.len());
+    let mut last_connect_error = None;
+
+    while let Some(upstream) = load_balancer.select(&tried) {
+        let mut request = request.clone();
+        request.url = Cow::Borrowed(upstream.url());
+        http_span.record_upstream_url(upstream.url());
+
+        match fetcher.fetch(request).instrument(http_span.span()).await {
+            (Err(err), info) if err.is_connect() => {
+                upstream.eject();
+                tried.push(upstream.index());
+                last_connect_error = Some((Err(err), info));
+            }
+            result => return result,
+        }
+    }
+
+    last_connect_error.unwrap_or_else(|| (Err(FetchError::any("No subgraph upstream available")), None))
+}
+
 pub(crate) async fn retrying_fetch<'ctx, R: Runtime, F, T>(
     ctx: &mut SubgraphContext<'ctx, R>,
     fetch: impl Fn() -> F + Send + Sync,
@@ -146,7 +235,13 @@ where
     F: Future<Output = (FetchResult<T>, Option<ResponseInfo>)> + Send,
     T: Send,
 {
-    let mut fetch_result = rate_limited_fetch(ctx, &fetch).await;
+    let engine = ctx.execution_context().engine;
+    let subgraph_name = ctx.endpoint().subgraph_name();
+    let hedging = ctx.hedger().zip(ctx.retry_budget());
+    let fetch = || hedged_fetch(engine, subgraph_name, hedging, &fetch);
+
+    let permit = acquire_circuit_breaker_permit(ctx)?;
+    let mut fetch_result = rate_limited_fetch(ctx, permit, &fetch).await;
 
     if ctx.retry_budget().is_none() {
         return fetch_result;
@@ -171,11 +266,14 @@ where
                     let backoff_ms = (exp_backoff * jitter).round() as u64;
 
                     ctx.engine().runtime.sleep(Duration::from_millis(backoff_ms)).await;
+
+                    // The circuit may have opened while we were waiting.
+                    let permit = acquire_circuit_breaker_permit(ctx)?;
                     ctx.record_request_retry();
 
                     counter += 1;
 
-                    fetch_result = rate_limited_fetch(ctx, &fetch).await;
+                    fetch_result = rate_limited_fetch(ctx, permit, &fetch).await;
                 } else {
                     ctx.record_aborted_request_retry();
 
@@ -186,8 +284,100 @@ where
     }
 }
 
+/// A `None` permit means the subgraph has no circuit breaker.
+fn acquire_circuit_breaker_permit<'ctx, R: Runtime>(
+    ctx: &mut SubgraphContext<'ctx, R>,
+) -> ExecutionResult<Option<CircuitBreakerPermit<'ctx>>> {
+    let Some(breaker) = ctx.circuit_breaker() else {
+        return Ok(None);
+    };
+
+    match breaker.try_acquire() {
+        Some(permit) => Ok(Some(permit)),
+        None => {
+            ctx.record_circuit_breaker_rejection();
+
+            Err(GraphqlError::new(
+                format!(
+                    "Request to subgraph '{}' was not sent, its circuit breaker is open",
+                    ctx.endpoint().subgraph_name()
+                ),
+                ErrorCode::SubgraphRequestError,
+            )
+            .into())
+        }
+    }
+}
+
+/// Sends a second identical request if the subgraph hasn't answered within the hedging delay and
+/// both the retry budget and the subgraph rate limit allow it. The first successful response wins.
+async fn hedged_fetch<R: Runtime, F, T>(
+    engine: &Engine<R>,
+    subgraph_name: &str,
+    hedging: Option<(&Hedger, &TpsBudget)>,
+    fetch: &(impl Fn() -> F + Send + Sync),
+) -> (FetchResult<T>, Option<ResponseInfo>)
+where
+    F: Future<Output = (FetchResult<T>, Option<ResponseInfo>)> + Send,
+    T: Send,
+{
+    let Some((hedger, retry_budget)) = hedging else {
+        return fetch().await;
+    };
+
+    // Only the latencies of the original request are recorded. Those of a winning hedged request
+    // would pull the percentile down and make hedging ever more frequent.
+    let start = Instant::now();
+    let first = pin!(fetch());
+    let first = match future::select(first, pin!(engine.runtime.sleep(hedger.delay()))).await {
+        Either::Left((result, _)) => {
+            if result.0.is_ok() {
+                hedger.record_latency(start.elapsed());
+            }
+            return result;
+        }
+        Either::Right(((), first)) => first,
+    };
+
+    let hedge = retry_budget.withdraw()
+        && engine
+            .runtime
+            .rate_limiter()
+            .limit(&RateLimitKey::Subgraph(subgraph_name.into()))
+            .await
+            .is_ok();
+
+    if !hedge {
+        let result = first.await;
+        if result.0.is_ok() {
+            hedger.record_latency(start.elapsed());
+        }
+        return result;
+    }
+
+    tracing::debug!("Sending hedged subgraph request");
+    match future::select(first, pin!(fetch())).await {
+        Either::Left(((Ok(response), info), _)) => {
+            hedger.record_latency(start.elapsed());
+            (Ok(response), info)
+        }
+        Either::Right(((Ok(response), info), _)) => (Ok(response), info),
+        Either::Left((_, hedged)) => hedged.await,
+        Either::Right((_, first)) => {
+            let result = first.await;
+            if result.0.is_ok() {
+                hedger.record_latency(start.elapsed());
+            }
+            result
+        }
+    }
+}
+
+/// The circuit breaker permit is dropped without an outcome if the rate limiter rejects the
+/// request, as the subgraph was never reached.
 async fn rate_limited_fetch<'ctx, R: Runtime, F, T>(
     ctx: &mut SubgraphContext<'ctx, R>,
+    permit: Option<CircuitBreakerPermit<'ctx>>,
     fetch: impl Fn() -> F + Send,
 ) -> ExecutionResult<T>
 where
@@ -206,6 +396,7 @@ where
     ctx.increment_inflight_requests();
     let (result, response_info) = fetch().await;
     ctx.decrement_inflight_requests();
+    ctx.record_circuit_breaker_outcome(permit, result.is_ok());
 
     match response_info {
         Some(response_info) => ctx.push_request_execution(SubgraphRequestExecutionKind::Responsed(response_info)),
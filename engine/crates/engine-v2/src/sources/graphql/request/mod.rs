@@ -1,7 +1,43 @@
 mod execute;
+mod multipart;
 mod prepare;
 mod types;
 
 pub(super) use execute::*;
+pub(super) use multipart::*;
 pub(super) use prepare::*;
 pub(super) use types::*;
+
+use schema::GraphqlEndpoint;
+use url::Url;
+
+use crate::engine::RequestKey;
+
+/// Identical requests to a subgraph, down to the headers sent, share the same key.
+pub(super) fn request_key(
+    endpoint: GraphqlEndpoint<'_>,
+    url: &Url,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> RequestKey {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(endpoint.subgraph_name().as_bytes());
+    hasher.update(&[0x00]);
+    hasher.update(url.as_str().as_bytes());
+    hasher.update(&[0x00]);
+    hasher.update(&body.len().to_ne_bytes());
+    hasher.update(body);
+
+    let mut headers = headers.iter().collect::<Vec<_>>();
+    headers.sort_unstable_by(|(a_name, a_value), (b_name, b_value)| {
+        (a_name.as_str(), a_value.as_bytes()).cmp(&(b_name.as_str(), b_value.as_bytes()))
+    });
+    for (name, value) in headers {
+        hasher.update(name.as_str().as_bytes());
+        hasher.update(&[0x00]);
+        hasher.update(&value.len().to_ne_bytes());
+        hasher.update(value.as_bytes());
+    }
+
+    hasher.finalize().into()
+}
@@ -0,0 +1,101 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::operation::{UploadId, Variables};
+
+/// Subgraph request following the GraphQL multipart request spec, sent whenever the variables
+/// forwarded to the subgraph reference uploaded files.
+pub(crate) struct MultipartSubgraphRequest {
+    pub boundary: String,
+    pub body: Bytes,
+}
+
+impl MultipartSubgraphRequest {
+    pub(crate) fn content_type(&self) -> http::HeaderValue {
+        http::HeaderValue::try_from(format!("multipart/form-data; boundary={}", self.boundary))
+            .expect("boundary is a valid header value")
+    }
+
+    /// Returns `None` if the subgraph request uses no file, in which case it should be sent as
+    /// JSON. `uploads` are the files used with their position in the request as returned by
+    /// [super::SubgraphVariables::uploads].
+    pub(crate) fn build(
+        json_body: &[u8],
+        uploads: &[(UploadId, String)],
+        variables: &Variables,
+    ) -> Result<Option<Self>, serde_json::Error> {
+        if uploads.is_empty() {
+            return Ok(None);
+        }
+
+        // A file used multiple times is sent once, referenced by all of its positions.
+        let mut map: Vec<(UploadId, Vec<&str>)> = Vec::new();
+        for (id, path) in uploads {
+            match map.iter_mut().find(|(i, _)| i == id) {
+                Some((_, paths)) => paths.push(path),
+                None => map.push((*id, vec![path])),
+            }
+        }
+
+        let boundary = format!("grafbase-{:032x}", rand::random::<u128>());
+        let mut body = BytesMut::with_capacity(
+            json_body.len()
+                + map
+                    .iter()
+                    .map(|(id, _)| variables[*id].content.len() + 256)
+                    .sum::<usize>(),
+        );
+
+        write_part_header(&mut body, &boundary, "operations", None, Some("application/json"));
+        body.put_slice(json_body);
+
+        write_part_header(&mut body, &boundary, "map", None, Some("application/json"));
+        let map_json = serde_json::Value::Object(
+            map.iter()
+                .enumerate()
+                .map(|(i, (_, paths))| (i.to_string(), paths.clone().into()))
+                .collect(),
+        );
+        body.put_slice(&serde_json::to_vec(&map_json)?);
+
+        for (i, (id, _)) in map.iter().enumerate() {
+            let upload = &variables[*id];
+            write_part_header(
+                &mut body,
+                &boundary,
+                &i.to_string(),
+                Some(&upload.filename),
+                upload.content_type.as_deref(),
+            );
+            body.put_slice(&upload.content);
+        }
+
+        body.put_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+        Ok(Some(MultipartSubgraphRequest {
+            boundary,
+            body: body.freeze(),
+        }))
+    }
+}
+
+fn write_part_header(
+    body: &mut BytesMut,
+    boundary: &str,
+    name: &str,
+    filename: Option<&str>,
+    content_type: Option<&str>,
+) {
+    if !body.is_empty() {
+        body.put_slice(b"\r\n");
+    }
+    body.put_slice(format!("--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"").as_bytes());
+    if let Some(filename) = filename {
+        let filename = filename.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A");
+        body.put_slice(format!("; filename=\"{filename}\"").as_bytes());
+    }
+    body.put_slice(b"\r\n");
+    if let Some(content_type) = content_type {
+        body.put_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+    }
+    body.put_slice(b"\r\n");
+}
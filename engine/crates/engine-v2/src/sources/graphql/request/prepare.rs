@@ -5,13 +5,12 @@ use std::{
 
 use engine_parser::types::OperationType;
 use itertools::Itertools;
-use schema::{EntityDefinition, SubgraphId};
+use schema::{EntityDefinition, RequiredFieldSetId, SubgraphId};
 
-use crate::operation::{
-    FieldArgumentsWalker, PlanField, PlanSelectionSet, PlanWalker, QueryInputValueId, SelectionSetType,
-};
+use crate::operation::{PlanField, PlanSelectionSet, PlanWalker, QueryInputValueId, SelectionSetType};
 
 const VARIABLE_PREFIX: &str = "var";
+const CONTEXT_VARIABLE_PREFIX: &str = "ctx";
 
 macro_rules! indent_write {
     ($dst:ident, $($arg:tt)*) => {{
@@ -75,6 +74,20 @@ pub(crate) struct PreparedFederationEntityOperation {
     pub query: String,
     pub entities_variable_name: String,
     pub variables: QueryVariables,
+    /// Variables of the `@fromContext` arguments, their value depends on the representation.
+    pub context_variables: Vec<ContextVariable>,
+    /// Kept to build a query with one `_entities` field per distinct set of context values.
+    selection_set: String,
+    variable_definitions: String,
+    context_variable_ends: Vec<usize>,
+}
+
+/// A variable populated with the value of a `@fromContext` argument, named
+/// "{$CONTEXT_VARIABLE_PREFIX}{idx}" with `idx` being its position.
+pub(crate) struct ContextVariable {
+    pub ty: String,
+    /// Path to the value in each entity which may provide it, the closest ancestor wins.
+    pub field_set_ids: Vec<RequiredFieldSetId>,
 }
 
 impl PreparedFederationEntityOperation {
@@ -90,17 +103,23 @@ impl PreparedFederationEntityOperation {
         };
 
         let entities_variable_name = format!("{VARIABLE_PREFIX}{}", ctx.variables.len());
+        let mut variable_definitions = String::with_capacity(ctx.estimated_variable_definitions_string_len);
+        ctx.write_operation_arguments_without_parenthesis(&mut variable_definitions)?;
+
         let mut query = String::with_capacity(
             // Rough approximation of the final string length counted by hand
-            selection_set.len() + 60 + ctx.estimated_variable_definitions_string_len + 2 * entities_variable_name.len(),
+            selection_set.len() + 60 + variable_definitions.len() + 2 * entities_variable_name.len(),
         );
         query.push_str("query");
         query.push('(');
         write!(query, "${entities_variable_name}: [_Any!]!")?;
 
-        if !ctx.variables.is_empty() {
+        if !variable_definitions.is_empty() {
             query.push(',');
-            ctx.write_operation_arguments_without_parenthesis(&mut query)?;
+            query.push_str(&variable_definitions);
+        }
+        for (idx, variable) in ctx.context_variables.iter().enumerate() {
+            write!(query, ", ${CONTEXT_VARIABLE_PREFIX}{idx}: {}", variable.ty)?;
         }
         query.push(')');
 
@@ -109,12 +128,66 @@ impl PreparedFederationEntityOperation {
             " {{\n  _entities(representations: ${entities_variable_name}){selection_set}}}"
         )?;
 
+        let context_variables = std::mem::take(&mut ctx.context_variables);
+        let context_variable_ends = std::mem::take(&mut ctx.context_variable_ends);
+
         Ok(PreparedFederationEntityOperation {
             query,
             entities_variable_name,
             variables: ctx.into_query_variables(),
+            context_variables,
+            selection_set,
+            variable_definitions,
+            context_variable_ends,
         })
     }
+
+    /// A GraphQL field argument has a single value, so representations with different context
+    /// values are split into groups, each retrieved with its own aliased `_entities` field: `_{group}`.
+    /// Variables are suffixed by `_{group}` except for the operation ones.
+    pub(crate) fn query_for_context_groups(&self, group_count: usize) -> Result<String, Error> {
+        let mut query = String::with_capacity((self.selection_set.len() + 64) * group_count);
+        query.push_str("query(");
+        for group in 0..group_count {
+            if group > 0 {
+                query.push_str(", ");
+            }
+            write!(query, "${}_{group}: [_Any!]!", self.entities_variable_name)?;
+            for (idx, variable) in self.context_variables.iter().enumerate() {
+                write!(query, ", ${CONTEXT_VARIABLE_PREFIX}{idx}_{group}: {}", variable.ty)?;
+            }
+        }
+        if !self.variable_definitions.is_empty() {
+            query.push_str(", ");
+            query.push_str(&self.variable_definitions);
+        }
+        query.push_str(") {\n");
+
+        for group in 0..group_count {
+            write!(
+                query,
+                "  _{group}: _entities(representations: ${}_{group})",
+                self.entities_variable_name
+            )?;
+            let mut start = 0;
+            for &end in &self.context_variable_ends {
+                query.push_str(&self.selection_set[start..end]);
+                write!(query, "_{group}")?;
+                start = end;
+            }
+            query.push_str(&self.selection_set[start..]);
+        }
+        query.push('}');
+
+        Ok(query)
+    }
+
+    pub(crate) fn context_variable_name(idx: usize, group: Option<usize>) -> String {
+        match group {
+            Some(group) => format!("{CONTEXT_VARIABLE_PREFIX}{idx}_{group}"),
+            None => format!("{CONTEXT_VARIABLE_PREFIX}{idx}"),
+        }
+    }
 }
 
 /// All variables associated with a subgraph query. Each one is associated with the variable name
@@ -143,6 +216,9 @@ struct QueryBuilderContext {
     subgraph_id: SubgraphId,
     variables: HashMap<QueryInputValueId, QueryVariable>,
     estimated_variable_definitions_string_len: usize,
+    context_variables: Vec<ContextVariable>,
+    // Offsets in the selection set right after each context variable name.
+    context_variable_ends: Vec<usize>,
 }
 
 impl QueryBuilderContext {
@@ -151,6 +227,8 @@ impl QueryBuilderContext {
             subgraph_id,
             variables: HashMap::new(),
             estimated_variable_definitions_string_len: 0,
+            context_variables: Vec::new(),
+            context_variable_ends: Vec::new(),
         }
     }
 
@@ -325,7 +403,7 @@ impl QueryBuilderContext {
         } else {
             indent_write!(buffer, "{response_key}: {name}")?;
         }
-        self.write_arguments(buffer, field.arguments())?;
+        self.write_arguments(buffer, field)?;
         if let Some(selection_set) = field.selection_set() {
             self.write_selection_set(
                 SelectionSetType::maybe_from(field.definition().ty().definition().id()),
@@ -338,36 +416,70 @@ impl QueryBuilderContext {
         Ok(())
     }
 
-    fn write_arguments(&mut self, buffer: &mut Buffer, arguments: FieldArgumentsWalker<'_>) -> Result<(), Error> {
-        if !arguments.is_empty() {
-            write!(
-                buffer,
-                "({})",
-                arguments.into_iter().format_with(", ", |arg, f| {
-                    // If the argument is a constant value that would still be present after query
-                    // normalization we keep it to avoid adding unnecessary variables.
-                    if let Some(value) = arg
-                        .value()
-                        .and_then(|value| value.to_normalized_query_const_value_str())
-                    {
-                        f(&format_args!("{}: {}", arg.definition().name(), value))
-                    } else {
-                        let idx = self.variables.len();
-                        let var = self.variables.entry(arg.as_ref().input_value_id).or_insert_with(|| {
-                            let ty = arg.definition().ty().to_string();
-                            // prefix + ': ' + index (2) + ',' + ty.len()
-                            self.estimated_variable_definitions_string_len += VARIABLE_PREFIX.len() + 5 + ty.len();
-                            QueryVariable { idx, ty }
-                        });
-                        f(&format_args!(
-                            "{}: ${VARIABLE_PREFIX}{}",
-                            arg.definition().name(),
-                            var.idx
-                        ))
-                    }
-                })
-            )?;
+    fn write_arguments(&mut self, buffer: &mut Buffer, field: PlanField<'_>) -> Result<(), Error> {
+        let arguments = field.arguments();
+        let has_arguments = !arguments.is_empty();
+        // One record per argument and type providing the context.
+        let context_arguments = field
+            .definition()
+            .context_arguments_for_subgraph(self.subgraph_id)
+            .dedup_by(|a, b| a.name_id == b.name_id)
+            .collect::<Vec<_>>();
+        if !has_arguments && context_arguments.is_empty() {
+            return Ok(());
         }
+
+        buffer.push('(');
+        write!(
+            buffer,
+            "{}",
+            arguments.into_iter().format_with(", ", |arg, f| {
+                // If the argument is a constant value that would still be present after query
+                // normalization we keep it to avoid adding unnecessary variables.
+                if let Some(value) = arg
+                    .value()
+                    .and_then(|value| value.to_normalized_query_const_value_str())
+                {
+                    f(&format_args!("{}: {}", arg.definition().name(), value))
+                } else {
+                    let idx = self.variables.len();
+                    let var = self.variables.entry(arg.as_ref().input_value_id).or_insert_with(|| {
+                        let ty = arg.definition().ty().to_string();
+                        // prefix + ': ' + index (2) + ',' + ty.len()
+                        self.estimated_variable_definitions_string_len += VARIABLE_PREFIX.len() + 5 + ty.len();
+                        QueryVariable { idx, ty }
+                    });
+                    f(&format_args!(
+                        "{}: ${VARIABLE_PREFIX}{}",
+                        arg.definition().name(),
+                        var.idx
+                    ))
+                }
+            })
+        )?;
+
+        for (i, argument) in context_arguments.into_iter().enumerate() {
+            if i > 0 || has_arguments {
+                buffer.push_str(", ");
+            }
+            let idx = self.context_variables.len();
+            write!(buffer, "{}: ${CONTEXT_VARIABLE_PREFIX}{idx}", argument.name())?;
+            self.context_variable_ends.push(buffer.len());
+            self.context_variables.push(ContextVariable {
+                ty: argument.argument_type().to_string(),
+                field_set_ids: field
+                    .context_requirements()
+                    .iter()
+                    .filter(|requirement| {
+                        requirement.argument.subgraph_id == self.subgraph_id
+                            && requirement.argument.name_id == argument.name_id
+                    })
+                    .map(|requirement| requirement.argument.field_set_id)
+                    .collect(),
+            });
+        }
+        buffer.push(')');
+
         Ok(())
     }
 }
@@ -1,6 +1,6 @@
 use serde::ser::SerializeMap;
 
-use crate::operation::PlanWalker;
+use crate::operation::{PlanWalker, UploadId};
 
 use super::QueryVariables;
 
@@ -30,6 +30,27 @@ pub(crate) struct SubgraphVariables<'a, ExtraVariable> {
     pub extra_variables: Vec<(&'a str, ExtraVariable)>,
 }
 
+impl<'a, ExtraVariable> SubgraphVariables<'a, ExtraVariable> {
+    /// Files used by the subgraph request with their position in the request, for example
+    /// `variables.files.2`.
+    pub fn uploads(&self) -> Vec<(UploadId, String)> {
+        let mut uploads = Vec::new();
+        if self.plan.variables.uploads.is_empty() {
+            return uploads;
+        }
+        let mut path = String::new();
+        for (name, input_value_id) in self.variables.iter() {
+            path.clear();
+            path.push_str("variables.");
+            path.push_str(&name);
+            self.plan
+                .walk_input_value(input_value_id)
+                .collect_uploads(&mut path, &mut uploads);
+        }
+        uploads
+    }
+}
+
 impl<'a, Input> serde::Serialize for SubgraphVariables<'a, Input>
 where
     Input: serde::Serialize,
@@ -75,6 +75,7 @@ impl GraphqlResolver {
             serde_json::to_string_pretty(&variables).unwrap_or_default()
         );
 
+        let uploads = variables.uploads();
         let body = serde_json::to_vec(&SubgraphGraphqlRequest {
             query: &self.operation.query,
             variables,
@@ -85,7 +86,8 @@ impl GraphqlResolver {
         async {
             let headers = ctx.subgraph_headers_with_rules(ctx.endpoint().header_rules());
 
-            let cache_ttl = ctx.endpoint().config.cache_ttl;
+            // Uploaded files aren't part of the cache key, so we never cache those requests.
+            let cache_ttl = ctx.endpoint().config.cache_ttl.filter(|_| uploads.is_empty());
             let cache_key = build_cache_key(ctx.endpoint().subgraph_name(), &body, &headers);
 
             if let Some((_, cache_key)) = cache_ttl.zip(cache_key.as_ref()) {
@@ -123,7 +125,7 @@ impl GraphqlResolver {
                 subgraph_response,
             };
 
-            execute_subgraph_request(ctx, headers, Bytes::from(body), ingester).await
+            execute_subgraph_request(ctx, headers, Bytes::from(body), None, uploads, ingester).await
         }
         .instrument(span)
         .await
@@ -1,17 +1,20 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use bytes::Bytes;
-use futures::{FutureExt, TryStreamExt};
+use futures::{FutureExt, TryFutureExt, TryStreamExt};
 use futures_util::{stream::BoxStream, StreamExt};
 use headers::HeaderMapExt;
-use runtime::fetch::{FetchRequest, Fetcher};
+use runtime::{
+    bytes::OwnedOrSharedBytes,
+    fetch::{FetchRequest, Fetcher},
+};
 use serde::de::DeserializeSeed;
 use tracing::Instrument;
 use url::Url;
 
 use super::{
     deserialize::{GraphqlResponseSeed, RootGraphqlErrors},
-    request::{retrying_fetch, SubgraphGraphqlRequest, SubgraphVariables},
+    request::{request_key, retrying_fetch, SubgraphGraphqlRequest, SubgraphVariables},
     GraphqlResolver, SubgraphContext,
 };
 use crate::{
@@ -69,6 +72,7 @@ impl GraphqlResolver {
             .await?;
 
         let request = FetchRequest {
+            subgraph_name: endpoint.subgraph_name(),
             url,
             method: http::Method::POST,
             headers,
@@ -81,9 +85,18 @@ impl GraphqlResolver {
                 },
             },
             timeout: endpoint.config.timeout,
+            compression: None,
+        };
+
+        let multiplexing_key = if endpoint.config.multiplex_subscriptions {
+            let body = serde_json::to_vec(&request.body).map_err(|err| format!("Failed to serialize query: {err}"))?;
+            Some(request_key(endpoint, &request.url, &request.headers, &body))
+        } else {
+            None
         };
 
-        let fetcher = ctx.engine.runtime.fetcher();
+        let engine = ctx.engine;
+        let fetcher = engine.runtime.fetcher();
         let http_span = ctx.create_subgraph_request_span(&request);
         let http_span1 = http_span.clone();
 
@@ -93,7 +106,12 @@ impl GraphqlResolver {
                 .then(|res| async { (res, None) })
                 .instrument(http_span1.span())
         })
-        .await;
+        .map_ok(|stream| stream.map_ok(Arc::new));
+
+        let stream = match multiplexing_key {
+            Some(key) => engine.subscription_multiplexers.websocket.subscribe(key, stream).await,
+            None => stream.await.map(StreamExt::boxed),
+        };
 
         let stream = stream.inspect_err(|_| {
             http_span.set_as_http_error(None);
@@ -114,7 +132,7 @@ impl GraphqlResolver {
                     resp.next_seed(&ctx).expect("Must have a root object to update"),
                     RootGraphqlErrors::new(&ctx, resp),
                 )
-                .deserialize(subgraph_response?)?;
+                .deserialize(subgraph_response?.as_ref())?;
 
                 Ok(subscription_response)
             });
@@ -155,18 +173,26 @@ impl GraphqlResolver {
                 http::HeaderValue::from_static("text/even-stream,application/json;q=0.9"),
             );
             FetchRequest {
+                subgraph_name: endpoint.subgraph_name(),
                 url: Cow::Borrowed(endpoint.url()),
                 method: http::Method::POST,
                 headers,
                 body: Bytes::from(body),
                 timeout: endpoint.config.timeout,
+                compression: None,
             }
         };
 
         ctx.record_request_size(&request);
 
+        let multiplexing_key = endpoint
+            .config
+            .multiplex_subscriptions
+            .then(|| request_key(endpoint, &request.url, &request.headers, &request.body));
+
         let http_span = ctx.create_subgraph_request_span(&request);
-        let fetcher = ctx.engine.runtime.fetcher();
+        let engine = ctx.engine;
+        let fetcher = engine.runtime.fetcher();
 
         let http_span1 = http_span.clone();
         let stream = retrying_fetch(ctx, move || {
@@ -174,8 +200,16 @@ impl GraphqlResolver {
                 .graphql_over_sse_stream(request.clone())
                 .then(|result| async { (result, None) })
                 .instrument(http_span1.span())
-        })
-        .await;
+        });
+
+        let stream = match multiplexing_key {
+            // Shared bytes, so that events are not copied for every subscriber.
+            Some(key) => {
+                let stream = stream.map_ok(|stream| stream.map_ok(|bytes| OwnedOrSharedBytes::Shared(bytes.into())));
+                engine.subscription_multiplexers.sse.subscribe(key, stream).await
+            }
+            None => stream.await.map(StreamExt::boxed),
+        };
 
         let stream = stream.inspect_err(|err| {
             http_span.set_as_http_error(err.as_fetch_invalid_status_code());
@@ -147,6 +147,8 @@ impl<'a> IntrospectionWriter<'a> {
                     .unwrap_or_default(),
                 // TODO: Need to implemented directives...
                 __Schema::Directives => self.response.push_empty_list().into(),
+                // Null propagation can only be disabled per request with `onError`.
+                __Schema::DefaultErrorBehavior => self.metadata.error_behavior.propagate.into(),
             }
         })
     }
@@ -273,6 +275,7 @@ impl<'a> IntrospectionWriter<'a> {
                         );
                         self.response.push_list(values).into()
                     }
+                    __Type::IsOneOf => input_object.is_one_of().into(),
                     _ => ResponseValue::Null,
                 })
             }
@@ -292,6 +295,7 @@ impl<'a> IntrospectionWriter<'a> {
                 .filter(|field| {
                     (!is_deprecated(field.directives()) || include_deprecated)
                         && !self.metadata.meta_fields.contains(&field.id())
+                        && !self.schema.is_inaccessible(field.id())
                 })
                 .map(|field| self.__field(field, shape_id)),
         );
@@ -0,0 +1,478 @@
+use crate::federated_graph::*;
+use std::collections::{BTreeSet, HashSet};
+
+/// Selects the elements of a federated graph that are part of a contract variant, based on their `@tag`
+/// directives.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ContractFilter {
+    /// When not empty, only the fields tagged with one of these tags, and the fields of types tagged with one of
+    /// these tags, are part of the contract.
+    pub include: BTreeSet<String>,
+    /// Elements tagged with one of these tags are not part of the contract, even if they also have an included tag.
+    pub exclude: BTreeSet<String>,
+}
+
+impl FederatedGraph {
+    /// Restrict the graph to a contract variant. Elements filtered out by the contract are marked
+    /// `@inaccessible`, so they are absent from the [API schema](crate::render_api_sdl) and cannot be queried.
+    /// The graph is otherwise left intact, so it can still be served from the same subgraphs.
+    ///
+    /// Beyond the tags, the contract removes:
+    ///
+    /// - fields whose type is not part of the contract, or with a required argument that is not,
+    /// - input objects with a required field that is not part of the contract,
+    /// - types left without fields, and types no longer referenced by the rest of the schema.
+    ///
+    /// Fields used in `@key`, `@requires`, `@provides` and `@fromContext` selections follow the same rules. Once
+    /// `@inaccessible`, the engine keeps them for query planning only.
+    pub fn apply_contract(&mut self, filter: &ContractFilter) {
+        if filter.include.is_empty() && filter.exclude.is_empty() {
+            return;
+        }
+
+        let hidden = Hidden::compute(self, filter);
+
+        for definition in &hidden.definitions {
+            let directives = match *definition {
+                Definition::Object(id) => {
+                    &mut self.type_definitions[usize::from(self.objects[id.0].type_definition_id)].directives
+                }
+                Definition::Interface(id) => {
+                    &mut self.type_definitions[usize::from(self.interfaces[id.0].type_definition_id)].directives
+                }
+                Definition::Scalar(id) | Definition::Enum(id) => &mut self.type_definitions[usize::from(id)].directives,
+                Definition::Union(id) => &mut self.unions[id.0].composed_directives,
+                Definition::InputObject(id) => &mut self.input_objects[id.0].composed_directives,
+            };
+
+            *directives = with_inaccessible(&mut self.directives, *directives);
+        }
+
+        for field_id in &hidden.fields {
+            let field = &mut self.fields[field_id.0];
+            field.composed_directives = with_inaccessible(&mut self.directives, field.composed_directives);
+        }
+
+        for input_value_id in &hidden.input_values {
+            let input_value = &mut self.input_value_definitions[input_value_id.0];
+            input_value.directives = with_inaccessible(&mut self.directives, input_value.directives);
+        }
+
+        for enum_value_id in &hidden.enum_values {
+            let enum_value = &mut self.enum_values[usize::from(*enum_value_id)];
+            enum_value.composed_directives = with_inaccessible(&mut self.directives, enum_value.composed_directives);
+        }
+    }
+}
+
+/// Directives are stored in contiguous ranges, so we append a copy of the range with `@inaccessible` added.
+fn with_inaccessible(all_directives: &mut Vec<Directive>, (DirectiveId(start), len): Directives) -> Directives {
+    let range = start..(start + len);
+
+    if all_directives[range.clone()].contains(&Directive::Inaccessible) {
+        return (DirectiveId(start), len);
+    }
+
+    let new_start = all_directives.len();
+    all_directives.extend_from_within(range);
+    all_directives.push(Directive::Inaccessible);
+
+    (DirectiveId(new_start), len + 1)
+}
+
+/// The elements of the graph that are not part of the contract.
+#[derive(Default)]
+struct Hidden {
+    definitions: BTreeSet<Definition>,
+    fields: BTreeSet<FieldId>,
+    input_values: BTreeSet<InputValueDefinitionId>,
+    enum_values: BTreeSet<EnumValueId>,
+}
+
+impl Hidden {
+    fn compute(graph: &FederatedGraph, filter: &ContractFilter) -> Self {
+        let mut hidden = Hidden::default();
+        let is_excluded = |directives: Directives| tags(graph, directives).any(|tag| filter.exclude.contains(tag));
+        let is_included = |directives: Directives| {
+            filter.include.is_empty() || tags(graph, directives).any(|tag| filter.include.contains(tag))
+        };
+
+        for definition in all_definitions(graph) {
+            if is_excluded(definition_directives(graph, definition)) {
+                hidden.definitions.insert(definition);
+            }
+        }
+
+        for (parent, fields) in output_fields(graph) {
+            let parent_is_included = is_included(definition_directives(graph, parent));
+
+            for (field_id, field) in fields {
+                if is_excluded(field.composed_directives)
+                    || !(parent_is_included || is_included(field.composed_directives))
+                {
+                    hidden.fields.insert(field_id);
+                }
+            }
+        }
+
+        for (idx, input_value) in graph.input_value_definitions.iter().enumerate() {
+            if is_excluded(input_value.directives) {
+                hidden.input_values.insert(InputValueDefinitionId(idx));
+            }
+        }
+
+        for (idx, enum_value) in graph.enum_values.iter().enumerate() {
+            if is_excluded(enum_value.composed_directives) {
+                hidden.enum_values.insert(EnumValueId::from(idx));
+            }
+        }
+
+        while hidden.cascade(graph) {}
+
+        hidden
+    }
+
+    /// Propagate the hidden elements to the elements that depend on them. Returns whether anything changed.
+    fn cascade(&mut self, graph: &FederatedGraph) -> bool {
+        let mut changed = false;
+
+        for (idx, input_value) in graph.input_value_definitions.iter().enumerate() {
+            let id = InputValueDefinitionId(idx);
+
+            if !self.input_values.contains(&id) && self.definitions.contains(&input_value.r#type.definition) {
+                changed |= self.input_values.insert(id);
+            }
+        }
+
+        for (parent, fields) in output_fields(graph) {
+            for (field_id, field) in fields {
+                if self.fields.contains(&field_id) {
+                    continue;
+                }
+
+                if self.definitions.contains(&field.r#type.definition)
+                    || self.any_required_input_value_hidden(graph, field.arguments)
+                {
+                    changed |= self.fields.insert(field_id);
+                }
+            }
+
+            let is_root = root_operation_types(graph).any(|root| Definition::Object(root) == parent);
+
+            if !is_root
+                && !self.definitions.contains(&parent)
+                && output_fields_of(graph, parent)
+                    .all(|(field_id, field)| graph[field.name].starts_with("__") || self.fields.contains(&field_id))
+            {
+                changed |= self.definitions.insert(parent);
+            }
+        }
+
+        for (idx, input_object) in graph.input_objects.iter().enumerate() {
+            let definition = Definition::InputObject(InputObjectId(idx));
+
+            if !self.definitions.contains(&definition)
+                && self.any_required_input_value_hidden(graph, input_object.fields)
+            {
+                changed |= self.definitions.insert(definition);
+            }
+        }
+
+        for (idx, union) in graph.unions.iter().enumerate() {
+            let definition = Definition::Union(UnionId(idx));
+
+            if !self.definitions.contains(&definition)
+                && union
+                    .members
+                    .iter()
+                    .all(|member| self.definitions.contains(&Definition::Object(*member)))
+            {
+                changed |= self.definitions.insert(definition);
+            }
+        }
+
+        let referenced = self.referenced_definitions(graph);
+
+        for definition in all_definitions(graph) {
+            let is_unreferenced_type = !matches!(definition, Definition::Scalar(_))
+                && !referenced.contains(&definition)
+                && !root_operation_types(graph).any(|root| Definition::Object(root) == definition);
+
+            if is_unreferenced_type {
+                changed |= self.definitions.insert(definition);
+            }
+        }
+
+        changed
+    }
+
+    fn any_required_input_value_hidden(&self, graph: &FederatedGraph, input_values: InputValueDefinitions) -> bool {
+        let (InputValueDefinitionId(start), _) = input_values;
+
+        graph[input_values].iter().enumerate().any(|(idx, input_value)| {
+            input_value.r#type.wrapping.is_required()
+                && input_value.default.is_none()
+                && self.input_values.contains(&InputValueDefinitionId(start + idx))
+        })
+    }
+
+    /// The types referenced by the part of the schema that is still visible.
+    fn referenced_definitions(&self, graph: &FederatedGraph) -> HashSet<Definition> {
+        let mut referenced = HashSet::new();
+
+        let visible_input_values = |input_values: InputValueDefinitions| {
+            let (InputValueDefinitionId(start), _) = input_values;
+
+            graph[input_values]
+                .iter()
+                .enumerate()
+                .filter(move |(idx, _)| !self.input_values.contains(&InputValueDefinitionId(start + idx)))
+                .map(|(_, input_value)| input_value.r#type.definition)
+        };
+
+        for (parent, fields) in output_fields(graph) {
+            if self.definitions.contains(&parent) {
+                continue;
+            }
+
+            for (field_id, field) in fields {
+                if !self.fields.contains(&field_id) {
+                    referenced.insert(field.r#type.definition);
+                    referenced.extend(visible_input_values(field.arguments));
+                }
+            }
+
+            let implements_interfaces = match parent {
+                Definition::Object(id) => &graph[id].implements_interfaces,
+                Definition::Interface(id) => &graph[id].implements_interfaces,
+                _ => continue,
+            };
+
+            referenced.extend(implements_interfaces.iter().copied().map(Definition::Interface));
+        }
+
+        for (idx, input_object) in graph.input_objects.iter().enumerate() {
+            if !self.definitions.contains(&Definition::InputObject(InputObjectId(idx))) {
+                referenced.extend(visible_input_values(input_object.fields));
+            }
+        }
+
+        for (idx, union) in graph.unions.iter().enumerate() {
+            if !self.definitions.contains(&Definition::Union(UnionId(idx))) {
+                referenced.extend(union.members.iter().copied().map(Definition::Object));
+            }
+        }
+
+        // Objects implementing a visible interface can be returned by the fields of that interface.
+        for object in graph.iter_objects() {
+            if object
+                .implements_interfaces
+                .iter()
+                .any(|interface| referenced.contains(&Definition::Interface(*interface)))
+            {
+                referenced.insert(Definition::Object(object.id()));
+            }
+        }
+
+        referenced
+    }
+}
+
+fn tags(graph: &FederatedGraph, directives: Directives) -> impl Iterator<Item = &str> {
+    graph[directives].iter().filter_map(|directive| match directive {
+        Directive::Other { name, arguments } if graph[*name] == "tag" => {
+            arguments.iter().find_map(|(argument_name, value)| match value {
+                Value::String(value) if graph[*argument_name] == "name" => Some(graph[*value].as_str()),
+                _ => None,
+            })
+        }
+        _ => None,
+    })
+}
+
+fn root_operation_types(graph: &FederatedGraph) -> impl Iterator<Item = ObjectId> {
+    let RootOperationTypes {
+        query,
+        mutation,
+        subscription,
+    } = graph.root_operation_types;
+
+    std::iter::once(query).chain(mutation).chain(subscription)
+}
+
+fn all_definitions(graph: &FederatedGraph) -> impl Iterator<Item = Definition> + '_ {
+    let objects = (0..graph.objects.len()).map(|idx| Definition::Object(ObjectId(idx)));
+    let interfaces = (0..graph.interfaces.len()).map(|idx| Definition::Interface(InterfaceId(idx)));
+    let unions = (0..graph.unions.len()).map(|idx| Definition::Union(UnionId(idx)));
+    let input_objects = (0..graph.input_objects.len()).map(|idx| Definition::InputObject(InputObjectId(idx)));
+    let enums = graph.iter_enums().map(|r#enum| Definition::Enum(r#enum.id()));
+    let scalars = graph.iter_scalars().map(|scalar| Definition::Scalar(scalar.id()));
+
+    objects
+        .chain(interfaces)
+        .chain(unions)
+        .chain(input_objects)
+        .chain(enums)
+        .chain(scalars)
+}
+
+fn definition_directives(graph: &FederatedGraph, definition: Definition) -> Directives {
+    match definition {
+        Definition::Object(id) => graph[graph[id].type_definition_id].directives,
+        Definition::Interface(id) => graph[graph[id].type_definition_id].directives,
+        Definition::Scalar(id) | Definition::Enum(id) => graph[id].directives,
+        Definition::Union(id) => graph[id].composed_directives,
+        Definition::InputObject(id) => graph[id].composed_directives,
+    }
+}
+
+fn definition_fields(graph: &FederatedGraph, definition: Definition) -> Fields {
+    match definition {
+        Definition::Object(id) => graph[id].fields.clone(),
+        Definition::Interface(id) => graph[id].fields.clone(),
+        _ => NO_FIELDS,
+    }
+}
+
+/// The fields of all objects and interfaces, with their parent.
+fn output_fields(
+    graph: &FederatedGraph,
+) -> impl Iterator<Item = (Definition, impl Iterator<Item = (FieldId, &Field)>)> {
+    let objects = (0..graph.objects.len()).map(|idx| Definition::Object(ObjectId(idx)));
+    let interfaces = (0..graph.interfaces.len()).map(|idx| Definition::Interface(InterfaceId(idx)));
+
+    objects
+        .chain(interfaces)
+        .map(move |parent| (parent, output_fields_of(graph, parent)))
+}
+
+fn output_fields_of(graph: &FederatedGraph, parent: Definition) -> impl Iterator<Item = (FieldId, &Field)> {
+    let fields = definition_fields(graph, parent);
+    let start = fields.start.0;
+
+    graph[fields]
+        .iter()
+        .enumerate()
+        .map(move |(idx, field)| (FieldId(start + idx), field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r#"
+        enum join__Graph {
+            PRODUCTS @join__graph(name: "products", url: "http://products:4000/graphql")
+        }
+
+        type Query {
+            products: [Product!]! @tag(name: "public")
+            internalStats(filter: StatsFilter): Stats
+        }
+
+        type Product @join__type(graph: PRODUCTS, key: "id") {
+            id: ID!
+            name: String! @tag(name: "public")
+            cost: Int @tag(name: "public") @tag(name: "internal")
+            supplier: Supplier @tag(name: "public")
+        }
+
+        type Supplier @tag(name: "public") {
+            name: String!
+            margin: Int @tag(name: "internal")
+        }
+
+        type Stats {
+            revenue: Int
+        }
+
+        input StatsFilter {
+            from: String
+        }
+    "#;
+
+    #[test]
+    fn include_and_exclude_tags() {
+        let mut graph = crate::from_sdl(SDL).unwrap();
+
+        graph.apply_contract(&ContractFilter {
+            include: BTreeSet::from(["public".to_owned()]),
+            exclude: BTreeSet::from(["internal".to_owned()]),
+        });
+
+        let api_sdl = crate::render_api_sdl(&graph);
+
+        expect_test::expect![[r#"
+            type Query {
+                products: [Product!]!
+            }
+
+            type Product {
+                name: String!
+                supplier: Supplier
+            }
+
+            type Supplier {
+                name: String!
+            }
+        "#]]
+        .assert_eq(&api_sdl);
+    }
+
+    #[test]
+    fn excluded_key_fields_are_inaccessible() {
+        let mut graph = crate::from_sdl(
+            r#"
+            enum join__Graph {
+                PRODUCTS @join__graph(name: "products", url: "http://products:4000/graphql")
+            }
+
+            type Query {
+                products: [Product!]!
+            }
+
+            type Product @join__type(graph: PRODUCTS, key: "sku") {
+                sku: String! @tag(name: "internal")
+                name: String!
+            }
+            "#,
+        )
+        .unwrap();
+
+        graph.apply_contract(&ContractFilter {
+            include: BTreeSet::new(),
+            exclude: BTreeSet::from(["internal".to_owned()]),
+        });
+
+        expect_test::expect![[r#"
+            type Query {
+                products: [Product!]!
+            }
+
+            type Product {
+                name: String!
+            }
+        "#]]
+        .assert_eq(&crate::render_api_sdl(&graph));
+
+        // The key itself is left untouched for the query planner.
+        let sku = graph
+            .fields
+            .iter()
+            .position(|field| graph[field.name] == "sku")
+            .unwrap();
+        assert!(graph.planner_field_ids().contains(&FieldId(sku)));
+        assert!(graph.objects.iter().any(|object| object.keys.len() == 1));
+    }
+
+    #[test]
+    fn empty_filter_keeps_everything() {
+        let mut graph = crate::from_sdl(SDL).unwrap();
+        let before = crate::render_api_sdl(&graph);
+
+        graph.apply_contract(&ContractFilter::default());
+
+        assert_eq!(crate::render_api_sdl(&graph), before);
+    }
+}
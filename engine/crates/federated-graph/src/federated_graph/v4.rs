@@ -6,7 +6,7 @@ mod r#type;
 mod type_definitions;
 mod view;
 
-use std::ops::Range;
+use std::{collections::HashSet, ops::Range};
 
 pub use self::{
     enum_values::{EnumValue, EnumValueRecord},
@@ -49,6 +49,11 @@ pub struct FederatedGraph {
     pub field_authorized_directives: Vec<(FieldId, AuthorizedDirectiveId)>,
     pub object_authorized_directives: Vec<(ObjectId, AuthorizedDirectiveId)>,
     pub interface_authorized_directives: Vec<(InterfaceId, AuthorizedDirectiveId)>,
+
+    /// All `@context` directives on objects and interfaces.
+    pub contexts: Vec<Context>,
+    /// All field arguments populated with `@fromContext`, sorted by field.
+    pub context_arguments: Vec<ContextArgument>,
 }
 
 impl FederatedGraph {
@@ -116,6 +121,68 @@ impl FederatedGraph {
             .take_while(move |(needle, _)| *needle == interface_id)
             .map(move |(_, authorized_directive_id)| &self[*authorized_directive_id])
     }
+
+    pub fn definition_contexts(&self, definition: Definition) -> impl Iterator<Item = &Context> {
+        self.contexts
+            .iter()
+            .filter(move |context| context.definition == definition)
+    }
+
+    pub fn field_context_arguments(&self, field_id: FieldId) -> impl Iterator<Item = &ContextArgument> {
+        let start = self
+            .context_arguments
+            .partition_point(|argument| argument.field_id < field_id);
+
+        self.context_arguments[start..]
+            .iter()
+            .take_while(move |argument| argument.field_id == field_id)
+    }
+
+    /// The fields used in `@key`, `@requires`, `@provides` and `@fromContext` selections. The query planner needs
+    /// them, even when they are `@inaccessible`.
+    pub fn planner_field_ids(&self) -> HashSet<FieldId> {
+        fn collect(selection_set: &SelectionSet, out: &mut HashSet<FieldId>) {
+            for selection in selection_set {
+                match selection {
+                    Selection::Field {
+                        field, subselection, ..
+                    } => {
+                        out.insert(*field);
+                        collect(subselection, out);
+                    }
+                    Selection::InlineFragment { subselection, .. } => collect(subselection, out),
+                }
+            }
+        }
+
+        let mut out = HashSet::new();
+
+        let keys = self
+            .objects
+            .iter()
+            .flat_map(|object| &object.keys)
+            .chain(self.interfaces.iter().flat_map(|interface| &interface.keys))
+            .map(|key| &key.fields);
+
+        let field_selections = self.fields.iter().flat_map(|field| {
+            field
+                .requires
+                .iter()
+                .map(|requires| &requires.fields)
+                .chain(field.provides.iter().map(|provides| &provides.fields))
+        });
+
+        let context_selections = self
+            .context_arguments
+            .iter()
+            .flat_map(|argument| argument.providers.iter().map(|(_, selection_set)| selection_set));
+
+        for selection_set in keys.chain(field_selections).chain(context_selections) {
+            collect(selection_set, &mut out);
+        }
+
+        out
+    }
 }
 
 #[derive(PartialEq, PartialOrd, Clone, Debug)]
@@ -249,6 +316,32 @@ pub struct FieldRequires {
     pub fields: SelectionSet,
 }
 
+/// Represents an `@context` directive on an object or interface in a subgraph.
+#[derive(Clone, Debug)]
+pub struct Context {
+    pub definition: Definition,
+    /// The name of the context, prefixed with the name of the subgraph: `subgraph__context`.
+    pub name: StringId,
+}
+
+/// Represents a field argument with `@fromContext` in a subgraph. The argument is not part of the
+/// API schema: its value is taken from the closest ancestor object providing the context.
+#[derive(Clone, Debug)]
+pub struct ContextArgument {
+    pub field_id: FieldId,
+    pub subgraph_id: SubgraphId,
+    /// The name of the argument in the subgraph.
+    pub name: StringId,
+    /// The type of the argument in the subgraph, for example `String!`.
+    pub r#type: StringId,
+    /// The name of the context, see [Context::name].
+    pub context: StringId,
+    /// The selection in the `@fromContext` field argument, without the context name.
+    pub selection: StringId,
+    /// [ContextArgument::selection] on each of the types providing the context.
+    pub providers: Vec<(Definition, SelectionSet)>,
+}
+
 pub type SelectionSet = Vec<Selection>;
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
@@ -344,6 +437,8 @@ impl Default for FederatedGraph {
             field_authorized_directives: Vec::new(),
             object_authorized_directives: Vec::new(),
             interface_authorized_directives: Vec::new(),
+            contexts: Vec::new(),
+            context_arguments: Vec::new(),
         }
     }
 }
@@ -613,6 +708,8 @@ impl From<super::FederatedGraphV3> for FederatedGraph {
             field_authorized_directives,
             object_authorized_directives,
             interface_authorized_directives,
+            contexts: Vec::new(),
+            context_arguments: Vec::new(),
         }
     }
 }
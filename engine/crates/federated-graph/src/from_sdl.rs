@@ -18,6 +18,7 @@ const JOIN_FIELD_DIRECTIVE_OVERRIDE_LABEL_ARGUMENT: &str = "overrideLabel";
 const JOIN_GRAPH_DIRECTIVE_NAME: &str = "join__graph";
 const JOIN_GRAPH_ENUM_NAME: &str = "join__Graph";
 const JOIN_TYPE_DIRECTIVE_NAME: &str = "join__type";
+const CONTEXT_DIRECTIVE_NAME: &str = "context";
 
 #[derive(Debug)]
 pub struct DomainError(String);
@@ -63,6 +64,9 @@ struct State<'a> {
     object_authorized_directives: Vec<(ObjectId, AuthorizedDirectiveId)>,
     interface_authorized_directives: Vec<(InterfaceId, AuthorizedDirectiveId)>,
 
+    contexts: Vec<Context>,
+    context_arguments: Vec<ContextArgument>,
+
     type_wrappers: Vec<WrappingType>,
 }
 
@@ -225,6 +229,7 @@ pub fn from_sdl(sdl: &str) -> Result<FederatedGraph, DomainError> {
     ingest_fields(&parsed, &mut state)?;
     // This needs to happen after all fields have been ingested, in order to attach selection sets.
     ingest_selection_sets(&parsed, &mut state)?;
+    state.context_arguments.sort_by_key(|argument| argument.field_id);
 
     Ok(FederatedGraph {
         type_definitions: std::mem::take(&mut state.graph.type_definitions),
@@ -243,6 +248,8 @@ pub fn from_sdl(sdl: &str) -> Result<FederatedGraph, DomainError> {
         field_authorized_directives: state.field_authorized_directives,
         object_authorized_directives: state.object_authorized_directives,
         interface_authorized_directives: state.interface_authorized_directives,
+        contexts: state.contexts,
+        context_arguments: state.context_arguments,
     })
 }
 
@@ -492,11 +499,35 @@ fn parse_join_implements(
 }
 
 fn ingest_selection_sets<'a>(parsed: &'a ast::TypeSystemDocument, state: &mut State<'a>) -> Result<(), DomainError> {
+    ingest_contexts(parsed, state);
     ingest_field_directives_after_graph(parsed, state)?;
     ingest_authorized_directives(parsed, state)?;
     ingest_entity_keys(parsed, state)
 }
 
+fn ingest_contexts(parsed: &ast::TypeSystemDocument, state: &mut State<'_>) {
+    for typedef in parsed.definitions().filter_map(|def| match def {
+        ast::Definition::Type(ty) | ast::Definition::TypeExtension(ty) => Some(ty),
+        _ => None,
+    }) {
+        let Some(definition) = state.definition_names.get(typedef.name()).copied() else {
+            continue;
+        };
+
+        for directive in typedef.directives().filter(|dir| dir.name() == CONTEXT_DIRECTIVE_NAME) {
+            let Some(name) = directive.get_argument("name").and_then(|arg| match arg.value() {
+                ast::Value::String(s) | ast::Value::BlockString(s) => Some(s),
+                _ => None,
+            }) else {
+                continue;
+            };
+
+            let name = state.insert_string(name);
+            state.contexts.push(Context { definition, name });
+        }
+    }
+}
+
 fn ingest_authorized_directives(parsed: &ast::TypeSystemDocument, state: &mut State<'_>) -> Result<(), DomainError> {
     for typedef in parsed.definitions().filter_map(|def| match def {
         ast::Definition::Type(ty) => Some(ty),
@@ -719,6 +750,8 @@ where
                 provides.push(field_provides)
             }
 
+            ingest_context_arguments(field_id, subgraph_id, directive, state)?;
+
             if let Some(field_requires) = directive
                 .get_argument("requires")
                 .and_then(|arg| match arg.value() {
@@ -749,6 +782,75 @@ where
     Ok(())
 }
 
+/// `@join__field(graph: ..., contextArguments: [{ context: "...", name: "...", type: "...", selection: "..." }])`
+fn ingest_context_arguments(
+    field_id: FieldId,
+    subgraph_id: SubgraphId,
+    directive: ast::Directive<'_>,
+    state: &mut State<'_>,
+) -> Result<(), DomainError> {
+    #[derive(serde::Deserialize)]
+    struct ContextArgumentSdl {
+        context: String,
+        name: String,
+        r#type: String,
+        selection: String,
+    }
+
+    let Some(arguments) = directive
+        .get_argument("contextArguments")
+        .and_then(|arguments| arguments.value().into_json())
+    else {
+        return Ok(());
+    };
+
+    let arguments: Vec<ContextArgumentSdl> = serde_json::from_value(arguments)
+        .map_err(|err| DomainError(format!("Invalid contextArguments in @join__field: {err}")))?;
+
+    for ContextArgumentSdl {
+        context,
+        name,
+        r#type,
+        selection,
+    } in arguments
+    {
+        let context = state.insert_string(&context);
+        let provider_definitions = state
+            .contexts
+            .iter()
+            .filter(|ctx| ctx.name == context)
+            .map(|ctx| ctx.definition)
+            .collect::<Vec<_>>();
+
+        if provider_definitions.is_empty() {
+            return Err(DomainError(format!(
+                "The context '{}' used in contextArguments is not declared with @context",
+                state.strings[context.0]
+            )));
+        }
+
+        let parsed_selection = parse_context_selection(&selection)?;
+        let providers = provider_definitions
+            .into_iter()
+            .map(|definition| Ok((definition, attach_selection_set(&parsed_selection, definition, state)?)))
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        let argument = ContextArgument {
+            field_id,
+            subgraph_id,
+            name: state.insert_string(&name),
+            r#type: state.insert_string(&r#type),
+            context,
+            selection: state.insert_string(&selection),
+            providers,
+        };
+
+        state.context_arguments.push(argument);
+    }
+
+    Ok(())
+}
+
 fn ingest_authorized_directive<'a>(
     parent_id: Definition,
     fields: impl Iterator<Item = ast::FieldDefinition<'a>>,
@@ -1197,6 +1299,17 @@ fn parse_selection_set(fields: &str) -> Result<executable_ast::ExecutableDocumen
         .map_err(DomainError)
 }
 
+/// The selection of a context argument is either a selection set in braces, or inline fragments.
+fn parse_context_selection(selection: &str) -> Result<executable_ast::ExecutableDocument, DomainError> {
+    let selection = selection.trim();
+    let selection = selection
+        .strip_prefix('{')
+        .and_then(|selection| selection.strip_suffix('}'))
+        .unwrap_or(selection);
+
+    parse_selection_set(selection)
+}
+
 /// Attach a selection set defined in strings to a FederatedGraph, transforming the strings into
 /// field ids.
 fn attach_selection_set(
@@ -1516,6 +1629,7 @@ fn collect_composed_directives<'a>(
             "authorized" => {}
             "join__implements" => {}
             "join__unionMember" => {}
+            CONTEXT_DIRECTIVE_NAME => {}
             other => {
                 let name = state.insert_string(other);
                 let arguments = directive
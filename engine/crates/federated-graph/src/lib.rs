@@ -1,8 +1,9 @@
 use grafbase_workspace_hack as _;
 
+mod contract;
 mod federated_graph;
 
-pub use self::federated_graph::*;
+pub use self::{contract::ContractFilter, federated_graph::*};
 
 #[cfg(feature = "render_sdl")]
 mod render_sdl;
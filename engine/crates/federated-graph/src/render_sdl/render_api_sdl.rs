@@ -281,12 +281,13 @@ fn write_field_arguments<'a, 'b: 'a>(
     args: &[InputValueDefinition],
     graph: &'a FederatedGraph,
 ) -> fmt::Result {
-    if args.is_empty() {
+    if args.iter().all(|arg| has_inaccessible(&arg.directives, graph)) {
         return Ok(());
     }
 
     let mut inner = args
         .iter()
+        .filter(|arg| !has_inaccessible(&arg.directives, graph))
         .map(|arg| {
             let name = &graph[arg.name];
             let r#type = render_field_type(&arg.r#type, graph);
@@ -70,6 +70,7 @@ pub fn render_federated_sdl(graph: &FederatedGraph) -> Result<String, fmt::Error
 
         with_formatter(&mut sdl, |f| {
             render_composed_directives(definition.directives, f, graph)?;
+            render_contexts(Definition::Object(object.id()), f, graph)?;
 
             for authorized_directive in graph.object_authorized_directives(object.id()) {
                 render_authorized_directive(authorized_directive, f, graph)?;
@@ -138,6 +139,7 @@ pub fn render_federated_sdl(graph: &FederatedGraph) -> Result<String, fmt::Error
             }
 
             render_composed_directives(definition.directives, f, graph)?;
+            render_contexts(Definition::Interface(interface.id()), f, graph)?;
 
             if !interface.join_implements.is_empty() {
                 for (subgraph_id, interface_id) in &interface.join_implements {
@@ -375,11 +377,12 @@ fn write_field(field_id: FieldId, field: &Field, graph: &FederatedGraph, sdl: &m
     write!(sdl, "{INDENT}{field_name}{args}: {field_type}")?;
 
     for subgraph in &field.resolvable_in {
-        write_resolvable_in(*subgraph, field, graph, sdl)?;
+        write_resolvable_in(*subgraph, field_id, field, graph, sdl)?;
     }
 
     write_provides(field, graph, sdl)?;
     write_requires(field, graph, sdl)?;
+    write_context_arguments(field_id, field, graph, sdl)?;
     write_composed_directives(field.composed_directives, graph, sdl)?;
     write_overrides(field, graph, sdl)?;
     write_authorized(field_id, graph, sdl)?;
@@ -405,7 +408,13 @@ fn write_composed_directives(directives: Directives, graph: &FederatedGraph, sdl
     with_formatter(sdl, |f| render_composed_directives(directives, f, graph))
 }
 
-fn write_resolvable_in(subgraph: SubgraphId, field: &Field, graph: &FederatedGraph, sdl: &mut String) -> fmt::Result {
+fn write_resolvable_in(
+    subgraph: SubgraphId,
+    field_id: FieldId,
+    field: &Field,
+    graph: &FederatedGraph,
+    sdl: &mut String,
+) -> fmt::Result {
     let subgraph_name = GraphEnumVariantName(&graph[graph[subgraph].name]);
     let provides = MaybeDisplay(
         field
@@ -421,7 +430,9 @@ fn write_resolvable_in(subgraph: SubgraphId, field: &Field, graph: &FederatedGra
             .find(|requires| requires.subgraph_id == subgraph)
             .map(|fieldset| format!(", requires: {}", SelectionSetDisplay(&fieldset.fields, graph))),
     );
-    write!(sdl, " @join__field(graph: {subgraph_name}{provides}{requires})")?;
+    write!(sdl, " @join__field(graph: {subgraph_name}{provides}{requires}")?;
+    write_context_arguments_argument(field_id, subgraph, graph, sdl)?;
+    sdl.push(')');
 
     Ok(())
 }
@@ -481,6 +492,64 @@ fn write_requires(field: &Field, graph: &FederatedGraph, sdl: &mut String) -> fm
     Ok(())
 }
 
+fn write_context_arguments(field_id: FieldId, field: &Field, graph: &FederatedGraph, sdl: &mut String) -> fmt::Result {
+    let mut subgraphs = graph
+        .field_context_arguments(field_id)
+        .map(|argument| argument.subgraph_id)
+        .filter(|subgraph_id| !field.resolvable_in.contains(subgraph_id))
+        .collect::<Vec<_>>();
+    subgraphs.sort();
+    subgraphs.dedup();
+
+    for subgraph_id in subgraphs {
+        let subgraph_name = GraphEnumVariantName(&graph[graph[subgraph_id].name]);
+        write!(sdl, " @join__field(graph: {subgraph_name}")?;
+        write_context_arguments_argument(field_id, subgraph_id, graph, sdl)?;
+        sdl.push(')');
+    }
+
+    Ok(())
+}
+
+/// `, contextArguments: [{ context: "...", name: "...", type: "...", selection: "..." }]`
+fn write_context_arguments_argument(
+    field_id: FieldId,
+    subgraph_id: SubgraphId,
+    graph: &FederatedGraph,
+    sdl: &mut String,
+) -> fmt::Result {
+    let mut arguments = graph
+        .field_context_arguments(field_id)
+        .filter(|argument| argument.subgraph_id == subgraph_id)
+        .peekable();
+
+    if arguments.peek().is_none() {
+        return Ok(());
+    }
+
+    sdl.push_str(", contextArguments: [");
+
+    for (idx, argument) in arguments.enumerate() {
+        if idx > 0 {
+            sdl.push_str(", ");
+        }
+
+        sdl.push_str("{context: ");
+        write_quoted(sdl, &graph[argument.context])?;
+        sdl.push_str(", name: ");
+        write_quoted(sdl, &graph[argument.name])?;
+        sdl.push_str(", type: ");
+        write_quoted(sdl, &graph[argument.r#type])?;
+        sdl.push_str(", selection: ");
+        write_quoted(sdl, &graph[argument.selection])?;
+        sdl.push('}');
+    }
+
+    sdl.push(']');
+
+    Ok(())
+}
+
 fn write_authorized(field_id: FieldId, graph: &FederatedGraph, sdl: &mut String) -> fmt::Result {
     let start = graph
         .field_authorized_directives
@@ -560,6 +629,15 @@ fn render_join_field(key: &Key, f: &mut fmt::Formatter<'_>, graph: &FederatedGra
     f.write_str("\n")
 }
 
+fn render_contexts(definition: Definition, f: &mut fmt::Formatter<'_>, graph: &FederatedGraph) -> fmt::Result {
+    for context in graph.definition_contexts(definition) {
+        f.write_str(" ")?;
+        DirectiveWriter::new("context", f, graph)?.arg("name", Value::String(context.name))?;
+    }
+
+    Ok(())
+}
+
 fn render_join_implement(
     subgraph_id: SubgraphId,
     interface_id: InterfaceId,
@@ -810,4 +888,58 @@ mod tests {
             from_sdl(&rendered).unwrap();
         }
     }
+
+    #[test]
+    fn context_arguments_roundtrip() {
+        let schema = r##"
+            enum join__Graph {
+                USERS @join__graph(name: "users", url: "http://users.example.com")
+                PAYMENTS @join__graph(name: "payments", url: "http://payments.example.com")
+            }
+
+            type Query {
+                me: User @join__field(graph: USERS)
+            }
+
+            type User
+                @context(name: "payments__userContext")
+                @join__type(graph: USERS, key: "id")
+                @join__type(graph: PAYMENTS, key: "id")
+            {
+                id: ID!
+                locale: String @join__field(graph: USERS)
+                transactions: [Transaction] @join__field(graph: PAYMENTS)
+            }
+
+            type Transaction
+                @join__type(graph: PAYMENTS, key: "id")
+            {
+                id: ID!
+                currency: String @join__field(graph: PAYMENTS, contextArguments: [{context: "payments__userContext", name: "locale", type: "String", selection: "{ locale }"}])
+            }
+        "##;
+
+        let parsed = from_sdl(schema).unwrap();
+
+        assert_eq!(parsed.contexts.len(), 1);
+        assert_eq!(&parsed[parsed.contexts[0].name], "payments__userContext");
+
+        assert_eq!(parsed.context_arguments.len(), 1);
+        let argument = &parsed.context_arguments[0];
+        assert_eq!(&parsed[argument.name], "locale");
+        assert_eq!(&parsed[argument.r#type], "String");
+        assert_eq!(&parsed[argument.selection], "{ locale }");
+        assert_eq!(argument.providers.len(), 1);
+
+        let rendered = render_federated_sdl(&parsed).unwrap();
+
+        assert!(rendered.contains(r#"@context(name: "payments__userContext")"#));
+        assert!(rendered.contains(
+            r#"currency: String @join__field(graph: PAYMENTS, contextArguments: [{context: "payments__userContext", name: "locale", type: "String", selection: "{ locale }"}])"#
+        ));
+
+        // Check that from_sdl accepts the rendered sdl
+        let reparsed = from_sdl(&rendered).unwrap();
+        assert_eq!(render_federated_sdl(&reparsed).unwrap(), rendered);
+    }
 }
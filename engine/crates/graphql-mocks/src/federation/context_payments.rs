@@ -0,0 +1,167 @@
+use std::io::Read;
+
+use async_graphql::{Context, EmptyMutation, EmptySubscription, FieldResult, Object, Schema, SimpleObject, Upload, ID};
+
+/// Transactions formatted with the locale of their user and the currency of the user's
+/// organization, both provided through `@fromContext` arguments.
+pub struct FederatedContextPaymentsSchema;
+
+impl crate::Subgraph for FederatedContextPaymentsSchema {
+    fn name(&self) -> String {
+        "payments".to_string()
+    }
+    async fn start(self) -> crate::MockGraphQlServer {
+        crate::MockGraphQlServer::new(self).await
+    }
+}
+
+// async-graphql doesn't support @context and @fromContext.
+const SDL: &str = r#"
+extend schema
+  @link(url: "https://specs.apollo.dev/federation/v2.8", import: ["@key", "@external", "@context", "@fromContext"])
+
+type Query {
+  transaction(id: ID!): Transaction
+}
+
+type User @key(fields: "id") @context(name: "userContext") {
+  id: ID!
+  locale: String! @external
+  transactions: [Transaction!]!
+}
+
+type Organization @key(fields: "id") @context(name: "organizationContext") {
+  id: ID!
+  currency: String! @external
+}
+
+type Transaction @key(fields: "id") {
+  id: ID!
+  amount: Int!
+  formattedAmount(locale: String! @fromContext(field: "$userContext { locale }")): String!
+  convertedAmount(currency: String! @fromContext(field: "$organizationContext { currency }")): String!
+  receipt(file: Upload!, locale: String! @fromContext(field: "$userContext { locale }")): String!
+}
+
+scalar Upload
+"#;
+
+impl FederatedContextPaymentsSchema {
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::build(Query, EmptyMutation, EmptySubscription)
+            .enable_federation()
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl super::super::Schema for FederatedContextPaymentsSchema {
+    async fn execute(
+        &self,
+        _headers: Vec<(String, String)>,
+        request: async_graphql::Request,
+    ) -> async_graphql::Response {
+        Self::schema().execute(request).await
+    }
+
+    fn execute_stream(
+        &self,
+        request: async_graphql::Request,
+    ) -> futures::stream::BoxStream<'static, async_graphql::Response> {
+        Box::pin(Self::schema().execute_stream(request))
+    }
+
+    fn sdl(&self) -> String {
+        SDL.to_string()
+    }
+}
+
+const TRANSACTIONS: &[(&str, &str, i32)] = &[("t1", "1", 100), ("t2", "2", 250), ("t3", "2", 40)];
+
+struct User {
+    id: ID,
+}
+
+#[Object]
+impl User {
+    async fn id(&self) -> &ID {
+        &self.id
+    }
+
+    async fn transactions(&self) -> Vec<Transaction> {
+        TRANSACTIONS
+            .iter()
+            .filter(|(_, user_id, _)| self.id == *user_id)
+            .map(|(id, _, amount)| Transaction {
+                id: (*id).into(),
+                amount: *amount,
+            })
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+struct Organization {
+    id: ID,
+}
+
+struct Transaction {
+    id: ID,
+    amount: i32,
+}
+
+#[Object]
+impl Transaction {
+    async fn id(&self) -> &ID {
+        &self.id
+    }
+
+    async fn amount(&self) -> i32 {
+        self.amount
+    }
+
+    async fn formatted_amount(&self, locale: String) -> String {
+        format!("{} ({locale})", self.amount)
+    }
+
+    async fn converted_amount(&self, currency: String) -> String {
+        format!("{} {currency}", self.amount)
+    }
+
+    async fn receipt(&self, ctx: &Context<'_>, file: Upload, locale: String) -> FieldResult<String> {
+        let mut content = String::new();
+        file.value(ctx)?.content.read_to_string(&mut content)?;
+        Ok(format!("{} ({locale}): {content}", self.amount))
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn transaction(&self, id: ID) -> Option<Transaction> {
+        find_transaction(id)
+    }
+
+    #[graphql(entity)]
+    async fn find_user_by_id(&self, id: ID) -> User {
+        User { id }
+    }
+
+    #[graphql(entity)]
+    async fn find_organization_by_id(&self, id: ID) -> Organization {
+        Organization { id }
+    }
+
+    #[graphql(entity)]
+    async fn find_transaction_by_id(&self, id: ID) -> Option<Transaction> {
+        find_transaction(id)
+    }
+}
+
+fn find_transaction(id: ID) -> Option<Transaction> {
+    TRANSACTIONS
+        .iter()
+        .find(|(transaction_id, _, _)| id == *transaction_id)
+        .map(|(_, _, amount)| Transaction { id, amount: *amount })
+}
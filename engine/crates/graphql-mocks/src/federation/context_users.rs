@@ -0,0 +1,109 @@
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema, SimpleObject, ID};
+
+/// Users with a locale and organizations with a currency, used as contexts by
+/// [super::FederatedContextPaymentsSchema].
+pub struct FederatedContextUsersSchema;
+
+impl crate::Subgraph for FederatedContextUsersSchema {
+    fn name(&self) -> String {
+        "users".to_string()
+    }
+    async fn start(self) -> crate::MockGraphQlServer {
+        crate::MockGraphQlServer::new(self).await
+    }
+}
+
+impl FederatedContextUsersSchema {
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        let users = vec![
+            User {
+                id: "1".into(),
+                name: "Alice".into(),
+                locale: "en-US".into(),
+            },
+            User {
+                id: "2".into(),
+                name: "Bob".into(),
+                locale: "fr-FR".into(),
+            },
+        ];
+
+        Schema::build(Query, EmptyMutation, EmptySubscription)
+            .data(users)
+            .enable_federation()
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl super::super::Schema for FederatedContextUsersSchema {
+    async fn execute(
+        &self,
+        _headers: Vec<(String, String)>,
+        request: async_graphql::Request,
+    ) -> async_graphql::Response {
+        Self::schema().execute(request).await
+    }
+
+    fn execute_stream(
+        &self,
+        request: async_graphql::Request,
+    ) -> futures::stream::BoxStream<'static, async_graphql::Response> {
+        Box::pin(Self::schema().execute_stream(request))
+    }
+
+    fn sdl(&self) -> String {
+        Self::schema().sdl_with_options(async_graphql::SDLExportOptions::new().federation())
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+struct User {
+    id: ID,
+    name: String,
+    locale: String,
+}
+
+struct Organization;
+
+#[Object]
+impl Organization {
+    async fn id(&self) -> ID {
+        "org-1".into()
+    }
+
+    async fn currency(&self) -> &'static str {
+        "EUR"
+    }
+
+    async fn members<'a>(&self, ctx: &'a Context<'_>) -> &'a [User] {
+        ctx.data_unchecked::<Vec<User>>()
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn me<'a>(&self, ctx: &'a Context<'_>) -> Option<&'a User> {
+        ctx.data_unchecked::<Vec<User>>().first()
+    }
+
+    async fn users<'a>(&self, ctx: &'a Context<'_>) -> &'a [User] {
+        ctx.data_unchecked::<Vec<User>>()
+    }
+
+    async fn organization(&self) -> Organization {
+        Organization
+    }
+
+    #[graphql(entity)]
+    async fn find_user_by_id<'a>(&self, ctx: &'a Context<'_>, id: ID) -> Option<&'a User> {
+        ctx.data_unchecked::<Vec<User>>().iter().find(|user| user.id == id)
+    }
+
+    #[graphql(entity)]
+    async fn find_organization_by_id(&self, id: ID) -> Option<Organization> {
+        (id == "org-1").then_some(Organization)
+    }
+}
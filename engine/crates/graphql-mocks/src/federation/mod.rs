@@ -1,6 +1,8 @@
 // Mostly taken from:
 // https://github.com/async-graphql/examples
 mod accounts;
+mod context_payments;
+mod context_users;
 mod inventory;
 mod products;
 mod reviews;
@@ -9,6 +11,8 @@ mod shipping;
 
 pub use self::shipping::FederatedShippingSchema;
 pub use accounts::FederatedAccountsSchema;
+pub use context_payments::FederatedContextPaymentsSchema;
+pub use context_users::FederatedContextUsersSchema;
 pub use inventory::FederatedInventorySchema;
 pub use products::FederatedProductsSchema;
 pub use reviews::FederatedReviewsSchema;
@@ -23,10 +23,21 @@ mod secure;
 mod slow;
 mod stateful;
 mod tea_shop;
+mod ticker;
+mod upload;
 
 pub use {
-    almost_empty::AlmostEmptySchema, echo::EchoSchema, error_schema::ErrorSchema, fake_github::FakeGithubSchema,
-    federation::*, secure::SecureSchema, slow::SlowSchema, stateful::Stateful, tea_shop::TeaShop,
+    almost_empty::AlmostEmptySchema,
+    echo::EchoSchema,
+    error_schema::ErrorSchema,
+    fake_github::FakeGithubSchema,
+    federation::*,
+    secure::SecureSchema,
+    slow::{FirstRequestSlowSchema, SlowSchema},
+    stateful::Stateful,
+    tea_shop::TeaShop,
+    ticker::TickerSchema,
+    upload::UploadSchema,
 };
 
 #[derive(Debug)]
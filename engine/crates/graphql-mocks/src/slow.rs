@@ -1,4 +1,9 @@
-use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema};
 
 use crate::MockGraphQlServer;
 
@@ -59,3 +64,65 @@ impl crate::Schema for SlowSchema {
         Self::schema().sdl_with_options(async_graphql::SDLExportOptions::new().federation())
     }
 }
+
+/// Only the first request is slow, every following one is answered right away.
+#[derive(Default)]
+pub struct FirstRequestSlowSchema {
+    requests: Arc<AtomicUsize>,
+}
+
+impl crate::Subgraph for FirstRequestSlowSchema {
+    fn name(&self) -> String {
+        "first_request_slow".to_string()
+    }
+
+    async fn start(self) -> MockGraphQlServer {
+        MockGraphQlServer::new(self).await
+    }
+}
+
+impl FirstRequestSlowSchema {
+    fn schema(&self) -> Schema<FirstRequestSlowQuery, EmptyMutation, EmptySubscription> {
+        Schema::build(FirstRequestSlowQuery, EmptyMutation, EmptySubscription)
+            .enable_federation()
+            .data(Arc::clone(&self.requests))
+            .finish()
+    }
+}
+
+struct FirstRequestSlowQuery;
+
+#[Object]
+impl FirstRequestSlowQuery {
+    /// Returns the index of the request, the first one being delayed by `ms`.
+    async fn request_index(&self, ctx: &Context<'_>, ms: u32) -> usize {
+        let index = ctx.data_unchecked::<Arc<AtomicUsize>>().fetch_add(1, Ordering::Relaxed);
+        if index == 0 {
+            tokio::time::sleep(tokio::time::Duration::from_millis(ms.into())).await;
+        }
+        index
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::Schema for FirstRequestSlowSchema {
+    async fn execute(
+        &self,
+        _headers: Vec<(String, String)>,
+        request: async_graphql::Request,
+    ) -> async_graphql::Response {
+        self.schema().execute(request).await
+    }
+
+    fn execute_stream(
+        &self,
+        request: async_graphql::Request,
+    ) -> futures::stream::BoxStream<'static, async_graphql::Response> {
+        Box::pin(self.schema().execute_stream(request))
+    }
+
+    fn sdl(&self) -> String {
+        self.schema()
+            .sdl_with_options(async_graphql::SDLExportOptions::new().federation())
+    }
+}
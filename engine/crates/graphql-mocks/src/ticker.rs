@@ -0,0 +1,127 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+};
+
+use async_graphql::{EmptyMutation, Object, Schema, Subscription};
+use futures::{channel::mpsc, Stream, StreamExt};
+
+/// A subscription only emitting when told to, which keeps track of the subscriptions it serves.
+#[derive(Clone, Default)]
+pub struct TickerSchema {
+    state: Arc<TickerState>,
+}
+
+#[derive(Default)]
+struct TickerState {
+    opened: AtomicUsize,
+    active: AtomicUsize,
+    subscribers: Mutex<Vec<mpsc::UnboundedSender<i32>>>,
+}
+
+impl crate::Subgraph for TickerSchema {
+    fn name(&self) -> String {
+        "ticker".to_string()
+    }
+
+    async fn start(self) -> crate::MockGraphQlServer {
+        crate::MockGraphQlServer::new(self).await
+    }
+}
+
+impl TickerSchema {
+    /// Sends a tick to all the open subscriptions.
+    pub fn tick(&self, value: i32) {
+        self.state
+            .subscribers
+            .lock()
+            .unwrap()
+            .retain(|subscriber| subscriber.unbounded_send(value).is_ok());
+    }
+
+    /// How many subscriptions were opened since the start.
+    pub fn opened_subscriptions(&self) -> usize {
+        self.state.opened.load(Ordering::SeqCst)
+    }
+
+    /// How many subscriptions are currently open.
+    pub fn active_subscriptions(&self) -> usize {
+        self.state.active.load(Ordering::SeqCst)
+    }
+
+    fn schema(&self) -> Schema<Query, EmptyMutation, Ticks> {
+        Schema::build(Query, EmptyMutation, Ticks)
+            .data(Arc::clone(&self.state))
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::Schema for TickerSchema {
+    async fn execute(
+        &self,
+        _headers: Vec<(String, String)>,
+        request: async_graphql::Request,
+    ) -> async_graphql::Response {
+        self.schema().execute(request).await
+    }
+
+    fn execute_stream(
+        &self,
+        request: async_graphql::Request,
+    ) -> futures::stream::BoxStream<'static, async_graphql::Response> {
+        Box::pin(self.schema().execute_stream(request))
+    }
+
+    fn sdl(&self) -> String {
+        self.schema().sdl()
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn active_subscriptions(&self, ctx: &async_graphql::Context<'_>) -> usize {
+        ctx.data_unchecked::<Arc<TickerState>>().active.load(Ordering::SeqCst)
+    }
+}
+
+struct Ticks;
+
+#[Subscription]
+impl Ticks {
+    async fn ticks(&self, ctx: &async_graphql::Context<'_>) -> impl Stream<Item = i32> {
+        let state = Arc::clone(ctx.data_unchecked::<Arc<TickerState>>());
+        let (sender, receiver) = mpsc::unbounded();
+
+        state.opened.fetch_add(1, Ordering::SeqCst);
+        state.active.fetch_add(1, Ordering::SeqCst);
+        state.subscribers.lock().unwrap().push(sender);
+
+        ActiveSubscription { receiver, state }
+    }
+}
+
+struct ActiveSubscription {
+    receiver: mpsc::UnboundedReceiver<i32>,
+    state: Arc<TickerState>,
+}
+
+impl Stream for ActiveSubscription {
+    type Item = i32;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for ActiveSubscription {
+    fn drop(&mut self) {
+        self.state.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
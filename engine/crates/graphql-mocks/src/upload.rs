@@ -0,0 +1,88 @@
+use std::io::Read;
+
+use async_graphql::{Context, EmptySubscription, FieldResult, Object, Schema, SimpleObject, Upload};
+
+/// A schema accepting file uploads following the GraphQL multipart request spec.
+#[derive(Default)]
+pub struct UploadSchema;
+
+impl crate::Subgraph for UploadSchema {
+    fn name(&self) -> String {
+        "upload".to_string()
+    }
+    async fn start(self) -> crate::MockGraphQlServer {
+        crate::MockGraphQlServer::new(self).await
+    }
+}
+
+impl UploadSchema {
+    fn schema(&self) -> Schema<Query, Mutation, EmptySubscription> {
+        Schema::build(Query, Mutation, EmptySubscription).finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl super::Schema for UploadSchema {
+    async fn execute(
+        &self,
+        _headers: Vec<(String, String)>,
+        request: async_graphql::Request,
+    ) -> async_graphql::Response {
+        self.schema().execute(request).await
+    }
+
+    fn execute_stream(
+        &self,
+        request: async_graphql::Request,
+    ) -> futures::stream::BoxStream<'static, async_graphql::Response> {
+        Box::pin(self.schema().execute_stream(request))
+    }
+
+    fn sdl(&self) -> String {
+        self.schema().sdl_with_options(async_graphql::SDLExportOptions::new())
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn hello(&self) -> &'static str {
+        "world"
+    }
+}
+
+#[derive(SimpleObject)]
+struct File {
+    filename: String,
+    content_type: Option<String>,
+    content: String,
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn single_upload(&self, ctx: &Context<'_>, file: Upload) -> FieldResult<File> {
+        read_file(ctx, file)
+    }
+
+    async fn multiple_upload(&self, ctx: &Context<'_>, files: Vec<Upload>) -> FieldResult<Vec<File>> {
+        files.into_iter().map(|file| read_file(ctx, file)).collect()
+    }
+
+    async fn echo(&self, text: String) -> String {
+        text
+    }
+}
+
+fn read_file(ctx: &Context<'_>, file: Upload) -> FieldResult<File> {
+    let value = file.value(ctx)?;
+    let mut content = String::new();
+    value.content.try_clone()?.read_to_string(&mut content)?;
+    Ok(File {
+        filename: value.filename,
+        content_type: value.content_type,
+        content,
+    })
+}
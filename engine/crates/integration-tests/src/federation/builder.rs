@@ -9,7 +9,10 @@ use crate::{mock_trusted_documents::MockTrustedDocumentsClient, TestTrustedDocum
 pub use bench::*;
 use futures::{future::BoxFuture, FutureExt};
 use graphql_mocks::MockGraphQlServer;
-use runtime::{fetch::dynamic::DynamicFetcher, hooks::DynamicHooks, trusted_documents_client};
+use runtime::{
+    fetch::dynamic::DynamicFetcher, hooks::DynamicHooks, kv::KvStore, rate_limiting::RateLimiter,
+    trusted_documents_client,
+};
 pub use test_runtime::*;
 
 use super::{subgraph::Subgraphs, DockerSubgraph, TestGateway};
@@ -96,6 +99,16 @@ impl TestGatewayBuilder {
         self.runtime.fetcher = fetcher.into();
         self
     }
+
+    pub fn with_kv(mut self, kv: KvStore) -> Self {
+        self.runtime.kv = kv;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.runtime.rate_limiter = rate_limiter;
+        self
+    }
     //-- Runtime customization --
 
     pub async fn build(self) -> TestGateway {
@@ -29,6 +29,7 @@ pub struct DeterministicEngineBuilder<'a> {
     runtime: TestRuntime,
     schema: &'a str,
     query: &'a str,
+    on_error: Option<&'a str>,
     subgraphs_json_responses: Vec<String>,
 }
 
@@ -46,6 +47,13 @@ impl<'a> DeterministicEngineBuilder<'a> {
         self
     }
 
+    /// Sets the `onError` request parameter, `NULL` or `PROPAGATE`.
+    #[must_use]
+    pub fn with_on_error(mut self, on_error: &'a str) -> Self {
+        self.on_error = Some(on_error);
+        self
+    }
+
     pub fn without_operation_cache(mut self) -> Self {
         self.runtime.hot_cache_factory = InMemoryOperationCacheFactory::inactive();
         self
@@ -73,7 +81,11 @@ impl<'a> DeterministicEngineBuilder<'a> {
             },
         )
         .await;
-        let body = Bytes::from(serde_json::to_vec(&serde_json::json!({"query": self.query})).unwrap());
+        let mut body = serde_json::json!({"query": self.query});
+        if let Some(on_error) = self.on_error {
+            body["onError"] = on_error.into();
+        }
+        let body = Bytes::from(serde_json::to_vec(&body).unwrap());
         DeterministicEngine {
             engine: Arc::new(engine),
             request_parts: http::Request::builder()
@@ -99,6 +111,7 @@ impl DeterministicEngine {
             runtime: TestRuntime::default(),
             schema,
             query,
+            on_error: None,
             subgraphs_json_responses: Vec::new(),
         }
     }
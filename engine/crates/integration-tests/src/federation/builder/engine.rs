@@ -1,6 +1,9 @@
 use std::{collections::HashMap, sync::Arc};
 
-use crate::federation::{subgraph::Subgraphs, TestRuntimeContext};
+use crate::federation::{
+    subgraph::{Subgraph, Subgraphs},
+    TestRuntimeContext,
+};
 use engine_config_builder::{build_with_sdl_config, build_with_toml_config};
 use federated_graph::FederatedGraphV3;
 use grafbase_telemetry::metrics::meter_from_global_provider;
@@ -60,7 +63,18 @@ pub(super) async fn build(
 
     let config = match config_source {
         Some(ConfigSource::Toml(toml)) => {
-            let config: gateway_config::Config = toml::from_str(&toml).unwrap();
+            let mut config: gateway_config::Config = toml::from_str(&toml).unwrap();
+
+            // Mock subgraphs only serve subscriptions over websockets.
+            for subgraph in subgraphs.iter() {
+                if let Subgraph::Mock { server, .. } = subgraph {
+                    if let Some(subgraph_config) = config.subgraphs.get_mut(&server.name) {
+                        subgraph_config
+                            .websocket_url
+                            .get_or_insert_with(|| server.websocket_url());
+                    }
+                }
+            }
 
             update_runtime_with_toml_config(&mut runtime, &config, access_log_sender);
             build_with_toml_config(&config, graph.into_latest())
@@ -17,7 +17,6 @@ use crate::engine_v1::GraphQlRequest;
 
 pub struct TestGateway {
     router: axum::Router,
-    #[allow(unused)]
     engine: Arc<engine_v2::Engine<TestRuntime>>,
     #[allow(unused)]
     context: TestRuntimeContext,
@@ -93,6 +92,10 @@ impl TestGateway {
         http::Response::from_parts(parts, bytes)
     }
 
+    pub fn engine(&self) -> &Arc<engine_v2::Engine<TestRuntime>> {
+        &self.engine
+    }
+
     pub fn subgraph<S: graphql_mocks::Subgraph>(&self) -> &MockSubgraph {
         self.subgraphs.get_mock_by_type::<S>().unwrap()
     }
@@ -1,30 +1,43 @@
 use std::future::IntoFuture;
 
-use futures::{future::BoxFuture, StreamExt, TryStreamExt};
+use futures::{future::BoxFuture, stream::BoxStream, StreamExt, TryStreamExt};
 use tower::ServiceExt;
 
 pub struct MultipartStreamRequest(pub(super) super::TestRequest);
 
-impl IntoFuture for MultipartStreamRequest {
-    type Output = GraphqlStreamingResponse;
-
-    type IntoFuture = BoxFuture<'static, Self::Output>;
+impl MultipartStreamRequest {
+    /// The parts of the response as they're received, for subscriptions which don't end by themselves.
+    pub async fn into_stream(self) -> BoxStream<'static, serde_json::Value> {
+        let (_, stream) = self.send().await;
+        stream
+    }
 
-    fn into_future(self) -> Self::IntoFuture {
+    async fn send(self) -> (http::response::Parts, BoxStream<'static, serde_json::Value>) {
         let (router, mut request) = self.0.into_router_and_request();
         request
             .headers_mut()
             .entry(http::header::ACCEPT)
             .or_insert(http::HeaderValue::from_static("multipart/mixed,application/json;q=0.9"));
+        let (parts, body) = router.oneshot(request).await.unwrap().into_parts();
+        let stream = multipart_stream::parse(body.into_data_stream(), "-").map(|result| match result {
+            Ok(part) => match serde_json::from_slice(&part.body) {
+                Ok(value) => value,
+                Err(error) => serde_json::Value::String(format!("JSON serialization error: {error}")),
+            },
+            Err(error) => serde_json::Value::String(format!("Multipart error: {error}")),
+        });
+        (parts, stream.boxed())
+    }
+}
+
+impl IntoFuture for MultipartStreamRequest {
+    type Output = GraphqlStreamingResponse;
+
+    type IntoFuture = BoxFuture<'static, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
         Box::pin(async move {
-            let (parts, body) = router.oneshot(request).await.unwrap().into_parts();
-            let stream = multipart_stream::parse(body.into_data_stream(), "-").map(|result| match result {
-                Ok(part) => match serde_json::from_slice(&part.body) {
-                    Ok(value) => value,
-                    Err(error) => serde_json::Value::String(format!("JSON serialization error: {error}")),
-                },
-                Err(error) => serde_json::Value::String(format!("Multipart error: {error}")),
-            });
+            let (parts, stream) = self.send().await;
             GraphqlStreamingResponse {
                 status: parts.status,
                 headers: parts.headers,
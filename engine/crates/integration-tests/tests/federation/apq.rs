@@ -1,6 +1,7 @@
 use engine_v2::Engine;
 use graphql_mocks::FakeGithubSchema;
 use integration_tests::{federation::EngineV2Ext, runtime};
+use runtime_local::InMemoryKvStore;
 
 #[test]
 fn single_field_from_single_server() {
@@ -92,6 +93,49 @@ fn single_field_from_single_server() {
     });
 }
 
+#[test]
+fn persisted_query_is_shared_through_the_kv_store() {
+    runtime().block_on(async move {
+        let kv = InMemoryKvStore::runtime();
+        let first = Engine::builder()
+            .with_subgraph(FakeGithubSchema)
+            .with_kv(kv.clone())
+            .build()
+            .await;
+        let second = Engine::builder()
+            .with_subgraph(FakeGithubSchema)
+            .with_kv(kv)
+            .build()
+            .await;
+
+        let query = "query { serverVersion }";
+        let apq_ext = serde_json::json!({
+            "persistedQuery": {
+                "version": 1,
+                "sha256Hash": sha256(query)
+            }
+        });
+
+        // Registered on the first instance
+        insta::assert_json_snapshot!(first.post(query).extensions(&apq_ext).await, @r###"
+        {
+          "data": {
+            "serverVersion": "1"
+          }
+        }
+        "###);
+
+        // Known by the second one
+        insta::assert_json_snapshot!(second.post("").extensions(&apq_ext).await, @r###"
+        {
+          "data": {
+            "serverVersion": "1"
+          }
+        }
+        "###);
+    });
+}
+
 fn sha256(query: &str) -> String {
     use sha2::{Digest, Sha256};
     let digest = <Sha256 as Digest>::digest(query.as_bytes());
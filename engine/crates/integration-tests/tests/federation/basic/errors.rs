@@ -253,6 +253,42 @@ fn invalid_response_for_nullable_field() {
     "###);
 }
 
+#[test]
+fn missing_required_field() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::new(
+            SCHEMA,
+            "query { me { id username } }",
+            &[json!({"data": {"me": {"id": "1"}}})],
+        )
+        .await
+        .execute()
+        .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Error decoding response from upstream: Missing required field named 'username' at line 1 column 24",
+          "locations": [
+            {
+              "line": 1,
+              "column": 9
+            }
+          ],
+          "path": [
+            "me"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_INVALID_RESPONSE_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
+
 #[test]
 fn subgraph_field_error() {
     let response = integration_tests::runtime().block_on(async {
@@ -987,3 +987,46 @@ fn header_regex_remove_should_remove_duplicates() {
     }
     "#);
 }
+
+#[test]
+fn accept_encoding_is_sent_when_subgraph_accepts_compressed_responses() {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(EchoSchema)
+            .with_toml_config(
+                r###"
+                [subgraphs.echo.compression]
+                accept_compressed_responses = true
+                "###,
+            )
+            .build()
+            .await;
+
+        engine.post("query { headers { name value }}").await
+    });
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "headers": [
+          {
+            "name": "accept",
+            "value": "application/graphql-response+json; charset=utf-8, application/json; charset=utf-8"
+          },
+          {
+            "name": "accept-encoding",
+            "value": "gzip, br, zstd"
+          },
+          {
+            "name": "content-length",
+            "value": "78"
+          },
+          {
+            "name": "content-type",
+            "value": "application/json"
+          }
+        ]
+      }
+    }
+    "#);
+}
@@ -56,3 +56,64 @@ fn supports_unused_builtin_scalars() {
     }
     "###);
 }
+
+const DATE_TIME_SCHEMA: &str = r#"
+    directive @join__field(graph: join__Graph, requires: String, provides: String) on FIELD_DEFINITION
+    directive @join__graph(name: String!, url: String!) on ENUM_VALUE
+
+    enum join__Graph {
+      EVENTS @join__graph(name: "events", url: "http://127.0.0.1:46697")
+    }
+
+    scalar Instant @specifiedBy(url: "https://scalars.graphql.org/andimarek/date-time")
+
+    type Query {
+      events(after: Instant): [Int!] @join__field(graph: EVENTS)
+    }
+"#;
+
+#[test]
+fn built_in_scalar_input_validation_can_be_disabled() {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_federated_sdl(DATE_TIME_SCHEMA)
+            .with_toml_config(
+                r#"
+                [scalars.Instant]
+                validate_input = false
+                "#,
+            )
+            .build()
+            .await;
+
+        engine.post(r#"query { events(after: "yesterday") }"#).await
+    });
+
+    // The value is forwarded to the subgraph, which isn't running.
+    for error in response.errors().iter() {
+        let message = error["message"].as_str().unwrap_or_default();
+        assert!(!message.contains("cannot be coerced"), "{message}");
+    }
+}
+
+#[test]
+fn built_in_scalars_are_validated_during_input_coercion() {
+    let (literal, variable) = runtime().block_on(async move {
+        let engine = Engine::builder().with_federated_sdl(DATE_TIME_SCHEMA).build().await;
+
+        let literal = engine.post(r#"query { events(after: "yesterday") }"#).await;
+        let variable = engine
+            .post("query Events($after: Instant) { events(after: $after) }")
+            .variables(json!({"after": "2024-13-01T00:00:00Z"}))
+            .await;
+
+        (literal, variable)
+    });
+
+    for response in [literal, variable] {
+        let errors = response.errors();
+        assert_eq!(errors.len(), 1, "{errors:#?}");
+        let message = errors[0]["message"].as_str().unwrap_or_default();
+        assert!(message.contains("cannot be coerced into a Instant scalar"), "{message}");
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use engine_v2::Engine;
+use futures::{future::BoxFuture, FutureExt};
+use graphql_mocks::Stateful;
+use integration_tests::{federation::EngineV2Ext, runtime};
+use runtime::rate_limiting::{Error, RateLimitKey, RateLimiter, RateLimiterContext, RateLimiterInner};
+
+const HALF_OPEN_CONFIG: &str = r###"
+    [subgraphs.stateful.circuit_breaker]
+    enabled = true
+    failure_threshold = 1
+    open_duration = "200ms"
+"###;
+
+/// Rejects every subgraph request while enabled.
+#[derive(Clone, Default)]
+struct RejectingRateLimiter(Arc<AtomicBool>);
+
+impl RejectingRateLimiter {
+    fn reject(&self, reject: bool) {
+        self.0.store(reject, Ordering::Relaxed);
+    }
+}
+
+impl RateLimiterInner for RejectingRateLimiter {
+    fn limit<'a>(&'a self, context: &'a dyn RateLimiterContext) -> BoxFuture<'a, Result<(), Error>> {
+        let rejected = self.0.load(Ordering::Relaxed) && matches!(context.key(), Some(RateLimitKey::Subgraph(_)));
+        async move {
+            if rejected {
+                Err(Error::ExceededCapacity)
+            } else {
+                Ok(())
+            }
+        }
+        .boxed()
+    }
+}
+
+#[test]
+fn circuit_breaker_opens_after_consecutive_failures() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(Stateful::default())
+            .with_toml_config(
+                r###"
+                [subgraphs.stateful.circuit_breaker]
+                enabled = true
+                failure_threshold = 2
+                open_duration = "1h"
+                "###,
+            )
+            .build()
+            .await;
+
+        for _ in 0..2 {
+            let response = engine.post("query { incrementAndFailIfLessThan(n: 100) }").await;
+            assert_eq!(response.errors()[0]["extensions"]["code"], "SUBGRAPH_REQUEST_ERROR");
+        }
+
+        let response = engine.post("query { value }").await;
+
+        insta::assert_json_snapshot!(response.errors(), @r###"
+        [
+          {
+            "message": "Request to subgraph 'stateful' was not sent, its circuit breaker is open",
+            "path": [
+              "value"
+            ],
+            "extensions": {
+              "code": "SUBGRAPH_REQUEST_ERROR"
+            }
+          }
+        ]
+        "###);
+    });
+}
+
+#[test]
+fn circuit_breaker_disabled_by_default() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(Stateful::default()).build().await;
+
+        for _ in 0..10 {
+            let response = engine.post("query { incrementAndFailIfLessThan(n: 10) }").await;
+            assert_eq!(response.errors()[0]["extensions"]["code"], "SUBGRAPH_REQUEST_ERROR");
+        }
+
+        let response = engine.post("query { value }").await;
+
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "value": 10
+          }
+        }
+        "###);
+    });
+}
+
+#[test]
+fn circuit_breaker_closes_after_successful_probe() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(Stateful::default())
+            .with_toml_config(HALF_OPEN_CONFIG)
+            .build()
+            .await;
+
+        let response = engine.post("query { incrementAndFailIfLessThan(n: 1) }").await;
+        assert_eq!(response.errors()[0]["extensions"]["code"], "SUBGRAPH_REQUEST_ERROR");
+
+        let response = engine.post("query { value }").await;
+        assert_eq!(
+            response.errors()[0]["message"],
+            "Request to subgraph 'stateful' was not sent, its circuit breaker is open"
+        );
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        for _ in 0..2 {
+            let response = engine.post("query { value }").await;
+
+            insta::assert_json_snapshot!(response, @r###"
+            {
+              "data": {
+                "value": 1
+              }
+            }
+            "###);
+        }
+    });
+}
+
+#[test]
+fn circuit_breaker_reopens_after_failed_probe() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(Stateful::default())
+            .with_toml_config(HALF_OPEN_CONFIG)
+            .build()
+            .await;
+
+        let response = engine.post("query { incrementAndFailIfLessThan(n: 2) }").await;
+        assert_eq!(response.errors()[0]["extensions"]["code"], "SUBGRAPH_REQUEST_ERROR");
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        // The probe fails as well.
+        let response = engine.post("query { incrementAndFailIfLessThan(n: 2) }").await;
+        assert_eq!(response.errors()[0]["extensions"]["code"], "SUBGRAPH_REQUEST_ERROR");
+
+        let response = engine.post("query { value }").await;
+        assert_eq!(
+            response.errors()[0]["message"],
+            "Request to subgraph 'stateful' was not sent, its circuit breaker is open"
+        );
+    });
+}
+
+#[test]
+fn rate_limited_probe_does_not_keep_the_circuit_open() {
+    runtime().block_on(async move {
+        let rate_limiter = RejectingRateLimiter::default();
+
+        let engine = Engine::builder()
+            .with_subgraph(Stateful::default())
+            .with_toml_config(HALF_OPEN_CONFIG)
+            .with_rate_limiter(RateLimiter::new(rate_limiter.clone()))
+            .build()
+            .await;
+
+        let response = engine.post("query { incrementAndFailIfLessThan(n: 1) }").await;
+        assert_eq!(response.errors()[0]["extensions"]["code"], "SUBGRAPH_REQUEST_ERROR");
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        // The probe is rejected before reaching the subgraph and must give its slot back.
+        rate_limiter.reject(true);
+        let response = engine.post("query { value }").await;
+        assert_eq!(response.errors()[0]["extensions"]["code"], "RATE_LIMITED");
+
+        rate_limiter.reject(false);
+        let response = engine.post("query { value }").await;
+
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "value": 1
+          }
+        }
+        "###);
+    });
+}
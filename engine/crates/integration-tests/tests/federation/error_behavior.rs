@@ -0,0 +1,295 @@
+use integration_tests::federation::DeterministicEngine;
+use serde_json::json;
+
+const SCHEMA: &str = include_str!("../../data/federated-graph-schema.graphql");
+
+#[test]
+fn null_propagates_by_default() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::new(
+            SCHEMA,
+            "query { me { id username } }",
+            &[json!({"data": {"me": {"id": "1", "username": null}}, "errors": [{"message": "failed!", "path": ["me", "username"]}]})],
+        )
+        .await
+        .execute()
+        .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "failed!",
+          "path": [
+            "me",
+            "username"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn on_error_null_only_nulls_the_failing_field() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::builder(SCHEMA, "query { me { id username } }")
+            .with_on_error("NULL")
+            .with_subgraph_response(json!({"data": {"me": {"id": "1", "username": null}}, "errors": [{"message": "failed!", "path": ["me", "username"]}]}))
+            .build()
+            .await
+            .execute()
+            .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "me": {
+          "id": "1",
+          "username": null
+        }
+      },
+      "errors": [
+        {
+          "message": "failed!",
+          "path": [
+            "me",
+            "username"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn on_error_null_keeps_missing_required_fields_as_null() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::builder(SCHEMA, "query { me { id username } }")
+            .with_on_error("NULL")
+            .with_subgraph_response(json!({"data": {"me": {"id": "1"}}}))
+            .build()
+            .await
+            .execute()
+            .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "me": {
+          "id": "1",
+          "username": null
+        }
+      },
+      "errors": [
+        {
+          "message": "Error decoding response from upstream: Missing required field named 'username'",
+          "locations": [
+            {
+              "line": 1,
+              "column": 17
+            }
+          ],
+          "path": [
+            "me",
+            "username"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_INVALID_RESPONSE_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn default_error_behavior_is_exposed_in_introspection() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::new(
+            SCHEMA,
+            "query { __schema { defaultErrorBehavior } }",
+            Vec::<serde_json::Value>::new(),
+        )
+        .await
+        .execute()
+        .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "__schema": {
+          "defaultErrorBehavior": "PROPAGATE"
+        }
+      }
+    }
+    "###);
+}
+
+const SEMANTIC_NON_NULL_SCHEMA: &str = r#"
+    enum join__Graph {
+      ACCOUNTS @join__graph(name: "accounts", url: "http://127.0.0.1:46697")
+    }
+
+    type User {
+        id: ID!
+        name: String @semanticNonNull
+        nicknames: [String] @semanticNonNull(levels: [1])
+    }
+
+    type Query {
+        me: User @join__field(graph: ACCOUNTS)
+    }
+"#;
+
+#[test]
+fn semantic_non_null_without_error_is_reported() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::new(
+            SEMANTIC_NON_NULL_SCHEMA,
+            "query { me { id name nicknames } }",
+            &[json!({"data": {"me": {"id": "1", "name": null, "nicknames": [null, "Rusty"]}}})],
+        )
+        .await
+        .execute()
+        .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "me": {
+          "id": "1",
+          "name": null,
+          "nicknames": [
+            null,
+            "Rusty"
+          ]
+        }
+      },
+      "errors": [
+        {
+          "message": "Error decoding response from upstream: Unexpected null without any error for a non-null value",
+          "locations": [
+            {
+              "line": 1,
+              "column": 17
+            }
+          ],
+          "path": [
+            "me",
+            "name"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_INVALID_RESPONSE_ERROR"
+          }
+        },
+        {
+          "message": "Error decoding response from upstream: Unexpected null without any error for a non-null value",
+          "locations": [
+            {
+              "line": 1,
+              "column": 22
+            }
+          ],
+          "path": [
+            "me",
+            "nicknames",
+            0
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_INVALID_RESPONSE_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn semantic_non_null_explained_by_a_subgraph_error() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::new(
+            SEMANTIC_NON_NULL_SCHEMA,
+            "query { me { id name nicknames } }",
+            &[json!({
+                "data": {"me": {"id": "1", "name": null, "nicknames": null}},
+                "errors": [{"message": "failed!", "path": ["me", "name"]}]
+            })],
+        )
+        .await
+        .execute()
+        .await
+    });
+    // `nicknames` itself may be null, only its items are semantically non-null.
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "me": {
+          "id": "1",
+          "name": null,
+          "nicknames": null
+        }
+      },
+      "errors": [
+        {
+          "message": "failed!",
+          "path": [
+            "me",
+            "name"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn on_error_null_reports_null_required_fields_without_error() {
+    let response = integration_tests::runtime().block_on(async {
+        DeterministicEngine::builder(SCHEMA, "query { me { id username } }")
+            .with_on_error("NULL")
+            .with_subgraph_response(json!({"data": {"me": {"id": "1", "username": null}}}))
+            .build()
+            .await
+            .execute()
+            .await
+    });
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "me": {
+          "id": "1",
+          "username": null
+        }
+      },
+      "errors": [
+        {
+          "message": "Error decoding response from upstream: Unexpected null without any error for a non-null value",
+          "locations": [
+            {
+              "line": 1,
+              "column": 17
+            }
+          ],
+          "path": [
+            "me",
+            "username"
+          ],
+          "extensions": {
+            "code": "SUBGRAPH_INVALID_RESPONSE_ERROR"
+          }
+        }
+      ]
+    }
+    "###);
+}
@@ -0,0 +1,156 @@
+use engine_v2::Engine;
+use graphql_mocks::{FederatedContextPaymentsSchema, FederatedContextUsersSchema};
+use integration_tests::{federation::EngineV2Ext, runtime};
+
+#[test]
+fn context_argument_is_read_from_ancestor() {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FederatedContextUsersSchema)
+            .with_subgraph(FederatedContextPaymentsSchema)
+            .build()
+            .await;
+
+        engine
+            .post("query { me { transactions { id formattedAmount } } }")
+            .await
+    });
+
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "me": {
+          "transactions": [
+            {
+              "id": "t1",
+              "formattedAmount": "100 (en-US)"
+            }
+          ]
+        }
+      }
+    }
+    "###);
+}
+
+#[test]
+fn context_groups_share_a_single_request() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FederatedContextUsersSchema)
+            .with_subgraph(FederatedContextPaymentsSchema)
+            .build()
+            .await;
+
+        let response = engine
+            .post("query { users { name transactions { id formattedAmount } } }")
+            .await;
+
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "users": [
+              {
+                "name": "Alice",
+                "transactions": [
+                  {
+                    "id": "t1",
+                    "formattedAmount": "100 (en-US)"
+                  }
+                ]
+              },
+              {
+                "name": "Bob",
+                "transactions": [
+                  {
+                    "id": "t2",
+                    "formattedAmount": "250 (fr-FR)"
+                  },
+                  {
+                    "id": "t3",
+                    "formattedAmount": "40 (fr-FR)"
+                  }
+                ]
+              }
+            ]
+          }
+        }
+        "###);
+
+        // Both locales are sent within the same request, each with its own group of representations.
+        let requests = engine.drain_graphql_requests_sent_to::<FederatedContextPaymentsSchema>();
+        let entity_requests = requests
+            .iter()
+            .filter(|request| request.query.contains("formattedAmount"))
+            .collect::<Vec<_>>();
+        assert_eq!(entity_requests.len(), 1);
+        assert!(entity_requests[0].query.contains("_0: _entities"));
+        assert!(entity_requests[0].query.contains("_1: _entities"));
+    });
+}
+
+#[test]
+fn nested_contexts() {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FederatedContextUsersSchema)
+            .with_subgraph(FederatedContextPaymentsSchema)
+            .build()
+            .await;
+
+        engine
+            .post("query { organization { members { name transactions { formattedAmount convertedAmount } } } }")
+            .await
+    });
+
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": {
+        "organization": {
+          "members": [
+            {
+              "name": "Alice",
+              "transactions": [
+                {
+                  "formattedAmount": "100 (en-US)",
+                  "convertedAmount": "100 EUR"
+                }
+              ]
+            },
+            {
+              "name": "Bob",
+              "transactions": [
+                {
+                  "formattedAmount": "250 (fr-FR)",
+                  "convertedAmount": "250 EUR"
+                },
+                {
+                  "formattedAmount": "40 (fr-FR)",
+                  "convertedAmount": "40 EUR"
+                }
+              ]
+            }
+          ]
+        }
+      }
+    }
+    "###);
+}
+
+#[test]
+fn missing_context() {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FederatedContextUsersSchema)
+            .with_subgraph(FederatedContextPaymentsSchema)
+            .build()
+            .await;
+
+        // No User ancestor provides the locale, so the subgraph receives null for a required argument.
+        engine
+            .post(r#"query { transaction(id: "t1") { id formattedAmount } }"#)
+            .await
+    });
+
+    assert_eq!(response["data"], serde_json::json!({ "transaction": null }));
+    assert!(!response.errors().is_empty(), "{response}");
+}
@@ -1,6 +1,7 @@
 mod application_graphql_response_json;
 mod application_json;
 mod batch;
+mod multipart_upload;
 
 use engine_v2::Engine;
 use graphql_mocks::{FakeGithubSchema, Stateful};
@@ -251,7 +252,7 @@ fn missing_content_type(#[case] accept: &'static str) {
         {
           "errors": [
             {
-              "message": "Missing or invalid Content-Type header. Only 'application/json' is supported.",
+              "message": "Missing or invalid Content-Type header. Only 'application/json' and 'multipart/form-data' are supported.",
               "extensions": {
                 "code": "BAD_REQUEST"
               }
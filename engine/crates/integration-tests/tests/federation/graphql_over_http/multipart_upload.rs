@@ -0,0 +1,315 @@
+use engine_v2::Engine;
+use graphql_mocks::{FederatedContextPaymentsSchema, FederatedContextUsersSchema, UploadSchema};
+use integration_tests::{federation::EngineV2Ext, runtime};
+
+const BOUNDARY: &str = "------------------------boundary";
+
+fn multipart_body(operations: serde_json::Value, map: serde_json::Value, files: &[(&str, &str, &str)]) -> Vec<u8> {
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n{operations}\r\n\
+         --{BOUNDARY}\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n{map}\r\n"
+    );
+    for (name, filename, content) in files {
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\n\
+             Content-Type: text/plain\r\n\r\n{content}\r\n"
+        ));
+    }
+    body.push_str(&format!("--{BOUNDARY}--\r\n"));
+    body.into_bytes()
+}
+
+fn multipart_request(body: Vec<u8>) -> http::Request<Vec<u8>> {
+    multipart_request_builder()
+        .header("apollo-require-preflight", "true")
+        .body(body)
+        .unwrap()
+}
+
+fn multipart_request_builder() -> http::request::Builder {
+    http::Request::builder()
+        .uri("http://localhost/graphql")
+        .method(http::Method::POST)
+        .header(http::header::ACCEPT, "application/json")
+        .header(
+            http::header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+}
+
+#[test]
+fn single_upload() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "mutation($file: Upload!) { singleUpload(file: $file) { filename contentType content } }",
+                    "variables": { "file": null }
+                }),
+                serde_json::json!({ "0": ["variables.file"] }),
+                &[("0", "hello.txt", "Hello world!")],
+            )))
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "data": {
+            "singleUpload": {
+              "filename": "hello.txt",
+              "contentType": "text/plain",
+              "content": "Hello world!"
+            }
+          }
+        }
+        "###);
+        assert_eq!(status, 200);
+    })
+}
+
+#[test]
+fn multiple_uploads() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "mutation($files: [Upload!]!) { multipleUpload(files: $files) { filename content } }",
+                    "variables": { "files": [null, null] }
+                }),
+                serde_json::json!({ "0": ["variables.files.0"], "1": ["variables.files.1"] }),
+                &[("0", "a.txt", "first"), ("1", "b.txt", "second")],
+            )))
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "data": {
+            "multipleUpload": [
+              {
+                "filename": "a.txt",
+                "content": "first"
+              },
+              {
+                "filename": "b.txt",
+                "content": "second"
+              }
+            ]
+          }
+        }
+        "###);
+        assert_eq!(status, 200);
+    })
+}
+
+#[test]
+fn upload_in_entity_request_with_context_arguments() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FederatedContextUsersSchema)
+            .with_subgraph(FederatedContextPaymentsSchema)
+            .build()
+            .await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "query($file: Upload!) { me { transactions { id receipt(file: $file) } } }",
+                    "variables": { "file": null }
+                }),
+                serde_json::json!({ "0": ["variables.file"] }),
+                &[("0", "receipt.txt", "Hello world!")],
+            )))
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "data": {
+            "me": {
+              "transactions": [
+                {
+                  "id": "t1",
+                  "receipt": "100 (en-US): Hello world!"
+                }
+              ]
+            }
+          }
+        }
+        "###);
+        assert_eq!(status, 200);
+    })
+}
+
+#[test]
+fn missing_file() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "mutation($file: Upload!) { singleUpload(file: $file) { filename } }",
+                    "variables": { "file": null }
+                }),
+                serde_json::json!({ "0": ["variables.file"] }),
+                &[],
+            )))
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "errors": [
+            {
+              "message": "Bad request, GraphQL request is not well formed: Multipart request deserialization failure: File '0' referenced in 'map' is missing",
+              "extensions": {
+                "code": "BAD_REQUEST"
+              }
+            }
+          ]
+        }
+        "###);
+        assert_eq!(status, 400);
+    })
+}
+
+#[test]
+fn string_variables_are_never_files() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "mutation($file: Upload!, $text: String!) { singleUpload(file: $file) { filename content } echo(text: $text) }",
+                    "variables": { "file": null, "text": "#__graphql_file__:0" }
+                }),
+                serde_json::json!({ "0": ["variables.file"] }),
+                &[("0", "hello.txt", "Hello world!")],
+            )))
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "data": {
+            "singleUpload": {
+              "filename": "hello.txt",
+              "content": "Hello world!"
+            },
+            "echo": "#__graphql_file__:0"
+          }
+        }
+        "###);
+        assert_eq!(status, 200);
+    })
+}
+
+#[test]
+fn files_are_only_accepted_for_upload_scalars() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "mutation($text: String!) { echo(text: $text) }",
+                    "variables": { "text": null }
+                }),
+                serde_json::json!({ "0": ["variables.text"] }),
+                &[("0", "hello.txt", "Hello world!")],
+            )))
+            .await;
+
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        let message = body["errors"][0]["message"].as_str().unwrap_or_default();
+        assert!(
+            message.contains("Found a Upload value where we expected a String scalar"),
+            "{body:#}"
+        );
+        assert!(body["data"].is_null(), "{body:#}");
+        assert!(engine.drain_graphql_requests_sent_to::<UploadSchema>().is_empty());
+    })
+}
+
+#[test]
+fn file_must_replace_null() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(multipart_request(multipart_body(
+                serde_json::json!({
+                    "query": "mutation($file: Upload!) { singleUpload(file: $file) { filename } }",
+                    "variables": { "file": "hello" }
+                }),
+                serde_json::json!({ "0": ["variables.file"] }),
+                &[("0", "hello.txt", "Hello world!")],
+            )))
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "errors": [
+            {
+              "message": "Bad request, GraphQL request is not well formed: Multipart request deserialization failure: Invalid file path 'variables.file' in 'map'",
+              "extensions": {
+                "code": "BAD_REQUEST"
+              }
+            }
+          ]
+        }
+        "###);
+        assert_eq!(status, 400);
+    })
+}
+
+#[test]
+fn csrf_prevention_header_is_required() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(UploadSchema).build().await;
+
+        let response = engine
+            .raw_execute(
+                multipart_request_builder()
+                    .body(multipart_body(
+                        serde_json::json!({
+                            "query": "mutation($file: Upload!) { singleUpload(file: $file) { filename } }",
+                            "variables": { "file": null }
+                        }),
+                        serde_json::json!({ "0": ["variables.file"] }),
+                        &[("0", "hello.txt", "Hello world!")],
+                    ))
+                    .unwrap(),
+            )
+            .await;
+
+        let status = response.status();
+        let body: serde_json::Value = serde_json::from_slice(&response.into_body()).unwrap();
+        insta::assert_json_snapshot!(body, @r###"
+        {
+          "errors": [
+            {
+              "message": "Multipart requests must set a non-empty apollo-require-preflight, x-apollo-operation-name or x-grafbase-csrf-protection header",
+              "extensions": {
+                "code": "BAD_REQUEST"
+              }
+            }
+          ]
+        }
+        "###);
+        assert_eq!(status, 400);
+    })
+}
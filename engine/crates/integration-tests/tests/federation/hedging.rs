@@ -0,0 +1,100 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use engine_v2::Engine;
+use futures::{future::BoxFuture, FutureExt};
+use graphql_mocks::FirstRequestSlowSchema;
+use integration_tests::{federation::EngineV2Ext, runtime};
+use runtime::rate_limiting::{Error, RateLimitKey, RateLimiter, RateLimiterContext, RateLimiterInner};
+
+const CONFIG: &str = r###"
+    [subgraphs.first_request_slow.retry]
+    enabled = true
+    min_per_second = 10
+
+    [subgraphs.first_request_slow.hedging]
+    enabled = true
+    delay = "50ms"
+"###;
+
+/// Lets a fixed number of subgraph requests through.
+struct SubgraphTokens(AtomicUsize);
+
+impl RateLimiterInner for SubgraphTokens {
+    fn limit<'a>(&'a self, context: &'a dyn RateLimiterContext) -> BoxFuture<'a, Result<(), Error>> {
+        let allowed = !matches!(context.key(), Some(RateLimitKey::Subgraph(_)))
+            || self
+                .0
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |tokens| tokens.checked_sub(1))
+                .is_ok();
+
+        async move {
+            if allowed {
+                Ok(())
+            } else {
+                Err(Error::ExceededCapacity)
+            }
+        }
+        .boxed()
+    }
+}
+
+#[test]
+fn hedged_request_answers_for_a_slow_subgraph() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FirstRequestSlowSchema::default())
+            .with_toml_config(CONFIG)
+            .build()
+            .await;
+
+        let start = Instant::now();
+        let response = engine.post("query { requestIndex(ms: 5000) }").await;
+
+        // The hedged request is the second one received by the subgraph.
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "requestIndex": 1
+          }
+        }
+        "###);
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(
+            engine.drain_graphql_requests_sent_to::<FirstRequestSlowSchema>().len(),
+            2
+        );
+    });
+}
+
+#[test]
+fn hedged_request_is_not_sent_without_rate_limit_token() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FirstRequestSlowSchema::default())
+            .with_toml_config(CONFIG)
+            .with_rate_limiter(RateLimiter::new(SubgraphTokens(AtomicUsize::new(1))))
+            .build()
+            .await;
+
+        let response = engine.post("query { requestIndex(ms: 300) }").await;
+
+        // Only the original request was sent, its single token doesn't pay for the hedge.
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "requestIndex": 0
+          }
+        }
+        "###);
+        assert_eq!(
+            engine.drain_graphql_requests_sent_to::<FirstRequestSlowSchema>().len(),
+            1
+        );
+    });
+}
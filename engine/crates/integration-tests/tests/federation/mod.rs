@@ -1,12 +1,19 @@
 mod apq;
 mod auth;
 mod basic;
+mod circuit_breaker;
 mod entity_caching;
+mod error_behavior;
+mod from_context;
 mod graphql_over_http;
+mod hedging;
 mod hooks;
 mod inaccessible;
 mod introspection;
 mod issues;
+mod one_of;
+mod operation_cache_warming;
+mod request_deduplication;
 mod subgraph_retries;
 mod subgraphs;
 mod subscriptions;
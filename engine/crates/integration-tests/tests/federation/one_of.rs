@@ -0,0 +1,115 @@
+use engine_v2::Engine;
+use integration_tests::{federation::EngineV2Ext, runtime};
+use serde_json::json;
+
+const SCHEMA: &str = r#"
+    directive @join__field(graph: join__Graph, requires: String, provides: String) on FIELD_DEFINITION
+    directive @join__graph(name: String!, url: String!) on ENUM_VALUE
+
+    enum join__Graph {
+      PRODUCTS @join__graph(name: "products", url: "http://127.0.0.1:46697")
+    }
+
+    type Query {
+      product(by: ProductBy!): Int @join__field(graph: PRODUCTS)
+      products(filter: ProductFilter): [Int!] @join__field(graph: PRODUCTS)
+    }
+
+    input ProductBy @oneOf {
+      id: ID
+      sku: String
+    }
+
+    input ProductFilter {
+      name: String
+    }
+"#;
+
+fn error_messages(query: &str, variables: serde_json::Value) -> Vec<String> {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder().with_federated_sdl(SCHEMA).build().await;
+        engine.post(query).variables(variables).await
+    });
+
+    response
+        .errors()
+        .iter()
+        .map(|error| error["message"].as_str().expect("message to be a string").to_string())
+        .collect()
+}
+
+#[test]
+fn one_of_is_exposed_in_introspection() {
+    let response = runtime().block_on(async move {
+        let engine = Engine::builder().with_federated_sdl(SCHEMA).build().await;
+        engine
+            .post(
+                r#"query {
+                    productBy: __type(name: "ProductBy") { isOneOf }
+                    productFilter: __type(name: "ProductFilter") { isOneOf }
+                    query: __type(name: "Query") { isOneOf }
+                }"#,
+            )
+            .await
+    });
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "productBy": {
+          "isOneOf": true
+        },
+        "productFilter": {
+          "isOneOf": false
+        },
+        "query": {
+          "isOneOf": null
+        }
+      }
+    }
+    "#);
+}
+
+#[test]
+fn one_of_literal_requires_exactly_one_field() {
+    insta::assert_json_snapshot!(error_messages(r#"query { product(by: { id: "1", sku: "a" }) }"#, json!({})), @r#"
+    [
+      "Exactly one field must be provided for the @oneOf input object ProductBy, but 2 were provided"
+    ]
+    "#);
+    insta::assert_json_snapshot!(error_messages(r#"query { product(by: {}) }"#, json!({})), @r#"
+    [
+      "Exactly one field must be provided for the @oneOf input object ProductBy, but 0 were provided"
+    ]
+    "#);
+    insta::assert_json_snapshot!(error_messages(r#"query { product(by: { id: null }) }"#, json!({})), @r#"
+    [
+      "Field 'id' of the @oneOf input object ProductBy cannot be null"
+    ]
+    "#);
+}
+
+#[test]
+fn one_of_literal_rejects_nullable_variables() {
+    insta::assert_json_snapshot!(error_messages(r#"query($id: ID) { product(by: { id: $id }) }"#, json!({ "id": "1" })), @r#"
+    [
+      "Variable $id must be non-nullable to be used in the @oneOf input object ProductBy"
+    ]
+    "#);
+}
+
+#[test]
+fn one_of_variable_requires_exactly_one_field() {
+    let query = "query($by: ProductBy!) { product(by: $by) }";
+
+    insta::assert_json_snapshot!(error_messages(query, json!({ "by": { "id": "1", "sku": "a" } })), @r#"
+    [
+      "Variable $by has an invalid value. Exactly one field must be provided for the @oneOf input object ProductBy, but 2 were provided"
+    ]
+    "#);
+    insta::assert_json_snapshot!(error_messages(query, json!({ "by": { "sku": null } })), @r#"
+    [
+      "Variable $by has an invalid value. Field 'sku' of the @oneOf input object ProductBy cannot be null"
+    ]
+    "#);
+}
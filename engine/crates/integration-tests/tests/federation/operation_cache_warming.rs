@@ -0,0 +1,33 @@
+use engine_v2::Engine;
+use graphql_mocks::{AlmostEmptySchema, FakeGithubSchema};
+use integration_tests::{federation::EngineV2Ext, runtime};
+
+#[test]
+fn most_used_operations_can_be_prepared_by_another_engine() {
+    runtime().block_on(async move {
+        let engine = Engine::builder().with_subgraph(FakeGithubSchema).build().await;
+
+        for _ in 0..3 {
+            engine.post("query { serverVersion }").await;
+        }
+        engine.post("query { favoriteRepository }").await;
+
+        let same_graph = Engine::builder().with_subgraph(FakeGithubSchema).build().await;
+        let warmed = same_graph.engine().warm(engine.engine().most_used_operations(1)).await;
+        assert_eq!(warmed, 1);
+
+        let warmed = same_graph.engine().warm(engine.engine().most_used_operations(10)).await;
+        assert_eq!(warmed, 2);
+
+        // Warmed operations are tracked by the new engine as well.
+        assert_eq!(same_graph.engine().most_used_operations(10).len(), 2);
+
+        // Operations which aren't valid for the new graph are skipped.
+        let other_graph = Engine::builder().with_subgraph(AlmostEmptySchema).build().await;
+        let warmed = other_graph
+            .engine()
+            .warm(engine.engine().most_used_operations(10))
+            .await;
+        assert_eq!(warmed, 0);
+    });
+}
@@ -0,0 +1,133 @@
+use std::future::IntoFuture;
+
+use engine_v2::Engine;
+use graphql_mocks::{
+    FederatedInventorySchema, FederatedProductsSchema, FederatedReviewsSchema, FirstRequestSlowSchema, Stateful,
+};
+use integration_tests::{federation::EngineV2Ext, runtime};
+
+#[test]
+fn concurrent_operations_share_the_subgraph_request() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FirstRequestSlowSchema::default())
+            .with_toml_config(
+                r###"
+                [subgraphs.first_request_slow]
+                deduplicate_requests = true
+                "###,
+            )
+            .build()
+            .await;
+
+        let query = "query { requestIndex(ms: 500) }";
+        let (first, second) = futures::join!(engine.post(query).into_future(), engine.post(query).into_future());
+
+        // Both got the response of the first, slow, request.
+        for response in [first, second] {
+            insta::assert_json_snapshot!(response, @r###"
+            {
+              "data": {
+                "requestIndex": 0
+              }
+            }
+            "###);
+        }
+
+        assert_eq!(
+            engine.drain_graphql_requests_sent_to::<FirstRequestSlowSchema>().len(),
+            1
+        );
+    });
+}
+
+#[test]
+fn requests_are_not_deduplicated_by_default() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FirstRequestSlowSchema::default())
+            .build()
+            .await;
+
+        let query = "query { requestIndex(ms: 500) }";
+        let (first, second) = futures::join!(engine.post(query).into_future(), engine.post(query).into_future());
+
+        let mut indices = [first, second].map(|response| response.into_data()["requestIndex"].as_u64().unwrap());
+        indices.sort_unstable();
+
+        assert_eq!(indices, [0, 1]);
+        assert_eq!(
+            engine.drain_graphql_requests_sent_to::<FirstRequestSlowSchema>().len(),
+            2
+        );
+    });
+}
+
+#[test]
+fn identical_entity_requests_of_an_operation_are_deduplicated() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(FederatedProductsSchema)
+            .with_subgraph(FederatedReviewsSchema)
+            .with_subgraph(FederatedInventorySchema)
+            .with_toml_config(
+                r###"
+                [subgraphs.reviews]
+                deduplicate_requests = true
+                "###,
+            )
+            .build()
+            .await;
+
+        let response = engine
+            .post(
+                r#"
+                query {
+                    first: product(upc: "top-1") { reviews { id body } }
+                    second: product(upc: "top-1") { reviews { id body } }
+                }
+                "#,
+            )
+            .await
+            .into_data();
+
+        assert_eq!(response["first"], response["second"]);
+        assert!(!response["first"]["reviews"].as_array().unwrap().is_empty());
+        assert_eq!(
+            engine.drain_graphql_requests_sent_to::<FederatedReviewsSchema>().len(),
+            1
+        );
+    });
+}
+
+#[test]
+fn mutations_are_never_deduplicated() {
+    runtime().block_on(async move {
+        let engine = Engine::builder()
+            .with_subgraph(Stateful::default())
+            .with_toml_config(
+                r###"
+                [subgraphs.stateful]
+                deduplicate_requests = true
+                "###,
+            )
+            .build()
+            .await;
+
+        engine.post("mutation { set(val: 1) }").await.into_data();
+
+        let mutation = "mutation { multiply(by: 2) }";
+        futures::join!(engine.post(mutation).into_future(), engine.post(mutation).into_future());
+
+        let response = engine.post("query { value }").await;
+
+        insta::assert_json_snapshot!(response, @r###"
+        {
+          "data": {
+            "value": 4
+          }
+        }
+        "###);
+        assert_eq!(engine.drain_graphql_requests_sent_to::<Stateful>().len(), 4);
+    });
+}
@@ -1,3 +1,4 @@
 mod multipart;
+mod multiplexing;
 mod sse;
 mod sse_subgraph;
@@ -0,0 +1,174 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use engine_v2::Engine;
+use futures::{stream::BoxStream, StreamExt};
+use graphql_mocks::TickerSchema;
+use integration_tests::{federation::EngineV2Ext, runtime};
+use serde_json::json;
+
+const CONFIG: &str = r#"
+    [subgraphs.ticker]
+    multiplex_subscriptions = true
+"#;
+
+const SUBSCRIPTION: &str = "subscription { ticks }";
+
+/// Ticks until the stream receives something, the subscription may not be established yet.
+async fn next_tick(ticker: &TickerSchema, stream: &mut BoxStream<'static, serde_json::Value>) -> serde_json::Value {
+    for value in 0..100 {
+        ticker.tick(value);
+        if let Ok(Some(event)) = tokio::time::timeout(Duration::from_millis(50), stream.next()).await {
+            return event;
+        }
+    }
+    unreachable!("The subscription didn't receive any tick");
+}
+
+async fn wait_for(condition: impl Fn() -> bool) {
+    for _ in 0..100 {
+        if condition() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(condition(), "Timed out waiting for the condition");
+}
+
+#[test]
+fn identical_subscriptions_share_one_upstream_stream() {
+    runtime().block_on(async move {
+        let ticker = TickerSchema::default();
+        let engine = Engine::builder()
+            .with_subgraph(ticker.clone())
+            .with_toml_config(CONFIG)
+            .build()
+            .await;
+
+        let mut first = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        next_tick(&ticker, &mut first).await;
+
+        let mut second = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        let event = next_tick(&ticker, &mut second).await;
+        assert!(event["data"]["ticks"].is_number(), "{event}");
+
+        // The first subscriber gets the same ticks.
+        ticker.tick(1000);
+        let mut first_ticks = Vec::new();
+        while let Ok(Some(event)) = tokio::time::timeout(Duration::from_millis(200), first.next()).await {
+            first_ticks.push(event);
+        }
+        assert!(
+            first_ticks.contains(&json!({"data": {"ticks": 1000}})),
+            "{first_ticks:?}"
+        );
+
+        assert_eq!(ticker.opened_subscriptions(), 1);
+        assert_eq!(ticker.active_subscriptions(), 1);
+    });
+}
+
+#[test]
+fn upstream_stream_is_closed_when_the_last_subscriber_leaves() {
+    runtime().block_on(async move {
+        let ticker = TickerSchema::default();
+        let engine = Engine::builder()
+            .with_subgraph(ticker.clone())
+            .with_toml_config(CONFIG)
+            .build()
+            .await;
+
+        let mut first = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        next_tick(&ticker, &mut first).await;
+
+        let mut second = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        next_tick(&ticker, &mut second).await;
+
+        // The remaining subscriber keeps receiving ticks.
+        drop(first);
+        ticker.tick(1000);
+        let mut second_ticks = Vec::new();
+        while let Ok(Some(event)) = tokio::time::timeout(Duration::from_millis(200), second.next()).await {
+            second_ticks.push(event);
+        }
+        assert!(
+            second_ticks.contains(&json!({"data": {"ticks": 1000}})),
+            "{second_ticks:?}"
+        );
+        assert_eq!(ticker.active_subscriptions(), 1);
+
+        // No tick is needed to notice that nobody is listening anymore.
+        drop(second);
+        wait_for(|| ticker.active_subscriptions() == 0).await;
+
+        // A new subscription opens a new upstream stream.
+        let mut third = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        next_tick(&ticker, &mut third).await;
+        assert_eq!(ticker.opened_subscriptions(), 2);
+    });
+}
+
+#[test]
+fn first_subscriber_cancelling_while_connecting() {
+    runtime().block_on(async move {
+        // Accepts connections but never completes the websocket handshake.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        tokio::spawn({
+            let connections = Arc::clone(&connections);
+            async move {
+                let mut sockets = Vec::new();
+                while let Ok((socket, _)) = listener.accept().await {
+                    connections.fetch_add(1, Ordering::SeqCst);
+                    sockets.push(socket);
+                }
+            }
+        });
+
+        let engine = Engine::builder()
+            .with_subgraph(TickerSchema::default())
+            .with_toml_config(format!(
+                r#"
+                [subgraphs.ticker]
+                multiplex_subscriptions = true
+                websocket_url = "ws://{address}"
+                "#
+            ))
+            .build()
+            .await;
+
+        let mut first = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        assert!(tokio::time::timeout(Duration::from_millis(200), first.next())
+            .await
+            .is_err());
+
+        let mut second = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        assert!(tokio::time::timeout(Duration::from_millis(200), second.next())
+            .await
+            .is_err());
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        // The second subscriber doesn't wait forever for a connection nobody drives anymore.
+        drop(first);
+        let event = tokio::time::timeout(Duration::from_secs(5), second.next())
+            .await
+            .expect("The second subscriber to be notified");
+
+        if let Some(event) = event {
+            assert!(!event["errors"].as_array().unwrap().is_empty(), "{event}");
+        }
+
+        // And a new subscriber opens a new connection rather than joining a dead one.
+        let mut third = engine.post(SUBSCRIPTION).into_multipart_stream().into_stream().await;
+        assert!(tokio::time::timeout(Duration::from_millis(200), third.next())
+            .await
+            .is_err());
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    });
+}
@@ -21,6 +21,7 @@ pub struct FederatedGraphConfig {
     pub timeout: Option<Duration>,
     pub entity_caching: EntityCachingConfig,
     pub retry: Option<RetryConfig>,
+    pub scalars: BTreeMap<String, ScalarConfig>,
 }
 
 /// Configuration for a subgraph of the current federated graph
@@ -52,8 +53,66 @@ pub struct SubgraphConfig {
     /// Retry configuration
     pub retry: Option<RetryConfig>,
 
+    /// Hedging configuration
+    pub hedging: Option<HedgingConfig>,
+
     /// Optional entity caching config for this subgraph.
     pub entity_caching: Option<EntityCachingConfig>,
+
+    /// Compression of the traffic with this subgraph
+    pub compression: Option<SubgraphCompressionConfig>,
+
+    /// Whether identical subscriptions share a single upstream stream
+    pub multiplex_subscriptions: bool,
+
+    /// Whether identical in-flight queries share a single subgraph request
+    pub deduplicate_requests: bool,
+
+    /// Circuit breaker configuration
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+
+    /// Replicas of the subgraph, replacing the URL from the federated graph
+    pub urls: Vec<String>,
+
+    /// How requests are spread across the replicas
+    pub load_balancing: Option<LoadBalancingConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompressionAlgorithm {
+    Gzip,
+    Brotli,
+    Zstd,
+}
+
+impl From<gateway_config::CompressionAlgorithm> for CompressionAlgorithm {
+    fn from(algorithm: gateway_config::CompressionAlgorithm) -> Self {
+        match algorithm {
+            gateway_config::CompressionAlgorithm::Gzip => CompressionAlgorithm::Gzip,
+            gateway_config::CompressionAlgorithm::Brotli => CompressionAlgorithm::Brotli,
+            gateway_config::CompressionAlgorithm::Zstd => CompressionAlgorithm::Zstd,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SubgraphCompressionConfig {
+    /// Whether the subgraph may send compressed responses.
+    pub accept_compressed_responses: bool,
+    /// Algorithm used to compress `_entities` request bodies, if any.
+    pub entities_request: Option<CompressionAlgorithm>,
+    /// Minimum size in bytes of an `_entities` request body to be compressed.
+    pub entities_request_min_size: u64,
+}
+
+impl From<gateway_config::SubgraphCompressionConfig> for SubgraphCompressionConfig {
+    fn from(config: gateway_config::SubgraphCompressionConfig) -> Self {
+        SubgraphCompressionConfig {
+            accept_compressed_responses: config.accept_compressed_responses,
+            entities_request: config.entities_request.map(Into::into),
+            entities_request_min_size: config.entities_request_min_size.bytes().max(0) as u64,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -226,6 +285,66 @@ pub struct RetryConfig {
     pub retry_mutations: bool,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LoadBalancingConfig {
+    /// How the URL of each request is chosen.
+    pub strategy: LoadBalancingStrategy,
+    /// How long a URL is left out after a connection error.
+    pub ejection_duration: Option<Duration>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LoadBalancingStrategy {
+    #[default]
+    RoundRobin,
+    LeastInflight,
+}
+
+impl From<gateway_config::LoadBalancingConfig> for LoadBalancingConfig {
+    fn from(config: gateway_config::LoadBalancingConfig) -> Self {
+        Self {
+            strategy: match config.strategy {
+                gateway_config::LoadBalancingStrategy::RoundRobin => LoadBalancingStrategy::RoundRobin,
+                gateway_config::LoadBalancingStrategy::LeastInflight => LoadBalancingStrategy::LeastInflight,
+            },
+            ejection_duration: config.ejection_duration,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HedgingConfig {
+    /// How long to wait for a response before sending the hedged request.
+    pub delay: Option<Duration>,
+    /// Send the hedged request once the subgraph took longer than this percentile of its recent response times.
+    pub percentile: Option<f32>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CircuitBreakerConfig {
+    /// Open the circuit after this many consecutive failed requests.
+    pub failure_threshold: Option<u32>,
+    /// Open the circuit when the fraction of failed requests within the window reaches this value.
+    pub error_rate: Option<f32>,
+    /// How many requests must be seen within the window before the error rate is considered.
+    pub minimum_requests: Option<u32>,
+    /// The window over which the error rate is computed.
+    pub window: Option<Duration>,
+    /// How long the circuit stays open before probe requests are let through.
+    pub open_duration: Option<Duration>,
+    /// How many successful probe requests are needed to close the circuit again.
+    pub half_open_probes: Option<u32>,
+}
+
+/// Validation settings of a built-in scalar such as `DateTime` or `UUID`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarConfig {
+    /// Whether arguments and variables are validated against the scalar format.
+    pub validate_input: bool,
+    /// Whether subgraph responses are validated against the scalar format.
+    pub validate_output: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
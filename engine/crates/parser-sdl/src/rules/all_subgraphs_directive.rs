@@ -127,7 +127,14 @@ mod tests {
                         rate_limit: None,
                         timeout: None,
                         retry: None,
+                        hedging: None,
                         entity_caching: None,
+                        compression: None,
+                        multiplex_subscriptions: false,
+                        deduplicate_requests: false,
+                        circuit_breaker: None,
+                        urls: [],
+                        load_balancing: None,
                     },
                 },
                 header_rules: [
@@ -175,6 +182,7 @@ mod tests {
                 timeout: None,
                 entity_caching: Disabled,
                 retry: None,
+                scalars: {},
             },
         )
         "###);
@@ -287,7 +287,14 @@ mod tests {
                         rate_limit: None,
                         timeout: None,
                         retry: None,
+                        hedging: None,
                         entity_caching: None,
+                        compression: None,
+                        multiplex_subscriptions: false,
+                        deduplicate_requests: false,
+                        circuit_breaker: None,
+                        urls: [],
+                        load_balancing: None,
                     },
                     "Reviews": SubgraphConfig {
                         name: "Reviews",
@@ -304,7 +311,14 @@ mod tests {
                         rate_limit: None,
                         timeout: None,
                         retry: None,
+                        hedging: None,
                         entity_caching: None,
+                        compression: None,
+                        multiplex_subscriptions: false,
+                        deduplicate_requests: false,
+                        circuit_breaker: None,
+                        urls: [],
+                        load_balancing: None,
                     },
                 },
                 header_rules: [],
@@ -324,6 +338,7 @@ mod tests {
                 timeout: None,
                 entity_caching: Disabled,
                 retry: None,
+                scalars: {},
             },
         )
         "###);
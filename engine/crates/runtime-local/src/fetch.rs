@@ -1,17 +1,92 @@
-use std::future::Future;
+mod client;
+mod compression;
+#[cfg(unix)]
+mod unix;
+mod websocket;
+
+use std::{collections::HashMap, future::Future, sync::Arc};
 
 use bytes::Bytes;
-use futures_util::Stream;
+use futures_util::{stream::BoxStream, Stream};
 use futures_util::{StreamExt, TryStreamExt};
 use reqwest::RequestBuilder;
 use reqwest_eventsource::RequestBuilderExt;
 use runtime::bytes::OwnedOrSharedBytes;
-use runtime::fetch::{FetchError, FetchRequest, FetchResult, Fetcher};
+use runtime::fetch::{ContentEncoding, FetchError, FetchRequest, FetchResult, Fetcher};
 use runtime::hooks::ResponseInfo;
+use websocket::WebsocketConnector;
 
 #[derive(Default, Clone)]
 pub struct NativeFetcher {
     client: reqwest::Client,
+    /// Clients of the subgraphs with custom transport settings.
+    subgraph_clients: HashMap<String, reqwest::Client>,
+    /// Websocket connectors of the subgraphs with custom transport settings.
+    subgraph_websocket_connectors: HashMap<String, Arc<WebsocketConnector>>,
+    /// Subgraphs with a custom limit on the size of decompressed responses.
+    max_decompressed_response_sizes: HashMap<String, usize>,
+    /// Pooled client of the subgraphs listening on a unix socket.
+    #[cfg(unix)]
+    unix_client: unix::Client,
+}
+
+impl NativeFetcher {
+    pub fn new(config: &gateway_config::Config) -> anyhow::Result<Self> {
+        let subgraph_clients = config
+            .subgraphs
+            .iter()
+            .filter_map(|(name, subgraph)| {
+                let http_client = subgraph.http_client.as_ref()?;
+                let client =
+                    client::build(http_client).map_err(|err| err.context(format!("HTTP client of subgraph '{name}'")));
+                Some(client.map(|client| (name.clone(), client)))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let subgraph_websocket_connectors = config
+            .subgraphs
+            .iter()
+            .filter_map(|(name, subgraph)| {
+                let http_client = subgraph.http_client.as_ref()?;
+                let connector = WebsocketConnector::build(http_client)
+                    .map_err(|err| err.context(format!("websocket connector of subgraph '{name}'")));
+                Some(connector.map(|connector| (name.clone(), Arc::new(connector))))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let max_decompressed_response_sizes = config
+            .subgraphs
+            .iter()
+            .filter_map(|(name, subgraph)| {
+                let size = subgraph.compression.as_ref()?.max_decompressed_response_size;
+                Some((name.clone(), size.bytes() as usize))
+            })
+            .collect();
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            subgraph_clients,
+            subgraph_websocket_connectors,
+            max_decompressed_response_sizes,
+            #[cfg(unix)]
+            unix_client: unix::Client::default(),
+        })
+    }
+
+    fn client(&self, subgraph_name: &str) -> &reqwest::Client {
+        self.subgraph_clients.get(subgraph_name).unwrap_or(&self.client)
+    }
+
+    fn max_decompressed_response_size(&self, subgraph_name: &str) -> usize {
+        self.max_decompressed_response_sizes
+            .get(subgraph_name)
+            .copied()
+            .unwrap_or_else(|| {
+                gateway_config::SubgraphCompressionConfig::default()
+                    .max_decompressed_response_size
+                    .bytes() as usize
+            })
+    }
 }
 
 impl Fetcher for NativeFetcher {
@@ -19,11 +94,26 @@ impl Fetcher for NativeFetcher {
         &self,
         request: FetchRequest<'_, Bytes>,
     ) -> (FetchResult<http::Response<OwnedOrSharedBytes>>, Option<ResponseInfo>) {
+        let max_decompressed_size = self.max_decompressed_response_size(request.subgraph_name);
+
+        #[cfg(unix)]
+        if request.url.scheme() == unix::SCHEME {
+            return self.unix_client.fetch(request, max_decompressed_size).await;
+        }
+
         let mut info = ResponseInfo::builder();
+        let client = self.client(request.subgraph_name);
 
-        let result = self.client.execute(into_reqwest(request)).await.map_err(|e| {
+        let request = match into_reqwest(request) {
+            Ok(request) => request,
+            Err(e) => return (Err(e), Some(info.finalize(0))),
+        };
+
+        let result = client.execute(request).await.map_err(|e| {
             if e.is_timeout() {
                 FetchError::Timeout
+            } else if e.is_connect() {
+                FetchError::Connect(e.without_url().to_string())
             } else {
                 reqwest_error_to_fetch_error(e)
             }
@@ -39,14 +129,14 @@ impl Fetcher for NativeFetcher {
         };
 
         let status = resp.status();
-        let headers = std::mem::take(resp.headers_mut());
+        let mut headers = std::mem::take(resp.headers_mut());
         let extensions = std::mem::take(resp.extensions_mut());
         let version = resp.version();
         let result = resp.bytes().await.map_err(reqwest_error_to_fetch_error);
 
         info.track_response();
 
-        let bytes = match result {
+        let bytes = match result.and_then(|bytes| decode_body(&mut headers, bytes, max_decompressed_size)) {
             Ok(bytes) => bytes,
             Err(e) => return (Err(e), Some(info.finalize(0))),
         };
@@ -65,7 +155,14 @@ impl Fetcher for NativeFetcher {
         &self,
         request: FetchRequest<'_, Bytes>,
     ) -> FetchResult<impl Stream<Item = FetchResult<OwnedOrSharedBytes>> + Send + 'static> {
-        let events = RequestBuilder::from_parts(self.client.clone(), into_reqwest(request))
+        #[cfg(unix)]
+        if request.url.scheme() == unix::SCHEME {
+            let events = self.unix_client.event_stream(request).await?;
+            return Ok(graphql_over_sse(events).boxed());
+        }
+
+        let client = self.client(request.subgraph_name).clone();
+        let events = RequestBuilder::from_parts(client, into_reqwest(request)?)
             .eventsource()
             .unwrap()
             .map_err(|err| match err {
@@ -74,25 +171,13 @@ impl Fetcher for NativeFetcher {
                 }
                 err => FetchError::AnyError(err.to_string()),
             })
-            .try_take_while(|event| {
-                let is_complete = if let reqwest_eventsource::Event::Message(message) = event {
-                    message.event == "complete"
-                } else {
-                    false
-                };
-                async move { Ok(!is_complete) }
-            })
             .try_filter_map(|event| async move {
-                let reqwest_eventsource::Event::Message(message) = event else {
-                    return Ok(None);
-                };
-                if message.event == "next" {
-                    Ok(Some(OwnedOrSharedBytes::Owned(message.data.into())))
-                } else {
-                    Err(FetchError::AnyError(format!("Unexpected event: {}", message.event)))
-                }
+                Ok(match event {
+                    reqwest_eventsource::Event::Message(message) => Some(message),
+                    reqwest_eventsource::Event::Open => None,
+                })
             });
-        Ok(events)
+        Ok(graphql_over_sse(events).boxed())
     }
 
     fn graphql_over_websocket_stream<T>(
@@ -107,41 +192,133 @@ impl Fetcher for NativeFetcher {
         // graphql_ws_client requires a 'static body which we can't provide.
         let body = serde_json::value::to_raw_value(&request.body).map_err(|err| FetchError::any(err.to_string()));
         let headers = Headers(request.headers);
-        let mut ws_request = request.url.as_ref().into_client_request().unwrap();
+        let url = request.url.into_owned();
+        let connector = self.subgraph_websocket_connectors.get(request.subgraph_name).cloned();
 
         async move {
-            let (connection, _) = {
+            let body = body?;
+
+            #[cfg(unix)]
+            if url.scheme() == unix::SCHEME {
+                let target = unix::Target::parse(&url);
+                let mut ws_request = target.websocket_url().into_client_request().map_err(FetchError::any)?;
                 ws_request.headers_mut().insert(
                     "Sec-WebSocket-Protocol",
-                    HeaderValue::from_str("graphql-transport-ws").unwrap(),
+                    HeaderValue::from_static("graphql-transport-ws"),
                 );
 
-                async_tungstenite::tokio::connect_async(ws_request)
+                let stream = target.connect().await?;
+                let (connection, _) = async_tungstenite::tokio::client_async(ws_request, stream)
                     .await
-                    .map_err(FetchError::any)?
+                    .map_err(FetchError::any)?;
+
+                return graphql_over_websocket(connection, headers, body).await;
+            }
+
+            let mut ws_request = url.as_ref().into_client_request().unwrap();
+            ws_request.headers_mut().insert(
+                "Sec-WebSocket-Protocol",
+                HeaderValue::from_str("graphql-transport-ws").unwrap(),
+            );
+
+            let connection = match connector {
+                Some(connector) => connector.connect(ws_request).await?,
+                None => {
+                    async_tungstenite::tokio::connect_async(ws_request)
+                        .await
+                        .map_err(FetchError::any)?
+                        .0
+                }
             };
 
-            Ok(graphql_ws_client::Client::build(connection)
-                .payload(headers)
-                .map_err(FetchError::any)?
-                .subscribe(WebsocketRequest(body?))
-                .await
-                .map_err(FetchError::any)?
-                .map(|item| item.map_err(FetchError::any)))
+            graphql_over_websocket(connection, headers, body).await
         }
     }
 }
 
+/// Turns the server-sent events of a GraphQL subscription into its responses.
+fn graphql_over_sse(
+    events: impl Stream<Item = FetchResult<eventsource_stream::Event>> + Send + 'static,
+) -> impl Stream<Item = FetchResult<OwnedOrSharedBytes>> + Send + 'static {
+    events
+        .try_take_while(|message| {
+            let is_complete = message.event == "complete";
+            async move { Ok(!is_complete) }
+        })
+        .and_then(|message| async move {
+            if message.event == "next" {
+                Ok(OwnedOrSharedBytes::Owned(message.data.into()))
+            } else {
+                Err(FetchError::AnyError(format!("Unexpected event: {}", message.event)))
+            }
+        })
+}
+
+async fn graphql_over_websocket<C>(
+    connection: C,
+    headers: Headers,
+    body: Box<serde_json::value::RawValue>,
+) -> FetchResult<BoxStream<'static, FetchResult<serde_json::Value>>>
+where
+    C: graphql_ws_client::Connection + Send + 'static,
+{
+    Ok(graphql_ws_client::Client::build(connection)
+        .payload(headers)
+        .map_err(FetchError::any)?
+        .subscribe(WebsocketRequest(body))
+        .await
+        .map_err(FetchError::any)?
+        .map(|item| item.map_err(FetchError::any))
+        .boxed())
+}
+
 fn reqwest_error_to_fetch_error(e: reqwest::Error) -> FetchError {
     FetchError::any(e.without_url())
 }
 
-fn into_reqwest(request: FetchRequest<'_, Bytes>) -> reqwest::Request {
+fn into_reqwest(request: FetchRequest<'_, Bytes>) -> FetchResult<reqwest::Request> {
     let mut req = reqwest::Request::new(request.method, request.url.into_owned());
     *req.headers_mut() = request.headers;
-    *req.body_mut() = Some(request.body.into());
+
+    let body = encode_body(req.headers_mut(), request.body, request.compression)?;
+
+    *req.body_mut() = Some(body.into());
     *req.timeout_mut() = Some(request.timeout);
-    req
+    Ok(req)
+}
+
+/// Compresses the request body if requested, updating the headers accordingly.
+fn encode_body(headers: &mut http::HeaderMap, body: Bytes, compression: Option<ContentEncoding>) -> FetchResult<Bytes> {
+    let Some(encoding) = compression else {
+        return Ok(body);
+    };
+
+    let body = compression::compress(encoding, &body)?;
+    headers.insert(
+        http::header::CONTENT_ENCODING,
+        http::HeaderValue::from_static(encoding.as_str()),
+    );
+    headers.insert(http::header::CONTENT_LENGTH, http::HeaderValue::from(body.len()));
+
+    Ok(body)
+}
+
+/// Decompresses the response body if the subgraph used a supported `Content-Encoding`. Headers
+/// are updated to describe the decompressed body.
+fn decode_body(headers: &mut http::HeaderMap, bytes: Bytes, max_decompressed_size: usize) -> FetchResult<Bytes> {
+    let Some(encoding) = headers
+        .get(http::header::CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .and_then(ContentEncoding::parse)
+    else {
+        return Ok(bytes);
+    };
+
+    let bytes = compression::decompress(encoding, &bytes, max_decompressed_size)?;
+    headers.remove(http::header::CONTENT_ENCODING);
+    headers.insert(http::header::CONTENT_LENGTH, http::HeaderValue::from(bytes.len()));
+
+    Ok(bytes)
 }
 
 struct Headers(http::HeaderMap);
@@ -0,0 +1,57 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use gateway_config::{SubgraphHttpClientConfig, SubgraphTlsConfig};
+
+/// Builds the HTTP client of a subgraph with custom transport settings.
+pub(super) fn build(config: &SubgraphHttpClientConfig) -> anyhow::Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(SubgraphTlsConfig { cert, key, ca }) = &config.tls {
+        match (cert, key) {
+            (Some(cert), Some(key)) => {
+                // rustls expects the certificate chain and the private key in a single PEM.
+                let mut pem = read(cert, "client certificate")?;
+                pem.push(b'\n');
+                pem.extend(read(key, "client key")?);
+
+                let identity = reqwest::Identity::from_pem(&pem).context("parsing the client certificate and key")?;
+                builder = builder.identity(identity);
+            }
+            (None, None) => (),
+            _ => anyhow::bail!("both the client certificate and key must be set for mutual TLS"),
+        }
+
+        for path in ca {
+            let certificate = reqwest::Certificate::from_pem(&read(path, "root certificate")?)
+                .with_context(|| format!("parsing the root certificate {}", path.display()))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy.as_str()).context("configuring the proxy")?);
+    }
+
+    if config.http2_prior_knowledge {
+        builder = builder.http2_prior_knowledge();
+    }
+
+    if let Some(max_idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+
+    if let Some(idle_timeout) = config.pool_idle_timeout {
+        builder = builder.pool_idle_timeout(idle_timeout);
+    }
+
+    if let Some(connect_timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+
+    builder.build().context("building the HTTP client")
+}
+
+pub(super) fn read(path: &Path, what: &str) -> anyhow::Result<Vec<u8>> {
+    fs::read(path).with_context(|| format!("loading the {what} {}", path.display()))
+}
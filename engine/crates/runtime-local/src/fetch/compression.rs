@@ -0,0 +1,93 @@
+use std::io::{Read, Write};
+
+use bytes::Bytes;
+use runtime::fetch::{ContentEncoding, FetchError, FetchResult};
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+// Favors speed over ratio, requests and responses are compressed on the fly.
+const BROTLI_QUALITY: u32 = 4;
+const BROTLI_WINDOW_SIZE: u32 = 22;
+const ZSTD_LEVEL: i32 = 3;
+
+pub(super) fn compress(encoding: ContentEncoding, body: &[u8]) -> FetchResult<Bytes> {
+    let compressed = match encoding {
+        ContentEncoding::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+            encoder.write_all(body).and_then(|_| encoder.finish())
+        }
+        ContentEncoding::Brotli => {
+            let mut encoder =
+                brotli::CompressorWriter::new(Vec::new(), BROTLI_BUFFER_SIZE, BROTLI_QUALITY, BROTLI_WINDOW_SIZE);
+            encoder.write_all(body).map(|_| encoder.into_inner())
+        }
+        ContentEncoding::Zstd => zstd::encode_all(body, ZSTD_LEVEL),
+    };
+
+    compressed.map(Bytes::from).map_err(|err| {
+        FetchError::any(format!(
+            "Failed to compress request body with {}: {err}",
+            encoding.as_str()
+        ))
+    })
+}
+
+/// Decompresses the body, failing if the decompressed body is larger than `limit` bytes to
+/// protect against decompression bombs.
+pub(super) fn decompress(encoding: ContentEncoding, body: &[u8], limit: usize) -> FetchResult<Bytes> {
+    let mut decompressed = Vec::with_capacity((body.len() * 4).min(limit));
+    // Reading one more byte than the limit tells us whether the body exceeds it.
+    let max_len = limit as u64 + 1;
+    let result = match encoding {
+        ContentEncoding::Gzip => flate2::read::GzDecoder::new(body)
+            .take(max_len)
+            .read_to_end(&mut decompressed),
+        ContentEncoding::Brotli => brotli::Decompressor::new(body, BROTLI_BUFFER_SIZE)
+            .take(max_len)
+            .read_to_end(&mut decompressed),
+        ContentEncoding::Zstd => zstd::stream::read::Decoder::new(body)
+            .and_then(|decoder| decoder.take(max_len).read_to_end(&mut decompressed)),
+    };
+
+    if let Err(err) = result {
+        return Err(FetchError::any(format!(
+            "Failed to decompress response body with {}: {err}",
+            encoding.as_str()
+        )));
+    }
+
+    if decompressed.len() > limit {
+        return Err(FetchError::any(format!(
+            "Decompressed response body exceeds the limit of {limit} bytes"
+        )));
+    }
+
+    Ok(Bytes::from(decompressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let body =
+            br#"{"query":"query($representations:[_Any!]!){_entities(representations:$representations){__typename}}"}"#
+                .repeat(32);
+
+        for encoding in [ContentEncoding::Gzip, ContentEncoding::Brotli, ContentEncoding::Zstd] {
+            let compressed = compress(encoding, &body).unwrap();
+            assert!(compressed.len() < body.len());
+            assert_eq!(decompress(encoding, &compressed, body.len()).unwrap(), body.as_slice());
+        }
+    }
+
+    #[test]
+    fn decompression_limit() {
+        let body = vec![b'0'; 1024 * 1024];
+
+        for encoding in [ContentEncoding::Gzip, ContentEncoding::Brotli, ContentEncoding::Zstd] {
+            let compressed = compress(encoding, &body).unwrap();
+            assert!(decompress(encoding, &compressed, body.len() - 1).is_err());
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! Subgraphs listening on a unix domain socket, addressed with URLs such as
+//! `unix:///run/users.sock:/graphql`: the socket path, then the HTTP path after a colon.
+
+use std::{
+    ffi::OsStr,
+    io,
+    os::unix::ffi::OsStrExt,
+    path::PathBuf,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use eventsource_stream::Eventsource;
+use futures_util::{future::BoxFuture, FutureExt, Stream, TryStreamExt};
+use http_body_util::{BodyExt, Full};
+use hyper_util::{
+    client::legacy::connect::{Connected, Connection},
+    rt::{TokioExecutor, TokioIo},
+};
+use runtime::{
+    bytes::OwnedOrSharedBytes,
+    fetch::{FetchError, FetchRequest, FetchResult},
+    hooks::ResponseInfo,
+};
+use tokio::net::UnixStream;
+
+pub(super) const SCHEME: &str = "unix";
+
+pub(super) struct Target {
+    socket: PathBuf,
+    path_and_query: String,
+}
+
+impl Target {
+    pub fn parse(url: &url::Url) -> Self {
+        // The socket path itself can't contain a colon.
+        let (socket, path) = match url.path().split_once(':') {
+            Some((socket, path)) if !path.is_empty() => (socket, path),
+            Some((socket, _)) => (socket, "/"),
+            None => (url.path(), "/"),
+        };
+
+        let path_and_query = match url.query() {
+            Some(query) => format!("{path}?{query}"),
+            None => path.to_string(),
+        };
+
+        // The URL path is percent-encoded, the socket path must not be.
+        let socket = percent_encoding::percent_decode_str(socket).collect::<Vec<u8>>();
+
+        Self {
+            socket: PathBuf::from(OsStr::from_bytes(&socket)),
+            path_and_query,
+        }
+    }
+
+    pub async fn connect(&self) -> FetchResult<UnixStream> {
+        UnixStream::connect(&self.socket)
+            .await
+            .map_err(|err| FetchError::Connect(format!("{}: {err}", self.socket.display())))
+    }
+
+    /// URL used for the websocket handshake once connected to the socket.
+    pub fn websocket_url(&self) -> String {
+        format!("ws://localhost{}", self.path_and_query)
+    }
+
+    /// URI of the request sent through the [`Client`]. The socket path is hex-encoded as the
+    /// host, so that connections are pooled per socket.
+    fn uri(&self) -> FetchResult<http::Uri> {
+        let host = self
+            .socket
+            .as_os_str()
+            .as_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        format!("{SCHEME}://{host}{}", self.path_and_query)
+            .parse()
+            .map_err(FetchError::any)
+    }
+}
+
+/// HTTP client keeping connections to unix sockets open for reuse.
+#[derive(Clone)]
+pub(super) struct Client(hyper_util::client::legacy::Client<UnixConnector, Full<Bytes>>);
+
+impl Default for Client {
+    fn default() -> Self {
+        Self(hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(UnixConnector))
+    }
+}
+
+impl Client {
+    pub(super) async fn fetch(
+        &self,
+        request: FetchRequest<'_, Bytes>,
+        max_decompressed_size: usize,
+    ) -> (FetchResult<http::Response<OwnedOrSharedBytes>>, Option<ResponseInfo>) {
+        let mut info = ResponseInfo::builder();
+        let timeout = request.timeout;
+
+        let result = tokio::time::timeout(timeout, async {
+            let response = self.send(request).await?;
+            info.track_connection();
+
+            let (parts, body) = response.into_parts();
+            let bytes = body.collect().await.map_err(FetchError::any)?.to_bytes();
+            info.track_response();
+
+            Ok(http::Response::from_parts(parts, bytes))
+        })
+        .await
+        .unwrap_or(Err(FetchError::Timeout));
+
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => return (Err(e), Some(info.finalize(0))),
+        };
+
+        let (mut parts, bytes) = response.into_parts();
+        let status = parts.status;
+        let bytes = match super::decode_body(&mut parts.headers, bytes, max_decompressed_size) {
+            Ok(bytes) => bytes,
+            Err(e) => return (Err(e), Some(info.finalize(0))),
+        };
+
+        (
+            Ok(http::Response::from_parts(parts, OwnedOrSharedBytes::Shared(bytes))),
+            Some(info.finalize(status.as_u16())),
+        )
+    }
+
+    pub(super) async fn event_stream(
+        &self,
+        mut request: FetchRequest<'_, Bytes>,
+    ) -> FetchResult<impl Stream<Item = FetchResult<eventsource_stream::Event>> + Send + 'static> {
+        request.headers.insert(
+            http::header::ACCEPT,
+            http::HeaderValue::from_static("text/event-stream"),
+        );
+
+        let response = tokio::time::timeout(request.timeout, self.send(request))
+            .await
+            .unwrap_or(Err(FetchError::Timeout))?;
+
+        if !response.status().is_success() {
+            return Err(FetchError::InvalidStatusCode(response.status()));
+        }
+
+        Ok(response
+            .into_body()
+            .into_data_stream()
+            .eventsource()
+            .map_err(FetchError::any))
+    }
+
+    async fn send(&self, request: FetchRequest<'_, Bytes>) -> FetchResult<http::Response<hyper::body::Incoming>> {
+        let target = Target::parse(&request.url);
+        let mut headers = request.headers;
+        let body = super::encode_body(&mut headers, request.body, request.compression)?;
+        headers
+            .entry(http::header::HOST)
+            .or_insert(http::HeaderValue::from_static("localhost"));
+
+        let mut http_request = http::Request::new(Full::new(body));
+        *http_request.method_mut() = request.method;
+        *http_request.uri_mut() = target.uri()?;
+        *http_request.headers_mut() = headers;
+
+        self.0.request(http_request).await.map_err(|err| {
+            if err.is_connect() {
+                FetchError::Connect(format!("{}: {err}", target.socket.display()))
+            } else {
+                FetchError::any(err)
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct UnixConnector;
+
+impl tower_service::Service<http::Uri> for UnixConnector {
+    type Response = UnixConnection;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, io::Result<UnixConnection>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: http::Uri) -> Self::Future {
+        async move {
+            let socket = uri
+                .host()
+                .and_then(decode_hex)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid unix socket URI"))?;
+
+            let stream = UnixStream::connect(PathBuf::from(OsStr::from_bytes(&socket))).await?;
+            Ok(UnixConnection(TokioIo::new(stream)))
+        }
+        .boxed()
+    }
+}
+
+fn decode_hex(host: &str) -> Option<Vec<u8>> {
+    (0..host.len())
+        .step_by(2)
+        .map(|i| host.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
+
+pub(super) struct UnixConnection(TokioIo<UnixStream>);
+
+impl Connection for UnixConnection {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl hyper::rt::Read for UnixConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: hyper::rt::ReadBufCursor<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl hyper::rt::Write for UnixConnection {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        path::Path,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use futures_util::{SinkExt, StreamExt};
+    use hyper::{body::Incoming, service::service_fn};
+    use runtime::fetch::Fetcher;
+    use tokio::net::UnixListener;
+    use tungstenite::{
+        handshake::server::{Request, Response},
+        Message,
+    };
+
+    use super::*;
+    use crate::NativeFetcher;
+
+    fn parse(url: &str) -> (PathBuf, String) {
+        let target = Target::parse(&url.parse().unwrap());
+        (target.socket, target.path_and_query)
+    }
+
+    /// Socket path with a space, percent-encoded in subgraph URLs.
+    fn socket_path(dir: &tempfile::TempDir) -> PathBuf {
+        dir.path().join("sub graph.sock")
+    }
+
+    fn url(socket: &Path, path: &str) -> url::Url {
+        format!("unix://{}:{path}", socket.display()).parse().unwrap()
+    }
+
+    fn request(url: &url::Url) -> FetchRequest<'_, Bytes> {
+        FetchRequest {
+            subgraph_name: "unix",
+            url: std::borrow::Cow::Borrowed(url),
+            method: http::Method::POST,
+            headers: http::HeaderMap::new(),
+            body: Bytes::from_static(br#"{"query":"{ hello }"}"#),
+            timeout: Duration::from_secs(5),
+            compression: None,
+        }
+    }
+
+    /// HTTP server answering with the request path and body, and counting the accepted connections.
+    fn http_server(socket: &Path) -> Arc<AtomicUsize> {
+        let listener = UnixListener::bind(socket).unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = connections.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                accepted.fetch_add(1, Ordering::SeqCst);
+
+                tokio::spawn(async move {
+                    let service = service_fn(|request: http::Request<Incoming>| async move {
+                        let sse = request.headers().get(http::header::ACCEPT)
+                            == Some(&http::HeaderValue::from_static("text/event-stream"));
+                        let path = request.uri().path().to_string();
+                        let body = request.into_body().collect().await.unwrap().to_bytes();
+
+                        let response = if sse {
+                            let data = r#"{"data":{"hello":"world"}}"#;
+                            http::Response::builder()
+                                .header(http::header::CONTENT_TYPE, "text/event-stream")
+                                .body(Full::new(Bytes::from(format!(
+                                    "event: next\ndata: {data}\n\nevent: next\ndata: {data}\n\nevent: complete\n\n"
+                                ))))
+                        } else {
+                            http::Response::builder().body(Full::new(Bytes::from(format!(
+                                "{path} {}",
+                                String::from_utf8_lossy(&body)
+                            ))))
+                        };
+
+                        Ok::<_, Infallible>(response.unwrap())
+                    });
+
+                    let _ = hyper::server::conn::http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+
+        connections
+    }
+
+    /// Minimal `graphql-transport-ws` server sending a single response to the first subscription.
+    fn websocket_server(socket: &Path) {
+        let listener = UnixListener::bind(socket).unwrap();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let callback = |_: &Request, mut response: Response| {
+                response.headers_mut().insert(
+                    "Sec-WebSocket-Protocol",
+                    http::HeaderValue::from_static("graphql-transport-ws"),
+                );
+                Ok(response)
+            };
+            let mut websocket = async_tungstenite::tokio::accept_hdr_async(stream, callback)
+                .await
+                .unwrap();
+
+            while let Some(Ok(message)) = websocket.next().await {
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                let message: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+                let replies = match message["type"].as_str() {
+                    Some("connection_init") => vec![serde_json::json!({"type": "connection_ack"})],
+                    Some("subscribe") => vec![
+                        serde_json::json!({"type": "next", "id": message["id"], "payload": {"data": {"hello": "world"}}}),
+                        serde_json::json!({"type": "complete", "id": message["id"]}),
+                    ],
+                    _ => Vec::new(),
+                };
+
+                for reply in replies {
+                    websocket.send(Message::Text(reply.to_string())).await.unwrap();
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn socket_and_path() {
+        assert_eq!(
+            parse("unix:///run/users.sock:/graphql?debug=1"),
+            ("/run/users.sock".into(), "/graphql?debug=1".to_string())
+        );
+        assert_eq!(
+            parse("unix:///run/users.sock"),
+            ("/run/users.sock".into(), "/".to_string())
+        );
+        assert_eq!(
+            parse("unix:///run/users.sock:"),
+            ("/run/users.sock".into(), "/".to_string())
+        );
+        assert_eq!(
+            parse("unix:///run/sub%20graph.sock:/graphql"),
+            ("/run/sub graph.sock".into(), "/graphql".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn http_roundtrip_reuses_the_connection() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = socket_path(&dir);
+        let connections = http_server(&socket);
+        let url = url(&socket, "/graphql");
+        let fetcher = NativeFetcher::default();
+
+        for _ in 0..3 {
+            let (response, _) = fetcher.fetch(request(&url)).await;
+            let response = response.unwrap();
+
+            assert_eq!(response.status(), http::StatusCode::OK);
+            assert_eq!(
+                String::from_utf8_lossy(response.body()),
+                r#"/graphql {"query":"{ hello }"}"#
+            );
+
+            // The connection goes back to the pool in a background task once the response is read.
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn sse_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = socket_path(&dir);
+        http_server(&socket);
+        let url = url(&socket, "/graphql");
+
+        let responses = NativeFetcher::default()
+            .graphql_over_sse_stream(request(&url))
+            .await
+            .unwrap()
+            .map(|response| String::from_utf8(response.unwrap().into()).unwrap())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(responses, vec![r#"{"data":{"hello":"world"}}"#; 2]);
+    }
+
+    #[tokio::test]
+    async fn websocket_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = socket_path(&dir);
+        websocket_server(&socket);
+        let url = url(&socket, "/ws");
+
+        let request = FetchRequest {
+            subgraph_name: "unix",
+            url: std::borrow::Cow::Borrowed(&url),
+            method: http::Method::GET,
+            headers: http::HeaderMap::new(),
+            body: serde_json::json!({"query": "subscription { hello }"}),
+            timeout: Duration::from_secs(5),
+            compression: None,
+        };
+
+        let responses = NativeFetcher::default()
+            .graphql_over_websocket_stream(request)
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(responses, vec![serde_json::json!({"data": {"hello": "world"}})]);
+    }
+}
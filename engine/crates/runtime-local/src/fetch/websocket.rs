@@ -0,0 +1,294 @@
+//! Websocket connections to subgraphs with custom transport settings. They use the same client
+//! certificate, root certificates, proxy and connect timeout as the HTTP client of the subgraph.
+
+use std::{sync::Arc, time::Duration};
+
+use anyhow::Context;
+use async_tungstenite::{
+    tokio::{ConnectStream, Connector},
+    WebSocketStream,
+};
+use base64::Engine as _;
+use gateway_config::{SubgraphHttpClientConfig, SubgraphTlsConfig};
+use runtime::fetch::{FetchError, FetchResult};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use tungstenite::handshake::client::Request;
+use url::Url;
+
+/// Upper bound on the size of the response of a proxy to a `CONNECT` request.
+const MAX_PROXY_RESPONSE_SIZE: usize = 8 * 1024;
+
+pub(super) struct WebsocketConnector {
+    tls: Arc<rustls::ClientConfig>,
+    proxy: Option<Url>,
+    connect_timeout: Option<Duration>,
+}
+
+impl WebsocketConnector {
+    pub fn build(config: &SubgraphHttpClientConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            tls: Arc::new(tls_config(config.tls.as_ref())?),
+            proxy: config.proxy.clone(),
+            connect_timeout: config.connect_timeout,
+        })
+    }
+
+    pub async fn connect(&self, request: Request) -> FetchResult<WebSocketStream<ConnectStream>> {
+        let uri = request.uri();
+        let host = uri
+            .host()
+            .ok_or_else(|| FetchError::any("websocket URL without host"))?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string();
+        let port = uri
+            .port_u16()
+            .unwrap_or(if uri.scheme_str() == Some("wss") { 443 } else { 80 });
+
+        let stream = match &self.proxy {
+            Some(proxy) => self.with_connect_timeout(tunnel(proxy, &host, port)).await?,
+            None => {
+                self.with_connect_timeout(async {
+                    TcpStream::connect((host.as_str(), port))
+                        .await
+                        .map_err(|err| FetchError::Connect(err.to_string()))
+                })
+                .await?
+            }
+        };
+
+        let (connection, _) = async_tungstenite::tokio::client_async_tls_with_connector(
+            request,
+            stream,
+            Some(Connector::Rustls(self.tls.clone())),
+        )
+        .await
+        .map_err(FetchError::any)?;
+
+        Ok(connection)
+    }
+
+    async fn with_connect_timeout(
+        &self,
+        connect: impl std::future::Future<Output = FetchResult<TcpStream>>,
+    ) -> FetchResult<TcpStream> {
+        match self.connect_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, connect)
+                .await
+                .unwrap_or(Err(FetchError::Timeout)),
+            None => connect.await,
+        }
+    }
+}
+
+/// Trusts the default web PKI roots and the configured ones, presenting the client certificate
+/// if there is one.
+fn tls_config(config: Option<&SubgraphTlsConfig>) -> anyhow::Result<rustls::ClientConfig> {
+    let mut roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+
+    let Some(SubgraphTlsConfig { cert, key, ca }) = config else {
+        return Ok(rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth());
+    };
+
+    for path in ca {
+        for certificate in rustls_pemfile::certs(&mut super::client::read(path, "root certificate")?.as_slice()) {
+            let certificate =
+                certificate.with_context(|| format!("parsing the root certificate {}", path.display()))?;
+            roots
+                .add(certificate)
+                .with_context(|| format!("adding the root certificate {}", path.display()))?;
+        }
+    }
+
+    let builder = rustls::ClientConfig::builder().with_root_certificates(roots);
+
+    match (cert, key) {
+        (Some(cert), Some(key)) => {
+            let chain = rustls_pemfile::certs(&mut super::client::read(cert, "client certificate")?.as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .context("parsing the client certificate")?;
+            let key = rustls_pemfile::private_key(&mut super::client::read(key, "client key")?.as_slice())
+                .context("parsing the client key")?
+                .context("no private key found in the client key file")?;
+
+            builder
+                .with_client_auth_cert(chain, key)
+                .context("configuring the client certificate")
+        }
+        (None, None) => Ok(builder.with_no_client_auth()),
+        _ => anyhow::bail!("both the client certificate and key must be set for mutual TLS"),
+    }
+}
+
+/// Opens a tunnel to the subgraph through an HTTP proxy with a `CONNECT` request.
+async fn tunnel(proxy: &Url, host: &str, port: u16) -> FetchResult<TcpStream> {
+    if proxy.scheme() != "http" {
+        return Err(FetchError::any(format!(
+            "unsupported proxy scheme '{}' for websocket subscriptions, only http proxies are supported",
+            proxy.scheme()
+        )));
+    }
+
+    let proxy_host = proxy
+        .host_str()
+        .ok_or_else(|| FetchError::any("proxy URL without host"))?;
+    let proxy_port = proxy.port_or_known_default().unwrap_or(80);
+
+    let mut stream = TcpStream::connect((proxy_host, proxy_port))
+        .await
+        .map_err(|err| FetchError::Connect(format!("proxy {proxy_host}:{proxy_port}: {err}")))?;
+
+    let authority = if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if !proxy.username().is_empty() {
+        let credentials = format!("{}:{}", proxy.username(), proxy.password().unwrap_or_default());
+        let credentials = base64::engine::general_purpose::STANDARD.encode(credentials);
+        request.push_str(&format!("Proxy-Authorization: Basic {credentials}\r\n"));
+    }
+    request.push_str("\r\n");
+
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|err| FetchError::Connect(err.to_string()))?;
+
+    // Read byte by byte, anything after the response head belongs to the subgraph connection.
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_PROXY_RESPONSE_SIZE {
+            return Err(FetchError::Connect("proxy response is too large".into()));
+        }
+
+        let byte = stream
+            .read_u8()
+            .await
+            .map_err(|err| FetchError::Connect(format!("reading the proxy response: {err}")))?;
+        response.push(byte);
+    }
+
+    let status_line = response.split(|byte| *byte == b'\r').next().unwrap_or_default();
+    let status_line = String::from_utf8_lossy(status_line);
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(stream),
+        _ => Err(FetchError::Connect(format!("proxy refused the tunnel: {status_line}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use futures_util::{SinkExt, StreamExt};
+    use rustls::server::WebPkiClientVerifier;
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+    use tungstenite::{client::IntoClientRequest, Message};
+
+    use super::*;
+
+    fn test_data(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test-data/tls")
+            .join(name)
+    }
+
+    /// Echo websocket server over TLS, requiring a client certificate signed by the test CA.
+    async fn mtls_server() -> u16 {
+        let read = |name| std::fs::read(test_data(name)).unwrap();
+
+        let mut roots = rustls::RootCertStore::empty();
+        for certificate in rustls_pemfile::certs(&mut read("ca.pem").as_slice()) {
+            roots.add(certificate.unwrap()).unwrap();
+        }
+
+        let config = rustls::ServerConfig::builder()
+            .with_client_cert_verifier(WebPkiClientVerifier::builder(Arc::new(roots)).build().unwrap())
+            .with_single_cert(
+                rustls_pemfile::certs(&mut read("server.pem").as_slice())
+                    .collect::<Result<_, _>>()
+                    .unwrap(),
+                rustls_pemfile::private_key(&mut read("server.key").as_slice())
+                    .unwrap()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let acceptor = acceptor.clone();
+
+                tokio::spawn(async move {
+                    let Ok(stream) = acceptor.accept(stream).await else {
+                        return;
+                    };
+                    let Ok(mut websocket) = async_tungstenite::tokio::accept_async(stream).await else {
+                        return;
+                    };
+                    while let Some(Ok(message)) = websocket.next().await {
+                        if websocket.send(message).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        port
+    }
+
+    fn connector(client_certificate: bool) -> WebsocketConnector {
+        let (cert, key) = if client_certificate {
+            (Some(test_data("client.pem")), Some(test_data("client.key")))
+        } else {
+            (None, None)
+        };
+
+        WebsocketConnector::build(&SubgraphHttpClientConfig {
+            tls: Some(SubgraphTlsConfig {
+                cert,
+                key,
+                ca: vec![test_data("ca.pem")],
+            }),
+            connect_timeout: Some(Duration::from_secs(5)),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn client_certificate_is_presented() {
+        let port = mtls_server().await;
+        let request = format!("wss://localhost:{port}").into_client_request().unwrap();
+
+        let mut connection = connector(true).connect(request).await.unwrap();
+        connection.send(Message::Text("hello".into())).await.unwrap();
+
+        assert_eq!(connection.next().await.unwrap().unwrap(), Message::Text("hello".into()));
+    }
+
+    #[tokio::test]
+    async fn server_requiring_a_client_certificate_rejects_the_connection_without_one() {
+        let port = mtls_server().await;
+        let request = format!("wss://localhost:{port}").into_client_request().unwrap();
+
+        let result = connector(false).connect(request).await;
+        assert!(result.is_err());
+    }
+}
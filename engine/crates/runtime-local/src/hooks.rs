@@ -207,6 +207,33 @@ impl HooksWasi {
             None => Self(None),
         }
     }
+
+    /// Whether a hooks component is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Instantiates each interface implemented by the hooks component once.
+    pub async fn check_instantiation(&self) -> Result<(), wasi_component_loader::Error> {
+        let Some(inner) = &self.0 else {
+            return Ok(());
+        };
+
+        if let Some(pool) = &inner.gateway {
+            pool.check().await?;
+        }
+        if let Some(pool) = &inner.authorization {
+            pool.check().await?;
+        }
+        if let Some(pool) = &inner.subgraph {
+            pool.check().await?;
+        }
+        if let Some(pool) = &inner.responses {
+            pool.check().await?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Hooks for HooksWasi {
@@ -8,7 +8,10 @@ pub(super) struct Pool<T: RecycleableComponentInstance>(managed::Pool<ComponentM
 
 impl<T: RecycleableComponentInstance> Pool<T> {
     pub(super) fn new(loader: &Arc<ComponentLoader>) -> Option<Self> {
-        if loader.implements_interface(T::interface_name()) {
+        if T::interface_names()
+            .iter()
+            .any(|&name| loader.implements_interface(name))
+        {
             let mgr = ComponentMananger::<T>::new(loader.clone());
 
             let pool = managed::Pool::builder(mgr)
@@ -25,6 +28,11 @@ impl<T: RecycleableComponentInstance> Pool<T> {
         let span = info_span!("get instance from pool");
         self.0.get().instrument(span).await.expect("no io, should not fail")
     }
+
+    /// Instantiates a new component outside of the pool, to check it can still be created.
+    pub(super) async fn check(&self) -> Result<(), wasi_component_loader::Error> {
+        T::new(&self.0.manager().component_loader).await.map(drop)
+    }
 }
 
 pub(super) struct ComponentMananger<T> {
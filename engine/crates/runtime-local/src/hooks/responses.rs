@@ -57,6 +57,12 @@ impl ResponseHooks<Context> for HooksWasi {
                             status_code: info.status_code,
                         })
                     }
+                    runtime::hooks::SubgraphRequestExecutionKind::CircuitOpen => {
+                        SubgraphRequestExecutionKind::CircuitOpen
+                    }
+                    runtime::hooks::SubgraphRequestExecutionKind::Deduplicated => {
+                        SubgraphRequestExecutionKind::Deduplicated
+                    }
                 })
                 .collect(),
             cache_status: match cache_status {
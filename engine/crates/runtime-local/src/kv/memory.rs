@@ -0,0 +1,63 @@
+use runtime::kv::{KvResult, KvStore, KvStoreInner};
+use std::{
+    borrow::Cow,
+    time::{Duration, Instant},
+};
+
+/// Maximum number of entries kept in memory.
+const MAX_ENTRIES: u64 = 10_000;
+
+/// Automatic persisted queries are stored here as well, so we can't assume a small number of entries.
+/// Least recently used entries are evicted once full, and expired ones are dropped when read.
+pub struct InMemoryKvStore {
+    inner: mini_moka::sync::Cache<String, CacheValue>,
+}
+
+#[derive(Clone)]
+struct CacheValue {
+    data: Vec<u8>,
+    expires_at: Option<Instant>,
+}
+
+impl InMemoryKvStore {
+    pub fn runtime() -> KvStore {
+        KvStore::new(Self::default())
+    }
+}
+
+impl Default for InMemoryKvStore {
+    fn default() -> Self {
+        Self {
+            inner: mini_moka::sync::Cache::new(MAX_ENTRIES),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl KvStoreInner for InMemoryKvStore {
+    async fn get(&self, name: &str, _cache_ttl: Option<Duration>) -> KvResult<Option<Vec<u8>>> {
+        let Some(value) = self.inner.get(&name.to_string()) else {
+            return Ok(None);
+        };
+
+        match value.expires_at {
+            Some(instant) if instant < Instant::now() => {
+                self.inner.invalidate(&name.to_string());
+                Ok(None)
+            }
+            _ => Ok(Some(value.data)),
+        }
+    }
+
+    async fn put(&self, name: &str, bytes: Cow<'_, [u8]>, expiration_ttl: Option<Duration>) -> KvResult<()> {
+        self.inner.insert(
+            name.to_string(),
+            CacheValue {
+                data: bytes.into_owned(),
+                expires_at: expiration_ttl.map(|ttl| Instant::now() + ttl),
+            },
+        );
+
+        Ok(())
+    }
+}
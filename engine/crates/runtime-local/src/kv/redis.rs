@@ -0,0 +1,58 @@
+use std::{borrow::Cow, time::Duration};
+
+use redis::{AsyncCommands, SetExpiry, SetOptions};
+use runtime::kv::{KvError, KvResult, KvStore, KvStoreInner};
+
+use crate::redis::Pool;
+
+/// Key-value store shared between gateway instances through Redis.
+pub struct RedisKvStore {
+    pool: Pool,
+    key_prefix: String,
+}
+
+impl RedisKvStore {
+    pub fn runtime(pool: Pool, key_prefix: &str) -> KvStore {
+        KvStore::new(RedisKvStore {
+            pool,
+            key_prefix: key_prefix.to_string(),
+        })
+    }
+
+    fn key(&self, name: &str) -> String {
+        format!("{}-{name}", self.key_prefix)
+    }
+
+    async fn connection(&self) -> KvResult<deadpool::managed::Object<crate::redis::Manager>> {
+        self.pool.get().await.map_err(|error| {
+            tracing::error!("error fetching a Redis connection: {error}");
+            KvError::Kv(error.to_string())
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl KvStoreInner for RedisKvStore {
+    async fn get(&self, name: &str, _cache_ttl: Option<Duration>) -> KvResult<Option<Vec<u8>>> {
+        let mut connection = self.connection().await?;
+
+        connection
+            .get(self.key(name))
+            .await
+            .map_err(|error| KvError::Kv(error.to_string()))
+    }
+
+    async fn put(&self, name: &str, bytes: Cow<'_, [u8]>, expiration_ttl: Option<Duration>) -> KvResult<()> {
+        let mut connection = self.connection().await?;
+
+        let mut options = SetOptions::default();
+        if let Some(ttl) = expiration_ttl {
+            options = options.with_expiration(SetExpiry::PX(ttl.as_millis() as usize));
+        }
+
+        connection
+            .set_options(self.key(name), bytes.as_ref(), options)
+            .await
+            .map_err(|error| KvError::Kv(error.to_string()))
+    }
+}
@@ -22,7 +22,11 @@ pub use entity_cache::memory::InMemoryEntityCache;
 pub use entity_cache::redis::RedisEntityCache;
 pub use fetch::NativeFetcher;
 pub use kv::*;
-pub use operation_cache::{InMemoryOperationCache, InMemoryOperationCacheFactory};
+pub use operation_cache::memory::{
+    InMemoryOperationCache, InMemoryOperationCacheConfig, InMemoryOperationCacheFactory,
+};
+#[cfg(feature = "redis")]
+pub use operation_cache::redis::{RedisOperationCache, RedisOperationCacheFactory};
 pub use pg::{LazyPgConnectionsPool, LocalPgTransportFactory};
 pub use ufd_invoker::UdfInvokerImpl;
 
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use redis::{AsyncCommands, SetExpiry, SetOptions};
+use runtime::operation_cache::{OperationCache, OperationCacheFactory};
+
+use super::memory::{InMemoryOperationCache, InMemoryOperationCacheFactory};
+use crate::redis::Pool;
+
+/// Prepared operations are immutable for a given key, which includes the schema version. Entries
+/// only expire so that operations of old schemas don't accumulate forever.
+const EXPIRATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Operation cache shared between gateway instances through Redis, with a local in-memory cache
+/// in front of it.
+pub struct RedisOperationCacheFactory {
+    pool: Pool,
+    key_prefix: String,
+    local: InMemoryOperationCacheFactory,
+}
+
+impl RedisOperationCacheFactory {
+    pub fn new(pool: Pool, key_prefix: &str, local: InMemoryOperationCacheFactory) -> Self {
+        RedisOperationCacheFactory {
+            pool,
+            key_prefix: key_prefix.to_string(),
+            local,
+        }
+    }
+}
+
+impl OperationCacheFactory for RedisOperationCacheFactory {
+    type Cache<V> = RedisOperationCache<V>
+    where
+        V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned;
+
+    async fn create<V>(&self) -> Self::Cache<V>
+    where
+        V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        RedisOperationCache {
+            pool: self.pool.clone(),
+            key_prefix: self.key_prefix.clone(),
+            local: self.local.create().await,
+        }
+    }
+}
+
+pub struct RedisOperationCache<V> {
+    pool: Pool,
+    key_prefix: String,
+    local: InMemoryOperationCache<V>,
+}
+
+impl<V> RedisOperationCache<V>
+where
+    V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+{
+    async fn get_shared(&self, key: &str) -> anyhow::Result<Option<V>> {
+        let mut connection = self.pool.get().await?;
+        let bytes: Option<Vec<u8>> = connection.get(self.key(key)).await?;
+
+        Ok(match bytes {
+            Some(bytes) => Some(postcard::from_bytes(&bytes)?),
+            None => None,
+        })
+    }
+
+    async fn insert_shared(&self, key: &str, value: &V) -> anyhow::Result<()> {
+        let bytes = postcard::to_stdvec(value)?;
+        let mut connection = self.pool.get().await?;
+        let options = SetOptions::default().with_expiration(SetExpiry::EX(EXPIRATION.as_secs() as usize));
+
+        Ok(connection.set_options(self.key(key), bytes, options).await?)
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}-{key}", self.key_prefix)
+    }
+}
+
+impl<V> OperationCache<V> for RedisOperationCache<V>
+where
+    V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+{
+    async fn insert(&self, key: String, value: V) {
+        if let Err(error) = self.insert_shared(&key, &value).await {
+            tracing::error!("error storing an operation in Redis: {error}");
+        }
+
+        self.local.insert(key, value).await;
+    }
+
+    async fn get(&self, key: &String) -> Option<V> {
+        if let Some(value) = self.local.get(key).await {
+            return Some(value);
+        }
+
+        match self.get_shared(key).await {
+            Ok(Some(value)) => {
+                self.local.insert(key.clone(), value.clone()).await;
+                Some(value)
+            }
+            Ok(None) => None,
+            Err(error) => {
+                tracing::error!("error fetching an operation from Redis: {error}");
+                None
+            }
+        }
+    }
+}
@@ -1,3 +1,31 @@
 pub mod in_memory;
 #[cfg(feature = "redis")]
 pub mod redis;
+
+use std::borrow::Cow;
+
+use gateway_config::ClientRateLimitKey;
+use runtime::rate_limiting::{RateLimitKey, RateLimiterContext};
+
+/// The key a request is counted under. With a client key, the global limit is counted separately
+/// for each client, requests without the identifying header sharing the global one.
+fn resolve_key<'a>(
+    client_key: Option<&ClientRateLimitKey>,
+    context: &'a dyn RateLimiterContext,
+) -> Option<RateLimitKey<'a>> {
+    let key = context.key()?;
+
+    let Some(client_key) = client_key.filter(|_| matches!(key, RateLimitKey::Global)) else {
+        return Some(key.clone());
+    };
+
+    let client = http::HeaderName::from_bytes(client_key.header_name().as_bytes())
+        .ok()
+        .and_then(|name| context.header(name))
+        .and_then(|value| value.to_str().ok());
+
+    Some(match client {
+        Some(client) => RateLimitKey::Client(Cow::Borrowed(client)),
+        None => RateLimitKey::Global,
+    })
+}
@@ -1,16 +1,27 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::num::NonZeroU32;
 use std::sync::Arc;
 use std::{collections::HashMap, sync::RwLock};
 
 use futures_util::future::BoxFuture;
 use futures_util::FutureExt;
-use gateway_config::{Config, GraphRateLimit};
+use gateway_config::{ClientRateLimitKey, Config, GraphRateLimit};
 use governor::Quota;
 
 use runtime::rate_limiting::{Error, RateLimitKey, RateLimiter, RateLimiterContext};
 use tokio::sync::watch;
 
-type Limiters = HashMap<RateLimitKey<'static>, governor::DefaultKeyedRateLimiter<usize>>;
+use crate::rate_limiting::resolve_key;
+
+/// Above this number of clients tracked by a limiter, the ones whose limit was replenished are
+/// forgotten.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Default)]
+struct Limiters {
+    by_key: HashMap<RateLimitKey<'static>, governor::DefaultKeyedRateLimiter<usize>>,
+    client_key: Option<ClientRateLimitKey>,
+}
 
 pub struct InMemoryRateLimiter {
     limiters: Arc<RwLock<Limiters>>,
@@ -35,7 +46,7 @@ pub fn as_keyed_rate_limit_config(config: &Config) -> HashMap<RateLimitKey<'stat
 
 impl InMemoryRateLimiter {
     pub fn runtime(rate_limiting_configs: HashMap<RateLimitKey<'static>, GraphRateLimit>) -> RateLimiter {
-        let mut limiters = HashMap::new();
+        let mut limiters = Limiters::default();
 
         // add subgraph rate limiting configuration
         for (key, limits) in rate_limiting_configs {
@@ -43,7 +54,7 @@ impl InMemoryRateLimiter {
                 continue;
             };
 
-            limiters.insert(key.clone(), limiter);
+            limiters.by_key.insert(key.clone(), limiter);
         }
 
         let limiters = Arc::new(RwLock::new(limiters));
@@ -51,7 +62,10 @@ impl InMemoryRateLimiter {
     }
 
     pub fn runtime_with_watcher(mut config: watch::Receiver<Config>) -> RateLimiter {
-        let mut limiters = HashMap::new();
+        let mut limiters = Limiters {
+            client_key: client_key(&config.borrow()),
+            ..Default::default()
+        };
         let rate_limiting_configs = as_keyed_rate_limit_config(&config.borrow());
 
         // add subgraph rate limiting configuration
@@ -60,7 +74,7 @@ impl InMemoryRateLimiter {
                 continue;
             };
 
-            limiters.insert(key.clone(), limiter);
+            limiters.by_key.insert(key.clone(), limiter);
         }
 
         let limiters = Arc::new(RwLock::new(limiters));
@@ -73,7 +87,8 @@ impl InMemoryRateLimiter {
                 };
 
                 let mut limiters = limiters.write().unwrap();
-                limiters.clear();
+                limiters.by_key.clear();
+                limiters.client_key = client_key(&config.borrow());
 
                 let rate_limiting_configs = as_keyed_rate_limit_config(&config.borrow());
                 for (key, limits) in rate_limiting_configs {
@@ -81,7 +96,7 @@ impl InMemoryRateLimiter {
                         continue;
                     };
 
-                    limiters.insert(key, limiter);
+                    limiters.by_key.insert(key, limiter);
                 }
             }
         });
@@ -90,6 +105,10 @@ impl InMemoryRateLimiter {
     }
 }
 
+fn client_key(config: &Config) -> Option<ClientRateLimitKey> {
+    config.gateway.rate_limit.as_ref()?.client_key.clone()
+}
+
 fn create_limiter(rate_limit_config: GraphRateLimit) -> Option<governor::DefaultKeyedRateLimiter<usize>> {
     let Some(quota) = (rate_limit_config.limit as u64).checked_div(rate_limit_config.duration.as_secs()) else {
         tracing::error!("the duration for rate limit cannot be zero");
@@ -107,13 +126,29 @@ fn create_limiter(rate_limit_config: GraphRateLimit) -> Option<governor::Default
 impl runtime::rate_limiting::RateLimiterInner for InMemoryRateLimiter {
     fn limit<'a>(&'a self, context: &'a dyn RateLimiterContext) -> BoxFuture<'a, Result<(), Error>> {
         async {
-            let Some(key) = context.key() else { return Ok(()) };
             let limiters = self.limiters.read().unwrap();
+            let Some(key) = resolve_key(limiters.client_key.as_ref(), context) else {
+                return Ok(());
+            };
 
-            if let Some(rate_limiter) = limiters.get(key) {
+            // Clients have their own bucket in the global limiter.
+            let (key, bucket) = match &key {
+                RateLimitKey::Client(client) => {
+                    let mut hasher = DefaultHasher::new();
+                    client.hash(&mut hasher);
+                    (&RateLimitKey::Global, hasher.finish() as usize)
+                }
+                key => (key, usize::MIN),
+            };
+
+            if let Some(rate_limiter) = limiters.by_key.get(key) {
                 rate_limiter
-                    .check_key(&usize::MIN)
+                    .check_key(&bucket)
                     .map_err(|_err| Error::ExceededCapacity)?;
+
+                if rate_limiter.len() > MAX_TRACKED_CLIENTS {
+                    rate_limiter.retain_recent();
+                }
             };
 
             Ok(())
@@ -9,7 +9,7 @@ use grafbase_telemetry::otel::opentelemetry::{
 use runtime::rate_limiting::{Error, RateLimitKey, RateLimiter, RateLimiterContext};
 use tokio::sync::watch;
 
-use crate::redis::Pool;
+use crate::{rate_limiting::resolve_key, redis::Pool};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RateLimitRedisConfig<'a> {
@@ -85,6 +85,9 @@ impl RedisRateLimiter {
             RateLimitKey::Subgraph(ref graph) => {
                 format!("{}:subgraph:rate_limit:{graph}:{bucket}", self.key_prefix)
             }
+            RateLimitKey::Client(ref client) => {
+                format!("{}:rate_limit:client:{client}:{bucket}", self.key_prefix)
+            }
         }
     }
 
@@ -94,10 +97,20 @@ impl RedisRateLimiter {
     }
 
     async fn limit_inner(&self, context: &dyn RateLimiterContext) -> Result<(), Error> {
-        let Some(key) = context.key() else { return Ok(()) };
+        let client_key = self
+            .config_watcher
+            .borrow()
+            .gateway
+            .rate_limit
+            .as_ref()
+            .and_then(|rt| rt.client_key.clone());
+
+        let Some(key) = resolve_key(client_key.as_ref(), context) else {
+            return Ok(());
+        };
 
-        let config = match key {
-            RateLimitKey::Global => self
+        let config = match &key {
+            RateLimitKey::Global | RateLimitKey::Client(_) => self
                 .config_watcher
                 .borrow()
                 .gateway
@@ -141,9 +154,9 @@ impl RedisRateLimiter {
         let bucket_percentage = (current_ts % duration_ns) as f64 / duration_ns as f64;
 
         // The counter key for the current window.
-        let current_bucket = self.generate_key(current_bucket, key);
+        let current_bucket = self.generate_key(current_bucket, &key);
         // The counter key for the previous window.
-        let previous_bucket = self.generate_key(previous_bucket, key);
+        let previous_bucket = self.generate_key(previous_bucket, &key);
 
         // We execute multiple commands in one pipelined query to be _fast_.
         let mut pipe = redis::pipe();
@@ -67,6 +67,18 @@ impl RedisPoolFactory {
     }
 }
 
+/// Checks that a connection to Redis can be established and answers to a PING.
+pub async fn ping(pool: &Pool) -> anyhow::Result<()> {
+    let mut connection = pool.get().await.context("getting a Redis connection")?;
+
+    redis::cmd("PING")
+        .query_async::<_, String>(&mut *connection)
+        .await
+        .context("sending PING to Redis")?;
+
+    Ok(())
+}
+
 fn new_pool(url: &str, tls_config: Option<RedisTlsConfig<'_>>) -> anyhow::Result<Pool> {
     let tls_config = match tls_config {
         Some(tls) => {
@@ -5,10 +5,13 @@ use futures_util::{stream::BoxStream, Stream, StreamExt, TryFutureExt};
 
 use crate::{bytes::OwnedOrSharedBytes, hooks::ResponseInfo};
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum FetchError {
     #[error("{0}")]
     AnyError(String),
+    /// The connection to the server could not be established.
+    #[error("{0}")]
+    Connect(String),
     #[error("Timeout")]
     Timeout,
     #[error("Invalid status code: {0:?}")]
@@ -20,6 +23,10 @@ impl FetchError {
         FetchError::AnyError(error.to_string())
     }
 
+    pub fn is_connect(&self) -> bool {
+        matches!(self, FetchError::Connect(_))
+    }
+
     pub fn as_invalid_status_code(&self) -> Option<http::StatusCode> {
         match self {
             FetchError::InvalidStatusCode(status) => Some(*status),
@@ -34,11 +41,41 @@ pub type FetchResult<T> = Result<T, FetchError>;
 /// bit of a waste to use http::Request
 #[derive(Clone)]
 pub struct FetchRequest<'a, Body> {
+    /// Name of the subgraph the request is sent to.
+    pub subgraph_name: &'a str,
     pub url: Cow<'a, url::Url>,
     pub method: http::Method,
     pub headers: http::HeaderMap,
     pub body: Body,
     pub timeout: Duration,
+    /// Compression to apply on the body before sending it.
+    pub compression: Option<ContentEncoding>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncoding {
+    Gzip,
+    Brotli,
+    Zstd,
+}
+
+impl ContentEncoding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Brotli => "br",
+            ContentEncoding::Zstd => "zstd",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "gzip" | "x-gzip" => Some(ContentEncoding::Gzip),
+            "br" => Some(ContentEncoding::Brotli),
+            "zstd" => Some(ContentEncoding::Zstd),
+            _ => None,
+        }
+    }
 }
 
 pub trait Fetcher: Send + Sync + 'static {
@@ -129,11 +166,13 @@ pub mod dynamic {
         {
             self.0
                 .graphql_over_websocket_stream(FetchRequest {
+                    subgraph_name: request.subgraph_name,
                     method: request.method,
                     url: request.url,
                     headers: request.headers,
                     body: serde_json::to_value(request.body).unwrap(),
                     timeout: request.timeout,
+                    compression: request.compression,
                 })
                 .await
         }
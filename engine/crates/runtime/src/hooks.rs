@@ -133,6 +133,9 @@ pub enum SubgraphRequestExecutionKind {
     RequestError,
     RateLimited,
     Responsed(ResponseInfo),
+    CircuitOpen,
+    /// The response or error of an identical in-flight request was used.
+    Deduplicated,
 }
 
 impl ResponseInfo {
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use futures_util::future::BoxFuture;
 use futures_util::FutureExt;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum Error {
     #[error("Too many requests")]
     ExceededCapacity,
@@ -51,6 +51,8 @@ impl RateLimiter {
 pub enum RateLimitKey<'a> {
     Global,
     Subgraph(Cow<'a, str>),
+    /// The global limit, counted separately for the client identified by this value.
+    Client(Cow<'a, str>),
 }
 
 impl<'a> From<&'a str> for RateLimitKey<'a> {
@@ -87,6 +89,43 @@ impl<'a> RateLimiterContext for RateLimitKey<'a> {
     }
 }
 
+/// Context of the global rate limit of a request, whose headers may identify the client.
+pub struct GlobalRateLimitContext<'a> {
+    headers: &'a http::HeaderMap,
+    key: RateLimitKey<'static>,
+}
+
+impl<'a> GlobalRateLimitContext<'a> {
+    pub fn new(headers: &'a http::HeaderMap) -> Self {
+        Self {
+            headers,
+            key: RateLimitKey::Global,
+        }
+    }
+}
+
+impl<'a> RateLimiterContext for GlobalRateLimitContext<'a> {
+    fn header(&self, name: http::HeaderName) -> Option<&http::HeaderValue> {
+        self.headers.get(name)
+    }
+
+    fn graphql_operation_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn ip(&self) -> Option<IpAddr> {
+        None
+    }
+
+    fn jwt_claim(&self, _: &str) -> Option<&serde_json::Value> {
+        None
+    }
+
+    fn key(&self) -> Option<&RateLimitKey<'_>> {
+        Some(&self.key)
+    }
+}
+
 impl std::ops::Deref for RateLimiter {
     type Target = dyn RateLimiterInner;
 
@@ -19,6 +19,9 @@ pub struct EngineMetrics {
     subgraph_cache_hits: Counter<u64>,
     subgraph_cache_partial_hits: Counter<u64>,
     subgraph_cache_misses: Counter<u64>,
+    subgraph_circuit_breakers_open: UpDownCounter<i64>,
+    subgraph_circuit_breaker_rejections: Counter<u64>,
+    subgraph_deduplicated_requests: Counter<u64>,
     operation_cache_hits: Counter<u64>,
     operation_cache_misses: Counter<u64>,
     query_preparation_latency: Histogram<u64>,
@@ -73,6 +76,16 @@ pub struct SubgraphCacheMissAttributes {
     pub name: String,
 }
 
+#[derive(Debug)]
+pub struct SubgraphCircuitBreakerAttributes {
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct SubgraphRequestDeduplicatedAttributes {
+    pub name: String,
+}
+
 #[derive(Debug)]
 pub struct QueryPreparationAttributes {
     pub operation: Option<GraphqlOperationAttributes>,
@@ -99,6 +112,13 @@ impl EngineMetrics {
             subgraph_cache_hits: meter.u64_counter("graphql.subgraph.request.cache.hit").init(),
             subgraph_cache_partial_hits: meter.u64_counter("graphql.subgraph.request.cache.partial_hit").init(),
             subgraph_cache_misses: meter.u64_counter("graphql.subgraph.request.cache.miss").init(),
+            subgraph_circuit_breakers_open: meter
+                .i64_up_down_counter("graphql.subgraph.circuit_breaker.open")
+                .init(),
+            subgraph_circuit_breaker_rejections: meter
+                .u64_counter("graphql.subgraph.request.circuit_breaker.rejected")
+                .init(),
+            subgraph_deduplicated_requests: meter.u64_counter("graphql.subgraph.request.deduplicated").init(),
             operation_cache_hits: meter.u64_counter("graphql.operation.cache.hit").init(),
             operation_cache_misses: meter.u64_counter("graphql.operation.cache.miss").init(),
             query_preparation_latency: meter.u64_histogram("graphql.operation.prepare.duration").init(),
@@ -224,6 +244,38 @@ impl EngineMetrics {
         self.subgraph_requests_inflight.add(-1, &attributes);
     }
 
+    pub fn record_subgraph_circuit_breaker_opened(
+        &self,
+        SubgraphCircuitBreakerAttributes { name }: SubgraphCircuitBreakerAttributes,
+    ) {
+        let attributes = [KeyValue::new("graphql.subgraph.name", name)];
+        self.subgraph_circuit_breakers_open.add(1, &attributes);
+    }
+
+    pub fn record_subgraph_circuit_breaker_closed(
+        &self,
+        SubgraphCircuitBreakerAttributes { name }: SubgraphCircuitBreakerAttributes,
+    ) {
+        let attributes = [KeyValue::new("graphql.subgraph.name", name)];
+        self.subgraph_circuit_breakers_open.add(-1, &attributes);
+    }
+
+    pub fn record_subgraph_circuit_breaker_rejection(
+        &self,
+        SubgraphCircuitBreakerAttributes { name }: SubgraphCircuitBreakerAttributes,
+    ) {
+        let attributes = [KeyValue::new("graphql.subgraph.name", name)];
+        self.subgraph_circuit_breaker_rejections.add(1, &attributes);
+    }
+
+    pub fn record_subgraph_request_deduplicated(
+        &self,
+        SubgraphRequestDeduplicatedAttributes { name }: SubgraphRequestDeduplicatedAttributes,
+    ) {
+        let attributes = [KeyValue::new("graphql.subgraph.name", name)];
+        self.subgraph_deduplicated_requests.add(1, &attributes);
+    }
+
     pub fn record_subgraph_cache_hit(&self, SubgraphCacheHitAttributes { name }: SubgraphCacheHitAttributes) {
         let attributes = [KeyValue::new("graphql.subgraph.name", name)];
         self.subgraph_cache_hits.add(1, &attributes);
@@ -53,6 +53,18 @@ where
     }
 }
 
+/// The resource attached to everything exported: the configured attributes and the service name.
+pub(super) fn resource(config: &TelemetryConfig) -> Resource {
+    let mut resource_attributes: Vec<_> = config
+        .resource_attributes
+        .iter()
+        .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+        .collect();
+
+    resource_attributes.push(KeyValue::new("service.name", config.service_name.clone()));
+    Resource::new(resource_attributes)
+}
+
 /// Creates a new OTEL tracing layer that uses a [`BatchSpanProcessor`] to collect and export traces.
 /// It's wrapped in a [`reload::Layer`] enabling its replacement.
 pub fn build<S, R, I>(config: &TelemetryConfig, id_generator: I, runtime: R) -> Result<OtelTelemetry<S>, TracingError>
@@ -61,14 +73,7 @@ where
     R: RuntimeChannel,
     I: IdGenerator + 'static,
 {
-    let mut resource_attributes: Vec<_> = config
-        .resource_attributes
-        .iter()
-        .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
-        .collect();
-
-    resource_attributes.push(KeyValue::new("service.name", config.service_name.clone()));
-    let resource = Resource::new(resource_attributes);
+    let resource = resource(config);
 
     let meter_provider = Some(super::metrics::build_meter_provider(
         runtime.clone(),
@@ -12,38 +12,12 @@ where
 {
     cfg_if::cfg_if! {
         if #[cfg(feature = "otlp")] {
-            use opentelemetry_sdk::logs::{BatchConfigBuilder, BatchLogProcessor, Config};
-            use std::time::Duration;
+            use opentelemetry_sdk::logs::Config;
 
             let mut builder = LoggerProvider::builder().with_config(Config::default().with_resource(resource));
 
             if let Some(config) = config.logs_otlp_config() {
-                use opentelemetry_otlp::LogExporterBuilder;
-
-                let exporter = match super::exporter::build_otlp_exporter(config)? {
-                    either::Either::Left(grpc) => LogExporterBuilder::Tonic(grpc)
-                        .build_log_exporter()
-                        .map_err(|e| TracingError::LogsExporterSetup(e.to_string()))?,
-                    either::Either::Right(http) => LogExporterBuilder::Http(http)
-                        .build_log_exporter()
-                        .map_err(|e| TracingError::LogsExporterSetup(e.to_string()))?,
-                };
-
-                let processor = {
-                    let config = config.batch_export;
-
-                    let config = BatchConfigBuilder::default()
-                        .with_max_queue_size(config.max_queue_size)
-                        .with_scheduled_delay(Duration::from_secs(config.scheduled_delay.num_seconds() as u64))
-                        .with_max_export_batch_size(config.max_export_batch_size)
-                        .build();
-
-                    BatchLogProcessor::builder(exporter, runtime.clone())
-                        .with_batch_config(config)
-                        .build()
-                };
-
-                builder = builder.with_log_processor(processor);
+                builder = builder.with_log_processor(build_otlp_processor(runtime, config)?);
             }
 
             Ok(Some(builder.build()))
@@ -52,3 +26,61 @@ where
         }
     }
 }
+
+/// Builds a logger provider for the gateway access logs, exporting to the global
+/// `telemetry.exporters.otlp` exporter. Returns `None` if that exporter is not enabled.
+#[cfg(feature = "otlp")]
+pub fn build_access_logs_provider<R>(
+    runtime: R,
+    config: &TelemetryConfig,
+) -> Result<Option<LoggerProvider>, TracingError>
+where
+    R: RuntimeChannel,
+{
+    use opentelemetry_sdk::logs::Config;
+
+    let Some(otlp_config) = config.exporters.otlp.as_ref().filter(|c| c.enabled) else {
+        return Ok(None);
+    };
+
+    let provider = LoggerProvider::builder()
+        .with_config(Config::default().with_resource(super::layer::resource(config)))
+        .with_log_processor(build_otlp_processor(runtime, otlp_config)?)
+        .build();
+
+    Ok(Some(provider))
+}
+
+#[cfg(feature = "otlp")]
+fn build_otlp_processor<R>(
+    runtime: R,
+    config: &crate::config::OtlpExporterConfig,
+) -> Result<opentelemetry_sdk::logs::BatchLogProcessor<R>, TracingError>
+where
+    R: RuntimeChannel,
+{
+    use opentelemetry_otlp::LogExporterBuilder;
+    use opentelemetry_sdk::logs::{BatchConfigBuilder, BatchLogProcessor};
+    use std::time::Duration;
+
+    let exporter = match super::exporter::build_otlp_exporter(config)? {
+        either::Either::Left(grpc) => LogExporterBuilder::Tonic(grpc)
+            .build_log_exporter()
+            .map_err(|e| TracingError::LogsExporterSetup(e.to_string()))?,
+        either::Either::Right(http) => LogExporterBuilder::Http(http)
+            .build_log_exporter()
+            .map_err(|e| TracingError::LogsExporterSetup(e.to_string()))?,
+    };
+
+    let batch_config = config.batch_export;
+
+    let batch_config = BatchConfigBuilder::default()
+        .with_max_queue_size(batch_config.max_queue_size)
+        .with_scheduled_delay(Duration::from_secs(batch_config.scheduled_delay.num_seconds() as u64))
+        .with_max_export_batch_size(batch_config.max_export_batch_size)
+        .build();
+
+    Ok(BatchLogProcessor::builder(exporter, runtime)
+        .with_batch_config(batch_config)
+        .build())
+}
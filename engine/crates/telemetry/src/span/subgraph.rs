@@ -91,6 +91,13 @@ impl SubgraphHttpRequestSpan {
         }
     }
 
+    /// Records the replica the request was sent to when the subgraph is load balanced.
+    pub fn record_upstream_url(&self, url: &Url) {
+        self.record("server.address", url.host_str());
+        self.record("server.port", url.port());
+        self.record("url.full", url.as_str());
+    }
+
     pub fn record_resend_count(&self, count: usize) {
         if count > 0 {
             self.record("http.request.resend_count", count);
@@ -1,8 +1,8 @@
 use bindings::component::grafbase::types::{
-    CacheStatus, ExecutedHttpRequest, ExecutedOperation, ExecutedSubgraphRequest, SharedContext,
-    SubgraphRequestExecutionKind,
+    CacheStatus, ExecutedHttpRequest, ExecutedOperation, ExecutedSubgraphRequestV2, SharedContext,
+    SubgraphRequestExecutionKindV2,
 };
-use bindings::exports::component::grafbase::responses::Guest;
+use bindings::exports::component::grafbase::responses_v2::Guest;
 
 #[allow(warnings)]
 mod bindings;
@@ -23,6 +23,8 @@ pub enum ResponseData {
     RequestError,
     RateLimited,
     Responsed(ResponseInfo),
+    CircuitOpen,
+    Deduplicated,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -76,8 +78,8 @@ struct AuditInfo<'a> {
 }
 
 impl Guest for Component {
-    fn on_subgraph_response(_: SharedContext, request: ExecutedSubgraphRequest) -> Vec<u8> {
-        let ExecutedSubgraphRequest {
+    fn on_subgraph_response(_: SharedContext, request: ExecutedSubgraphRequestV2) -> Vec<u8> {
+        let ExecutedSubgraphRequestV2 {
             subgraph_name,
             method,
             url,
@@ -90,15 +92,17 @@ impl Guest for Component {
         let responses = executions
             .into_iter()
             .map(|r| match r {
-                SubgraphRequestExecutionKind::Response(info) => ResponseData::Responsed(ResponseInfo {
+                SubgraphRequestExecutionKindV2::Response(info) => ResponseData::Responsed(ResponseInfo {
                     connection_time: info.connection_time_ms,
                     response_time: info.response_time_ms,
                     status_code: info.status_code,
                 }),
-                SubgraphRequestExecutionKind::InternalServerError => ResponseData::InternalServerError,
-                SubgraphRequestExecutionKind::HookError => ResponseData::HookError,
-                SubgraphRequestExecutionKind::RequestError => ResponseData::RequestError,
-                SubgraphRequestExecutionKind::RateLimited => ResponseData::RateLimited,
+                SubgraphRequestExecutionKindV2::InternalServerError => ResponseData::InternalServerError,
+                SubgraphRequestExecutionKindV2::HookError => ResponseData::HookError,
+                SubgraphRequestExecutionKindV2::RequestError => ResponseData::RequestError,
+                SubgraphRequestExecutionKindV2::RateLimited => ResponseData::RateLimited,
+                SubgraphRequestExecutionKindV2::CircuitOpen => ResponseData::CircuitOpen,
+                SubgraphRequestExecutionKindV2::Deduplicated => ResponseData::Deduplicated,
             })
             .collect();
 
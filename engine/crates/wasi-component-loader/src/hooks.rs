@@ -23,8 +23,8 @@ pub trait RecycleableComponentInstance: Sized + Send + 'static {
     /// Resets the store to the original state. This must be called if wanting to reuse this instance.
     fn recycle(&mut self) -> crate::Result<()>;
 
-    /// Retrieves the name of the interface this component implements.
-    fn interface_name() -> &'static str;
+    /// Retrieves the names of the interface versions this component can implement, newest first.
+    fn interface_names() -> &'static [&'static str];
 }
 
 /// A macro to define a component instance.
@@ -37,9 +37,10 @@ pub trait RecycleableComponentInstance: Sized + Send + 'static {
 /// # Arguments
 ///
 /// * `$ty` - The identifier for the generated struct.
-/// * `$name` - The name of the component that will be instantiated.
+/// * `$name` - The name of the interface that will be instantiated. Older versions of the interface can
+///   follow, the newest one exported by the guest is used.
 macro_rules! component_instance {
-    ($ty:ident: $name:expr) => {
+    ($ty:ident: $($name:expr),+) => {
         /// A struct representing an instance of the component.
         ///
         /// This struct wraps the `ComponentInstance` and provides the necessary
@@ -69,15 +70,17 @@ macro_rules! component_instance {
 
         impl $crate::RecycleableComponentInstance for $ty {
             async fn new(loader: &ComponentLoader) -> $crate::Result<Self> {
-                ComponentInstance::new(loader, $name).await.map(Self)
+                ComponentInstance::new(loader, <Self as $crate::RecycleableComponentInstance>::interface_names())
+                    .await
+                    .map(Self)
             }
 
             fn recycle(&mut self) -> $crate::Result<()> {
                 self.0.recycle()
             }
 
-            fn interface_name() -> &'static str {
-                $name
+            fn interface_names() -> &'static [&'static str] {
+                &[$($name),+]
             }
         }
     };
@@ -128,12 +131,18 @@ impl ComponentInstance {
     /// # Arguments
     ///
     /// * `loader` - A reference to the `ComponentLoader` used to load the component.
-    /// * `interface_name` - The name of the interface this component implements.
+    /// * `interface_names` - The versions of the interface this component implements, newest first.
     ///
     /// # Returns
     ///
     /// A `Result` containing the newly created component instance on success, or an error on failure.
-    async fn new(loader: &ComponentLoader, interface_name: &'static str) -> crate::Result<Self> {
+    async fn new(loader: &ComponentLoader, interface_names: &'static [&'static str]) -> crate::Result<Self> {
+        let interface_name = interface_names
+            .iter()
+            .copied()
+            .find(|&name| loader.implements_interface(name))
+            .unwrap_or(interface_names[0]);
+
         let mut store = initialize_store(loader.config(), loader.engine())?;
 
         let instance = loader
@@ -374,6 +383,11 @@ impl ComponentInstance {
         Ok(Some(result))
     }
 
+    /// The name of the interface version the guest exports.
+    pub(crate) fn interface_name(&self) -> &'static str {
+        self.interface_name
+    }
+
     /// Retrieves the interface ID of the component instance.
     ///
     /// This function returns the index of the component export associated with the
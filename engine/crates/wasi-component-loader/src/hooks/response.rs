@@ -5,11 +5,12 @@ use crate::{
     context::SharedContext,
     names::{
         ON_HTTP_RESPONSE_FUNCTION, ON_OPERATION_RESPONSE_FUNCTION, ON_SUBGRAPH_RESPONSE_FUNCTION, RESPONSES_INTERFACE,
+        RESPONSES_V2_INTERFACE,
     },
     ComponentLoader,
 };
 
-component_instance!(ResponsesComponentInstance: RESPONSES_INTERFACE);
+component_instance!(ResponsesComponentInstance: RESPONSES_V2_INTERFACE, RESPONSES_INTERFACE);
 
 /// Data from an executed HTTP request.
 #[derive(Debug, Clone, Lower, ComponentType)]
@@ -145,6 +146,12 @@ pub enum SubgraphRequestExecutionKind {
     /// A response was received.
     #[component(name = "response")]
     Response(SubgraphResponse),
+    /// Request was not sent, the subgraph circuit breaker is open.
+    #[component(name = "circuit-open")]
+    CircuitOpen,
+    /// Request was not sent, the response or error of an identical in-flight request was used.
+    #[component(name = "deduplicated")]
+    Deduplicated,
 }
 
 /// A response info from subgraph fetch.
@@ -174,6 +181,87 @@ pub struct ExecutedSubgraphRequest {
     pub has_errors: bool,
 }
 
+/// `subgraph-request-execution-kind` of the first `responses` interface, which has neither a
+/// `circuit-open` nor a `deduplicated` case.
+#[derive(Debug, Clone, Copy, Lower, ComponentType)]
+#[component(variant)]
+enum SubgraphRequestExecutionKindV1 {
+    #[component(name = "internal-server-error")]
+    InternalServerError,
+    #[component(name = "hook-error")]
+    HookError,
+    #[component(name = "request-error")]
+    RequestError,
+    #[component(name = "rate-limited")]
+    RateLimited,
+    #[component(name = "response")]
+    Response(SubgraphResponse),
+}
+
+impl SubgraphRequestExecutionKindV1 {
+    /// Deduplicated requests were never sent nor failed, so older guests don't see them.
+    fn from_kind(kind: SubgraphRequestExecutionKind) -> Option<Self> {
+        Some(match kind {
+            SubgraphRequestExecutionKind::InternalServerError => Self::InternalServerError,
+            SubgraphRequestExecutionKind::HookError => Self::HookError,
+            // The request was not sent, closest to a failed request for older guests.
+            SubgraphRequestExecutionKind::RequestError | SubgraphRequestExecutionKind::CircuitOpen => {
+                Self::RequestError
+            }
+            SubgraphRequestExecutionKind::RateLimited => Self::RateLimited,
+            SubgraphRequestExecutionKind::Response(response) => Self::Response(response),
+            SubgraphRequestExecutionKind::Deduplicated => return None,
+        })
+    }
+}
+
+/// `executed-subgraph-request` of the first `responses` interface.
+#[derive(Debug, Clone, Lower, ComponentType)]
+#[component(record)]
+struct ExecutedSubgraphRequestV1 {
+    #[component(name = "subgraph-name")]
+    subgraph_name: String,
+    #[component(name = "method")]
+    method: String,
+    #[component(name = "url")]
+    url: String,
+    #[component(name = "executions")]
+    executions: Vec<SubgraphRequestExecutionKindV1>,
+    #[component(name = "cache-status")]
+    cache_status: CacheStatus,
+    #[component(name = "total-duration-ms")]
+    total_duration_ms: u64,
+    #[component(name = "has-errors")]
+    has_errors: bool,
+}
+
+impl From<ExecutedSubgraphRequest> for ExecutedSubgraphRequestV1 {
+    fn from(request: ExecutedSubgraphRequest) -> Self {
+        let ExecutedSubgraphRequest {
+            subgraph_name,
+            method,
+            url,
+            executions,
+            cache_status,
+            total_duration_ms,
+            has_errors,
+        } = request;
+
+        Self {
+            subgraph_name,
+            method,
+            url,
+            executions: executions
+                .into_iter()
+                .filter_map(SubgraphRequestExecutionKindV1::from_kind)
+                .collect(),
+            cache_status,
+            total_duration_ms,
+            has_errors,
+        }
+    }
+}
+
 impl ResponsesComponentInstance {
     /// Allows inspection of the response from a subgraph request.
     ///
@@ -191,10 +279,16 @@ impl ResponsesComponentInstance {
         context: SharedContext,
         request: ExecutedSubgraphRequest,
     ) -> crate::Result<Vec<u8>> {
-        self.call1_one_output(ON_SUBGRAPH_RESPONSE_FUNCTION, context, request)
-            .await?
-            .map(|result: Vec<u8>| Ok(result))
-            .unwrap_or_else(|| Ok(Vec::new()))
+        let result = if self.interface_name() == RESPONSES_INTERFACE {
+            let request = ExecutedSubgraphRequestV1::from(request);
+            self.call1_one_output(ON_SUBGRAPH_RESPONSE_FUNCTION, context, request)
+                .await?
+        } else {
+            self.call1_one_output(ON_SUBGRAPH_RESPONSE_FUNCTION, context, request)
+                .await?
+        };
+
+        Ok(result.unwrap_or_default())
     }
 
     /// Allows inspection of the response from an executed operation.
@@ -3,6 +3,7 @@ pub(crate) const GATEWAY_REQUEST_INTERFACE: &str = "component:grafbase/gateway-r
 pub(crate) const AUTHORIZATION_INTERFACE: &str = "component:grafbase/authorization";
 pub(crate) const SUBGRAPH_REQUEST_INTERFACE: &str = "component:grafbase/subgraph-request";
 pub(crate) const RESPONSES_INTERFACE: &str = "component:grafbase/responses";
+pub(crate) const RESPONSES_V2_INTERFACE: &str = "component:grafbase/responses-v2";
 
 pub(crate) const GATEWAY_HOOK_FUNCTION: &str = "on-gateway-request";
 pub(crate) const AUTHORIZE_EDGE_PRE_EXECUTION_HOOK_FUNCTION: &str = "authorize-edge-pre-execution";
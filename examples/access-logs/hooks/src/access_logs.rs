@@ -1,6 +1,6 @@
 use crate::bindings::{
     self,
-    component::grafbase::types::{SubgraphRequestExecutionKind, SubgraphResponse},
+    component::grafbase::types::{SubgraphRequestExecutionKindV2, SubgraphResponse},
 };
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -33,16 +33,20 @@ pub enum ResponseData {
     RequestError,
     RateLimited,
     Response(ResponseInfo),
+    CircuitOpen,
+    Deduplicated,
 }
 
-impl From<SubgraphRequestExecutionKind> for ResponseData {
-    fn from(value: SubgraphRequestExecutionKind) -> Self {
+impl From<SubgraphRequestExecutionKindV2> for ResponseData {
+    fn from(value: SubgraphRequestExecutionKindV2) -> Self {
         match value {
-            SubgraphRequestExecutionKind::InternalServerError => Self::InternalServerError,
-            SubgraphRequestExecutionKind::HookError => Self::HookError,
-            SubgraphRequestExecutionKind::RequestError => Self::RequestError,
-            SubgraphRequestExecutionKind::RateLimited => Self::RateLimited,
-            SubgraphRequestExecutionKind::Response(info) => Self::Response(info.into()),
+            SubgraphRequestExecutionKindV2::InternalServerError => Self::InternalServerError,
+            SubgraphRequestExecutionKindV2::HookError => Self::HookError,
+            SubgraphRequestExecutionKindV2::RequestError => Self::RequestError,
+            SubgraphRequestExecutionKindV2::RateLimited => Self::RateLimited,
+            SubgraphRequestExecutionKindV2::Response(info) => Self::Response(info.into()),
+            SubgraphRequestExecutionKindV2::CircuitOpen => Self::CircuitOpen,
+            SubgraphRequestExecutionKindV2::Deduplicated => Self::Deduplicated,
         }
     }
 }
@@ -1,8 +1,8 @@
 use access_logs::{AuditInfo, OperationInfo, SubgraphInfo};
 use bindings::{
     component::grafbase::types::CacheStatus,
-    exports::component::grafbase::responses::{
-        ExecutedOperation, ExecutedSubgraphRequest, Guest, SharedContext,
+    exports::component::grafbase::responses_v2::{
+        ExecutedOperation, ExecutedSubgraphRequestV2, Guest, SharedContext,
     },
 };
 
@@ -13,7 +13,7 @@ mod bindings;
 struct Component;
 
 impl Guest for Component {
-    fn on_subgraph_response(_: SharedContext, request: ExecutedSubgraphRequest) -> Vec<u8> {
+    fn on_subgraph_response(_: SharedContext, request: ExecutedSubgraphRequestV2) -> Vec<u8> {
         // One response per subgraph execution.
         let responses = request.executions.into_iter().map(Into::into).collect();
 
@@ -54,7 +54,7 @@ impl Guest for Component {
 
     fn on_http_response(
         context: SharedContext,
-        request: bindings::exports::component::grafbase::responses::ExecutedHttpRequest,
+        request: bindings::exports::component::grafbase::responses_v2::ExecutedHttpRequest,
     ) {
         let info = AuditInfo {
             method: &request.method,
@@ -0,0 +1,86 @@
+use size::Size;
+
+use crate::size_ext;
+
+/// Response compression for clients, negotiated with the `Accept-Encoding` header.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompressionConfig {
+    /// Enables response compression. Default: false.
+    pub enabled: bool,
+    /// Responses smaller than this size are never compressed. At most 64KiB. Default: 1KiB.
+    #[serde(deserialize_with = "deserialize_min_size")]
+    pub min_size: Size,
+    /// Algorithms the gateway may use, the client preference decides between them.
+    pub algorithms: Vec<CompressionAlgorithm>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_size: Size::from_kibibytes(1),
+            algorithms: vec![
+                CompressionAlgorithm::Gzip,
+                CompressionAlgorithm::Brotli,
+                CompressionAlgorithm::Zstd,
+            ],
+        }
+    }
+}
+
+fn deserialize_min_size<'de, D>(deserializer: D) -> Result<Size, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let size = size_ext::deserialize_positive_size(deserializer)?;
+    if size.bytes() > i64::from(u16::MAX) {
+        return Err(serde::de::Error::custom(format!(
+            "min_size must be at most {} bytes",
+            u16::MAX
+        )));
+    }
+    Ok(size)
+}
+
+impl CompressionConfig {
+    pub fn is_algorithm_enabled(&self, algorithm: CompressionAlgorithm) -> bool {
+        self.algorithms.contains(&algorithm)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionAlgorithm {
+    Gzip,
+    #[serde(alias = "br")]
+    Brotli,
+    Zstd,
+}
+
+/// Compression of the traffic between the gateway and a subgraph.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SubgraphCompressionConfig {
+    /// Sends `Accept-Encoding` to the subgraph and decompresses its responses. Default: false.
+    pub accept_compressed_responses: bool,
+    /// Algorithm used to compress `_entities` request bodies. Not compressed by default.
+    pub entities_request: Option<CompressionAlgorithm>,
+    /// `_entities` request bodies smaller than this size are sent uncompressed. Default: 4KiB.
+    #[serde(deserialize_with = "size_ext::deserialize_positive_size")]
+    pub entities_request_min_size: Size,
+    /// Compressed responses larger than this size once decompressed are rejected. Default: 64MiB.
+    #[serde(deserialize_with = "size_ext::deserialize_positive_size")]
+    pub max_decompressed_response_size: Size,
+}
+
+impl Default for SubgraphCompressionConfig {
+    fn default() -> Self {
+        Self {
+            accept_compressed_responses: false,
+            entities_request: None,
+            entities_request_min_size: Size::from_kibibytes(4),
+            max_decompressed_response_size: Size::from_mebibytes(64),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use std::{borrow::Cow, net::SocketAddr};
+use std::{borrow::Cow, net::SocketAddr, time::Duration};
+
+use url::Url;
 
 /// Health endpoint configuration.
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -6,7 +8,10 @@ use std::{borrow::Cow, net::SocketAddr};
 pub struct HealthConfig {
     pub enabled: bool,
     pub listen: Option<SocketAddr>,
+    /// Liveness endpoint, healthy as soon as the gateway serves a graph.
     pub path: Cow<'static, str>,
+    /// Readiness endpoint, optionally checking the dependencies of the gateway.
+    pub readiness: ReadinessConfig,
 }
 
 impl Default for HealthConfig {
@@ -15,6 +20,62 @@ impl Default for HealthConfig {
             enabled: true,
             listen: None,
             path: Cow::Borrowed("/health"),
+            readiness: ReadinessConfig::default(),
+        }
+    }
+}
+
+/// Readiness endpoint configuration.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReadinessConfig {
+    pub path: Cow<'static, str>,
+    /// Check the connection to the Redis instances used for rate limiting, entity caching, the
+    /// key-value store and operation caching.
+    pub redis: bool,
+    /// Check that the hooks component can still be instantiated.
+    pub hooks: bool,
+    /// Probe every subgraph, see the `health_check` subgraph setting.
+    pub subgraphs: bool,
+    /// Maximum duration of each check. Default: 5 seconds.
+    #[serde(deserialize_with = "duration_str::deserialize_duration")]
+    pub timeout: Duration,
+    /// How long the results of the checks are reused by later requests. Default: 1 second.
+    #[serde(deserialize_with = "duration_str::deserialize_duration")]
+    pub cache_ttl: Duration,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        ReadinessConfig {
+            path: Cow::Borrowed("/health/ready"),
+            redis: false,
+            hooks: false,
+            subgraphs: false,
+            timeout: Duration::from_secs(5),
+            cache_ttl: Duration::from_secs(1),
+        }
+    }
+}
+
+/// How a subgraph is probed by the readiness endpoint. By default a `{ __typename }` query is
+/// sent to the subgraph URL.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SubgraphHealthCheckConfig {
+    pub enabled: bool,
+    /// Send a GET request to this URL instead of a GraphQL query, any 2xx status is healthy.
+    pub url: Option<Url>,
+    /// GraphQL query sent to the subgraph.
+    pub query: Cow<'static, str>,
+}
+
+impl Default for SubgraphHealthCheckConfig {
+    fn default() -> Self {
+        SubgraphHealthCheckConfig {
+            enabled: true,
+            url: None,
+            query: Cow::Borrowed("{ __typename }"),
         }
     }
 }
@@ -0,0 +1,34 @@
+use std::{path::PathBuf, time::Duration};
+
+use url::Url;
+
+/// Transport settings of the HTTP client used for a subgraph.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SubgraphHttpClientConfig {
+    /// TLS settings, such as a client certificate for mutual TLS.
+    pub tls: Option<SubgraphTlsConfig>,
+    /// Send all requests to the subgraph through this HTTP(S) proxy.
+    pub proxy: Option<Url>,
+    /// Use HTTP/2 without negotiating it first. The subgraph must support it.
+    pub http2_prior_knowledge: bool,
+    /// Maximum number of idle connections kept open per host.
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open. Default: 90 seconds.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub pool_idle_timeout: Option<Duration>,
+    /// Timeout for establishing a connection. By default only the request timeout applies.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub connect_timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SubgraphTlsConfig {
+    /// PEM file with the client certificate, used for mutual TLS together with `key`.
+    pub cert: Option<PathBuf>,
+    /// PEM file with the private key of the client certificate.
+    pub key: Option<PathBuf>,
+    /// PEM files with root certificates to trust in addition to the default ones.
+    pub ca: Vec<PathBuf>,
+}
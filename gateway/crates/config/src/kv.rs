@@ -0,0 +1,35 @@
+use crate::RateLimitRedisTlsConfig;
+
+/// Key-value store used for automatic persisted queries and JWKS caching.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KvConfig {
+    pub storage: KvStorage,
+    pub redis: KvRedisConfig,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KvStorage {
+    #[default]
+    Memory,
+    Redis,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KvRedisConfig {
+    pub url: url::Url,
+    pub key_prefix: String,
+    pub tls: Option<RateLimitRedisTlsConfig>,
+}
+
+impl Default for KvRedisConfig {
+    fn default() -> Self {
+        Self {
+            url: url::Url::parse("redis://localhost:6379").expect("must be correct"),
+            key_prefix: String::from("grafbase-kv"),
+            tls: None,
+        }
+    }
+}
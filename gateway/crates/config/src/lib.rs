@@ -1,11 +1,15 @@
 use grafbase_workspace_hack as _;
 
 pub mod authentication;
+pub mod compression;
 pub mod cors;
 pub mod entity_caching;
 pub mod header;
 pub mod health;
 pub mod hooks;
+pub mod http_client;
+pub mod kv;
+pub mod operation_caching;
 pub mod rate_limit;
 mod size_ext;
 pub mod telemetry;
@@ -14,11 +18,15 @@ use std::{collections::BTreeMap, net::SocketAddr, path::PathBuf, time::Duration}
 
 use ascii::AsciiString;
 pub use authentication::*;
+pub use compression::*;
 pub use cors::*;
 pub use entity_caching::*;
 pub use header::*;
 pub use health::*;
 pub use hooks::*;
+pub use http_client::*;
+pub use kv::*;
+pub use operation_caching::*;
 pub use rate_limit::*;
 use serde_dynamic_string::DynamicString;
 use size::Size;
@@ -63,6 +71,14 @@ pub struct Config {
     pub health: HealthConfig,
     /// Global configuration for entity caching
     pub entity_caching: EntityCachingConfig,
+    /// Caching and warm-up of prepared operations
+    pub operation_caching: OperationCachingConfig,
+    /// Storage for automatic persisted queries and JWKS
+    pub kv: KvConfig,
+    /// Response compression settings
+    pub compression: CompressionConfig,
+    /// Validation of built-in scalars such as `DateTime` or `UUID`, by scalar name
+    pub scalars: BTreeMap<String, ScalarConfig>,
 }
 
 impl Default for Config {
@@ -84,6 +100,10 @@ impl Default for Config {
             hooks: Default::default(),
             health: Default::default(),
             entity_caching: Default::default(),
+            operation_caching: Default::default(),
+            kv: Default::default(),
+            compression: Default::default(),
+            scalars: Default::default(),
         }
     }
 }
@@ -103,6 +123,13 @@ pub struct GatewayConfig {
     pub retry: RetryConfig,
     /// Access logs configuration
     pub access_logs: AccessLogsConfig,
+    /// How long in-flight requests are given to finish on shutdown. Default: 3 seconds.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub drain_period: Option<Duration>,
+    /// How long the readiness endpoint reports the shutdown before new connections are refused,
+    /// so that load balancers stop routing traffic to the gateway. Default: no delay.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub shutdown_delay: Option<Duration>,
 }
 
 #[derive(Debug, Default, serde::Deserialize, Clone, Copy)]
@@ -137,12 +164,64 @@ pub enum LogMode {
 pub struct AccessLogsConfig {
     /// Enable writing access logs
     pub enabled: bool,
+    /// Where the access logs are written to
+    pub sink: AccessLogSink,
     /// The path to the access log files
     pub path: PathBuf,
     /// How often logs are rotated.
     pub rotate: RotateMode,
+    /// How many rotated files are kept. Default: all of them.
+    pub max_files: Option<usize>,
+    /// Rotated files older than this are deleted. Default: never.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub max_age: Option<Duration>,
+    /// Compress rotated files with gzip
+    pub compress: bool,
     /// What happens if the log channel is full
     pub mode: LogMode,
+    /// The syslog server, required with the syslog sink
+    pub syslog: Option<SyslogConfig>,
+}
+
+#[derive(Debug, Default, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessLogSink {
+    /// Rolling files in the configured path
+    #[default]
+    File,
+    /// One line per log to the standard output
+    Stdout,
+    /// OpenTelemetry logs, sent with the `telemetry.exporters.otlp` exporter
+    Otlp,
+    /// A syslog server, over UDP or TCP
+    Syslog,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SyslogConfig {
+    /// The address of the syslog server, e.g. `logs.internal:514`
+    pub address: String,
+    /// The transport protocol. Default: udp
+    #[serde(default)]
+    pub protocol: SyslogProtocol,
+    /// The application name added to each message. Default: grafbase-gateway
+    #[serde(default = "SyslogConfig::default_app_name")]
+    pub app_name: String,
+}
+
+impl SyslogConfig {
+    fn default_app_name() -> String {
+        "grafbase-gateway".to_string()
+    }
+}
+
+#[derive(Debug, Default, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogProtocol {
+    #[default]
+    Udp,
+    Tcp,
 }
 
 impl AccessLogsConfig {
@@ -164,9 +243,48 @@ pub struct SubgraphConfig {
     #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
     pub timeout: Option<Duration>,
     pub retry: Option<RetryConfig>,
+    /// Send a second request when the subgraph is slow to answer a query.
+    pub hedging: Option<HedgingConfig>,
     /// Subgraph specific entity caching config  this overrides the global config if there
     /// is any
     pub entity_caching: Option<EntityCachingConfig>,
+    /// Compression of requests sent to and responses received from this subgraph
+    pub compression: Option<SubgraphCompressionConfig>,
+    /// Share a single upstream stream between identical subscriptions to this subgraph.
+    pub multiplex_subscriptions: bool,
+    /// Share the response or error of identical in-flight queries to this subgraph.
+    pub deduplicate_requests: bool,
+    /// Stop sending requests to this subgraph for a while when it keeps failing.
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// Replicas of the subgraph, replacing the URL from the federated graph. Requests are
+    /// balanced across them.
+    pub urls: Vec<Url>,
+    /// How requests are spread across the `urls`.
+    pub load_balancing: Option<LoadBalancingConfig>,
+    /// Transport settings of the HTTP client used for this subgraph.
+    pub http_client: Option<SubgraphHttpClientConfig>,
+    /// How the readiness endpoint probes this subgraph.
+    pub health_check: Option<SubgraphHealthCheckConfig>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoadBalancingConfig {
+    /// How the URL of each request is chosen. Default: round robin.
+    pub strategy: LoadBalancingStrategy,
+    /// How long a URL is left out after a connection error. Default: 10 seconds.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub ejection_duration: Option<Duration>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadBalancingStrategy {
+    /// Each URL in turn.
+    #[default]
+    RoundRobin,
+    /// The URL with the fewest requests in flight.
+    LeastInflight,
 }
 
 #[derive(Debug, serde::Deserialize, Clone, Copy, Default, PartialEq)]
@@ -185,11 +303,77 @@ pub struct RetryConfig {
     pub retry_mutations: bool,
 }
 
+#[derive(Debug, serde::Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct HedgingConfig {
+    /// Should queries be hedged or not. Mutations are never hedged.
+    pub enabled: bool,
+    /// How long to wait for a response before sending the hedged request. Also used until
+    /// enough responses were observed for the percentile. Default: 100 milliseconds.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub delay: Option<Duration>,
+    /// Send the hedged request once the subgraph took longer than this percentile of its recent
+    /// response times, for example 0.95.
+    pub percentile: Option<f32>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CircuitBreakerConfig {
+    /// Should the circuit breaker be used or not.
+    pub enabled: bool,
+    /// Open the circuit after this many consecutive failed requests. Default: 5.
+    pub failure_threshold: Option<u32>,
+    /// Open the circuit when the fraction of failed requests within the window reaches this value.
+    /// Disabled by default.
+    pub error_rate: Option<f32>,
+    /// How many requests must be seen within the window before the error rate is considered. Default: 10.
+    pub minimum_requests: Option<u32>,
+    /// The window over which the error rate is computed. Default: 10 seconds.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub window: Option<Duration>,
+    /// How long the circuit stays open before probe requests are let through. Default: 30 seconds.
+    #[serde(deserialize_with = "duration_str::deserialize_option_duration")]
+    pub open_duration: Option<Duration>,
+    /// How many successful probe requests are needed to close the circuit again. Default: 1.
+    pub half_open_probes: Option<u32>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Copy, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScalarConfig {
+    /// Reject arguments and variables not matching the scalar format. True by default.
+    pub validate_input: bool,
+    /// Turn subgraph values not matching the scalar format into errors. False by default.
+    pub validate_output: bool,
+}
+
+impl Default for ScalarConfig {
+    fn default() -> Self {
+        Self {
+            validate_input: true,
+            validate_output: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, serde::Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct GraphConfig {
     pub path: Option<String>,
     pub introspection: bool,
+    /// Serve a contract variant of the graph, filtered by `@tag`
+    pub contract: Option<ContractConfig>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContractConfig {
+    /// Only expose the fields tagged with one of these tags, and the fields of types tagged with one of them.
+    /// Everything is exposed if empty.
+    pub include_tags: Vec<String>,
+    /// Never expose elements tagged with one of these tags.
+    pub exclude_tags: Vec<String>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize)]
@@ -209,6 +393,21 @@ pub struct NetworkConfig {
 pub struct TlsConfig {
     pub certificate: PathBuf,
     pub key: PathBuf,
+    /// CA certificates used to verify client certificates. Enables client certificate authentication.
+    pub client_ca: Option<PathBuf>,
+    /// Whether clients must present a certificate, when `client_ca` is set. Default: required
+    #[serde(default)]
+    pub client_auth: ClientAuthMode,
+}
+
+#[derive(Debug, Default, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientAuthMode {
+    /// Connections without a client certificate are accepted
+    Optional,
+    /// Connections without a valid client certificate are rejected
+    #[default]
+    Required,
 }
 
 #[derive(Debug, serde::Deserialize, Default, Clone)]
@@ -356,6 +555,112 @@ mod tests {
         assert_eq!(Some("/enterprise"), config.graph.path.as_deref());
     }
 
+    #[test]
+    fn graph_contract() {
+        let input = indoc! {r#"
+            [graph.contract]
+            include_tags = ["public"]
+            exclude_tags = ["internal", "beta"]
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        let expected = ContractConfig {
+            include_tags: vec!["public".to_owned()],
+            exclude_tags: vec!["internal".to_owned(), "beta".to_owned()],
+        };
+
+        assert_eq!(Some(expected), config.graph.contract);
+    }
+
+    #[test]
+    fn scalars() {
+        let input = indoc! {r#"
+            [scalars.DateTime]
+            validate_input = false
+
+            [scalars.Email]
+            validate_output = true
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        let date_time = ScalarConfig {
+            validate_input: false,
+            validate_output: false,
+        };
+
+        let email = ScalarConfig {
+            validate_input: true,
+            validate_output: true,
+        };
+
+        assert_eq!(Some(&date_time), config.scalars.get("DateTime"));
+        assert_eq!(Some(&email), config.scalars.get("Email"));
+    }
+
+    #[test]
+    fn operation_caching() {
+        let input = indoc! {r#"
+            [operation_caching]
+            limit = 500
+            storage = "redis"
+            warm_on_reload = true
+
+            [operation_caching.redis]
+            url = "redis://cache.internal:6379"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        let expected = OperationCachingConfig {
+            enabled: true,
+            limit: 500,
+            storage: OperationCachingStorage::Redis,
+            redis: OperationCachingRedisConfig {
+                url: "redis://cache.internal:6379".parse().unwrap(),
+                key_prefix: String::from("grafbase-operation-cache"),
+                tls: None,
+            },
+            warm_on_reload: true,
+            warming_limit: 100,
+        };
+
+        assert_eq!(expected, config.operation_caching);
+    }
+
+    #[test]
+    fn kv_redis() {
+        let input = indoc! {r#"
+            [kv]
+            storage = "redis"
+
+            [kv.redis]
+            url = "rediss://kv.internal:6380"
+            key_prefix = "gateway"
+
+            [kv.redis.tls]
+            ca = "/etc/grafbase/ca.pem"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        let expected = KvConfig {
+            storage: KvStorage::Redis,
+            redis: KvRedisConfig {
+                url: "rediss://kv.internal:6380".parse().unwrap(),
+                key_prefix: String::from("gateway"),
+                tls: Some(RateLimitRedisTlsConfig {
+                    cert: None,
+                    key: None,
+                    ca: Some(PathBuf::from("/etc/grafbase/ca.pem")),
+                }),
+            },
+        };
+
+        assert_eq!(expected, config.kv);
+    }
+
     #[test]
     fn csrf_defaults() {
         let config: Config = toml::from_str("").unwrap();
@@ -1406,7 +1711,16 @@ mod tests {
                 rate_limit: None,
                 timeout: None,
                 retry: None,
+                hedging: None,
                 entity_caching: None,
+                compression: None,
+                multiplex_subscriptions: false,
+                deduplicate_requests: false,
+                circuit_breaker: None,
+                urls: [],
+                load_balancing: None,
+                http_client: None,
+                health_check: None,
             },
         }
         "###);
@@ -1453,6 +1767,8 @@ mod tests {
             [gateway]
             timeout = "1s"
             subgraph_timeout = "2s"
+            drain_period = "10s"
+            shutdown_delay = "5s"
         "#};
 
         let config: Config = toml::from_str(input).unwrap();
@@ -1475,10 +1791,21 @@ mod tests {
             },
             access_logs: AccessLogsConfig {
                 enabled: false,
+                sink: File,
                 path: "",
                 rotate: Never,
+                max_files: None,
+                max_age: None,
+                compress: false,
                 mode: Blocking,
+                syslog: None,
             },
+            drain_period: Some(
+                10s,
+            ),
+            shutdown_delay: Some(
+                5s,
+            ),
         }
         "###);
     }
@@ -1524,11 +1851,37 @@ mod tests {
                     key_prefix: "grafbase",
                     tls: None,
                 },
+                client_key: None,
             },
         )
         "###);
     }
 
+    #[test]
+    fn global_rate_limiting_client_key() {
+        let input = indoc! {r#"
+            [gateway.rate_limit]
+            client_key = { header = "x-api-key" }
+        "#};
+
+        let config = toml::from_str::<Config>(input).unwrap();
+        let client_key = config.gateway.rate_limit.unwrap().client_key.unwrap();
+
+        assert_eq!(client_key, ClientRateLimitKey::Header("x-api-key".into()));
+        assert_eq!(client_key.header_name(), "x-api-key");
+
+        let input = indoc! {r#"
+            [gateway.rate_limit]
+            client_key = "client_certificate"
+        "#};
+
+        let config = toml::from_str::<Config>(input).unwrap();
+        let client_key = config.gateway.rate_limit.unwrap().client_key.unwrap();
+
+        assert_eq!(client_key, ClientRateLimitKey::ClientCertificate);
+        assert_eq!(client_key.header_name(), CLIENT_CERT_SUBJECT_HEADER);
+    }
+
     #[test]
     fn global_rate_limiting_redis_defaults() {
         let input = indoc! {r#"
@@ -1564,6 +1917,7 @@ mod tests {
                     key_prefix: "grafbase",
                     tls: None,
                 },
+                client_key: None,
             },
         )
         "###);
@@ -1609,6 +1963,7 @@ mod tests {
                     key_prefix: "grafbase",
                     tls: None,
                 },
+                client_key: None,
             },
         )
         "###);
@@ -1652,6 +2007,7 @@ mod tests {
                     key_prefix: "kekw",
                     tls: None,
                 },
+                client_key: None,
             },
         )
         "###);
@@ -1706,6 +2062,7 @@ mod tests {
                         },
                     ),
                 },
+                client_key: None,
             },
         )
         "###);
@@ -1763,6 +2120,7 @@ mod tests {
                         },
                     ),
                 },
+                client_key: None,
             },
         )
         "###);
@@ -1847,12 +2205,295 @@ mod tests {
                         retry_mutations: false,
                     },
                 ),
+                hedging: None,
                 entity_caching: None,
+                compression: None,
+                multiplex_subscriptions: false,
+                deduplicate_requests: false,
+                circuit_breaker: None,
+                urls: [],
+                load_balancing: None,
+                http_client: None,
+                health_check: None,
             },
         }
         "###);
     }
 
+    #[test]
+    fn subgraph_hedging() {
+        let input = indoc! {r#"
+            [subgraphs.products.hedging]
+            enabled = true
+            delay = "50ms"
+            percentile = 0.95
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        insta::assert_debug_snapshot!(&config.subgraphs["products"].hedging, @r###"
+        Some(
+            HedgingConfig {
+                enabled: true,
+                delay: Some(
+                    50ms,
+                ),
+                percentile: Some(
+                    0.95,
+                ),
+            },
+        )
+        "###);
+    }
+
+    #[test]
+    fn subgraph_circuit_breaker() {
+        let input = indoc! {r#"
+            [subgraphs.products.circuit_breaker]
+            enabled = true
+            failure_threshold = 3
+            error_rate = 0.5
+            window = "30s"
+            open_duration = "1m"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        insta::assert_debug_snapshot!(&config.subgraphs["products"].circuit_breaker, @r###"
+        Some(
+            CircuitBreakerConfig {
+                enabled: true,
+                failure_threshold: Some(
+                    3,
+                ),
+                error_rate: Some(
+                    0.5,
+                ),
+                minimum_requests: None,
+                window: Some(
+                    30s,
+                ),
+                open_duration: Some(
+                    60s,
+                ),
+                half_open_probes: None,
+            },
+        )
+        "###);
+    }
+
+    #[test]
+    fn subgraph_urls() {
+        let input = indoc! {r#"
+            [subgraphs.products]
+            urls = ["http://products-1:4000/graphql", "http://products-2:4000/graphql"]
+
+            [subgraphs.products.load_balancing]
+            strategy = "least_inflight"
+            ejection_duration = "5s"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+        let subgraph = &config.subgraphs["products"];
+
+        assert_eq!(
+            vec!["http://products-1:4000/graphql", "http://products-2:4000/graphql"],
+            subgraph.urls.iter().map(Url::as_str).collect::<Vec<_>>()
+        );
+
+        insta::assert_debug_snapshot!(&subgraph.load_balancing, @r###"
+        Some(
+            LoadBalancingConfig {
+                strategy: LeastInflight,
+                ejection_duration: Some(
+                    5s,
+                ),
+            },
+        )
+        "###);
+    }
+
+    #[test]
+    fn subgraph_http_client() {
+        let input = indoc! {r#"
+            [subgraphs.products.http_client]
+            proxy = "http://proxy.internal:3128"
+            http2_prior_knowledge = true
+            pool_max_idle_per_host = 16
+            pool_idle_timeout = "30s"
+            connect_timeout = "2s"
+
+            [subgraphs.products.http_client.tls]
+            cert = "/etc/grafbase/client.pem"
+            key = "/etc/grafbase/client.key"
+            ca = ["/etc/grafbase/internal-ca.pem"]
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        insta::assert_debug_snapshot!(&config.subgraphs["products"].http_client, @r###"
+        Some(
+            SubgraphHttpClientConfig {
+                tls: Some(
+                    SubgraphTlsConfig {
+                        cert: Some(
+                            "/etc/grafbase/client.pem",
+                        ),
+                        key: Some(
+                            "/etc/grafbase/client.key",
+                        ),
+                        ca: [
+                            "/etc/grafbase/internal-ca.pem",
+                        ],
+                    },
+                ),
+                proxy: Some(
+                    Url {
+                        scheme: "http",
+                        cannot_be_a_base: false,
+                        username: "",
+                        password: None,
+                        host: Some(
+                            Domain(
+                                "proxy.internal",
+                            ),
+                        ),
+                        port: Some(
+                            3128,
+                        ),
+                        path: "/",
+                        query: None,
+                        fragment: None,
+                    },
+                ),
+                http2_prior_knowledge: true,
+                pool_max_idle_per_host: Some(
+                    16,
+                ),
+                pool_idle_timeout: Some(
+                    30s,
+                ),
+                connect_timeout: Some(
+                    2s,
+                ),
+            },
+        )
+        "###);
+    }
+
+    #[test]
+    fn health_readiness() {
+        let input = indoc! {r#"
+            [health.readiness]
+            path = "/ready"
+            redis = true
+            subgraphs = true
+            timeout = "2s"
+            cache_ttl = "500ms"
+
+            [subgraphs.products.health_check]
+            url = "http://products:4000/healthz"
+
+            [subgraphs.users.health_check]
+            query = "{ me { id } }"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        assert_eq!(
+            config.health.readiness,
+            ReadinessConfig {
+                path: "/ready".into(),
+                redis: true,
+                hooks: false,
+                subgraphs: true,
+                timeout: Duration::from_secs(2),
+                cache_ttl: Duration::from_millis(500),
+            }
+        );
+
+        let products = config.subgraphs["products"].health_check.clone().unwrap();
+        assert_eq!(products.url.unwrap().as_str(), "http://products:4000/healthz");
+        assert_eq!(products.query, "{ __typename }");
+
+        let users = config.subgraphs["users"].health_check.clone().unwrap();
+        assert!(users.enabled);
+        assert_eq!(users.url, None);
+        assert_eq!(users.query, "{ me { id } }");
+    }
+
+    #[test]
+    fn compression_defaults() {
+        let config: Config = toml::from_str("").unwrap();
+
+        assert!(!config.compression.enabled);
+        assert_eq!(Size::from_kibibytes(1), config.compression.min_size);
+        assert_eq!(
+            vec![
+                CompressionAlgorithm::Gzip,
+                CompressionAlgorithm::Brotli,
+                CompressionAlgorithm::Zstd
+            ],
+            config.compression.algorithms
+        );
+    }
+
+    #[test]
+    fn compression() {
+        let input = indoc! {r#"
+            [compression]
+            enabled = true
+            min_size = "4KiB"
+            algorithms = ["br", "gzip"]
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        assert!(config.compression.enabled);
+        assert_eq!(Size::from_kibibytes(4), config.compression.min_size);
+        assert_eq!(
+            vec![CompressionAlgorithm::Brotli, CompressionAlgorithm::Gzip],
+            config.compression.algorithms
+        );
+    }
+
+    #[test]
+    fn subgraph_compression() {
+        let input = indoc! {r#"
+            [subgraphs.products.compression]
+            accept_compressed_responses = true
+            entities_request = "zstd"
+            entities_request_min_size = "16KiB"
+            max_decompressed_response_size = "8MiB"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+        let compression = config.subgraphs["products"].compression.clone().unwrap();
+
+        assert!(compression.accept_compressed_responses);
+        assert_eq!(Some(CompressionAlgorithm::Zstd), compression.entities_request);
+        assert_eq!(Size::from_kibibytes(16), compression.entities_request_min_size);
+        assert_eq!(Size::from_mebibytes(8), compression.max_decompressed_response_size);
+    }
+
+    #[test]
+    fn compression_min_size_too_large() {
+        let input = indoc! {r#"
+            [compression]
+            min_size = 65536
+        "#};
+
+        let error = toml::from_str::<Config>(input).unwrap_err();
+
+        insta::assert_snapshot!(&error.to_string(), @r###"
+        TOML parse error at line 2, column 12
+          |
+        2 | min_size = 65536
+          |            ^^^^^
+        min_size must be at most 65535 bytes
+        "###);
+    }
+
     #[test]
     fn access_logs_default() {
         let input = indoc! {r#"
@@ -1866,9 +2507,14 @@ mod tests {
         insta::assert_debug_snapshot!(&config.gateway.access_logs, @r###"
         AccessLogsConfig {
             enabled: true,
+            sink: File,
             path: "/path",
             rotate: Never,
+            max_files: None,
+            max_age: None,
+            compress: false,
             mode: Blocking,
+            syslog: None,
         }
         "###);
     }
@@ -1887,9 +2533,14 @@ mod tests {
         insta::assert_debug_snapshot!(&config.gateway.access_logs, @r###"
         AccessLogsConfig {
             enabled: true,
+            sink: File,
             path: "/path",
             rotate: Minutely,
+            max_files: None,
+            max_age: None,
+            compress: false,
             mode: Blocking,
+            syslog: None,
         }
         "###);
     }
@@ -1908,11 +2559,16 @@ mod tests {
         insta::assert_debug_snapshot!(&config.gateway.access_logs, @r###"
         AccessLogsConfig {
             enabled: true,
+            sink: File,
             path: "/path",
             rotate: Size(
                 1024 bytes,
             ),
+            max_files: None,
+            max_age: None,
+            compress: false,
             mode: Blocking,
+            syslog: None,
         }
         "###);
 
@@ -1928,12 +2584,111 @@ mod tests {
         insta::assert_debug_snapshot!(&config.gateway.access_logs, @r###"
         AccessLogsConfig {
             enabled: true,
+            sink: File,
             path: "/path",
             rotate: Size(
                 1024 bytes,
             ),
+            max_files: None,
+            max_age: None,
+            compress: false,
+            mode: Blocking,
+            syslog: None,
+        }
+        "###);
+    }
+
+    #[test]
+    fn access_logs_retention() {
+        let input = indoc! {r#"
+            [gateway.access_logs]
+            enabled = true
+            path = "/path"
+            rotate = "daily"
+            max_files = 7
+            max_age = "30d"
+            compress = true
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        insta::assert_debug_snapshot!(&config.gateway.access_logs, @r###"
+        AccessLogsConfig {
+            enabled: true,
+            sink: File,
+            path: "/path",
+            rotate: Daily,
+            max_files: Some(
+                7,
+            ),
+            max_age: Some(
+                2592000s,
+            ),
+            compress: true,
+            mode: Blocking,
+            syslog: None,
+        }
+        "###);
+    }
+
+    #[test]
+    fn access_logs_syslog() {
+        let input = indoc! {r#"
+            [gateway.access_logs]
+            enabled = true
+            sink = "syslog"
+
+            [gateway.access_logs.syslog]
+            address = "logs.internal:514"
+            protocol = "tcp"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        insta::assert_debug_snapshot!(&config.gateway.access_logs, @r###"
+        AccessLogsConfig {
+            enabled: true,
+            sink: Syslog,
+            path: "",
+            rotate: Never,
+            max_files: None,
+            max_age: None,
+            compress: false,
             mode: Blocking,
+            syslog: Some(
+                SyslogConfig {
+                    address: "logs.internal:514",
+                    protocol: Tcp,
+                    app_name: "grafbase-gateway",
+                },
+            ),
         }
         "###);
     }
+
+    #[test]
+    fn tls_client_auth() {
+        let input = indoc! {r#"
+            [tls]
+            certificate = "/etc/grafbase/cert.pem"
+            key = "/etc/grafbase/key.pem"
+            client_ca = "/etc/grafbase/client-ca.pem"
+            client_auth = "optional"
+        "#};
+
+        let config: Config = toml::from_str(input).unwrap();
+
+        insta::assert_debug_snapshot!(&config.tls, @r###"
+        Some(
+            TlsConfig {
+                certificate: "/etc/grafbase/cert.pem",
+                key: "/etc/grafbase/key.pem",
+                client_ca: Some(
+                    "/etc/grafbase/client-ca.pem",
+                ),
+                client_auth: Optional,
+            },
+        )
+        "###);
+    }
 }
@@ -0,0 +1,56 @@
+use crate::EntityCachingRedisTlsConfig;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OperationCachingConfig {
+    /// Whether prepared operations are cached at all. True by default.
+    pub enabled: bool,
+    /// Maximum number of prepared operations kept in memory. Defaults to 1000.
+    pub limit: usize,
+    /// Where prepared operations are shared between gateway instances.
+    pub storage: OperationCachingStorage,
+    pub redis: OperationCachingRedisConfig,
+    /// Prepare the most used operations against a new graph before it starts serving requests.
+    pub warm_on_reload: bool,
+    /// How many of the most used operations are prepared during warm-up. Defaults to 100.
+    pub warming_limit: usize,
+}
+
+impl Default for OperationCachingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            limit: 1000,
+            storage: Default::default(),
+            redis: Default::default(),
+            warm_on_reload: false,
+            warming_limit: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationCachingStorage {
+    #[default]
+    Memory,
+    Redis,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OperationCachingRedisConfig {
+    pub url: url::Url,
+    pub key_prefix: String,
+    pub tls: Option<EntityCachingRedisTlsConfig>,
+}
+
+impl Default for OperationCachingRedisConfig {
+    fn default() -> Self {
+        Self {
+            url: url::Url::parse("redis://localhost:6379").expect("must be correct"),
+            key_prefix: String::from("grafbase-operation-cache"),
+            tls: None,
+        }
+    }
+}
@@ -20,6 +20,34 @@ pub struct RateLimitConfig {
     pub storage: RateLimitStorage,
     #[serde(default)]
     pub redis: RateLimitRedisConfig,
+    /// Counts the global limit separately for each client, instead of for all requests together.
+    pub client_key: Option<ClientRateLimitKey>,
+}
+
+/// How clients are told apart by the global rate limit.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientRateLimitKey {
+    /// The value of a request header. Requests without it share a single limit.
+    Header(String),
+    /// The subject of the verified TLS client certificate, see `tls.client_ca`.
+    ClientCertificate,
+}
+
+/// Header set by the gateway to the subject of the verified TLS client certificate.
+pub const CLIENT_CERT_SUBJECT_HEADER: &str = "x-grafbase-client-cert-subject";
+
+/// Header set by the gateway to the subject alternative names of the verified TLS client certificate.
+pub const CLIENT_CERT_SANS_HEADER: &str = "x-grafbase-client-cert-sans";
+
+impl ClientRateLimitKey {
+    /// The header identifying the client.
+    pub fn header_name(&self) -> &str {
+        match self {
+            Self::Header(name) => name,
+            Self::ClientCertificate => CLIENT_CERT_SUBJECT_HEADER,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
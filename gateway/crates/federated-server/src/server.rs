@@ -1,4 +1,5 @@
 mod access_logs;
+mod compression;
 mod cors;
 mod csrf;
 mod gateway;
@@ -6,6 +7,7 @@ mod graph_fetch_method;
 mod graph_updater;
 mod health;
 mod state;
+mod tls;
 mod trusted_documents_client;
 
 pub use graph_fetch_method::GraphFetchMethod;
@@ -19,7 +21,7 @@ use engine_v2_axum::{
     middleware::{ResponseHookLayer, TelemetryLayer},
     websocket::{WebsocketAccepter, WebsocketService},
 };
-use gateway_config::{Config, TlsConfig};
+use gateway_config::{ClientRateLimitKey, Config, TlsConfig};
 use state::ServerState;
 use std::{net::SocketAddr, path::PathBuf, time::Duration};
 use tokio::signal;
@@ -85,6 +87,9 @@ pub async fn serve(
     let (sender, mut gateway) = watch::channel(None);
     gateway.mark_unchanged();
 
+    let (drain_sender, draining) = watch::channel(false);
+    let (shutdown_sender, shutdown) = watch::channel(false);
+
     let meter = grafbase_telemetry::metrics::meter_from_global_provider();
     let pending_logs_counter = meter.i64_up_down_counter("grafbase.gateway.access_log.pending").init();
 
@@ -107,11 +112,17 @@ pub async fn serve(
             config_hot_reload.then_some(config_path).flatten(),
             sender,
             hooks.clone(),
+            shutdown,
         )
         .await?;
 
     if config.gateway.access_logs.enabled {
-        access_logs::start(&config.gateway.access_logs, access_log_receiver, pending_logs_counter)?;
+        access_logs::start(
+            &config.gateway.access_logs,
+            &config.telemetry,
+            access_log_receiver,
+            pending_logs_counter,
+        )?;
     }
 
     let (websocket_sender, websocket_receiver) = mpsc::channel(16);
@@ -127,6 +138,8 @@ pub async fn serve(
     let state = ServerState::new(
         gateway.clone(),
         config.request_body_limit.bytes().max(0) as usize,
+        health::ReadinessChecks::new(&config),
+        draining,
         server_runtime.clone(),
     );
 
@@ -155,7 +168,9 @@ pub async fn serve(
                 state.clone(),
             ));
         } else {
-            router = router.route(&config.health.path, get(health::health));
+            router = router
+                .route(&config.health.path, get(health::health))
+                .route(&config.health.readiness.path, get(health::readiness));
         }
     }
 
@@ -165,8 +180,26 @@ pub async fn serve(
         router = csrf::inject_layer(router);
     }
 
+    let client_certificate_rate_limit = config
+        .gateway
+        .rate_limit
+        .as_ref()
+        .is_some_and(|rate_limit| rate_limit.client_key == Some(ClientRateLimitKey::ClientCertificate));
+
+    // Also strips the certificate headers sent by clients, which could otherwise pick their rate limit.
+    if config.tls.as_ref().is_some_and(|tls| tls.client_ca.is_some()) || client_certificate_rate_limit {
+        router = tls::inject_client_certificate_layer(router);
+    }
+
+    if config.compression.enabled {
+        router = router.layer(compression::generate(&config.compression));
+    }
+
     cfg_if::cfg_if! {
         if #[cfg(feature = "lambda")] {
+            // Lambda instances are not drained.
+            drop(drain_sender);
+            drop(shutdown_sender);
             let result = lambda_bind(path, router).await;
         } else {
             use std::net::{IpAddr, Ipv4Addr};
@@ -177,7 +210,13 @@ pub async fn serve(
                 .or(config.network.listen_address)
                 .unwrap_or(DEFAULT_LISTEN_ADDRESS);
 
-            let result = bind(addr, path, router, config.tls.as_ref()).await;
+            let shutdown = GracefulShutdown {
+                drain_sender,
+                shutdown_sender,
+                delay: config.gateway.shutdown_delay.unwrap_or_default(),
+                drain_period: config.gateway.drain_period.unwrap_or(DEFAULT_DRAIN_PERIOD),
+            };
+            let result = bind(addr, path, router, config.tls.as_ref(), shutdown).await;
         }
     }
 
@@ -190,23 +229,28 @@ pub async fn serve(
 }
 
 #[cfg_attr(feature = "lambda", allow(unused))]
-async fn bind(addr: SocketAddr, path: &str, router: Router<()>, tls: Option<&TlsConfig>) -> crate::Result<()> {
+async fn bind(
+    addr: SocketAddr,
+    path: &str,
+    router: Router<()>,
+    tls: Option<&TlsConfig>,
+    shutdown: GracefulShutdown,
+) -> crate::Result<()> {
     let app = router.into_make_service();
 
     let handle = axum_server::Handle::new();
 
     // Spawn a task to gracefully shutdown server.
-    tokio::spawn(graceful_shutdown(handle.clone()));
+    tokio::spawn(shutdown.run(handle.clone()));
 
     match tls {
         Some(tls) => {
             tracing::info!("GraphQL endpoint exposed at https://{addr}{path}");
 
-            let rustls_config = axum_server::tls_rustls::RustlsConfig::from_pem_file(&tls.certificate, &tls.key)
-                .await
-                .map_err(crate::Error::CertificateError)?;
+            let reloading_tls = tls::ReloadingTls::load(tls, true)?;
 
-            axum_server::bind_rustls(addr, rustls_config)
+            axum_server::bind(addr)
+                .acceptor(tls::ClientCertificateAcceptor::new(reloading_tls.config.clone()))
                 .handle(handle)
                 .serve(app)
                 .await
@@ -274,40 +318,78 @@ where
     response
 }
 
-/// Waits for a termination signal and initiates a graceful shutdown of the server.
-///
-/// # Arguments
-///
-/// * `handle`: The handle for the server to manage graceful shutdown.
-///
-/// # Description
-///
-/// This function listens for termination signals (Ctrl+C or Unix termination signals)
-/// and triggers a graceful shutdown of the server, allowing ongoing requests to complete
-/// before shutting down.
-async fn graceful_shutdown(handle: axum_server::Handle) {
-    let ctrl_c = async {
-        signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
-    };
+/// Shutdown sequence of the server, started by a termination signal.
+#[cfg_attr(feature = "lambda", allow(unused))]
+struct GracefulShutdown {
+    /// Makes the readiness endpoint fail.
+    drain_sender: watch::Sender<bool>,
+    /// Completes the streaming responses and closes the websocket connections.
+    shutdown_sender: watch::Sender<bool>,
+    /// How long the readiness endpoint fails before new connections are refused.
+    delay: Duration,
+    /// How long in-flight requests are given to finish.
+    drain_period: Duration,
+}
 
-    #[cfg(unix)]
-    let terminate = async {
-        signal::unix::signal(signal::unix::SignalKind::terminate())
-            .expect("failed to install signal handler")
-            .recv()
-            .await;
-    };
+#[cfg_attr(feature = "lambda", allow(unused))]
+impl GracefulShutdown {
+    /// Waits for a termination signal and shuts the server down gracefully.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle`: The handle for the server to manage graceful shutdown.
+    ///
+    /// # Description
+    ///
+    /// This function listens for termination signals (Ctrl+C or Unix termination signals).
+    /// The readiness endpoint fails first, so that load balancers stop routing traffic to the
+    /// gateway. After the shutdown delay new connections are refused and ongoing requests are
+    /// allowed to complete within the drain period. At its end, streaming responses are completed
+    /// and websocket connections are closed with a going away code, before the remaining
+    /// connections are closed.
+    async fn run(self, handle: axum_server::Handle) {
+        let ctrl_c = async {
+            signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+        };
+
+        #[cfg(unix)]
+        let terminate = async {
+            signal::unix::signal(signal::unix::SignalKind::terminate())
+                .expect("failed to install signal handler")
+                .recv()
+                .await;
+        };
+
+        #[cfg(not(unix))]
+        let terminate = std::future::pending::<()>();
+
+        tokio::select! {
+            _ = ctrl_c => {},
+            _ = terminate => {},
+        }
 
-    #[cfg(not(unix))]
-    let terminate = std::future::pending::<()>();
+        let Self {
+            drain_sender,
+            shutdown_sender,
+            delay,
+            drain_period,
+        } = self;
 
-    tokio::select! {
-        _ = ctrl_c => {},
-        _ = terminate => {},
-    }
+        drain_sender.send_replace(true);
 
-    tracing::info!("Shutting down gracefully...");
-    handle.graceful_shutdown(Some(std::time::Duration::from_secs(3)));
+        if !delay.is_zero() {
+            tracing::info!("Shutting down gracefully in {delay:?}...");
+            tokio::time::sleep(delay).await;
+        }
+
+        tracing::info!("Shutting down gracefully, draining connections for {drain_period:?}...");
+        handle.graceful_shutdown(None);
+        tokio::time::sleep(drain_period).await;
+
+        shutdown_sender.send_replace(true);
+        tokio::time::sleep(STREAM_CLOSE_PERIOD).await;
+        handle.shutdown();
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -329,3 +411,8 @@ pub struct GdnResponse {
 }
 
 const DEFAULT_GATEWAY_TIMEOUT: Duration = Duration::from_secs(30);
+#[cfg_attr(feature = "lambda", allow(unused))]
+const DEFAULT_DRAIN_PERIOD: Duration = Duration::from_secs(3);
+/// How long streams and websockets are given to send their last messages at the end of the drain period.
+#[cfg_attr(feature = "lambda", allow(unused))]
+const STREAM_CLOSE_PERIOD: Duration = Duration::from_secs(1);
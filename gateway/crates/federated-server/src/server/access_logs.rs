@@ -1,21 +1,28 @@
-use gateway_config::{AccessLogsConfig, RotateMode};
+mod otlp;
+mod syslog;
+
+use gateway_config::{AccessLogSink, AccessLogsConfig, RotateMode, TelemetryConfig};
 use grafbase_telemetry::otel::opentelemetry::metrics::UpDownCounter;
-use rolling_logger::{RollingLogger, RotateStrategy};
+use otlp::OtlpWriter;
+use rolling_logger::{Retention, RollingLogger, RotateStrategy};
 use runtime_local::hooks::{AccessLogMessage, ChannelLogReceiver};
-use std::io::Write;
+use std::io::{self, Write};
+use syslog::SyslogWriter;
 
 /// Starts the access logging process.
 ///
 /// This function initializes the logging mechanism based on the provided
 /// configuration and begins receiving log messages from the specified
-/// channel. It handles different rotation strategies for the log files
-/// and ensures that logs are written correctly. The function runs in a
+/// channel. Logs are written to the configured sink: rolling files, the
+/// standard output, an OpenTelemetry logs exporter or a syslog server. The function runs in a
 /// blocking task to allow asynchronous operations to continue.
 ///
 /// # Arguments
 ///
 /// - `config`: The configuration for the access logs, which includes
-///   the path and rotation settings.
+///   the sink, and the path and rotation settings of the file sink.
+/// - `telemetry`: The telemetry configuration, whose OTLP exporter is
+///   used by the otlp sink.
 /// - `access_log_receiver`: A channel receiver to receive log messages.
 /// - `pending_logs_counter`: A counter to track the number of pending
 ///   logs for monitoring purposes.
@@ -31,19 +38,11 @@ use std::io::Write;
 /// logger initialization or during log writing operations.
 pub(crate) fn start(
     config: &AccessLogsConfig,
+    telemetry: &TelemetryConfig,
     access_log_receiver: ChannelLogReceiver,
     pending_logs_counter: UpDownCounter<i64>,
 ) -> crate::Result<()> {
-    let strategy = match config.rotate {
-        RotateMode::Never => RotateStrategy::never(),
-        RotateMode::Minutely => RotateStrategy::minutely(),
-        RotateMode::Hourly => RotateStrategy::hourly(),
-        RotateMode::Daily => RotateStrategy::daily(),
-        RotateMode::Size(max_size) => RotateStrategy::size(max_size.bytes().max(0).unsigned_abs()),
-    };
-
-    let mut log = RollingLogger::new(&config.path.join("access.log"), strategy)
-        .map_err(|e| crate::Error::InternalError(format!("unable to initialize access logs: {e}")))?;
+    let mut sink = Sink::new(config, telemetry)?;
 
     tokio::task::spawn_blocking(move || {
         while let Ok(msg) = access_log_receiver.recv() {
@@ -51,12 +50,12 @@ pub(crate) fn start(
 
             match msg {
                 AccessLogMessage::Data(data) => {
-                    if let Err(e) = log.write_all(&data).and_then(|_| log.write(b"\n")) {
+                    if let Err(e) = sink.write(&data) {
                         tracing::error!("error writing to access log: {e}");
                     }
                 }
                 AccessLogMessage::Shutdown(guard) => {
-                    if let Err(e) = log.flush() {
+                    if let Err(e) = sink.flush() {
                         tracing::error!("error flushing access log: {e}");
                     }
 
@@ -68,3 +67,78 @@ pub(crate) fn start(
 
     Ok(())
 }
+
+enum Sink {
+    File(RollingLogger),
+    Stdout(io::Stdout),
+    Otlp(OtlpWriter),
+    Syslog(SyslogWriter),
+}
+
+impl Sink {
+    fn new(config: &AccessLogsConfig, telemetry: &TelemetryConfig) -> crate::Result<Self> {
+        let init_error = |e: io::Error| crate::Error::InternalError(format!("unable to initialize access logs: {e}"));
+
+        match config.sink {
+            AccessLogSink::File => {
+                let strategy = match config.rotate {
+                    RotateMode::Never => RotateStrategy::never(),
+                    RotateMode::Minutely => RotateStrategy::minutely(),
+                    RotateMode::Hourly => RotateStrategy::hourly(),
+                    RotateMode::Daily => RotateStrategy::daily(),
+                    RotateMode::Size(max_size) => RotateStrategy::size(max_size.bytes().max(0).unsigned_abs()),
+                };
+
+                let retention = Retention {
+                    max_files: config.max_files,
+                    max_age: config.max_age,
+                };
+
+                let logger = RollingLogger::new(&config.path.join("access.log"), strategy)
+                    .map_err(init_error)?
+                    .with_retention(retention)
+                    .with_compression(config.compress);
+
+                Ok(Sink::File(logger))
+            }
+            AccessLogSink::Stdout => Ok(Sink::Stdout(io::stdout())),
+            AccessLogSink::Otlp => Ok(Sink::Otlp(OtlpWriter::new(telemetry)?)),
+            AccessLogSink::Syslog => {
+                let syslog = config.syslog.as_ref().ok_or_else(|| {
+                    crate::Error::InternalError(
+                        "the syslog access logs sink requires a gateway.access_logs.syslog section".into(),
+                    )
+                })?;
+
+                Ok(Sink::Syslog(SyslogWriter::new(syslog).map_err(init_error)?))
+            }
+        }
+    }
+
+    fn write(&mut self, data: &[u8]) -> io::Result<()> {
+        match self {
+            Sink::File(log) => log.write_all(data).and_then(|_| log.write_all(b"\n")),
+            Sink::Stdout(stdout) => {
+                let mut stdout = stdout.lock();
+                stdout.write_all(data).and_then(|_| stdout.write_all(b"\n"))
+            }
+            Sink::Otlp(otlp) => {
+                otlp.send(data);
+                Ok(())
+            }
+            Sink::Syslog(syslog) => syslog.send(data),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Sink::File(log) => log.flush(),
+            Sink::Stdout(stdout) => stdout.flush(),
+            Sink::Otlp(otlp) => {
+                otlp.flush();
+                Ok(())
+            }
+            Sink::Syslog(syslog) => syslog.flush(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::time::SystemTime;
+
+use gateway_config::TelemetryConfig;
+use grafbase_telemetry::otel::{
+    opentelemetry::logs::{AnyValue, LogRecord, Logger as _, LoggerProvider as _, Severity},
+    opentelemetry_sdk::{
+        logs::{Logger, LoggerProvider},
+        runtime::Tokio,
+    },
+};
+
+/// Sends access logs as OpenTelemetry log records, using the global OTLP exporter.
+pub(super) struct OtlpWriter {
+    provider: LoggerProvider,
+    logger: Logger,
+}
+
+impl OtlpWriter {
+    pub fn new(config: &TelemetryConfig) -> crate::Result<Self> {
+        let provider = grafbase_telemetry::otel::logs::build_access_logs_provider(Tokio, config)
+            .map_err(|e| crate::Error::InternalError(format!("unable to initialize access logs: {e}")))?
+            .ok_or_else(|| {
+                crate::Error::InternalError(
+                    "the otlp access logs sink requires the telemetry.exporters.otlp exporter to be enabled".into(),
+                )
+            })?;
+
+        let logger = provider.logger("grafbase-access-logs");
+
+        Ok(Self { provider, logger })
+    }
+
+    pub fn send(&self, data: &[u8]) {
+        let record = LogRecord::builder()
+            .with_body(AnyValue::from(String::from_utf8_lossy(data).into_owned()))
+            .with_severity_number(Severity::Info)
+            .with_observed_timestamp(SystemTime::now())
+            .build();
+
+        self.logger.emit(record);
+    }
+
+    pub fn flush(&self) {
+        for result in self.provider.force_flush() {
+            if let Err(e) = result {
+                tracing::error!("error exporting access logs: {e}");
+            }
+        }
+    }
+}
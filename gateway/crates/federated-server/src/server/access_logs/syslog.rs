@@ -0,0 +1,162 @@
+use std::{
+    io::{self, Write},
+    net::{SocketAddr, TcpStream, ToSocketAddrs, UdpSocket},
+    time::{Duration, Instant},
+};
+
+use gateway_config::{SyslogConfig, SyslogProtocol};
+use grafbase_telemetry::otel::opentelemetry::metrics::Counter;
+
+/// Facility `user`, severity `informational`.
+const PRIORITY: u8 = 14;
+
+/// Maximum duration of a connection attempt or of a write to the syslog server, so that an
+/// unreachable server does not stall the access logs.
+const IO_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Delay before the first reconnection attempt, doubled after every failed attempt.
+const MIN_RECONNECT_DELAY: Duration = Duration::from_millis(100);
+
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// Sends access logs to a syslog server, formatted following RFC 5424.
+///
+/// Over TCP, messages are framed with their length as described in RFC 6587. A broken TCP
+/// connection is re-established with an exponential backoff, messages being dropped until then.
+pub(super) struct SyslogWriter {
+    app_name: String,
+    transport: Transport,
+}
+
+enum Transport {
+    Udp(UdpSocket),
+    Tcp(TcpTransport),
+}
+
+struct TcpTransport {
+    address: SocketAddr,
+    stream: Option<TcpStream>,
+    reconnect_at: Instant,
+    reconnect_delay: Duration,
+    /// Messages dropped since the connection was lost.
+    dropped: u64,
+    dropped_counter: Counter<u64>,
+}
+
+impl SyslogWriter {
+    pub fn new(config: &SyslogConfig) -> io::Result<Self> {
+        let address = config.address.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("could not resolve syslog address {}", config.address),
+            )
+        })?;
+
+        let transport = match config.protocol {
+            SyslogProtocol::Udp => {
+                let local: SocketAddr = if address.is_ipv4() {
+                    ([0, 0, 0, 0], 0).into()
+                } else {
+                    ([0u16; 8], 0).into()
+                };
+
+                let socket = UdpSocket::bind(local)?;
+                socket.connect(address)?;
+
+                Transport::Udp(socket)
+            }
+            SyslogProtocol::Tcp => {
+                let meter = grafbase_telemetry::metrics::meter_from_global_provider();
+
+                Transport::Tcp(TcpTransport {
+                    address,
+                    stream: Some(connect(address)?),
+                    reconnect_at: Instant::now(),
+                    reconnect_delay: MIN_RECONNECT_DELAY,
+                    dropped: 0,
+                    dropped_counter: meter.u64_counter("grafbase.gateway.access_log.dropped").init(),
+                })
+            }
+        };
+
+        Ok(Self {
+            app_name: config.app_name.clone(),
+            transport,
+        })
+    }
+
+    pub fn send(&mut self, data: &[u8]) -> io::Result<()> {
+        let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+        let mut message = format!("<{PRIORITY}>1 {timestamp} - {} - - - ", self.app_name).into_bytes();
+        message.extend_from_slice(data);
+
+        match &mut self.transport {
+            Transport::Udp(socket) => socket.send(&message).map(|_| ()),
+            Transport::Tcp(tcp) => tcp.send(&message),
+        }
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        match &mut self.transport {
+            Transport::Udp(_) => Ok(()),
+            Transport::Tcp(tcp) => tcp.stream.as_mut().map(|stream| stream.flush()).unwrap_or(Ok(())),
+        }
+    }
+}
+
+impl TcpTransport {
+    fn send(&mut self, message: &[u8]) -> io::Result<()> {
+        let mut connection = match self.stream.take() {
+            Some(connection) => connection,
+            None if Instant::now() < self.reconnect_at => {
+                self.drop_message();
+                return Ok(());
+            }
+            None => match connect(self.address) {
+                Ok(connection) => {
+                    if self.dropped > 0 {
+                        tracing::warn!(
+                            "dropped {} access logs while disconnected from the syslog server",
+                            self.dropped
+                        );
+                    }
+
+                    self.dropped = 0;
+                    self.reconnect_delay = MIN_RECONNECT_DELAY;
+                    connection
+                }
+                Err(err) => {
+                    self.reconnect_at = Instant::now() + self.reconnect_delay;
+                    self.reconnect_delay = (self.reconnect_delay * 2).min(MAX_RECONNECT_DELAY);
+                    self.drop_message();
+
+                    return Err(err);
+                }
+            },
+        };
+
+        let result = write!(connection, "{} ", message.len())
+            .and_then(|_| connection.write_all(message))
+            .and_then(|_| connection.flush());
+
+        match result {
+            Ok(()) => self.stream = Some(connection),
+            Err(_) => self.drop_message(),
+        }
+
+        result
+    }
+
+    fn drop_message(&mut self) {
+        self.dropped += 1;
+        self.dropped_counter.add(1, &[]);
+    }
+}
+
+fn connect(address: SocketAddr) -> io::Result<TcpStream> {
+    let stream = TcpStream::connect_timeout(&address, IO_TIMEOUT)?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    Ok(stream)
+}
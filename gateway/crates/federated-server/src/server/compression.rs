@@ -0,0 +1,26 @@
+use gateway_config::{CompressionAlgorithm, CompressionConfig};
+use tower_http::compression::{
+    predicate::{NotForContentType, Predicate, SizeAbove},
+    CompressionLayer,
+};
+
+/// Generates a response compression layer based on the provided configuration.
+///
+/// The algorithm is negotiated with the client through the `Accept-Encoding` header. Streaming
+/// responses (`text/event-stream` and `multipart/mixed`) are never compressed, as the compressor
+/// would buffer the payloads before sending them to the client.
+pub(super) fn generate(config: &CompressionConfig) -> CompressionLayer<impl Predicate> {
+    let min_size = u16::try_from(config.min_size.bytes()).expect("min_size is validated when parsing the config");
+
+    let predicate = SizeAbove::new(min_size)
+        .and(NotForContentType::GRPC)
+        .and(NotForContentType::IMAGES)
+        .and(NotForContentType::SSE)
+        .and(NotForContentType::const_new("multipart/mixed"));
+
+    CompressionLayer::new()
+        .gzip(config.is_algorithm_enabled(CompressionAlgorithm::Gzip))
+        .br(config.is_algorithm_enabled(CompressionAlgorithm::Brotli))
+        .zstd(config.is_algorithm_enabled(CompressionAlgorithm::Zstd))
+        .compress_when(predicate)
+}
@@ -1,7 +1,7 @@
 use super::GdnResponse;
 use engine_v2::{Engine, SchemaVersion};
 use gateway_config::Config;
-use graphql_composition::VersionedFederatedGraph;
+use graphql_composition::{ContractFilter, VersionedFederatedGraph};
 use runtime::trusted_documents_client::Client;
 use runtime_local::HooksWasi;
 use std::{path::PathBuf, sync::Arc};
@@ -11,6 +11,7 @@ use ulid::Ulid;
 pub use gateway_runtime::GatewayRuntime;
 
 mod gateway_runtime;
+mod operation_cache;
 
 /// Send half of the gateway watch channel
 pub(crate) type GatewaySender = watch::Sender<Option<Arc<Engine<GatewayRuntime>>>>;
@@ -20,6 +21,10 @@ pub(crate) type GatewaySender = watch::Sender<Option<Arc<Engine<GatewayRuntime>>
 /// Anything part of the system that needs access to the gateway can use this
 pub(crate) type EngineWatcher = watch::Receiver<Option<Arc<Engine<GatewayRuntime>>>>;
 
+/// Set to true on shutdown: at the start of the drain for the readiness endpoint, at its end
+/// for the streams and websockets to close.
+pub(crate) type DrainWatcher = watch::Receiver<bool>;
+
 pub(crate) enum GraphDefinition {
     /// Response from GDN.
     Gdn(GdnResponse),
@@ -45,11 +50,13 @@ struct Graph {
 /// - `gateway_config`: The configuration settings for the gateway.
 /// - `hot_reload_config_path`: An optional path for hot reload configuration.
 /// - `hooks`: The hooks to be used in the gateway.
+/// - `shutdown`: Signals the end of the drain period, closing streams and websockets.
 pub(super) async fn generate(
     graph_definition: GraphDefinition,
     gateway_config: &Config,
     hot_reload_config_path: Option<PathBuf>,
     hooks: HooksWasi,
+    shutdown: DrainWatcher,
 ) -> crate::Result<Engine<GatewayRuntime>> {
     let Graph {
         federated_sdl,
@@ -62,13 +69,29 @@ pub(super) async fn generate(
     };
 
     let config = {
-        let graph = VersionedFederatedGraph::from_sdl(&federated_sdl)
-            .map_err(|e| crate::Error::SchemaValidationError(e.to_string()))?;
-
-        engine_config_builder::build_with_toml_config(gateway_config, graph.into_latest()).into_latest()
+        let mut graph = VersionedFederatedGraph::from_sdl(&federated_sdl)
+            .map_err(|e| crate::Error::SchemaValidationError(e.to_string()))?
+            .into_latest();
+
+        if let Some(contract) = &gateway_config.graph.contract {
+            graph.apply_contract(&ContractFilter {
+                include: contract.include_tags.iter().cloned().collect(),
+                exclude: contract.exclude_tags.iter().cloned().collect(),
+            });
+        }
+
+        engine_config_builder::build_with_toml_config(gateway_config, graph).into_latest()
     };
 
-    let mut runtime = GatewayRuntime::build(gateway_config, hot_reload_config_path, &config, version_id, hooks).await?;
+    let mut runtime = GatewayRuntime::build(
+        gateway_config,
+        hot_reload_config_path,
+        &config,
+        version_id,
+        hooks,
+        shutdown,
+    )
+    .await?;
 
     if let Some(trusted_documents) = trusted_documents {
         runtime.trusted_documents = trusted_documents;
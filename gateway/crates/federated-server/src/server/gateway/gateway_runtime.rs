@@ -1,15 +1,17 @@
 use std::path::PathBuf;
 
-use gateway_config::{Config, EntityCachingRedisConfig};
+use gateway_config::{Config, EntityCachingRedisConfig, KvRedisConfig, OperationCachingRedisConfig};
 use grafbase_telemetry::metrics::EngineMetrics;
 use runtime::entity_cache::EntityCache;
 use runtime_local::{
     rate_limiting::{in_memory::key_based::InMemoryRateLimiter, redis::RedisRateLimiter},
     redis::{RedisPoolFactory, RedisTlsConfig},
-    HooksWasi, InMemoryEntityCache, InMemoryKvStore, InMemoryOperationCacheFactory, NativeFetcher, RedisEntityCache,
+    HooksWasi, InMemoryEntityCache, InMemoryKvStore, InMemoryOperationCacheConfig, InMemoryOperationCacheFactory,
+    NativeFetcher, RedisEntityCache, RedisKvStore, RedisOperationCacheFactory,
 };
 use runtime_noop::trusted_documents::NoopTrustedDocuments;
 
+use super::{operation_cache::GatewayOperationCacheFactory, DrainWatcher};
 use crate::hot_reload::ConfigWatcher;
 
 /// Represents the runtime environment for the gateway, managing various components
@@ -22,7 +24,10 @@ pub struct GatewayRuntime {
     hooks: HooksWasi,
     rate_limiter: runtime::rate_limiting::RateLimiter,
     entity_cache: Box<dyn EntityCache>,
-    operation_cache_factory: InMemoryOperationCacheFactory,
+    operation_cache_factory: GatewayOperationCacheFactory,
+    /// Redis pools checked by the readiness endpoint, with the feature using them.
+    redis_pools: Vec<(&'static str, runtime_local::redis::Pool)>,
+    shutdown: DrainWatcher,
 }
 
 impl GatewayRuntime {
@@ -32,8 +37,10 @@ impl GatewayRuntime {
         config: &engine_v2::config::Config,
         version_id: Option<ulid::Ulid>,
         hooks: HooksWasi,
+        shutdown: DrainWatcher,
     ) -> Result<GatewayRuntime, crate::Error> {
         let mut redis_factory = RedisPoolFactory::default();
+        let mut redis_pools = Vec::new();
         let watcher = ConfigWatcher::init(gateway_config.clone(), hot_reload_config_path)?;
         let meter = grafbase_telemetry::metrics::meter_from_global_provider();
         let rate_limiter = match config.rate_limit_config() {
@@ -48,6 +55,8 @@ impl GatewayRuntime {
                     .pool(config.redis.url, tls)
                     .map_err(|e| crate::Error::InternalError(e.to_string()))?;
 
+                redis_pools.push(("rate_limiting", pool.clone()));
+
                 let global_config = runtime_local::rate_limiting::redis::RateLimitRedisConfig {
                     key_prefix: config.redis.key_prefix,
                 };
@@ -73,29 +82,95 @@ impl GatewayRuntime {
                     .pool(url.as_str(), tls)
                     .map_err(|e| crate::Error::InternalError(e.to_string()))?;
 
+                redis_pools.push(("entity_caching", pool.clone()));
+
                 Box::new(RedisEntityCache::new(pool, key_prefix))
             }
         };
 
+        let kv = match gateway_config.kv.storage {
+            gateway_config::KvStorage::Memory => InMemoryKvStore::runtime(),
+            gateway_config::KvStorage::Redis => {
+                let KvRedisConfig { url, key_prefix, tls } = &gateway_config.kv.redis;
+
+                let tls = tls.as_ref().map(|tls| RedisTlsConfig {
+                    cert: tls.cert.as_deref(),
+                    key: tls.key.as_deref(),
+                    ca: tls.ca.as_deref(),
+                });
+
+                let pool = redis_factory
+                    .pool(url.as_str(), tls)
+                    .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+                redis_pools.push(("kv", pool.clone()));
+
+                RedisKvStore::runtime(pool, key_prefix)
+            }
+        };
+
+        let operation_caching = &gateway_config.operation_caching;
+
+        let local_operation_cache_factory = if operation_caching.enabled {
+            InMemoryOperationCacheFactory {
+                config: InMemoryOperationCacheConfig {
+                    limit: operation_caching.limit,
+                },
+            }
+        } else {
+            InMemoryOperationCacheFactory::inactive()
+        };
+
+        let operation_cache_factory = match operation_caching.storage {
+            gateway_config::OperationCachingStorage::Redis if operation_caching.enabled => {
+                let OperationCachingRedisConfig { url, key_prefix, tls } = &operation_caching.redis;
+
+                let tls = tls.as_ref().map(|tls| RedisTlsConfig {
+                    cert: tls.cert.as_deref(),
+                    key: tls.key.as_deref(),
+                    ca: tls.ca.as_deref(),
+                });
+
+                let pool = redis_factory
+                    .pool(url.as_str(), tls)
+                    .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+                redis_pools.push(("operation_caching", pool.clone()));
+
+                GatewayOperationCacheFactory::Redis(RedisOperationCacheFactory::new(
+                    pool,
+                    key_prefix,
+                    local_operation_cache_factory,
+                ))
+            }
+            _ => GatewayOperationCacheFactory::Memory(local_operation_cache_factory),
+        };
+
         let runtime = GatewayRuntime {
-            fetcher: NativeFetcher::default(),
-            kv: InMemoryKvStore::runtime(),
+            fetcher: NativeFetcher::new(gateway_config).map_err(|e| crate::Error::InternalError(format!("{e:#}")))?,
+            kv,
             trusted_documents: runtime::trusted_documents_client::Client::new(NoopTrustedDocuments),
             hooks,
             metrics: EngineMetrics::build(&meter, version_id.map(|id| id.to_string())),
             rate_limiter,
             entity_cache,
-            operation_cache_factory: InMemoryOperationCacheFactory::default(),
+            operation_cache_factory,
+            redis_pools,
+            shutdown,
         };
 
         Ok(runtime)
     }
+
+    pub(crate) fn redis_pools(&self) -> &[(&'static str, runtime_local::redis::Pool)] {
+        &self.redis_pools
+    }
 }
 
 impl engine_v2::Runtime for GatewayRuntime {
     type Hooks = HooksWasi;
     type Fetcher = NativeFetcher;
-    type OperationCacheFactory = InMemoryOperationCacheFactory;
+    type OperationCacheFactory = GatewayOperationCacheFactory;
 
     fn fetcher(&self) -> &Self::Fetcher {
         &self.fetcher
@@ -132,4 +207,15 @@ impl engine_v2::Runtime for GatewayRuntime {
     fn metrics(&self) -> &grafbase_telemetry::metrics::EngineMetrics {
         &self.metrics
     }
+
+    fn graceful_shutdown(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let mut shutdown = self.shutdown.clone();
+
+        async move {
+            // Without a sender the server never drains, as in lambda mode.
+            if shutdown.wait_for(|shutdown| *shutdown).await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        }
+    }
 }
@@ -0,0 +1,51 @@
+use runtime::operation_cache::{OperationCache, OperationCacheFactory};
+use runtime_local::{
+    InMemoryOperationCache, InMemoryOperationCacheFactory, RedisOperationCache, RedisOperationCacheFactory,
+};
+
+/// The operation cache of the gateway, either local to this instance or shared through Redis
+/// depending on the `operation_caching.storage` setting.
+pub enum GatewayOperationCacheFactory {
+    Memory(InMemoryOperationCacheFactory),
+    Redis(RedisOperationCacheFactory),
+}
+
+impl OperationCacheFactory for GatewayOperationCacheFactory {
+    type Cache<V> = GatewayOperationCache<V>
+    where
+        V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned;
+
+    async fn create<V>(&self) -> Self::Cache<V>
+    where
+        V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        match self {
+            GatewayOperationCacheFactory::Memory(factory) => GatewayOperationCache::Memory(factory.create().await),
+            GatewayOperationCacheFactory::Redis(factory) => GatewayOperationCache::Redis(factory.create().await),
+        }
+    }
+}
+
+pub enum GatewayOperationCache<V> {
+    Memory(InMemoryOperationCache<V>),
+    Redis(RedisOperationCache<V>),
+}
+
+impl<V> OperationCache<V> for GatewayOperationCache<V>
+where
+    V: Clone + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+{
+    async fn insert(&self, key: String, value: V) {
+        match self {
+            GatewayOperationCache::Memory(cache) => cache.insert(key, value).await,
+            GatewayOperationCache::Redis(cache) => cache.insert(key, value).await,
+        }
+    }
+
+    async fn get(&self, key: &String) -> Option<V> {
+        match self {
+            GatewayOperationCache::Memory(cache) => cache.get(key).await,
+            GatewayOperationCache::Redis(cache) => cache.get(key).await,
+        }
+    }
+}
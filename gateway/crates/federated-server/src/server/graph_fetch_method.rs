@@ -1,4 +1,4 @@
-use super::gateway::{self, GatewayRuntime, GraphDefinition};
+use super::gateway::{self, DrainWatcher, GatewayRuntime, GraphDefinition};
 use engine_v2::Engine;
 use gateway_config::Config;
 use graph_ref::GraphRef;
@@ -32,6 +32,7 @@ impl GraphFetchMethod {
         hot_reload_config_path: Option<PathBuf>,
         sender: watch::Sender<Option<Arc<Engine<GatewayRuntime>>>>,
         hooks: HooksWasi,
+        shutdown: DrainWatcher,
     ) -> crate::Result<()> {
         #[cfg(feature = "lambda")]
         if matches!(self, GraphFetchMethod::FromGraphRef { .. }) {
@@ -50,7 +51,7 @@ impl GraphFetchMethod {
                     let config = config.clone();
                     use super::graph_updater::GraphUpdater;
 
-                    GraphUpdater::new(graph_ref, access_token, sender, config, hooks)?
+                    GraphUpdater::new(graph_ref, access_token, sender, config, hooks, shutdown)?
                         .poll()
                         .await;
 
@@ -63,6 +64,7 @@ impl GraphFetchMethod {
                     config,
                     hot_reload_config_path,
                     hooks,
+                    shutdown,
                 )
                 .await?;
 
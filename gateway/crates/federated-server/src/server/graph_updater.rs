@@ -1,7 +1,8 @@
-use crate::server::gateway::GraphDefinition;
+use crate::server::gateway::{GatewayRuntime, GraphDefinition};
 
-use super::gateway::GatewaySender;
+use super::gateway::{DrainWatcher, GatewaySender};
 use ascii::AsciiString;
+use engine_v2::Engine;
 use gateway_config::Config;
 use grafbase_telemetry::{
     metrics::meter_from_global_provider,
@@ -86,6 +87,7 @@ pub(super) struct GraphUpdater {
     gateway_config: Config,
     latencies: Histogram<u64>,
     hooks: HooksWasi,
+    shutdown: DrainWatcher,
 }
 
 impl GraphUpdater {
@@ -98,6 +100,7 @@ impl GraphUpdater {
     /// * `sender` - The sender used to send a new instance of the gateway to the server.
     /// * `gateway_config` - Configuration settings for the gateway.
     /// * `hooks` - Hooks for custom behavior during operation execution.
+    /// * `shutdown` - Signals the end of the drain period, closing streams and websockets.
     ///
     /// # Errors
     ///
@@ -108,6 +111,7 @@ impl GraphUpdater {
         sender: GatewaySender,
         gateway_config: Config,
         hooks: HooksWasi,
+        shutdown: DrainWatcher,
     ) -> crate::Result<Self> {
         let gdn_client = reqwest::ClientBuilder::new()
             .timeout(GDN_TIMEOUT)
@@ -152,6 +156,7 @@ impl GraphUpdater {
                 .u64_histogram("gdn.request.duration")
                 .init(),
             hooks,
+            shutdown,
         })
     }
 
@@ -262,6 +267,7 @@ impl GraphUpdater {
                 &self.gateway_config,
                 None,
                 self.hooks.clone(),
+                self.shutdown.clone(),
             )
             .await
             {
@@ -290,12 +296,39 @@ impl GraphUpdater {
 
             self.current_id = Some(version_id);
 
+            if self.gateway_config.operation_caching.warm_on_reload {
+                self.warm(&gateway).await;
+            }
+
             self.sender
                 .send(Some(Arc::new(gateway)))
                 .expect("internal error: channel closed");
         }
     }
 
+    /// Prepares the operations most used by the current gateway against the new one, so that
+    /// they're served from the operation cache right after the swap.
+    async fn warm(&self, gateway: &Engine<GatewayRuntime>) {
+        let limit = self.gateway_config.operation_caching.warming_limit;
+
+        let operations = self
+            .sender
+            .borrow()
+            .as_ref()
+            .map(|current| current.most_used_operations(limit))
+            .unwrap_or_default();
+
+        if operations.is_empty() {
+            return;
+        }
+
+        let start = SystemTime::now();
+        let warmed = gateway.warm(operations).await;
+        let duration = SystemTime::now().duration_since(start).unwrap_or_default();
+
+        tracing::info!("Warmed the operation cache with {warmed} operations in {duration:?}");
+    }
+
     fn record_duration(
         &self,
         GdnFetchLatencyAttributes { kind, status_code }: GdnFetchLatencyAttributes,
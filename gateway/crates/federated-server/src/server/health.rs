@@ -1,12 +1,19 @@
-use std::net::SocketAddr;
+use std::{borrow::Cow, collections::BTreeMap, future::Future, net::SocketAddr, time::Duration};
 
-use gateway_config::{HealthConfig, TlsConfig};
+use bytes::Bytes;
+use engine_v2::{Engine, Runtime};
+use futures_util::{future::BoxFuture, FutureExt};
+use gateway_config::{Config, HealthConfig, ReadinessConfig, SubgraphHealthCheckConfig, TlsConfig};
+use runtime::fetch::{FetchRequest, Fetcher};
+use runtime_local::NativeFetcher;
+use tokio::{sync::Mutex, time::Instant};
+use url::Url;
 
-use super::{state::ServerState, ServerRuntime};
+use super::{gateway::GatewayRuntime, state::ServerState, ServerRuntime};
 use axum::{extract::State, routing::get, Json, Router};
 use http::StatusCode;
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "status", rename_all = "lowercase")]
 pub(crate) enum HealthState {
     /// Indicates that the server is healthy and operational.
@@ -33,13 +40,237 @@ pub(crate) async fn health<SR>(State(state): State<ServerState<SR>>) -> (StatusC
     }
 }
 
+/// What the readiness endpoint checks, taken from the gateway configuration.
+pub(crate) struct ReadinessChecks {
+    config: ReadinessConfig,
+    subgraphs: BTreeMap<String, SubgraphHealthCheckConfig>,
+    /// Results of the last run of the checks. The lock is held while they run, so concurrent
+    /// requests wait for a single run instead of each probing every dependency.
+    last_run: Mutex<Option<(Instant, BTreeMap<String, DependencyStatus>)>>,
+}
+
+impl ReadinessChecks {
+    pub(crate) fn new(config: &Config) -> Self {
+        Self {
+            config: config.health.readiness.clone(),
+            subgraphs: config
+                .subgraphs
+                .iter()
+                .filter_map(|(name, subgraph)| Some((name.clone(), subgraph.health_check.clone()?)))
+                .collect(),
+            last_run: Mutex::new(None),
+        }
+    }
+
+    /// Status of each dependency, reusing the results of the last run for `cache_ttl`.
+    async fn dependencies(&self, engine: &Engine<GatewayRuntime>) -> BTreeMap<String, DependencyStatus> {
+        let mut last_run = self.last_run.lock().await;
+
+        if let Some((ran_at, dependencies)) = last_run.as_ref() {
+            if ran_at.elapsed() < self.config.cache_ttl {
+                return dependencies.clone();
+            }
+        }
+
+        let dependencies = self.run(engine).await;
+        *last_run = Some((Instant::now(), dependencies.clone()));
+
+        dependencies
+    }
+
+    /// Runs the enabled checks concurrently.
+    async fn run(&self, engine: &Engine<GatewayRuntime>) -> BTreeMap<String, DependencyStatus> {
+        let Self { config, subgraphs, .. } = self;
+        let runtime = engine.runtime();
+        let mut checks: Vec<(String, BoxFuture<'_, Result<(), String>>)> = Vec::new();
+
+        if config.redis {
+            for (name, pool) in runtime.redis_pools() {
+                let check = async move { runtime_local::redis::ping(pool).await.map_err(|err| format!("{err:#}")) };
+                checks.push((format!("redis.{name}"), check.boxed()));
+            }
+        }
+
+        if config.hooks && runtime.hooks().is_enabled() {
+            let check = async move {
+                runtime
+                    .hooks()
+                    .check_instantiation()
+                    .await
+                    .map_err(|err| err.to_string())
+            };
+            checks.push(("hooks".to_string(), check.boxed()));
+        }
+
+        if config.subgraphs {
+            let default_health_check = SubgraphHealthCheckConfig::default();
+
+            for (name, url) in engine.subgraph_urls() {
+                let health_check = subgraphs.get(name).unwrap_or(&default_health_check);
+                if !health_check.enabled {
+                    continue;
+                }
+
+                let check = probe_subgraph(runtime.fetcher(), name, url, health_check.clone(), config.timeout);
+                checks.push((format!("subgraph.{name}"), check.boxed()));
+            }
+        }
+
+        futures_util::future::join_all(
+            checks
+                .into_iter()
+                .map(|(name, check)| run_check(config.timeout, check).map(|status| (name, status))),
+        )
+        .await
+        .into_iter()
+        .collect()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct Readiness {
+    #[serde(flatten)]
+    state: HealthState,
+    /// The server is shutting down.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    draining: bool,
+    dependencies: BTreeMap<String, DependencyStatus>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub(crate) struct DependencyStatus {
+    #[serde(flatten)]
+    state: HealthState,
+    latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Handles readiness requests. On top of the liveness check, the enabled checks of the
+/// dependencies are run concurrently and reported individually.
+pub(crate) async fn readiness<SR>(State(state): State<ServerState<SR>>) -> (StatusCode, Json<Readiness>) {
+    let draining = *state.draining.borrow();
+    let engine = state.gateway.borrow().clone();
+
+    let Some(engine) = engine.filter(|_| !draining) else {
+        let readiness = Readiness {
+            state: HealthState::Unhealthy,
+            draining,
+            dependencies: BTreeMap::new(),
+        };
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(readiness));
+    };
+
+    let dependencies = state.readiness.dependencies(&engine).await;
+
+    let healthy = dependencies
+        .values()
+        .all(|dependency| matches!(dependency.state, HealthState::Healthy));
+
+    let (status, health) = if healthy {
+        (StatusCode::OK, HealthState::Healthy)
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, HealthState::Unhealthy)
+    };
+
+    let readiness = Readiness {
+        state: health,
+        draining: false,
+        dependencies,
+    };
+    (status, Json(readiness))
+}
+
+async fn run_check(timeout: Duration, check: impl Future<Output = Result<(), String>>) -> DependencyStatus {
+    let start = Instant::now();
+
+    let result = tokio::time::timeout(timeout, check)
+        .await
+        .unwrap_or_else(|_| Err(format!("timed out after {timeout:?}")));
+
+    DependencyStatus {
+        state: if result.is_ok() {
+            HealthState::Healthy
+        } else {
+            HealthState::Unhealthy
+        },
+        latency_ms: start.elapsed().as_millis() as u64,
+        error: result.err(),
+    }
+}
+
+/// Sends the health check request of a subgraph, either a GET to its health URL or a GraphQL
+/// query which must be answered without errors.
+async fn probe_subgraph(
+    fetcher: &NativeFetcher,
+    subgraph_name: &str,
+    url: &Url,
+    health_check: SubgraphHealthCheckConfig,
+    timeout: Duration,
+) -> Result<(), String> {
+    #[derive(serde::Deserialize)]
+    struct GraphqlResponse {
+        #[serde(default)]
+        errors: Vec<serde::de::IgnoredAny>,
+    }
+
+    let mut request = FetchRequest {
+        subgraph_name,
+        url: Cow::Borrowed(url),
+        method: http::Method::GET,
+        headers: http::HeaderMap::new(),
+        body: Bytes::new(),
+        timeout,
+        compression: None,
+    };
+
+    let is_query = match &health_check.url {
+        Some(url) => {
+            request.url = Cow::Owned(url.clone());
+            false
+        }
+        None => {
+            let body = serde_json::json!({ "query": health_check.query });
+
+            request.method = http::Method::POST;
+            request.body = Bytes::from(serde_json::to_vec(&body).map_err(|err| err.to_string())?);
+            request.headers.insert(
+                http::header::CONTENT_TYPE,
+                http::HeaderValue::from_static("application/json"),
+            );
+            request
+                .headers
+                .insert(http::header::ACCEPT, http::HeaderValue::from_static("application/json"));
+            true
+        }
+    };
+
+    let (result, _) = fetcher.fetch(request).await;
+    let response = result.map_err(|err| err.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(format!("unexpected status {}", response.status()));
+    }
+
+    if is_query {
+        let response: GraphqlResponse = serde_json::from_slice(response.body().as_ref())
+            .map_err(|err| format!("invalid GraphQL response: {err}"))?;
+
+        if !response.errors.is_empty() {
+            return Err(format!("the query returned {} error(s)", response.errors.len()));
+        }
+    }
+
+    Ok(())
+}
+
 /// Binds the health check endpoint to the specified address and configuration.
 ///
 /// # Arguments
 ///
 /// - `addr`: The socket address to bind the server to.
 /// - `tls_config`: Optional TLS configuration for secure connections.
-/// - `health_config`: Configuration for health check settings.
+/// - `health_config`: Configuration for the liveness and readiness endpoints.
 /// - `state`: The current state of the server.
 ///
 /// # Returns
@@ -55,18 +286,21 @@ pub(super) async fn bind_health_endpoint<SR: ServerRuntime>(
     let path = &health_config.path;
     let app = Router::new()
         .route(path, get(health))
+        .route(&health_config.readiness.path, get(readiness))
         .with_state(state)
         .into_make_service();
 
     tracing::info!("Health check endpoint exposed at {scheme}://{addr}{path}");
+    tracing::info!(
+        "Readiness endpoint exposed at {scheme}://{addr}{}",
+        health_config.readiness.path
+    );
 
     match tls_config {
         Some(tls) => {
-            let rustls_config = axum_server::tls_rustls::RustlsConfig::from_pem_file(&tls.certificate, &tls.key)
-                .await
-                .map_err(crate::Error::CertificateError)?;
+            let reloading_tls = super::tls::ReloadingTls::load(&tls, false)?;
 
-            axum_server::bind_rustls(addr, rustls_config)
+            axum_server::bind_rustls(addr, reloading_tls.config.clone())
                 .serve(app)
                 .await
                 .map_err(crate::Error::Server)?;
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use super::gateway::EngineWatcher;
+use super::{
+    gateway::{DrainWatcher, EngineWatcher},
+    health::ReadinessChecks,
+};
 
 pub(super) struct ServerStateInner<SR> {
     /// The gateway responsible for handling engine communication.
@@ -9,6 +12,12 @@ pub(super) struct ServerStateInner<SR> {
     /// The maximum size in bytes for the request body.
     pub request_body_limit_bytes: usize,
 
+    /// The dependencies checked by the readiness endpoint.
+    pub readiness: ReadinessChecks,
+
+    /// Set once the server is shutting down.
+    pub draining: DrainWatcher,
+
     /// The server runtime, defining how to trigger IO depending on the platform.
     #[cfg_attr(not(feature = "lambda"), allow(unused))]
     pub server_runtime: SR,
@@ -27,12 +36,20 @@ impl<SR> Clone for ServerState<SR> {
 }
 
 impl<SR> ServerState<SR> {
-    pub(super) fn new(gateway: EngineWatcher, request_body_limit_bytes: usize, server_runtime: SR) -> Self {
+    pub(super) fn new(
+        gateway: EngineWatcher,
+        request_body_limit_bytes: usize,
+        readiness: ReadinessChecks,
+        draining: DrainWatcher,
+        server_runtime: SR,
+    ) -> Self {
         Self {
             inner: Arc::new(ServerStateInner {
                 gateway,
                 server_runtime,
                 request_body_limit_bytes,
+                readiness,
+                draining,
             }),
         }
     }
@@ -0,0 +1,302 @@
+//! TLS for the gateway listener. Certificates are reloaded when their files change, and
+//! client certificates can be required or accepted when a client CA is configured.
+//!
+//! The subject and subject alternative names of a verified client certificate are exposed
+//! to the rest of the gateway as request headers, so they can be used by header rules and
+//! hooks like any other header. Clients cannot set these headers themselves: they're always
+//! removed from incoming requests when client certificate authentication is enabled. The
+//! subject can also identify clients for the global rate limit.
+
+use std::{
+    fs::File,
+    future::Future,
+    io::{self, BufReader},
+    net::IpAddr,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+
+use axum::{
+    extract::Request,
+    middleware::{self, Next},
+    response::Response,
+    Router,
+};
+use axum_server::{
+    accept::Accept,
+    tls_rustls::{RustlsAcceptor, RustlsConfig},
+};
+use gateway_config::{ClientAuthMode, TlsConfig, CLIENT_CERT_SANS_HEADER, CLIENT_CERT_SUBJECT_HEADER};
+use http::HeaderValue;
+use notify::{EventHandler, EventKind, PollWatcher, Watcher};
+use rustls::{server::WebPkiClientVerifier, RootCertStore, ServerConfig};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_rustls::server::TlsStream;
+use tower_http::add_extension::AddExtension;
+
+/// A rustls configuration kept up to date with the certificate files on disk.
+pub(super) struct ReloadingTls {
+    pub(super) config: RustlsConfig,
+    _watcher: PollWatcher,
+}
+
+impl ReloadingTls {
+    /// Loads the certificates and starts watching their files.
+    ///
+    /// # Arguments
+    ///
+    /// - `tls`: The TLS configuration of the listener.
+    /// - `client_auth`: Whether client certificates are verified with the configured client CA.
+    ///   The health endpoint disables it, so that probes don't need a certificate.
+    pub(super) fn load(tls: &TlsConfig, client_auth: bool) -> crate::Result<Self> {
+        let files = TlsFiles {
+            certificate: tls.certificate.clone(),
+            key: tls.key.clone(),
+            client_ca: tls.client_ca.clone().filter(|_| client_auth),
+            client_auth: tls.client_auth,
+        };
+
+        let config = RustlsConfig::from_config(files.server_config().map_err(crate::Error::CertificateError)?);
+
+        let paths: Vec<PathBuf> = files.paths().map(Path::to_path_buf).collect();
+
+        let reloader = Reloader {
+            files,
+            config: config.clone(),
+        };
+
+        let watcher_config = notify::Config::default().with_poll_interval(Duration::from_secs(1));
+        let mut watcher = PollWatcher::new(reloader, watcher_config).map_err(watch_error)?;
+
+        for path in paths {
+            watcher
+                .watch(&path, notify::RecursiveMode::NonRecursive)
+                .map_err(watch_error)?;
+        }
+
+        Ok(Self {
+            config,
+            _watcher: watcher,
+        })
+    }
+}
+
+fn watch_error(error: notify::Error) -> crate::Error {
+    crate::Error::InternalError(format!("unable to watch the TLS certificate files: {error}"))
+}
+
+struct TlsFiles {
+    certificate: PathBuf,
+    key: PathBuf,
+    client_ca: Option<PathBuf>,
+    client_auth: ClientAuthMode,
+}
+
+impl TlsFiles {
+    fn paths(&self) -> impl Iterator<Item = &Path> {
+        [Some(&self.certificate), Some(&self.key), self.client_ca.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(PathBuf::as_path)
+    }
+
+    fn server_config(&self) -> io::Result<Arc<ServerConfig>> {
+        let certificates = rustls_pemfile::certs(&mut open(&self.certificate)?).collect::<Result<Vec<_>, _>>()?;
+
+        let key = rustls_pemfile::private_key(&mut open(&self.key)?)?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no private key found in {}", self.key.display()),
+            )
+        })?;
+
+        let builder = match &self.client_ca {
+            Some(client_ca) => {
+                let mut roots = RootCertStore::empty();
+
+                for certificate in rustls_pemfile::certs(&mut open(client_ca)?) {
+                    roots.add(certificate?).map_err(io::Error::other)?;
+                }
+
+                let verifier = WebPkiClientVerifier::builder(Arc::new(roots));
+
+                let verifier = match self.client_auth {
+                    ClientAuthMode::Optional => verifier.allow_unauthenticated().build(),
+                    ClientAuthMode::Required => verifier.build(),
+                };
+
+                ServerConfig::builder().with_client_cert_verifier(verifier.map_err(io::Error::other)?)
+            }
+            None => ServerConfig::builder().with_no_client_auth(),
+        };
+
+        let mut config = builder.with_single_cert(certificates, key).map_err(io::Error::other)?;
+
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+        Ok(Arc::new(config))
+    }
+}
+
+fn open(path: &Path) -> io::Result<BufReader<File>> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+}
+
+struct Reloader {
+    files: TlsFiles,
+    config: RustlsConfig,
+}
+
+impl EventHandler for Reloader {
+    fn handle_event(&mut self, event: notify::Result<notify::Event>) {
+        match event.map(|e| e.kind) {
+            Ok(EventKind::Any | EventKind::Create(_) | EventKind::Modify(_) | EventKind::Other) => {
+                // The certificate and the key are often not replaced at the same time. Until both
+                // are there the loading fails, and we keep serving the previous certificate.
+                match self.files.server_config() {
+                    Ok(config) => {
+                        tracing::info!("reloaded TLS certificates");
+                        self.config.reload_from_config(config);
+                    }
+                    Err(e) => tracing::warn!("error reloading TLS certificates: {e}"),
+                }
+            }
+            Ok(_) => (),
+            Err(e) => {
+                tracing::error!("error watching TLS certificates: {e}");
+            }
+        }
+    }
+}
+
+/// The verified certificate a client presented during the TLS handshake.
+#[derive(Debug)]
+pub(super) struct ClientCertificate {
+    subject: String,
+    subject_alternative_names: Vec<String>,
+}
+
+impl ClientCertificate {
+    fn parse(der: &[u8]) -> Option<Self> {
+        use x509_parser::extensions::GeneralName;
+
+        let (_, certificate) = x509_parser::parse_x509_certificate(der).ok()?;
+
+        let subject_alternative_names = certificate
+            .subject_alternative_name()
+            .ok()
+            .flatten()
+            .map(|extension| {
+                extension
+                    .value
+                    .general_names
+                    .iter()
+                    .filter_map(|name| match name {
+                        GeneralName::DNSName(name) => Some(format!("DNS:{name}")),
+                        GeneralName::RFC822Name(email) => Some(format!("email:{email}")),
+                        GeneralName::URI(uri) => Some(format!("URI:{uri}")),
+                        GeneralName::IPAddress(bytes) => ip_address(bytes).map(|ip| format!("IP:{ip}")),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            subject: certificate.subject().to_string(),
+            subject_alternative_names,
+        })
+    }
+}
+
+fn ip_address(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => <[u8; 4]>::try_from(bytes).ok().map(IpAddr::from),
+        16 => <[u8; 16]>::try_from(bytes).ok().map(IpAddr::from),
+        _ => None,
+    }
+}
+
+/// Accepts TLS connections, attaching the client certificate to every request of the connection.
+#[derive(Clone)]
+pub(super) struct ClientCertificateAcceptor {
+    inner: RustlsAcceptor,
+}
+
+impl ClientCertificateAcceptor {
+    pub(super) fn new(config: RustlsConfig) -> Self {
+        Self {
+            inner: RustlsAcceptor::new(config),
+        }
+    }
+}
+
+impl<I, S> Accept<I, S> for ClientCertificateAcceptor
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    S: Send + 'static,
+{
+    type Stream = TlsStream<I>;
+    type Service = AddExtension<S, Option<Arc<ClientCertificate>>>;
+    type Future = Pin<Box<dyn Future<Output = io::Result<(Self::Stream, Self::Service)>> + Send>>;
+
+    fn accept(&self, stream: I, service: S) -> Self::Future {
+        let acceptor = self.inner.clone();
+
+        Box::pin(async move {
+            let (stream, service) = acceptor.accept(stream, service).await?;
+
+            let certificate = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certificates| certificates.first())
+                .and_then(|certificate| ClientCertificate::parse(certificate))
+                .map(Arc::new);
+
+            Ok((stream, AddExtension::new(service, certificate)))
+        })
+    }
+}
+
+/// Injects a middleware layer into the given router, exposing the client certificate as headers.
+pub(super) fn inject_client_certificate_layer(mut router: Router) -> Router {
+    router = router.layer(middleware::from_fn(client_certificate_middleware));
+    router
+}
+
+async fn client_certificate_middleware(mut request: Request, next: Next) -> Response {
+    let certificate = request
+        .extensions()
+        .get::<Option<Arc<ClientCertificate>>>()
+        .cloned()
+        .flatten();
+    let headers = request.headers_mut();
+
+    headers.remove(CLIENT_CERT_SUBJECT_HEADER);
+    headers.remove(CLIENT_CERT_SANS_HEADER);
+
+    if let Some(certificate) = certificate {
+        match HeaderValue::from_str(&certificate.subject) {
+            Ok(value) => {
+                headers.insert(CLIENT_CERT_SUBJECT_HEADER, value);
+            }
+            Err(_) => tracing::debug!("client certificate subject is not a valid header value"),
+        }
+
+        if !certificate.subject_alternative_names.is_empty() {
+            match HeaderValue::from_str(&certificate.subject_alternative_names.join(", ")) {
+                Ok(value) => {
+                    headers.insert(CLIENT_CERT_SANS_HEADER, value);
+                }
+                Err(_) => tracing::debug!("client certificate alternative names are not a valid header value"),
+            }
+        }
+    }
+
+    next.run(request).await
+}
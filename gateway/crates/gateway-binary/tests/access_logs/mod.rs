@@ -1,6 +1,13 @@
-use std::{collections::BTreeMap, sync::Arc, time::Duration};
-
-use crate::{load_schema, runtime, with_static_server, Client};
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, BufReader, Read},
+    net::{TcpListener, UdpSocket},
+    path::PathBuf,
+    sync::{mpsc, Arc},
+    time::Duration,
+};
+
+use crate::{load_schema, runtime, Client, GatewayBuilder};
 use futures_util::Future;
 use handlebars::Handlebars;
 use indoc::formatdoc;
@@ -20,6 +27,8 @@ enum ResponseKind {
     RequestError,
     RateLimited,
     Responsed(ResponseInfo),
+    CircuitOpen,
+    Deduplicated,
 }
 
 #[derive(serde::Deserialize, serde::Serialize, Debug)]
@@ -748,7 +757,136 @@ fn with_subgraph_status_500_retried() {
     "###);
 }
 
+/// Runs a `Simple` query, whose access log is checked by the sink tests.
+async fn simple_query(gateway: Arc<Client>) {
+    let resp = gateway
+        .gql::<serde_json::Value>("query Simple { me { id } }")
+        .header("traceparent", "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01")
+        .send()
+        .await;
+
+    assert_eq!(resp["data"]["me"]["id"], "1");
+}
+
+fn assert_simple_log(log: &[u8]) {
+    let log = serde_json::from_slice::<Log>(log).unwrap();
+
+    assert_eq!(log.trace_id, "0af7651916cd43dd8448eb211c80319c");
+    assert_eq!(log.status_code, 200);
+    assert_eq!(log.operations.len(), 1);
+    assert_eq!(log.operations[0].name, "Simple");
+}
+
+#[test]
+fn with_stdout_sink() {
+    let tmpdir = TempDir::new().unwrap();
+    let stdout_path = tmpdir.path().join("stdout");
+
+    let config = indoc::indoc! {r#"
+        [gateway.access_logs]
+        enabled = true
+        sink = "stdout"
+    "#};
+
+    with_gateway_stdout(config, Some(200), Some(stdout_path.clone()), simple_query);
+
+    // Each access log is a JSON object on its own line, mixed with the other output of the gateway.
+    let stdout = std::fs::read_to_string(stdout_path).unwrap();
+    let logs = stdout
+        .lines()
+        .filter(|line| serde_json::from_str::<Log>(line).is_ok())
+        .collect::<Vec<_>>();
+
+    assert_eq!(logs.len(), 1);
+    assert_simple_log(logs[0].as_bytes());
+}
+
+/// Splits a syslog message formatted following RFC 5424 into its header and its access log.
+fn parse_syslog_message(message: &[u8]) -> (String, Vec<u8>) {
+    let message = String::from_utf8(message.to_vec()).unwrap();
+    let (header, log) = message.split_at(message.find('{').unwrap());
+
+    // The timestamp changes on every run.
+    let mut parts = header.split(' ').collect::<Vec<_>>();
+    parts[1] = "[timestamp]";
+
+    (parts.join(" "), log.as_bytes().to_vec())
+}
+
+#[test]
+fn with_syslog_udp_sink() {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+
+    let config = formatdoc! {r#"
+        [gateway.access_logs]
+        enabled = true
+        sink = "syslog"
+
+        [gateway.access_logs.syslog]
+        address = "{}"
+        protocol = "udp"
+    "#, socket.local_addr().unwrap()};
+
+    with_gateway(&config, Some(200), simple_query);
+
+    let mut buffer = vec![0; 64 * 1024];
+    let len = socket.recv(&mut buffer).unwrap();
+
+    let (header, log) = parse_syslog_message(&buffer[..len]);
+    assert_eq!(header, "<14>1 [timestamp] - grafbase-gateway - - - ");
+    assert_simple_log(&log);
+}
+
+#[test]
+fn with_syslog_tcp_sink() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    // Reads the first message, framed with its length as described in RFC 6587.
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut len = Vec::new();
+        reader.read_until(b' ', &mut len).unwrap();
+        let len: usize = std::str::from_utf8(&len).unwrap().trim_end().parse().unwrap();
+
+        let mut message = vec![0; len];
+        reader.read_exact(&mut message).unwrap();
+        sender.send(message).unwrap();
+    });
+
+    let config = formatdoc! {r#"
+        [gateway.access_logs]
+        enabled = true
+        sink = "syslog"
+
+        [gateway.access_logs.syslog]
+        address = "{address}"
+        protocol = "tcp"
+        app_name = "gateway-test"
+    "#};
+
+    with_gateway(&config, Some(200), simple_query);
+
+    let message = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+
+    let (header, log) = parse_syslog_message(&message);
+    assert_eq!(header, "<14>1 [timestamp] - gateway-test - - - ");
+    assert_simple_log(&log);
+}
+
 fn with_gateway<T, F>(config: &str, subgraph_status: Option<u16>, test: T)
+where
+    T: FnOnce(Arc<Client>) -> F,
+    F: Future<Output = ()>,
+{
+    with_gateway_stdout(config, subgraph_status, None, test)
+}
+
+fn with_gateway_stdout<T, F>(config: &str, subgraph_status: Option<u16>, stdout_path: Option<PathBuf>, test: T)
 where
     T: FnOnce(Arc<Client>) -> F,
     F: Future<Output = ()>,
@@ -830,7 +968,16 @@ where
     println!("{config}");
     println!("{schema}");
 
-    with_static_server(config, &schema, None, None, |client| async move {
+    let builder = GatewayBuilder {
+        toml_config: config.into(),
+        schema: &schema,
+        log_level: None,
+        client_url_path: None,
+        client_headers: None,
+        stdout_path,
+    };
+
+    builder.run(|client| async move {
         const WAIT_SECONDS: u64 = 2;
 
         // wait for initial polling to be pushed to OTEL tables so we can ignore it with the
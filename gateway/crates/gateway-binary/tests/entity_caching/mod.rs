@@ -75,6 +75,7 @@ where
         log_level: None,
         client_url_path: None,
         client_headers: None,
+        stdout_path: None,
     }
     .run(test)
 }
@@ -4,6 +4,7 @@ mod access_logs;
 mod entity_caching;
 mod mocks;
 mod telemetry;
+mod tls;
 
 use std::{
     borrow::Cow,
@@ -191,6 +192,10 @@ impl Client {
         self.commands.kill_all()
     }
 
+    pub fn terminate_handles(&self) {
+        self.commands.terminate_all()
+    }
+
     pub fn endpoint(&self) -> &str {
         &self.endpoint
     }
@@ -263,6 +268,15 @@ impl CommandHandles {
             command.kill().unwrap();
         }
     }
+
+    /// Sends a termination signal, starting the graceful shutdown.
+    pub fn terminate_all(&self) {
+        for command in self.0.lock().unwrap().iter() {
+            for pid in command.pids() {
+                cmd!("kill", "-TERM", pid.to_string()).run().unwrap();
+            }
+        }
+    }
 }
 
 impl Default for CommandHandles {
@@ -361,6 +375,8 @@ struct GatewayBuilder<'a> {
     log_level: Option<String>,
     client_url_path: Option<&'a str>,
     client_headers: Option<&'static [(&'static str, &'static str)]>,
+    /// File receiving the standard output of the gateway, discarded otherwise.
+    stdout_path: Option<path::PathBuf>,
 }
 
 impl<'a> GatewayBuilder<'a> {
@@ -371,6 +387,7 @@ impl<'a> GatewayBuilder<'a> {
             log_level: None,
             client_url_path: None,
             client_headers: None,
+            stdout_path: None,
         }
     }
 
@@ -408,7 +425,11 @@ impl<'a> GatewayBuilder<'a> {
             args.push(level);
         }
 
-        let command = cmd(cargo_bin("grafbase-gateway"), &args).stdout_null().stderr_null();
+        let command = cmd(cargo_bin("grafbase-gateway"), &args).stderr_null();
+        let command = match self.stdout_path {
+            Some(path) => command.stdout_path(path),
+            None => command.stdout_null(),
+        };
 
         let endpoint = match self.client_url_path {
             Some(path) => format!("http://{addr}/{path}"),
@@ -459,6 +480,7 @@ fn with_static_server<'a, F, T>(
         log_level: None,
         client_url_path: path,
         client_headers: headers,
+        stdout_path: None,
     }
     .run(test)
 }
@@ -778,6 +800,97 @@ fn custom_path() {
     })
 }
 
+#[test]
+fn contract() {
+    let config = indoc! {r#"
+        [graph]
+        introspection = true
+
+        [graph.contract]
+        exclude_tags = ["internal"]
+    "#};
+
+    let server = runtime().block_on(async move {
+        let server = wiremock::MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/accounts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "me": { "id": "1", "username": "Alice" } }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/reviews"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "_entities": [{ "__typename": "User", "reviewCount": 3 }] }
+            })))
+            .mount(&server)
+            .await;
+
+        server
+    });
+
+    let mut hb = handlebars::Handlebars::new();
+    hb.register_template_string("t1", load_schema("contract")).unwrap();
+
+    let data = std::collections::BTreeMap::from([("subgraph_endpoint", format!("http://{}", server.address()))]);
+    let schema = hb.render("t1", &data).unwrap();
+
+    with_static_server(config, &schema, None, None, |client| async move {
+        insta::assert_snapshot!(introspect(client.endpoint()).await, @r###"
+        type Query {
+          me: User!
+        }
+
+        type User {
+          username: String!
+          reviewCount: Int!
+        }
+        "###);
+
+        // The excluded key is still used to fetch the user from the reviews subgraph.
+        let result: serde_json::Value = client.gql("query { me { username reviewCount } }").send().await;
+        let result = serde_json::to_string_pretty(&result).unwrap();
+
+        insta::assert_snapshot!(&result, @r###"
+        {
+          "data": {
+            "me": {
+              "username": "Alice",
+              "reviewCount": 3
+            }
+          }
+        }
+        "###);
+
+        let result: serde_json::Value = client.gql("query { me { id email } }").send().await;
+        let result = serde_json::to_string_pretty(&result).unwrap();
+
+        insta::assert_snapshot!(&result, @r###"
+        {
+          "errors": [
+            {
+              "message": "User does not have a field named 'id'",
+              "locations": [
+                {
+                  "line": 1,
+                  "column": 14
+                }
+              ],
+              "extensions": {
+                "code": "OPERATION_VALIDATION_ERROR"
+              }
+            }
+          ]
+        }
+        "###);
+    });
+
+    drop(server);
+}
+
 #[test]
 fn csrf_no_header() {
     let config = indoc! {r#"
@@ -843,6 +956,62 @@ fn csrf_with_header() {
     })
 }
 
+#[test]
+fn compression_enabled() {
+    let config = indoc! {r#"
+        [compression]
+        enabled = true
+        min_size = 0
+    "#};
+
+    let schema = load_schema("big");
+
+    let query = indoc! {r#"
+        query Me {
+          me {
+            id
+          }
+        }
+    "#};
+
+    let headers = &[("accept-encoding", "gzip")];
+
+    with_static_server(config, &schema, None, Some(headers), |client| async move {
+        let response = client.gql::<serde_json::Value>(query).request().await;
+
+        assert_eq!(http::StatusCode::OK, response.status());
+        assert_eq!(
+            Some("gzip"),
+            response
+                .headers()
+                .get(http::header::CONTENT_ENCODING)
+                .and_then(|value| value.to_str().ok())
+        );
+    })
+}
+
+#[test]
+fn compression_disabled_by_default() {
+    let schema = load_schema("big");
+
+    let query = indoc! {r#"
+        query Me {
+          me {
+            id
+          }
+        }
+    "#};
+
+    let headers = &[("accept-encoding", "gzip")];
+
+    with_static_server("", &schema, None, Some(headers), |client| async move {
+        let response = client.gql::<serde_json::Value>(query).request().await;
+
+        assert_eq!(http::StatusCode::OK, response.status());
+        assert!(response.headers().get(http::header::CONTENT_ENCODING).is_none());
+    })
+}
+
 #[test]
 fn hybrid_graph() {
     let schema = load_schema("big");
@@ -993,6 +1162,235 @@ fn health_custom_listener() {
     });
 }
 
+#[test]
+fn readiness_default_config() {
+    let schema = load_schema("big");
+
+    with_static_server("", &schema, None, None, |client| async move {
+        let mut url: reqwest::Url = client.endpoint().parse().unwrap();
+        url.set_path("/health/ready");
+
+        let response = client.client().get(url).send().await.unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body: serde_json::Value = response.json().await.unwrap();
+
+        insta::assert_json_snapshot!(&body, @r###"
+        {
+          "status": "healthy",
+          "dependencies": {}
+        }
+        "###);
+    });
+}
+
+#[test]
+fn readiness_checks_redis_pools() {
+    let config = indoc! {r#"
+        [health.readiness]
+        redis = true
+
+        [kv]
+        storage = "redis"
+
+        [operation_caching]
+        storage = "redis"
+    "#};
+
+    let schema = load_schema("big");
+
+    with_static_server(config, &schema, None, None, |client| async move {
+        let mut url: reqwest::Url = client.endpoint().parse().unwrap();
+        url.set_path("/health/ready");
+
+        let response = client.client().get(url).send().await.unwrap();
+
+        assert_eq!(response.status(), 200);
+
+        let body: serde_json::Value = response.json().await.unwrap();
+
+        insta::assert_json_snapshot!(&body, {
+            ".dependencies.*.latency_ms" => "[latency_ms]"
+        }, @r###"
+        {
+          "status": "healthy",
+          "dependencies": {
+            "redis.kv": {
+              "status": "healthy",
+              "latency_ms": "[latency_ms]"
+            },
+            "redis.operation_caching": {
+              "status": "healthy",
+              "latency_ms": "[latency_ms]"
+            }
+          }
+        }
+        "###);
+    });
+}
+
+#[test]
+fn readiness_subgraph_checks_are_cached() {
+    let server = runtime().block_on(async move {
+        let server = wiremock::MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/accounts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "__typename": "Query" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/reviews/health"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        server
+    });
+
+    let config = format!(
+        indoc! {r#"
+            [health.readiness]
+            subgraphs = true
+            cache_ttl = "1m"
+
+            [subgraphs.reviews.health_check]
+            url = "http://{}/reviews/health"
+        "#},
+        server.address()
+    );
+
+    let mut hb = handlebars::Handlebars::new();
+    hb.register_template_string("t1", load_schema("contract")).unwrap();
+
+    let data = std::collections::BTreeMap::from([("subgraph_endpoint", format!("http://{}", server.address()))]);
+    let schema = hb.render("t1", &data).unwrap();
+
+    with_static_server(config, &schema, None, None, |client| async move {
+        let mut url: reqwest::Url = client.endpoint().parse().unwrap();
+        url.set_path("/health/ready");
+
+        let responses =
+            futures_util::future::join_all((0..5).map(|_| client.client().get(url.clone()).send().map(Result::unwrap)))
+                .await;
+
+        let mut bodies = Vec::new();
+        for response in responses {
+            assert_eq!(response.status(), 503);
+            bodies.push(response.json::<serde_json::Value>().await.unwrap());
+        }
+
+        insta::assert_json_snapshot!(&bodies[0], {
+            ".dependencies.*.latency_ms" => "[latency_ms]"
+        }, @r###"
+        {
+          "status": "unhealthy",
+          "dependencies": {
+            "subgraph.accounts": {
+              "status": "healthy",
+              "latency_ms": "[latency_ms]"
+            },
+            "subgraph.reviews": {
+              "status": "unhealthy",
+              "latency_ms": "[latency_ms]",
+              "error": "unexpected status 503 Service Unavailable"
+            }
+          }
+        }
+        "###);
+
+        // Concurrent and later requests share a single run of the checks.
+        assert!(bodies.iter().all(|body| body == &bodies[0]));
+
+        let requests = server.received_requests().await.unwrap();
+        let count = |path: &str| requests.iter().filter(|request| request.url.path() == path).count();
+        assert_eq!(count("/accounts"), 1);
+        assert_eq!(count("/reviews/health"), 1);
+    });
+}
+
+#[test]
+fn readiness_fails_while_draining() {
+    let server = runtime().block_on(async move {
+        let server = wiremock::MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/accounts"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({
+                        "data": { "me": { "username": "Alice" } }
+                    }))
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&server)
+            .await;
+
+        server
+    });
+
+    let config = indoc! {r#"
+        [gateway]
+        shutdown_delay = "3s"
+        drain_period = "5s"
+    "#};
+
+    let mut hb = handlebars::Handlebars::new();
+    hb.register_template_string("t1", load_schema("contract")).unwrap();
+
+    let data = std::collections::BTreeMap::from([("subgraph_endpoint", format!("http://{}", server.address()))]);
+    let schema = hb.render("t1", &data).unwrap();
+
+    with_static_server(config, &schema, None, None, |client| async move {
+        let mut url: reqwest::Url = client.endpoint().parse().unwrap();
+        url.set_path("/health/ready");
+
+        let response = client.client().get(url.clone()).send().await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let in_flight = tokio::spawn({
+            let client = client.clone();
+            async move {
+                client
+                    .gql::<serde_json::Value>("query { me { username } }")
+                    .send()
+                    .await
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        client.terminate_handles();
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let response = client.client().get(url).send().await.unwrap();
+        assert_eq!(response.status(), 503);
+
+        let body: serde_json::Value = response.json().await.unwrap();
+
+        insta::assert_json_snapshot!(&body, @r###"
+        {
+          "status": "unhealthy",
+          "draining": true,
+          "dependencies": {}
+        }
+        "###);
+
+        insta::assert_json_snapshot!(in_flight.await.unwrap(), @r###"
+        {
+          "data": {
+            "me": {
+              "username": "Alice"
+            }
+          }
+        }
+        "###);
+    });
+}
+
 #[test]
 fn global_rate_limiting() {
     let config = indoc! {r#"
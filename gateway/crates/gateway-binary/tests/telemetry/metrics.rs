@@ -145,6 +145,15 @@ where
 }
 
 fn with_small_subgraph<T, F>(config: &str, test: T)
+where
+    T: FnOnce(String, u64, Arc<Client>, &'static clickhouse::Client) -> F,
+    F: Future<Output = ()>,
+{
+    with_slow_small_subgraph(config, Duration::ZERO, test)
+}
+
+/// Same as `with_small_subgraph`, with the subgraph taking `delay` to answer.
+fn with_slow_small_subgraph<T, F>(config: &str, delay: Duration, test: T)
 where
     T: FnOnce(String, u64, Arc<Client>, &'static clickhouse::Client) -> F,
     F: Future<Output = ()>,
@@ -175,14 +184,16 @@ where
     let server = runtime().block_on(async move {
         let server = wiremock::MockServer::start().await;
 
-        let response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "data": {
-                "me": {
-                    "id": "1",
-                    "username": "Alice",
+        let response = ResponseTemplate::new(200)
+            .set_body_json(serde_json::json!({
+                "data": {
+                    "me": {
+                        "id": "1",
+                        "username": "Alice",
+                    }
                 }
-            }
-        }));
+            }))
+            .set_delay(delay);
 
         Mock::given(method("POST")).respond_with(response).mount(&server).await;
 
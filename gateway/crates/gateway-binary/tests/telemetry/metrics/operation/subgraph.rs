@@ -1,5 +1,8 @@
+use std::time::Duration;
+
 use crate::telemetry::metrics::{
-    with_custom_gateway, with_gateway, with_small_subgraph, ExponentialHistogramRow, SumRow, METRICS_DELAY,
+    with_custom_gateway, with_gateway, with_slow_small_subgraph, with_small_subgraph, ExponentialHistogramRow, SumRow,
+    METRICS_DELAY,
 };
 
 #[test]
@@ -349,3 +352,53 @@ fn cache_miss_hit() {
             "###);
     });
 }
+
+#[test]
+fn deduplicated_requests() {
+    let config = indoc::indoc! {r#"
+        [subgraphs.accounts]
+        deduplicate_requests = true
+    "#};
+
+    with_slow_small_subgraph(
+        config,
+        Duration::from_millis(500),
+        |service_name, _, gateway, clickhouse| async move {
+            let query = "query Simple { me { id } }";
+            let (first, second) = futures_util::join!(
+                gateway.gql::<serde_json::Value>(query).send(),
+                gateway.gql::<serde_json::Value>(query).send()
+            );
+
+            assert_eq!(first, second);
+
+            tokio::time::sleep(METRICS_DELAY).await;
+
+            let rows = clickhouse
+                .query(
+                    r#"
+                SELECT Value, Attributes
+                FROM otel_metrics_sum
+                WHERE ServiceName = ?
+                    AND ScopeName = 'grafbase'
+                    AND MetricName = 'graphql.subgraph.request.deduplicated'
+                "#,
+                )
+                .bind(&service_name)
+                .fetch_all::<SumRow>()
+                .await
+                .unwrap();
+
+            insta::assert_json_snapshot!(rows, @r###"
+        [
+          {
+            "Value": 1.0,
+            "Attributes": {
+              "graphql.subgraph.name": "accounts"
+            }
+          }
+        ]
+        "###);
+        },
+    );
+}
@@ -403,6 +403,7 @@ where
         log_level: None,
         client_url_path: None,
         client_headers: None,
+        stdout_path: None,
     }
     .run(|client| async move {
         const WAIT_SECONDS: u64 = 2;
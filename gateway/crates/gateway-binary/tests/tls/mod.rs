@@ -0,0 +1,314 @@
+//! TLS listener with client certificate authentication. The certificates in `certs` are signed
+//! by `ca.pem`, except for `other-server.pem` and `untrusted-client.pem` signed by `other-ca.pem`.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use duct::cmd;
+use futures_util::Future;
+use indoc::formatdoc;
+use tempfile::TempDir;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::{cargo_bin, listen_address, load_schema, runtime, CommandHandles};
+
+const CERTIFICATES: &[&str] = &[
+    "ca.pem",
+    "client.key",
+    "client.pem",
+    "other-ca.pem",
+    "other-server.key",
+    "other-server.pem",
+    "server.key",
+    "server.pem",
+    "untrusted-client.key",
+    "untrusted-client.pem",
+];
+
+const SUBJECT_HEADER: &str = "x-grafbase-client-cert-subject";
+const SANS_HEADER: &str = "x-grafbase-client-cert-sans";
+
+struct TlsGateway {
+    url: String,
+    /// Copy of the certificates, read by the gateway.
+    certificates: PathBuf,
+    subgraph: MockServer,
+}
+
+impl TlsGateway {
+    /// A client trusting the certificates signed by `ca`, presenting the `identity` certificate if any.
+    fn client(&self, ca: &str, identity: Option<&str>) -> reqwest::Client {
+        let ca = fs::read(self.certificates.join(format!("{ca}.pem"))).unwrap();
+
+        let mut builder = reqwest::Client::builder()
+            .use_rustls_tls()
+            .tls_built_in_root_certs(false)
+            .add_root_certificate(reqwest::Certificate::from_pem(&ca).unwrap());
+
+        if let Some(identity) = identity {
+            let mut pem = fs::read(self.certificates.join(format!("{identity}.pem"))).unwrap();
+            pem.extend(fs::read(self.certificates.join(format!("{identity}.key"))).unwrap());
+            builder = builder.identity(reqwest::Identity::from_pem(&pem).unwrap());
+        }
+
+        builder.build().unwrap()
+    }
+
+    async fn query(&self, client: &reqwest::Client, headers: &[(&str, &str)]) -> reqwest::Result<reqwest::Response> {
+        let mut request = client
+            .post(&self.url)
+            .json(&serde_json::json!({ "query": "query { me { username } }" }));
+
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+
+        request.send().await
+    }
+
+    /// The certificate headers received by the subgraph for each request.
+    async fn subgraph_certificate_headers(&self) -> Vec<(Option<String>, Option<String>)> {
+        let header = |request: &wiremock::Request, name: &str| {
+            request
+                .headers
+                .get(name)
+                .map(|value| value.to_str().unwrap().to_string())
+        };
+
+        self.subgraph
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| (header(request, SUBJECT_HEADER), header(request, SANS_HEADER)))
+            .collect()
+    }
+}
+
+/// Starts a gateway listening over TLS, verifying client certificates with `ca.pem` in the
+/// `client_auth` mode and with the extra `config` appended to the configuration.
+fn with_tls_gateway<T, F>(client_auth: &str, config: &str, test: T)
+where
+    T: FnOnce(TlsGateway) -> F,
+    F: Future<Output = ()>,
+{
+    let temp_dir = TempDir::new().unwrap();
+    let certificates = temp_dir.path().join("certs");
+    fs::create_dir(&certificates).unwrap();
+
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tls/certs");
+    for name in CERTIFICATES {
+        fs::copy(source.join(name), certificates.join(name)).unwrap();
+    }
+
+    let subgraph = runtime().block_on(async {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/accounts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "me": { "username": "Alice" } }
+            })))
+            .mount(&server)
+            .await;
+
+        server
+    });
+
+    let mut hb = handlebars::Handlebars::new();
+    hb.register_template_string("t1", load_schema("contract")).unwrap();
+
+    let data = BTreeMap::from([("subgraph_endpoint", format!("http://{}", subgraph.address()))]);
+    let schema_path = temp_dir.path().join("schema.graphql");
+    fs::write(&schema_path, hb.render("t1", &data).unwrap()).unwrap();
+
+    let certs = certificates.display();
+    let config = formatdoc! {r#"
+        [tls]
+        certificate = "{certs}/server.pem"
+        key = "{certs}/server.key"
+        client_ca = "{certs}/ca.pem"
+        client_auth = "{client_auth}"
+
+        [[headers]]
+        rule = "forward"
+        name = "{SUBJECT_HEADER}"
+
+        [[headers]]
+        rule = "forward"
+        name = "{SANS_HEADER}"
+
+        {config}
+    "#};
+
+    let config_path = temp_dir.path().join("grafbase.toml");
+    fs::write(&config_path, config).unwrap();
+
+    let addr = listen_address();
+
+    let command = cmd!(
+        cargo_bin("grafbase-gateway"),
+        "--listen-address",
+        addr.to_string(),
+        "--schema",
+        &schema_path,
+        "--config",
+        &config_path,
+    )
+    .stdout_null()
+    .stderr_null();
+
+    let mut commands = CommandHandles::new();
+    commands.push(command.start().unwrap());
+
+    let gateway = TlsGateway {
+        url: format!("https://localhost:{}/graphql", addr.port()),
+        certificates,
+        subgraph,
+    };
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        runtime().block_on(async {
+            wait_for_listener(addr).await;
+            test(gateway).await
+        })
+    }));
+
+    commands.kill_all();
+
+    if let Err(err) = res {
+        std::panic::resume_unwind(err);
+    }
+}
+
+/// The listener only accepts TLS connections, so we wait for it to accept TCP connections.
+async fn wait_for_listener(addr: SocketAddr) {
+    for _ in 0..300 {
+        if tokio::net::TcpStream::connect(addr).await.is_ok() {
+            return;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("the gateway did not start listening on {addr}");
+}
+
+#[test]
+fn client_certificate_is_required() {
+    with_tls_gateway("required", "", |gateway| async move {
+        let without_certificate = gateway.client("ca", None);
+        assert!(gateway.query(&without_certificate, &[]).await.is_err());
+
+        let untrusted = gateway.client("ca", Some("untrusted-client"));
+        assert!(gateway.query(&untrusted, &[]).await.is_err());
+
+        let trusted = gateway.client("ca", Some("client"));
+        let response = gateway.query(&trusted, &[]).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        // Only the request with a valid certificate reached the subgraph.
+        assert_eq!(gateway.subgraph.received_requests().await.unwrap().len(), 1);
+    });
+}
+
+#[test]
+fn client_certificate_sets_headers() {
+    with_tls_gateway("required", "", |gateway| async move {
+        let client = gateway.client("ca", Some("client"));
+        let spoofed = [(SUBJECT_HEADER, "CN=admin"), (SANS_HEADER, "DNS:admin.example.com")];
+
+        let response = gateway.query(&client, &spoofed).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        insta::assert_debug_snapshot!(gateway.subgraph_certificate_headers().await, @r###"
+        [
+            (
+                Some(
+                    "CN=test-client, O=Grafbase",
+                ),
+                Some(
+                    "DNS:client.example.com, email:client@example.com",
+                ),
+            ),
+        ]
+        "###);
+    });
+}
+
+#[test]
+fn client_supplied_certificate_headers_are_stripped() {
+    with_tls_gateway("optional", "", |gateway| async move {
+        let client = gateway.client("ca", None);
+        let spoofed = [(SUBJECT_HEADER, "CN=admin"), (SANS_HEADER, "DNS:admin.example.com")];
+
+        let response = gateway.query(&client, &spoofed).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        assert_eq!(gateway.subgraph_certificate_headers().await, vec![(None, None)]);
+    });
+}
+
+#[test]
+fn rate_limit_per_client_certificate() {
+    let config = indoc::indoc! {r#"
+        [gateway.rate_limit]
+        global = { limit = 1, duration = "1s" }
+        client_key = "client_certificate"
+    "#};
+
+    with_tls_gateway("optional", config, |gateway| async move {
+        let client = gateway.client("ca", Some("client"));
+        let anonymous = gateway.client("ca", None);
+
+        let response = gateway.query(&client, &[]).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let response = gateway.query(&client, &[]).await.unwrap();
+        assert_eq!(response.status(), 429);
+
+        // Other clients have their own limit, which can't be picked with the certificate headers.
+        let response = gateway
+            .query(&anonymous, &[(SUBJECT_HEADER, "CN=test-client, O=Grafbase")])
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    });
+}
+
+#[test]
+fn certificates_are_reloaded() {
+    with_tls_gateway("required", "", |gateway| async move {
+        // Only trusts the certificate the gateway doesn't use yet.
+        let client = gateway.client("other-ca", Some("client"));
+        assert!(gateway.query(&client, &[]).await.is_err());
+
+        for name in ["server.pem", "server.key"] {
+            fs::copy(
+                gateway.certificates.join(format!("other-{name}")),
+                gateway.certificates.join(name),
+            )
+            .unwrap();
+        }
+
+        // The files are polled every second.
+        for _ in 0..100 {
+            if let Ok(response) = gateway.query(&client, &[]).await {
+                assert_eq!(response.status(), 200);
+                return;
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        panic!("the new certificate was not picked up");
+    });
+}
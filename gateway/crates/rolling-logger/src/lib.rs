@@ -10,13 +10,16 @@
 //!
 //! The `RollingLogger` struct provides methods to create a new logger,
 //! write data to the log, flush the log, and rotate the log file based on
-//! the specified strategy.
+//! the specified strategy. Rotated files can optionally be compressed with
+//! gzip and pruned by count or age with a [`Retention`].
 
 #![deny(missing_docs)]
 
 mod log_file;
+mod retention;
 mod strategy;
 
+pub use retention::Retention;
 pub use strategy::RotateStrategy;
 
 use grafbase_workspace_hack as _;
@@ -32,10 +35,14 @@ use std::{
 ///
 /// * `path` - The base file path for the current log file.
 /// * `file` - The current log file being written to.
+/// * `retention` - Which rotated files are kept on disk.
+/// * `compress` - Whether rotated files are compressed with gzip.
 #[derive(Debug)]
 pub struct RollingLogger {
     path: PathBuf,
     file: LogFile,
+    retention: Retention,
+    compress: bool,
 }
 
 impl RollingLogger {
@@ -56,14 +63,30 @@ impl RollingLogger {
         Ok(Self {
             path: path.to_owned(),
             file,
+            retention: Retention::default(),
+            compress: false,
         })
     }
 
+    /// Sets which rotated files are kept on disk. Files outside of the retention are
+    /// deleted after every rotation.
+    pub fn with_retention(mut self, retention: Retention) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Compresses rotated files with gzip, adding a `.gz` extension to their name.
+    pub fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
     /// Flushes the current log file and rotates it according to the specified strategy.
     ///
     /// This method first flushes the current log file to ensure all pending data is written.
     /// It then renames the current log file by appending a timestamp to its name and creates
-    /// a new log file to continue logging.
+    /// a new log file to continue logging. Finally the rotated file is compressed if enabled,
+    /// and rotated files outside of the retention are deleted.
     ///
     /// # Returns
     ///
@@ -79,6 +102,12 @@ impl RollingLogger {
 
         self.file = LogFile::new(&self.path, self.file.copy_new_rotate())?;
 
+        if self.compress {
+            retention::compress(Path::new(&path))?;
+        }
+
+        retention::apply(&self.path, self.retention)?;
+
         Ok(())
     }
 
@@ -125,7 +154,7 @@ impl Write for RollingLogger {
 
 #[cfg(test)]
 mod tests {
-    use super::{RollingLogger, RotateStrategy};
+    use super::{Retention, RollingLogger, RotateStrategy};
     use std::{
         io::Write,
         time::{Duration, SystemTime},
@@ -281,4 +310,105 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn retention_max_files() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("access.log");
+
+        let mut logger = RollingLogger::new(&path, RotateStrategy::size(3))
+            .unwrap()
+            .with_retention(Retention {
+                max_files: Some(1),
+                max_age: None,
+            });
+
+        writeln!(&mut logger, "foo").unwrap();
+
+        logger.set_rotate_start(SystemTime::UNIX_EPOCH + Duration::from_millis(1));
+        writeln!(&mut logger, "bar").unwrap();
+
+        logger.set_rotate_start(SystemTime::UNIX_EPOCH + Duration::from_millis(2));
+        writeln!(&mut logger, "lol").unwrap();
+
+        let mut files: Vec<_> = dir
+            .path()
+            .read_dir()
+            .unwrap()
+            .map(|file| file.unwrap().file_name().into_string().unwrap())
+            .collect();
+
+        files.sort();
+
+        assert_eq!(files, ["access.log", "access.log.2"]);
+    }
+
+    #[test]
+    fn retention_max_age() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("access.log");
+
+        let mut logger = RollingLogger::new(&path, RotateStrategy::size(3))
+            .unwrap()
+            .with_retention(Retention {
+                max_files: None,
+                max_age: Some(Duration::from_secs(60)),
+            });
+
+        writeln!(&mut logger, "foo").unwrap();
+
+        logger.set_rotate_start(SystemTime::now() - Duration::from_secs(120));
+        writeln!(&mut logger, "bar").unwrap();
+
+        writeln!(&mut logger, "lol").unwrap();
+
+        let rotated = dir
+            .path()
+            .read_dir()
+            .unwrap()
+            .map(|file| file.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name != "access.log")
+            .collect::<Vec<_>>();
+
+        assert_eq!(rotated.len(), 1);
+
+        let data = std::fs::read_to_string(dir.path().join(&rotated[0])).unwrap();
+
+        insta::assert_snapshot!(&data, @r###"
+        bar
+        "###);
+    }
+
+    #[test]
+    fn compress_rotated() {
+        use std::io::Read;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("access.log");
+
+        let mut logger = RollingLogger::new(&path, RotateStrategy::size(3))
+            .unwrap()
+            .with_compression(true);
+
+        writeln!(&mut logger, "foo").unwrap();
+
+        logger.set_rotate_start(SystemTime::UNIX_EPOCH + Duration::from_millis(1));
+        writeln!(&mut logger, "bar").unwrap();
+
+        assert!(!dir.path().join("access.log.1").exists());
+
+        let file = std::fs::File::open(dir.path().join("access.log.1.gz")).unwrap();
+        let mut data = String::new();
+        flate2::read::GzDecoder::new(file).read_to_string(&mut data).unwrap();
+
+        insta::assert_snapshot!(&data, @r###"
+        foo
+        "###);
+
+        let data = std::fs::read_to_string(path).unwrap();
+
+        insta::assert_snapshot!(&data, @r###"
+        bar
+        "###);
+    }
 }
@@ -0,0 +1,110 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use flate2::{write::GzEncoder, Compression};
+
+const GZIP_EXTENSION: &str = "gz";
+
+/// Defines which rotated log files are kept on disk.
+///
+/// By default every rotated file is kept forever.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Retention {
+    /// The maximum number of rotated files to keep. The oldest files are deleted first.
+    pub max_files: Option<usize>,
+    /// Rotated files started longer ago than this are deleted.
+    pub max_age: Option<Duration>,
+}
+
+impl Retention {
+    fn is_unlimited(&self) -> bool {
+        self.max_files.is_none() && self.max_age.is_none()
+    }
+}
+
+/// Compresses a rotated log file with gzip, replacing it with a `.gz` file.
+///
+/// # Returns
+///
+/// The path of the compressed file.
+pub(crate) fn compress(path: &Path) -> io::Result<PathBuf> {
+    let mut compressed_path = path.as_os_str().to_os_string();
+    compressed_path.push(format!(".{GZIP_EXTENSION}"));
+    let compressed_path = PathBuf::from(compressed_path);
+
+    let mut input = BufReader::new(File::open(path)?);
+    let mut encoder = GzEncoder::new(BufWriter::new(File::create(&compressed_path)?), Compression::default());
+
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+
+    std::fs::remove_file(path)?;
+
+    Ok(compressed_path)
+}
+
+/// Deletes the rotated files of the log at `path` which fall outside of the given retention.
+///
+/// Rotated files are recognized by their name: the name of the log file followed by the
+/// millisecond timestamp of when the file was started, and optionally the gzip extension.
+pub(crate) fn apply(path: &Path, retention: Retention) -> io::Result<()> {
+    if retention.is_unlimited() {
+        return Ok(());
+    }
+
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return Ok(());
+    };
+
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let mut rotated = Vec::new();
+
+    for entry in directory.read_dir()? {
+        let entry = entry?;
+
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else { continue };
+
+        if let Some(timestamp) = rotated_timestamp(file_name, name) {
+            rotated.push((timestamp, entry.path()));
+        }
+    }
+
+    // Newest first.
+    rotated.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
+
+    let cutoff = retention
+        .max_age
+        .and_then(|max_age| SystemTime::now().checked_sub(max_age))
+        .and_then(|cutoff| cutoff.duration_since(UNIX_EPOCH).ok())
+        .map(|cutoff| cutoff.as_millis());
+
+    for (i, (timestamp, path)) in rotated.into_iter().enumerate() {
+        let too_many = retention.max_files.is_some_and(|max_files| i >= max_files);
+        let too_old = cutoff.is_some_and(|cutoff| timestamp < cutoff);
+
+        if too_many || too_old {
+            std::fs::remove_file(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn rotated_timestamp(file_name: &str, candidate: &str) -> Option<u128> {
+    let suffix = candidate.strip_prefix(file_name)?.strip_prefix('.')?;
+    let suffix = suffix
+        .strip_suffix(GZIP_EXTENSION)
+        .and_then(|suffix| suffix.strip_suffix('.'))
+        .unwrap_or(suffix);
+
+    suffix.parse().ok()
+}
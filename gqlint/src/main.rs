@@ -1,8 +1,8 @@
 use grafbase_workspace_hack as _;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
-use graphql_lint::{lint, LinterError, Severity};
+use graphql_lint::{lint_with_config, output, Diagnostic, LintConfig, LinterError, Severity};
 use std::{fs, path::PathBuf, process};
 
 #[derive(Debug, Parser)]
@@ -10,12 +10,30 @@ use std::{fs, path::PathBuf, process};
 struct Interface {
     /// The GraphQL SDL file to lint
     schema: PathBuf,
+    /// A TOML file enabling, disabling or changing the severity of rules
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// The output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+    /// Exit with a non-zero code if any warning is found, not only errors
+    #[arg(long)]
+    deny_warnings: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+    Sarif,
 }
 
 #[derive(Debug, thiserror::Error)]
 enum Error {
     #[error("Could not read the provided schema file\nCaused by: {0}")]
-    ReadSchemaFile(#[from] std::io::Error),
+    ReadSchemaFile(std::io::Error),
+    #[error("Could not read the provided configuration file\nCaused by: {0}")]
+    ReadConfigFile(std::io::Error),
     #[error(transparent)]
     Lint(#[from] LinterError),
 }
@@ -24,11 +42,24 @@ fn report_error(error: Error) {
     eprintln!("{}", format!("Error: {error}").bright_red());
 }
 
-fn report_diagnostic(message: String, severity: Severity) {
-    let label = match severity {
-        Severity::Warning => "Warning",
-    };
-    println!("{}", format!("⚠️ [{label}]: {message}").bright_yellow());
+fn report_diagnostic(schema: &str, diagnostic: &Diagnostic) {
+    let Diagnostic {
+        rule,
+        severity,
+        message,
+        location,
+    } = diagnostic;
+
+    match severity {
+        Severity::Warning => println!(
+            "{}",
+            format!("⚠️ [Warning] {schema}:{location}: {message} ({rule})").bright_yellow()
+        ),
+        Severity::Error => println!(
+            "{}",
+            format!("❌ [Error] {schema}:{location}: {message} ({rule})").bright_red()
+        ),
+    }
 }
 
 fn report_success() {
@@ -39,7 +70,8 @@ fn main() {
     let arguments = Interface::parse();
 
     let exit_code = match try_main(arguments) {
-        Ok(()) => 0,
+        Ok(true) => 0,
+        Ok(false) => 1,
         Err(error) => {
             report_error(error);
             1
@@ -49,18 +81,33 @@ fn main() {
     process::exit(exit_code);
 }
 
-fn try_main(arguments: Interface) -> Result<(), Error> {
-    let schema = fs::read_to_string(arguments.schema)?;
-    let diagnostics = lint(&schema)?;
+/// Returns whether the schema passed the lint.
+fn try_main(arguments: Interface) -> Result<bool, Error> {
+    let schema = fs::read_to_string(&arguments.schema).map_err(Error::ReadSchemaFile)?;
 
-    if diagnostics.is_empty() {
-        report_success();
-        return Ok(());
-    }
+    let config = match arguments.config {
+        Some(path) => LintConfig::from_toml(&fs::read_to_string(path).map_err(Error::ReadConfigFile)?)?,
+        None => LintConfig::default(),
+    };
+
+    let diagnostics = lint_with_config(&schema, &config)?;
+    let schema_path = arguments.schema.to_string_lossy();
 
-    for (message, severity) in diagnostics {
-        report_diagnostic(message, severity);
+    match arguments.format {
+        OutputFormat::Text if diagnostics.is_empty() => report_success(),
+        OutputFormat::Text => {
+            for diagnostic in &diagnostics {
+                report_diagnostic(&schema_path, diagnostic);
+            }
+        }
+        OutputFormat::Json => println!("{}", output::to_json(&diagnostics)),
+        OutputFormat::Sarif => println!("{}", output::to_sarif(&diagnostics, &schema_path)),
     }
 
-    Ok(())
+    let passed = diagnostics.iter().all(|diagnostic| match diagnostic.severity {
+        Severity::Warning => !arguments.deny_warnings,
+        Severity::Error => false,
+    });
+
+    Ok(passed)
 }
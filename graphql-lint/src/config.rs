@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use crate::{LinterError, Rule, Severity};
+
+/// Lint configuration, usually loaded from a TOML file:
+///
+/// ```toml
+/// [rules]
+/// deprecated-without-reason = "error"
+/// root-field-affixes = "off"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Overrides of the default level of each rule.
+    pub rules: BTreeMap<Rule, RuleLevel>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    Warning,
+    Error,
+}
+
+impl LintConfig {
+    pub fn from_toml(config: &str) -> Result<Self, LinterError> {
+        toml::from_str(config).map_err(|error| LinterError::Config(error.to_string()))
+    }
+
+    /// The severity with which the rule is reported, `None` if the rule is disabled.
+    pub fn severity(&self, rule: Rule) -> Option<Severity> {
+        self.rules
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_level())
+            .severity()
+    }
+}
+
+impl RuleLevel {
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Warning => Some(Severity::Warning),
+            RuleLevel::Error => Some(Severity::Error),
+        }
+    }
+}
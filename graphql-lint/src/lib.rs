@@ -1,447 +1,35 @@
 use grafbase_workspace_hack as _;
 
-use cynic_parser::type_system::{
-    Definition, Directive, DirectiveDefinition, EnumDefinition, EnumValueDefinition, FieldDefinition,
-    InputObjectDefinition, InputValueDefinition, InterfaceDefinition, ObjectDefinition, ScalarDefinition,
-    TypeDefinition, UnionDefinition,
-};
-use cynic_parser::TypeSystemDocument;
-use heck::{ToLowerCamelCase, ToPascalCase, ToShoutySnakeCase};
-use thiserror::Error;
-
-enum CaseMatch<'a> {
-    Correct,
-    Incorrect { current: &'a str, fix: String },
-}
+mod config;
+mod diagnostic;
+mod linter;
+pub mod output;
+mod rules;
 
-enum Case {
-    Pascal,
-    ShoutySnake,
-    Camel,
-}
+pub use config::{LintConfig, RuleLevel};
+pub use diagnostic::{Diagnostic, Location, Severity};
+pub use rules::Rule;
 
-pub enum Severity {
-    Warning,
-}
+use linter::SchemaLinter;
+use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum LinterError {
     #[error("encountered a parsing error:\n{0}")]
     Parse(String),
+    #[error("invalid lint configuration:\n{0}")]
+    Config(String),
 }
 
-pub fn lint(schema: &str) -> Result<Vec<(String, Severity)>, LinterError> {
-    let parsed_schema =
-        cynic_parser::parse_type_system_document(schema).map_err(|error| LinterError::Parse(error.to_string()))?;
-    Ok(SchemaLinter::new().lint(&parsed_schema))
-}
-
-struct SchemaLinter {
-    diagnostics: Vec<(String, Severity)>,
+/// Lints the schema with every rule enabled at its default severity.
+pub fn lint(schema: &str) -> Result<Vec<Diagnostic>, LinterError> {
+    lint_with_config(schema, &LintConfig::default())
 }
 
-impl<'a> SchemaLinter {
-    pub fn new() -> Self {
-        Self {
-            diagnostics: Vec::new(),
-        }
-    }
-
-    pub fn lint(mut self, schema: &'a TypeSystemDocument) -> Vec<(String, Severity)> {
-        schema.definitions().for_each(|definition| match definition {
-            Definition::Schema(_) => {}
-            Definition::SchemaExtension(_) => {}
-            // TODO: we can optimize this by not rechecking spelling for extensions.
-            // We'll also need to do this to avoid duplicate warnings if extending a type with an incorrect name
-            Definition::TypeExtension(r#type) | Definition::Type(r#type) => {
-                match r#type {
-                    TypeDefinition::Scalar(scalar) => {
-                        self.visit_scalar(scalar);
-                        scalar
-                            .directives()
-                            .for_each(|directive| self.visit_directive_usage(r#type, directive));
-                    }
-                    TypeDefinition::Object(object) => {
-                        self.visit_object(object);
-                        object
-                            .directives()
-                            .for_each(|directive| self.visit_directive_usage(r#type, directive));
-                        object.fields().for_each(|field| {
-                            self.visit_field(r#type, field);
-                            field
-                                .arguments()
-                                .for_each(|argument| self.visit_field_argument(r#type, field, argument));
-                            field.directives().for_each(|directive_usage| {
-                                self.visit_directive_usage_field(r#type, field, directive_usage);
-                            });
-                        });
-                    }
-                    TypeDefinition::Interface(interface) => {
-                        self.visit_interface(interface);
-                        interface
-                            .directives()
-                            .for_each(|directive| self.visit_directive_usage(r#type, directive));
-                        interface.fields().for_each(|field| {
-                            self.visit_field(r#type, field);
-                            field
-                                .arguments()
-                                .for_each(|argument| self.visit_field_argument(r#type, field, argument));
-                            field.directives().for_each(|directive_usage| {
-                                self.visit_directive_usage_field(r#type, field, directive_usage);
-                            });
-                        });
-                    }
-                    TypeDefinition::Union(union) => {
-                        self.visit_union(union);
-                        union
-                            .directives()
-                            .for_each(|directive| self.visit_directive_usage(r#type, directive))
-                    }
-                    TypeDefinition::Enum(r#enum) => {
-                        self.visit_enum(r#enum);
-                        r#enum
-                            .directives()
-                            .for_each(|directive| self.visit_directive_usage(r#type, directive));
-                        r#enum.values().for_each(|value| {
-                            self.visit_enum_value(r#type, value);
-                            value.directives().for_each(|directive_usage| {
-                                self.visit_directive_usage_enum_value(r#enum, value, directive_usage);
-                            });
-                        });
-                    }
-                    TypeDefinition::InputObject(input_object) => {
-                        self.visit_input_object(input_object);
-                        input_object
-                            .directives()
-                            .for_each(|directive| self.visit_directive_usage(r#type, directive));
-                        input_object.fields().for_each(|input_value| {
-                            self.visit_input_value(r#type, input_value);
-                            input_value.directives().for_each(|directive_usage| {
-                                self.visit_directive_usage_input_value(input_object, input_value, directive_usage);
-                            });
-                        });
-                    }
-                };
-            }
-            Definition::Directive(directive) => {
-                self.visit_directive(directive);
-                directive
-                    .arguments()
-                    .for_each(|argument| self.visit_directive_argument(directive, argument));
-            }
-        });
-
-        self.diagnostics
-    }
-
-    fn case_check(current: &'a str, case: Case) -> CaseMatch<'_> {
-        let fix = match case {
-            Case::Pascal => current.to_pascal_case(),
-            Case::ShoutySnake => current.to_shouty_snake_case(),
-            Case::Camel => current.to_lower_camel_case(),
-        };
-
-        if fix == current {
-            CaseMatch::Correct
-        } else {
-            CaseMatch::Incorrect { current, fix }
-        }
-    }
-
-    pub fn visit_field_argument(
-        &mut self,
-        parent_type: TypeDefinition<'_>,
-        field: FieldDefinition<'_>,
-        argument: InputValueDefinition<'_>,
-    ) {
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(argument.name(), Case::Camel) {
-            self.diagnostics.push((
-                format!(
-                    "argument '{current}' on field '{}' on {} '{}' should be renamed to '{fix}'",
-                    field.name(),
-                    Self::type_definition_display(parent_type),
-                    parent_type.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_directive_argument(&mut self, directive: DirectiveDefinition<'_>, argument: InputValueDefinition<'_>) {
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(argument.name(), Case::Camel) {
-            self.diagnostics.push((
-                format!(
-                    "argument '{current}' on directive '{}' should be renamed to '{fix}'",
-                    directive.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_input_value(&mut self, parent: TypeDefinition<'_>, value: InputValueDefinition<'_>) {
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(value.name(), Case::Camel) {
-            self.diagnostics.push((
-                format!(
-                    "input value '{current}' on input '{}' should be renamed to '{fix}'",
-                    parent.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    fn type_definition_display(kind: TypeDefinition<'_>) -> &'static str {
-        match kind {
-            TypeDefinition::Scalar(_) => "scalar",
-            TypeDefinition::Object(_) => "type",
-            TypeDefinition::Interface(_) => "interface",
-            TypeDefinition::Union(_) => "union",
-            TypeDefinition::Enum(_) => "enum",
-            TypeDefinition::InputObject(_) => "input",
-        }
-    }
-
-    pub fn visit_field(&mut self, parent: TypeDefinition<'_>, field: FieldDefinition<'_>) {
-        let field_name = field.name();
-
-        // ignore system fields
-        if field_name.starts_with("__") {
-            return;
-        }
-
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(field_name, Case::Camel) {
-            self.diagnostics.push((
-                format!(
-                    "field '{current}' on {} '{}' should be renamed to '{fix}'",
-                    Self::type_definition_display(parent),
-                    parent.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-        match parent.name() {
-            "Query" => {
-                for prefix in ["query", "get", "list"] {
-                    if field_name.starts_with(prefix) {
-                        self.diagnostics.push((
-                            format!("field '{field_name}' on type 'Query' has a forbidden prefix: '{prefix}'"),
-                            Severity::Warning,
-                        ));
-                        break;
-                    }
-                }
-                if field_name.ends_with("Query") {
-                    self.diagnostics.push((
-                        format!("field '{field_name}' on type 'Query' has a forbidden suffix: 'Query'"),
-                        Severity::Warning,
-                    ));
-                }
-            }
-            "Mutation" => {
-                for prefix in ["mutation", "put", "post", "patch"] {
-                    if field_name.starts_with(prefix) {
-                        self.diagnostics.push((
-                            format!("field '{field_name}' on type 'Mutation' has a forbidden prefix: '{prefix}'"),
-                            Severity::Warning,
-                        ));
-                        break;
-                    }
-                }
-                if field_name.ends_with("Mutation") {
-                    self.diagnostics.push((
-                        format!("field '{field_name}' on type 'Mutation' has a forbidden suffix: 'Mutation'"),
-                        Severity::Warning,
-                    ));
-                }
-            }
-            "Subscription" => {
-                if field_name.starts_with("subscription") {
-                    self.diagnostics.push((
-                        format!("field '{field_name}' on type 'Subscription' has a forbidden prefix: 'subscription'"),
-                        Severity::Warning,
-                    ));
-                }
-                if field_name.ends_with("Subscription") {
-                    self.diagnostics.push((
-                        format!("field '{field_name}' on type 'Subscription' has a forbidden suffix: 'Subscription'"),
-                        Severity::Warning,
-                    ));
-                }
-            }
-            _ => {}
-        }
-    }
-
-    pub fn visit_directive(&mut self, directive: DirectiveDefinition<'_>) {
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(directive.name(), Case::Camel) {
-            self.diagnostics.push((
-                format!("directive '{current}' should be renamed to '{fix}'"),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_directive_usage(&mut self, parent: TypeDefinition<'_>, directive: Directive<'_>) {
-        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
-            self.diagnostics.push((
-                format!(
-                    "usage of directive 'deprecated' on {} '{}' does not populate the 'reason' argument",
-                    Self::type_definition_display(parent),
-                    parent.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_directive_usage_field(
-        &mut self,
-        parent_type: TypeDefinition<'_>,
-        parent_field: FieldDefinition<'_>,
-        directive: Directive<'_>,
-    ) {
-        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
-            self.diagnostics.push((
-                format!(
-                    "usage of directive 'deprecated' on field '{}' on {} '{}' does not populate the 'reason' argument",
-                    parent_field.name(),
-                    Self::type_definition_display(parent_type),
-                    parent_type.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_directive_usage_input_value(
-        &mut self,
-        parent_input: InputObjectDefinition<'_>,
-        parent_input_value: InputValueDefinition<'_>,
-        directive: Directive<'_>,
-    ) {
-        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
-            self.diagnostics.push((
-                format!(
-                    "usage of directive 'deprecated' on input value '{}' on input '{}' does not populate the 'reason' argument",
-                    parent_input_value.name(),
-                    parent_input.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_directive_usage_enum_value(
-        &mut self,
-        parent_enum: EnumDefinition<'_>,
-        parent_value: EnumValueDefinition<'_>,
-        directive: Directive<'_>,
-    ) {
-        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
-            self.diagnostics.push((
-                format!(
-                    "usage of directive 'deprecated' on enum value '{}' on enum '{}' does not populate the 'reason' argument",
-                    parent_value.value(),
-                    parent_enum.name()
-                ),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_input_object(&mut self, _input_object: InputObjectDefinition<'_>) {}
-
-    pub fn visit_union(&mut self, union: UnionDefinition<'_>) {
-        let union_name = union.name();
-        if union_name.starts_with("Union") {
-            self.diagnostics.push((
-                format!("union '{union_name}' has a forbidden prefix: 'Union'"),
-                Severity::Warning,
-            ));
-        }
-        if union_name.ends_with("Union") {
-            self.diagnostics.push((
-                format!("union '{union_name}' has a forbidden suffix: 'Union'"),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_scalar(&mut self, _scalar: ScalarDefinition<'_>) {}
-
-    pub fn visit_interface(&mut self, object: InterfaceDefinition<'_>) {
-        let interface_name = object.name();
-        if interface_name.starts_with("Interface") {
-            self.diagnostics.push((
-                format!("interface '{interface_name}' has a forbidden prefix: 'Interface'"),
-                Severity::Warning,
-            ));
-        }
-        if interface_name.ends_with("Interface") {
-            self.diagnostics.push((
-                format!("interface '{interface_name}' has a forbidden suffix: 'Interface'"),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_object(&mut self, object: ObjectDefinition<'_>) {
-        let object_name = object.name();
-
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(object_name, Case::Pascal) {
-            self.diagnostics.push((
-                format!("type '{current}' should be renamed to '{fix}'"),
-                Severity::Warning,
-            ));
-        }
-        if object_name.starts_with("Type") {
-            self.diagnostics.push((
-                format!("type '{object_name}' has a forbidden prefix: 'Type'"),
-                Severity::Warning,
-            ));
-        }
-        if object_name.ends_with("Type") {
-            self.diagnostics.push((
-                format!("type '{object_name}' has a forbidden suffix: 'Type'"),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_enum(&mut self, r#enum: EnumDefinition<'_>) {
-        let enum_name = r#enum.name();
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(enum_name, Case::Pascal) {
-            self.diagnostics.push((
-                format!("enum '{current}' should be renamed to '{fix}'"),
-                Severity::Warning,
-            ));
-        }
-        if enum_name.starts_with("Enum") {
-            self.diagnostics.push((
-                format!("enum '{enum_name}' has a forbidden prefix: 'Enum'"),
-                Severity::Warning,
-            ));
-        }
-        if enum_name.ends_with("Enum") {
-            self.diagnostics.push((
-                format!("enum '{enum_name}' has a forbidden suffix: 'Enum'"),
-                Severity::Warning,
-            ));
-        }
-    }
-
-    pub fn visit_enum_value(&mut self, parent: TypeDefinition<'_>, enum_value: EnumValueDefinition<'_>) {
-        let enum_name = parent.name();
-
-        let name = enum_value.value();
-        if let CaseMatch::Incorrect { current, fix } = Self::case_check(name, Case::ShoutySnake) {
-            self.diagnostics.push((
-                format!("value '{current}' on enum '{enum_name}' should be renamed to '{fix}'"),
-                Severity::Warning,
-            ));
-        }
-    }
+pub fn lint_with_config(schema: &str, config: &LintConfig) -> Result<Vec<Diagnostic>, LinterError> {
+    let parsed_schema =
+        cynic_parser::parse_type_system_document(schema).map_err(|error| LinterError::Parse(error.to_string()))?;
+    Ok(SchemaLinter::new(schema, config).lint(&parsed_schema))
 }
 
 #[test]
@@ -537,7 +125,7 @@ fn linter() {
 
     let messages = diagnostics
         .iter()
-        .map(|diagnostic| diagnostic.0.clone())
+        .map(|diagnostic| diagnostic.message.clone())
         .collect::<Vec<_>>();
 
     [
@@ -652,3 +240,287 @@ fn linter() {
 
     assert!(diagnostics.is_empty());
 }
+
+#[test]
+fn rule_configuration() {
+    let schema = r#"
+        type Query {
+          getHello: String @deprecated
+        }
+    "#;
+
+    let config = LintConfig::from_toml(
+        r#"
+        [rules]
+        root-field-affixes = "off"
+        deprecated-without-reason = "error"
+        "#,
+    )
+    .unwrap();
+
+    let diagnostics = lint_with_config(schema, &config).unwrap();
+
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic {
+            rule: Rule::DeprecatedWithoutReason,
+            severity: Severity::Error,
+            message: "usage of directive 'deprecated' on field 'getHello' on type 'Query' does not populate the 'reason' argument".to_string(),
+            location: Location { line: 3, column: 11 },
+        }]
+    );
+
+    let error = LintConfig::from_toml(
+        r#"
+        [rules]
+        unknown-rule = "error"
+        "#,
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, LinterError::Config(_)));
+}
+
+#[test]
+fn machine_readable_output() {
+    let diagnostics = lint("type Query {\n  getHello: String\n}\n").unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&output::to_json(&diagnostics)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "rule": "root-field-affixes",
+            "severity": "warning",
+            "message": "field 'getHello' on type 'Query' has a forbidden prefix: 'get'",
+            "location": { "line": 2, "column": 3 },
+        }])
+    );
+
+    let sarif: serde_json::Value = serde_json::from_str(&output::to_sarif(&diagnostics, "schema.graphql")).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["columnKind"], "unicodeCodePoints");
+    assert_eq!(
+        sarif["runs"][0]["results"][0],
+        serde_json::json!({
+            "ruleId": "root-field-affixes",
+            "level": "warning",
+            "message": { "text": "field 'getHello' on type 'Query' has a forbidden prefix: 'get'" },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "schema.graphql" },
+                    "region": { "startLine": 2, "startColumn": 3 },
+                },
+            }],
+        })
+    );
+}
+
+#[cfg(test)]
+fn messages(schema: &str, config: &str) -> Vec<String> {
+    let config = LintConfig::from_toml(config).unwrap();
+    lint_with_config(schema, &config)
+        .unwrap()
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect()
+}
+
+#[test]
+fn api_design_rules() {
+    let schema = r#"
+        "The root query"
+        type Query {
+          "All users"
+          users(first: Int! @deprecated(reason: "Use `last`"), after: String): [User]!
+          "Well described"
+          tags: [String!]!
+          matrix: [[Int!]]!
+        }
+
+        type Mutation {
+          "Creates a user"
+          createUser(input: CreateUserInput!): User
+          "Deletes a user"
+          deleteUser(id: ID!): Boolean
+          "Updates a user"
+          updateUser(input: CreateUserInput): User
+        }
+
+        "A user"
+        type User {
+          "Identifier"
+          id: ID!
+        }
+
+        input CreateUserInput {
+          "Name"
+          name: String! @deprecated(reason: "Use `displayName`")
+          "Nickname"
+          nickname: String! = "anonymous" @deprecated(reason: "Use `displayName`")
+        }
+    "#;
+
+    let messages = messages(
+        schema,
+        r#"
+        [rules]
+        missing-description = "warning"
+        nullable-list-items = "warning"
+        mutation-single-input = "warning"
+        "#,
+    );
+
+    assert_eq!(
+        messages,
+        vec![
+            "field 'users' on type 'Query' returns a list with nullable items: '[User]!'",
+            "argument 'first' on field 'users' on type 'Query' is required and cannot be deprecated",
+            "field 'matrix' on type 'Query' has no description",
+            "field 'matrix' on type 'Query' returns a list with nullable items: '[[Int!]]!'",
+            "type 'Mutation' has no description",
+            "input 'CreateUserInput' has no description",
+            "input value 'name' on input 'CreateUserInput' is required and cannot be deprecated",
+            "mutation field 'deleteUser' should take a single non-null input object argument named 'input'",
+            "mutation field 'updateUser' should take a single non-null input object argument named 'input'",
+        ]
+    );
+}
+
+#[test]
+fn relay_connection_rule() {
+    let schema = r#"
+        type Query {
+          users: UserConnection!
+          posts: PostConnection!
+        }
+
+        type UserConnection {
+          edges: [UserEdge!]!
+          pageInfo: PageInfo!
+        }
+
+        type UserEdge {
+          node: User!
+          cursor: String!
+        }
+
+        type PostConnection {
+          nodes: [Post!]!
+          pageInfo: PageInfo
+        }
+
+        type PageInfo {
+          hasNextPage: Boolean!
+          hasPreviousPage: Boolean
+          startCursor: String
+          endCursor: String
+        }
+
+        type User {
+          id: ID!
+        }
+
+        type Post {
+          id: ID!
+        }
+    "#;
+
+    let messages = messages(schema, "rules.relay-connection = \"warning\"");
+
+    assert_eq!(
+        messages,
+        vec![
+            "connection 'PostConnection' should have an 'edges' field returning a list of edge objects",
+            "connection 'PostConnection' should have a 'pageInfo' field of type 'PageInfo!'",
+            "'PageInfo' should have a 'hasPreviousPage' field of type 'Boolean!'",
+        ]
+    );
+}
+
+#[test]
+fn unused_types_rule() {
+    let schema = r#"
+        type Query {
+          user(filter: UserFilter): User
+        }
+
+        type User implements Node {
+          id: ID!
+          role: Role
+        }
+
+        interface Node {
+          id: ID!
+        }
+
+        input UserFilter {
+          role: Role
+        }
+
+        enum Role {
+          ADMIN
+          MEMBER
+        }
+
+        type Orphan {
+          id: ID!
+        }
+
+        type Product @key(fields: "id") {
+          id: ID!
+        }
+
+        scalar Unused
+    "#;
+
+    let messages = messages(schema, "rules.unused-types = \"warning\"");
+
+    assert_eq!(
+        messages,
+        vec![
+            "type 'Orphan' is not used anywhere in the schema",
+            "scalar 'Unused' is not used anywhere in the schema",
+        ]
+    );
+}
+
+#[test]
+fn federation_rules() {
+    let schema = r#"
+        type Query {
+          products: [Product!]!
+        }
+
+        type Product @key(fields: "id sku") @key(fields: "upc organization { id }") {
+          id: ID!
+          sku: String
+          upc: String!
+          weight: Int @external
+          price: Int @external
+          shippingEstimate: Int @requires(fields: "weight")
+        }
+
+        extend type Product {
+          organization: Organization
+        }
+
+        type Organization @key(fields: "id") {
+          id: ID! @external
+        }
+    "#;
+
+    let messages = lint(schema)
+        .unwrap()
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        vec![
+            "field 'sku' in the @key of type 'Product' should be non-null, not 'String'",
+            "field 'organization' in the @key of type 'Product' should be non-null, not 'Organization'",
+            "field 'price' on type 'Product' is marked @external but is not used by any @requires, @provides or @key",
+        ]
+    );
+}
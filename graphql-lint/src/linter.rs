@@ -0,0 +1,690 @@
+mod design;
+mod federation;
+mod usage;
+
+use cynic_parser::common::WrappingType;
+use cynic_parser::type_system::{
+    Definition, Directive, DirectiveDefinition, EnumDefinition, EnumValueDefinition, FieldDefinition,
+    InputObjectDefinition, InputValueDefinition, InterfaceDefinition, ObjectDefinition, ScalarDefinition, Type,
+    TypeDefinition, UnionDefinition, Value,
+};
+use cynic_parser::{Span, TypeSystemDocument};
+use heck::{ToLowerCamelCase, ToPascalCase, ToShoutySnakeCase};
+
+use crate::{diagnostic::LineIndex, Diagnostic, LintConfig, Rule};
+
+enum CaseMatch<'a> {
+    Correct,
+    Incorrect { current: &'a str, fix: String },
+}
+
+enum Case {
+    Pascal,
+    ShoutySnake,
+    Camel,
+}
+
+pub(crate) struct SchemaLinter<'a> {
+    config: &'a LintConfig,
+    lines: LineIndex<'a>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> SchemaLinter<'a> {
+    pub fn new(source: &'a str, config: &'a LintConfig) -> Self {
+        Self {
+            config,
+            lines: LineIndex::new(source),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn lint(mut self, schema: &TypeSystemDocument) -> Vec<Diagnostic> {
+        schema.definitions().for_each(|definition| match definition {
+            Definition::Schema(_) => {}
+            Definition::SchemaExtension(_) => {}
+            // TODO: we can optimize this by not rechecking spelling for extensions.
+            // We'll also need to do this to avoid duplicate warnings if extending a type with an incorrect name
+            Definition::TypeExtension(r#type) | Definition::Type(r#type) => {
+                let span = definition.span();
+                if let Definition::Type(_) = definition {
+                    self.visit_type_description(r#type, span);
+                }
+                match r#type {
+                    TypeDefinition::Scalar(scalar) => {
+                        self.visit_scalar(scalar);
+                        scalar
+                            .directives()
+                            .for_each(|directive| self.visit_directive_usage(r#type, span, directive));
+                    }
+                    TypeDefinition::Object(object) => {
+                        self.visit_object(object, span);
+                        object
+                            .directives()
+                            .for_each(|directive| self.visit_directive_usage(r#type, span, directive));
+                        object.fields().for_each(|field| {
+                            self.visit_field(r#type, field);
+                            field
+                                .arguments()
+                                .for_each(|argument| self.visit_field_argument(r#type, field, argument));
+                            field.directives().for_each(|directive_usage| {
+                                self.visit_directive_usage_field(r#type, field, directive_usage);
+                            });
+                        });
+                    }
+                    TypeDefinition::Interface(interface) => {
+                        self.visit_interface(interface, span);
+                        interface
+                            .directives()
+                            .for_each(|directive| self.visit_directive_usage(r#type, span, directive));
+                        interface.fields().for_each(|field| {
+                            self.visit_field(r#type, field);
+                            field
+                                .arguments()
+                                .for_each(|argument| self.visit_field_argument(r#type, field, argument));
+                            field.directives().for_each(|directive_usage| {
+                                self.visit_directive_usage_field(r#type, field, directive_usage);
+                            });
+                        });
+                    }
+                    TypeDefinition::Union(union) => {
+                        self.visit_union(union, span);
+                        union
+                            .directives()
+                            .for_each(|directive| self.visit_directive_usage(r#type, span, directive))
+                    }
+                    TypeDefinition::Enum(r#enum) => {
+                        self.visit_enum(r#enum, span);
+                        r#enum
+                            .directives()
+                            .for_each(|directive| self.visit_directive_usage(r#type, span, directive));
+                        r#enum.values().for_each(|value| {
+                            self.visit_enum_value(r#type, value);
+                            value.directives().for_each(|directive_usage| {
+                                self.visit_directive_usage_enum_value(r#enum, value, directive_usage);
+                            });
+                        });
+                    }
+                    TypeDefinition::InputObject(input_object) => {
+                        self.visit_input_object(input_object);
+                        input_object
+                            .directives()
+                            .for_each(|directive| self.visit_directive_usage(r#type, span, directive));
+                        input_object.fields().for_each(|input_value| {
+                            self.visit_input_value(r#type, input_value);
+                            input_value.directives().for_each(|directive_usage| {
+                                self.visit_directive_usage_input_value(input_object, input_value, directive_usage);
+                            });
+                        });
+                    }
+                };
+            }
+            Definition::Directive(directive) => {
+                self.visit_directive(directive);
+                directive
+                    .arguments()
+                    .for_each(|argument| self.visit_directive_argument(directive, argument));
+            }
+        });
+
+        self.check_mutation_inputs(schema);
+        self.check_relay_connections(schema);
+        self.check_unused_types(schema);
+        self.check_federation_keys(schema);
+        self.check_federation_externals(schema);
+
+        self.diagnostics
+    }
+
+    fn report(&mut self, rule: Rule, span: Span, message: String) {
+        if let Some(severity) = self.config.severity(rule) {
+            self.diagnostics.push(Diagnostic {
+                rule,
+                severity,
+                message,
+                location: self.lines.location(span.start),
+            });
+        }
+    }
+
+    fn case_check(current: &str, case: Case) -> CaseMatch<'_> {
+        let fix = match case {
+            Case::Pascal => current.to_pascal_case(),
+            Case::ShoutySnake => current.to_shouty_snake_case(),
+            Case::Camel => current.to_lower_camel_case(),
+        };
+
+        if fix == current {
+            CaseMatch::Correct
+        } else {
+            CaseMatch::Incorrect { current, fix }
+        }
+    }
+
+    pub fn visit_field_argument(
+        &mut self,
+        parent_type: TypeDefinition<'_>,
+        field: FieldDefinition<'_>,
+        argument: InputValueDefinition<'_>,
+    ) {
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(argument.name(), Case::Camel) {
+            self.report(
+                Rule::ArgumentNameCase,
+                argument.span(),
+                format!(
+                    "argument '{current}' on field '{}' on {} '{}' should be renamed to '{fix}'",
+                    field.name(),
+                    Self::type_definition_display(parent_type),
+                    parent_type.name()
+                ),
+            );
+        }
+
+        let location = || {
+            format!(
+                "argument '{}' on field '{}' on {} '{}'",
+                argument.name(),
+                field.name(),
+                Self::type_definition_display(parent_type),
+                parent_type.name()
+            )
+        };
+        self.visit_deprecated_input_value(argument, location);
+    }
+
+    pub fn visit_directive_argument(&mut self, directive: DirectiveDefinition<'_>, argument: InputValueDefinition<'_>) {
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(argument.name(), Case::Camel) {
+            self.report(
+                Rule::ArgumentNameCase,
+                argument.span(),
+                format!(
+                    "argument '{current}' on directive '{}' should be renamed to '{fix}'",
+                    directive.name()
+                ),
+            );
+        }
+
+        let location = || format!("argument '{}' on directive '{}'", argument.name(), directive.name());
+        self.visit_deprecated_input_value(argument, location);
+    }
+
+    pub fn visit_input_value(&mut self, parent: TypeDefinition<'_>, value: InputValueDefinition<'_>) {
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(value.name(), Case::Camel) {
+            self.report(
+                Rule::InputValueNameCase,
+                value.span(),
+                format!(
+                    "input value '{current}' on input '{}' should be renamed to '{fix}'",
+                    parent.name()
+                ),
+            );
+        }
+
+        if value.description().is_none() && !is_inaccessible(value.directives()) {
+            self.report(
+                Rule::MissingDescription,
+                value.span(),
+                format!(
+                    "input value '{}' on input '{}' has no description",
+                    value.name(),
+                    parent.name()
+                ),
+            );
+        }
+
+        if is_deprecated(value.directives()) && is_required(value) {
+            self.report(
+                Rule::DeprecatedRequiredArgument,
+                value.span(),
+                format!(
+                    "input value '{}' on input '{}' is required and cannot be deprecated",
+                    value.name(),
+                    parent.name()
+                ),
+            );
+        }
+    }
+
+    /// Checks `@deprecated` usages on arguments, which are otherwise not visited.
+    fn visit_deprecated_input_value(&mut self, argument: InputValueDefinition<'_>, location: impl Fn() -> String) {
+        let Some(directive) = argument.directives().find(|directive| directive.name() == "deprecated") else {
+            return;
+        };
+
+        if !directive.arguments().any(|argument| argument.name() == "reason") {
+            self.report(
+                Rule::DeprecatedWithoutReason,
+                argument.span(),
+                format!(
+                    "usage of directive 'deprecated' on {} does not populate the 'reason' argument",
+                    location()
+                ),
+            );
+        }
+
+        if is_required(argument) {
+            self.report(
+                Rule::DeprecatedRequiredArgument,
+                argument.span(),
+                format!("{} is required and cannot be deprecated", location()),
+            );
+        }
+    }
+
+    fn visit_type_description(&mut self, definition: TypeDefinition<'_>, span: Span) {
+        if definition.description().is_none() && !is_inaccessible(definition.directives()) {
+            self.report(
+                Rule::MissingDescription,
+                span,
+                format!(
+                    "{} '{}' has no description",
+                    Self::type_definition_display(definition),
+                    definition.name()
+                ),
+            );
+        }
+    }
+
+    fn type_definition_display(kind: TypeDefinition<'_>) -> &'static str {
+        match kind {
+            TypeDefinition::Scalar(_) => "scalar",
+            TypeDefinition::Object(_) => "type",
+            TypeDefinition::Interface(_) => "interface",
+            TypeDefinition::Union(_) => "union",
+            TypeDefinition::Enum(_) => "enum",
+            TypeDefinition::InputObject(_) => "input",
+        }
+    }
+
+    pub fn visit_field(&mut self, parent: TypeDefinition<'_>, field: FieldDefinition<'_>) {
+        let field_name = field.name();
+        let span = field.span();
+
+        // ignore system fields
+        if field_name.starts_with("__") {
+            return;
+        }
+
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(field_name, Case::Camel) {
+            self.report(
+                Rule::FieldNameCase,
+                span,
+                format!(
+                    "field '{current}' on {} '{}' should be renamed to '{fix}'",
+                    Self::type_definition_display(parent),
+                    parent.name()
+                ),
+            );
+        }
+
+        if field.description().is_none() && !is_inaccessible(field.directives()) {
+            self.report(
+                Rule::MissingDescription,
+                span,
+                format!(
+                    "field '{field_name}' on {} '{}' has no description",
+                    Self::type_definition_display(parent),
+                    parent.name()
+                ),
+            );
+        }
+
+        if has_nullable_list_items(field.ty()) {
+            self.report(
+                Rule::NullableListItems,
+                span,
+                format!(
+                    "field '{field_name}' on {} '{}' returns a list with nullable items: '{}'",
+                    Self::type_definition_display(parent),
+                    parent.name(),
+                    field.ty()
+                ),
+            );
+        }
+
+        match parent.name() {
+            "Query" => {
+                for prefix in ["query", "get", "list"] {
+                    if field_name.starts_with(prefix) {
+                        self.report(
+                            Rule::RootFieldAffixes,
+                            span,
+                            format!("field '{field_name}' on type 'Query' has a forbidden prefix: '{prefix}'"),
+                        );
+                        break;
+                    }
+                }
+                if field_name.ends_with("Query") {
+                    self.report(
+                        Rule::RootFieldAffixes,
+                        span,
+                        format!("field '{field_name}' on type 'Query' has a forbidden suffix: 'Query'"),
+                    );
+                }
+            }
+            "Mutation" => {
+                for prefix in ["mutation", "put", "post", "patch"] {
+                    if field_name.starts_with(prefix) {
+                        self.report(
+                            Rule::RootFieldAffixes,
+                            span,
+                            format!("field '{field_name}' on type 'Mutation' has a forbidden prefix: '{prefix}'"),
+                        );
+                        break;
+                    }
+                }
+                if field_name.ends_with("Mutation") {
+                    self.report(
+                        Rule::RootFieldAffixes,
+                        span,
+                        format!("field '{field_name}' on type 'Mutation' has a forbidden suffix: 'Mutation'"),
+                    );
+                }
+            }
+            "Subscription" => {
+                if field_name.starts_with("subscription") {
+                    self.report(
+                        Rule::RootFieldAffixes,
+                        span,
+                        format!("field '{field_name}' on type 'Subscription' has a forbidden prefix: 'subscription'"),
+                    );
+                }
+                if field_name.ends_with("Subscription") {
+                    self.report(
+                        Rule::RootFieldAffixes,
+                        span,
+                        format!("field '{field_name}' on type 'Subscription' has a forbidden suffix: 'Subscription'"),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn visit_directive(&mut self, directive: DirectiveDefinition<'_>) {
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(directive.name(), Case::Camel) {
+            self.report(
+                Rule::DirectiveNameCase,
+                directive.span(),
+                format!("directive '{current}' should be renamed to '{fix}'"),
+            );
+        }
+    }
+
+    pub fn visit_directive_usage(&mut self, parent: TypeDefinition<'_>, span: Span, directive: Directive<'_>) {
+        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
+            self.report(
+                Rule::DeprecatedWithoutReason,
+                span,
+                format!(
+                    "usage of directive 'deprecated' on {} '{}' does not populate the 'reason' argument",
+                    Self::type_definition_display(parent),
+                    parent.name()
+                ),
+            );
+        }
+    }
+
+    pub fn visit_directive_usage_field(
+        &mut self,
+        parent_type: TypeDefinition<'_>,
+        parent_field: FieldDefinition<'_>,
+        directive: Directive<'_>,
+    ) {
+        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
+            self.report(
+                Rule::DeprecatedWithoutReason,
+                parent_field.span(),
+                format!(
+                    "usage of directive 'deprecated' on field '{}' on {} '{}' does not populate the 'reason' argument",
+                    parent_field.name(),
+                    Self::type_definition_display(parent_type),
+                    parent_type.name()
+                ),
+            );
+        }
+    }
+
+    pub fn visit_directive_usage_input_value(
+        &mut self,
+        parent_input: InputObjectDefinition<'_>,
+        parent_input_value: InputValueDefinition<'_>,
+        directive: Directive<'_>,
+    ) {
+        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
+            self.report(
+                Rule::DeprecatedWithoutReason,
+                parent_input_value.span(),
+                format!(
+                    "usage of directive 'deprecated' on input value '{}' on input '{}' does not populate the 'reason' argument",
+                    parent_input_value.name(),
+                    parent_input.name()
+                ),
+            );
+        }
+    }
+
+    pub fn visit_directive_usage_enum_value(
+        &mut self,
+        parent_enum: EnumDefinition<'_>,
+        parent_value: EnumValueDefinition<'_>,
+        directive: Directive<'_>,
+    ) {
+        if directive.name() == "deprecated" && !directive.arguments().any(|argument| argument.name() == "reason") {
+            self.report(
+                Rule::DeprecatedWithoutReason,
+                parent_value.span(),
+                format!(
+                    "usage of directive 'deprecated' on enum value '{}' on enum '{}' does not populate the 'reason' argument",
+                    parent_value.value(),
+                    parent_enum.name()
+                ),
+            );
+        }
+    }
+
+    pub fn visit_input_object(&mut self, _input_object: InputObjectDefinition<'_>) {}
+
+    pub fn visit_union(&mut self, union: UnionDefinition<'_>, span: Span) {
+        let union_name = union.name();
+        if union_name.starts_with("Union") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("union '{union_name}' has a forbidden prefix: 'Union'"),
+            );
+        }
+        if union_name.ends_with("Union") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("union '{union_name}' has a forbidden suffix: 'Union'"),
+            );
+        }
+    }
+
+    pub fn visit_scalar(&mut self, _scalar: ScalarDefinition<'_>) {}
+
+    pub fn visit_interface(&mut self, object: InterfaceDefinition<'_>, span: Span) {
+        let interface_name = object.name();
+        if interface_name.starts_with("Interface") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("interface '{interface_name}' has a forbidden prefix: 'Interface'"),
+            );
+        }
+        if interface_name.ends_with("Interface") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("interface '{interface_name}' has a forbidden suffix: 'Interface'"),
+            );
+        }
+    }
+
+    pub fn visit_object(&mut self, object: ObjectDefinition<'_>, span: Span) {
+        let object_name = object.name();
+
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(object_name, Case::Pascal) {
+            self.report(
+                Rule::TypeNameCase,
+                span,
+                format!("type '{current}' should be renamed to '{fix}'"),
+            );
+        }
+        if object_name.starts_with("Type") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("type '{object_name}' has a forbidden prefix: 'Type'"),
+            );
+        }
+        if object_name.ends_with("Type") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("type '{object_name}' has a forbidden suffix: 'Type'"),
+            );
+        }
+    }
+
+    pub fn visit_enum(&mut self, r#enum: EnumDefinition<'_>, span: Span) {
+        let enum_name = r#enum.name();
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(enum_name, Case::Pascal) {
+            self.report(
+                Rule::TypeNameCase,
+                span,
+                format!("enum '{current}' should be renamed to '{fix}'"),
+            );
+        }
+        if enum_name.starts_with("Enum") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("enum '{enum_name}' has a forbidden prefix: 'Enum'"),
+            );
+        }
+        if enum_name.ends_with("Enum") {
+            self.report(
+                Rule::TypeNameAffixes,
+                span,
+                format!("enum '{enum_name}' has a forbidden suffix: 'Enum'"),
+            );
+        }
+    }
+
+    pub fn visit_enum_value(&mut self, parent: TypeDefinition<'_>, enum_value: EnumValueDefinition<'_>) {
+        let enum_name = parent.name();
+
+        let name = enum_value.value();
+        if let CaseMatch::Incorrect { current, fix } = Self::case_check(name, Case::ShoutySnake) {
+            self.report(
+                Rule::EnumValueNameCase,
+                enum_value.span(),
+                format!("value '{current}' on enum '{enum_name}' should be renamed to '{fix}'"),
+            );
+        }
+    }
+}
+
+fn is_deprecated<'a>(mut directives: impl Iterator<Item = Directive<'a>>) -> bool {
+    directives.any(|directive| directive.name() == "deprecated")
+}
+
+fn is_inaccessible<'a>(mut directives: impl Iterator<Item = Directive<'a>>) -> bool {
+    directives.any(|directive| directive.name() == "inaccessible")
+}
+
+fn is_non_null(ty: Type<'_>) -> bool {
+    // Wrappers are listed from the innermost to the outermost.
+    ty.wrappers().last() == Some(WrappingType::NonNull)
+}
+
+/// A required argument or input field is non-null and has no default value.
+fn is_required(value: InputValueDefinition<'_>) -> bool {
+    is_non_null(value.ty()) && value.default_value().is_none()
+}
+
+fn has_nullable_list_items(ty: Type<'_>) -> bool {
+    let mut inner_is_non_null = false;
+    for wrapper in ty.wrappers() {
+        match wrapper {
+            WrappingType::List if !inner_is_non_null => return true,
+            WrappingType::List => inner_is_non_null = false,
+            WrappingType::NonNull => inner_is_non_null = true,
+        }
+    }
+    false
+}
+
+/// The top-level field names of a field set, such as the `fields` argument of `@key`: `id
+/// organization { id }` returns `id` and `organization`.
+fn field_set_top_level_fields(field_set: &str) -> Vec<&str> {
+    let mut depth = 0usize;
+    let mut fields = Vec::new();
+    for token in field_set_tokens(field_set) {
+        match token {
+            "{" => depth += 1,
+            "}" => depth = depth.saturating_sub(1),
+            name if depth == 0 => fields.push(name),
+            _ => {}
+        }
+    }
+    fields
+}
+
+/// All the field names of a field set, at any depth.
+fn field_set_all_fields(field_set: &str) -> impl Iterator<Item = &str> {
+    let mut previous_is_fragment = false;
+    field_set_tokens(field_set).filter(move |token| {
+        let is_fragment = *token == "..." || *token == "on";
+        // Skips type conditions of inline fragments.
+        let is_field = !is_fragment && !previous_is_fragment && !matches!(*token, "{" | "}");
+        previous_is_fragment = *token == "on";
+        is_field
+    })
+}
+
+fn field_set_tokens(field_set: &str) -> impl Iterator<Item = &str> {
+    let mut rest = field_set;
+    std::iter::from_fn(move || {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        let token_len = match rest.chars().next()? {
+            '{' | '}' => 1,
+            '.' => rest.chars().take_while(|c| *c == '.').count(),
+            first => rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len())
+                .max(first.len_utf8()),
+        };
+        let (token, remaining) = rest.split_at(token_len);
+        rest = remaining;
+        Some(token)
+    })
+}
+
+/// The value of the `fields` argument of a federation directive.
+fn field_set_argument<'a>(directive: Directive<'a>) -> Option<&'a str> {
+    match directive.get_argument("fields").map(|argument| argument.value()) {
+        Some(Value::String(fields) | Value::BlockString(fields)) => Some(fields),
+        _ => None,
+    }
+}
+
+/// Type definitions and extensions, along with the span of their definition.
+fn type_definitions(schema: &TypeSystemDocument) -> impl Iterator<Item = (TypeDefinition<'_>, Span)> {
+    schema.definitions().filter_map(|definition| match definition {
+        Definition::Type(r#type) | Definition::TypeExtension(r#type) => Some((r#type, definition.span())),
+        _ => None,
+    })
+}
+
+/// The fields of an object or interface, empty for other kinds of types.
+fn output_fields(definition: TypeDefinition<'_>) -> Vec<FieldDefinition<'_>> {
+    match definition {
+        TypeDefinition::Object(object) => object.fields().collect(),
+        TypeDefinition::Interface(interface) => interface.fields().collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use cynic_parser::common::WrappingType;
+use cynic_parser::type_system::{Definition, FieldDefinition, TypeDefinition};
+use cynic_parser::{Span, TypeSystemDocument};
+
+use super::{is_non_null, output_fields, type_definitions, SchemaLinter};
+use crate::Rule;
+
+impl SchemaLinter<'_> {
+    pub(super) fn check_mutation_inputs(&mut self, schema: &TypeSystemDocument) {
+        let mutation_type = schema
+            .definitions()
+            .find_map(|definition| match definition {
+                Definition::Schema(schema) => schema.mutation_type().map(|root| root.named_type()),
+                _ => None,
+            })
+            .unwrap_or("Mutation");
+
+        let input_objects = type_definitions(schema)
+            .filter_map(|(definition, _)| match definition {
+                TypeDefinition::InputObject(input_object) => Some(input_object.name()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mutation_fields = type_definitions(schema)
+            .filter(|(definition, _)| definition.name() == mutation_type)
+            .flat_map(|(definition, _)| output_fields(definition));
+
+        for field in mutation_fields {
+            let arguments = field.arguments().collect::<Vec<_>>();
+            let is_single_input = match arguments.as_slice() {
+                [argument] => {
+                    argument.name() == "input"
+                        && argument.ty().wrappers().eq([WrappingType::NonNull])
+                        && input_objects.contains(argument.ty().name())
+                }
+                _ => false,
+            };
+
+            if !is_single_input {
+                self.report(
+                    Rule::MutationSingleInput,
+                    field.span(),
+                    format!(
+                        "mutation field '{}' should take a single non-null input object argument named 'input'",
+                        field.name()
+                    ),
+                );
+            }
+        }
+    }
+
+    pub(super) fn check_relay_connections(&mut self, schema: &TypeSystemDocument) {
+        let mut objects: HashMap<&str, (Span, Vec<FieldDefinition<'_>>)> = HashMap::new();
+        for (definition, span) in type_definitions(schema) {
+            if let TypeDefinition::Object(object) = definition {
+                let (_, fields) = objects.entry(object.name()).or_insert_with(|| (span, Vec::new()));
+                fields.extend(object.fields());
+            }
+        }
+
+        let field =
+            |fields: &[FieldDefinition<'_>], name: &str| fields.iter().find(|field| field.name() == name).copied();
+
+        let mut connections = objects
+            .iter()
+            .filter(|(name, _)| name.len() > "Connection".len() && name.ends_with("Connection"))
+            .map(|(name, (span, fields))| (*name, *span, fields))
+            .collect::<Vec<_>>();
+        connections.sort_by_key(|(_, span, _)| span.start);
+
+        let mut edges = Vec::new();
+
+        for (name, span, fields) in &connections {
+            match field(fields, "edges") {
+                Some(edges_field)
+                    if edges_field.ty().wrappers().any(|wrapper| wrapper == WrappingType::List)
+                        && objects.contains_key(edges_field.ty().name()) =>
+                {
+                    edges.push(edges_field.ty().name());
+                }
+                _ => self.report(
+                    Rule::RelayConnection,
+                    *span,
+                    format!("connection '{name}' should have an 'edges' field returning a list of edge objects"),
+                ),
+            }
+
+            match field(fields, "pageInfo") {
+                Some(page_info) if is_non_null(page_info.ty()) && page_info.ty().name() == "PageInfo" => {}
+                _ => self.report(
+                    Rule::RelayConnection,
+                    *span,
+                    format!("connection '{name}' should have a 'pageInfo' field of type 'PageInfo!'"),
+                ),
+            }
+        }
+
+        edges.sort_unstable();
+        edges.dedup();
+
+        for edge in edges {
+            let (span, fields) = &objects[edge];
+            for required_field in ["node", "cursor"] {
+                if field(fields, required_field).is_none() {
+                    self.report(
+                        Rule::RelayConnection,
+                        *span,
+                        format!("edge '{edge}' should have a '{required_field}' field"),
+                    );
+                }
+            }
+        }
+
+        if connections.is_empty() {
+            return;
+        }
+
+        let Some((span, fields)) = objects.get("PageInfo") else {
+            return;
+        };
+
+        for required_field in ["hasNextPage", "hasPreviousPage"] {
+            match field(fields, required_field) {
+                Some(field) if is_non_null(field.ty()) && field.ty().name() == "Boolean" => {}
+                _ => self.report(
+                    Rule::RelayConnection,
+                    *span,
+                    format!("'PageInfo' should have a '{required_field}' field of type 'Boolean!'"),
+                ),
+            }
+        }
+
+        for required_field in ["startCursor", "endCursor"] {
+            if field(fields, required_field).is_none() {
+                self.report(
+                    Rule::RelayConnection,
+                    *span,
+                    format!("'PageInfo' should have a '{required_field}' field"),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet};
+
+use cynic_parser::type_system::{FieldDefinition, TypeDefinition};
+use cynic_parser::TypeSystemDocument;
+
+use super::{field_set_all_fields, field_set_argument, field_set_top_level_fields, is_non_null, output_fields};
+use super::{type_definitions, SchemaLinter};
+use crate::Rule;
+
+impl SchemaLinter<'_> {
+    pub(super) fn check_federation_keys(&mut self, schema: &TypeSystemDocument) {
+        // Keys can refer to fields defined in extensions of the type.
+        let mut fields: HashMap<&str, Vec<FieldDefinition<'_>>> = HashMap::new();
+        for (definition, _) in type_definitions(schema) {
+            fields
+                .entry(definition.name())
+                .or_default()
+                .extend(output_fields(definition));
+        }
+
+        for (definition, span) in type_definitions(schema) {
+            for key in definition.directives().filter(|directive| directive.name() == "key") {
+                let Some(field_set) = field_set_argument(key) else {
+                    continue;
+                };
+
+                for key_field in field_set_top_level_fields(field_set) {
+                    let nullable_field = fields
+                        .get(definition.name())
+                        .and_then(|fields| fields.iter().find(|field| field.name() == key_field))
+                        .filter(|field| !is_non_null(field.ty()));
+
+                    if let Some(field) = nullable_field {
+                        self.report(
+                            Rule::FederationNullableKey,
+                            span,
+                            format!(
+                                "field '{key_field}' in the @key of {} '{}' should be non-null, not '{}'",
+                                Self::type_definition_display(definition),
+                                definition.name(),
+                                field.ty()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) fn check_federation_externals(&mut self, schema: &TypeSystemDocument) {
+        let mut referenced = HashSet::new();
+        for (definition, _) in type_definitions(schema) {
+            let field_directives = output_fields(definition)
+                .into_iter()
+                .flat_map(|field| field.directives().collect::<Vec<_>>());
+
+            for directive in definition.directives().chain(field_directives) {
+                if matches!(directive.name(), "key" | "requires" | "provides") {
+                    referenced.extend(field_set_argument(directive).into_iter().flat_map(field_set_all_fields));
+                }
+            }
+        }
+
+        for (definition, _) in type_definitions(schema) {
+            let type_is_external = definition.directives().any(|directive| directive.name() == "external");
+
+            for field in output_fields(definition) {
+                let is_external =
+                    type_is_external || field.directives().any(|directive| directive.name() == "external");
+
+                if is_external && !referenced.contains(field.name()) {
+                    self.report(
+                        Rule::FederationUnusedExternal,
+                        field.span(),
+                        format!(
+                            "field '{}' on {} '{}' is marked @external but is not used by any @requires, @provides or @key",
+                            field.name(),
+                            Self::type_definition_display(definition),
+                            definition.name()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
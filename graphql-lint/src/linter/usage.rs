@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use cynic_parser::type_system::{Definition, TypeDefinition};
+use cynic_parser::TypeSystemDocument;
+
+use super::{output_fields, type_definitions, SchemaLinter};
+use crate::Rule;
+
+impl SchemaLinter<'_> {
+    pub(super) fn check_unused_types(&mut self, schema: &TypeSystemDocument) {
+        let mut used = HashSet::new();
+        let mut has_schema_definition = false;
+
+        for definition in schema.definitions() {
+            match definition {
+                Definition::Schema(schema) | Definition::SchemaExtension(schema) => {
+                    has_schema_definition = true;
+                    used.extend(
+                        [schema.query_type(), schema.mutation_type(), schema.subscription_type()]
+                            .into_iter()
+                            .flatten()
+                            .map(|root| root.named_type()),
+                    );
+                }
+                Definition::Directive(directive) => {
+                    used.extend(directive.arguments().map(|argument| argument.ty().name()));
+                }
+                Definition::Type(r#type) | Definition::TypeExtension(r#type) => {
+                    for field in output_fields(r#type) {
+                        used.insert(field.ty().name());
+                        used.extend(field.arguments().map(|argument| argument.ty().name()));
+                    }
+
+                    match r#type {
+                        TypeDefinition::Object(object) => used.extend(object.implements_interfaces()),
+                        TypeDefinition::Interface(interface) => used.extend(interface.implements_interfaces()),
+                        TypeDefinition::Union(union) => used.extend(union.members().map(|member| member.name())),
+                        TypeDefinition::InputObject(input_object) => {
+                            used.extend(input_object.fields().map(|field| field.ty().name()))
+                        }
+                        TypeDefinition::Scalar(_) | TypeDefinition::Enum(_) => {}
+                    }
+                }
+            }
+        }
+
+        if !has_schema_definition {
+            used.extend(["Query", "Mutation", "Subscription"]);
+        }
+
+        for definition in schema.definitions() {
+            let Definition::Type(r#type) = definition else {
+                continue;
+            };
+
+            // Federation entities can be resolved through `_entities` without being referenced.
+            let is_entity = r#type.directives().any(|directive| directive.name() == "key");
+
+            if !used.contains(r#type.name()) && !is_entity {
+                self.report(
+                    Rule::UnusedTypes,
+                    definition.span(),
+                    format!(
+                        "{} '{}' is not used anywhere in the schema",
+                        Self::type_definition_display(r#type),
+                        r#type.name()
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Machine-readable outputs of the linter.
+
+use serde_json::json;
+
+use crate::{Diagnostic, Rule, Severity};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Serializes the diagnostics as a JSON array.
+pub fn to_json(diagnostics: &[Diagnostic]) -> String {
+    serde_json::to_string_pretty(diagnostics).expect("diagnostics are serializable")
+}
+
+/// Serializes the diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log, understood by most code scanning tools. `artifact_uri` is the path of the linted schema.
+pub fn to_sarif(diagnostics: &[Diagnostic], artifact_uri: &str) -> String {
+    let rules = Rule::ALL
+        .iter()
+        .map(|rule| {
+            let level = rule.default_level().severity();
+            json!({
+                "id": rule.id(),
+                "shortDescription": { "text": rule.description() },
+                "defaultConfiguration": {
+                    "enabled": level.is_some(),
+                    "level": level.map(Severity::as_str).unwrap_or("none"),
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            json!({
+                "ruleId": diagnostic.rule.id(),
+                "level": diagnostic.severity.as_str(),
+                "message": { "text": diagnostic.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": artifact_uri },
+                        "region": {
+                            "startLine": diagnostic.location.line,
+                            "startColumn": diagnostic.location.column,
+                        },
+                    },
+                }],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "graphql-lint",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/grafbase/grafbase/tree/main/graphql-lint",
+                    "rules": rules,
+                },
+            },
+            // Columns are counted in characters rather than the default UTF-16 code units.
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).expect("SARIF log is serializable")
+}
@@ -0,0 +1,130 @@
+use crate::RuleLevel;
+
+macro_rules! rules {
+    ($($(#[doc = $doc:literal])* $rule:ident => $id:literal,)*) => {
+        /// A lint rule. The kebab-case identifier of a rule is stable and is used both in the
+        /// configuration file and in the machine-readable outputs.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Rule {
+            $($(#[doc = $doc])* $rule,)*
+        }
+
+        impl Rule {
+            pub const ALL: &'static [Rule] = &[$(Rule::$rule,)*];
+
+            pub fn id(self) -> &'static str {
+                match self {
+                    $(Rule::$rule => $id,)*
+                }
+            }
+        }
+    };
+}
+
+rules! {
+    /// Object and enum names should be `PascalCase`.
+    TypeNameCase => "type-name-case",
+    /// Field names should be `camelCase`.
+    FieldNameCase => "field-name-case",
+    /// Field and directive argument names should be `camelCase`.
+    ArgumentNameCase => "argument-name-case",
+    /// Input object field names should be `camelCase`.
+    InputValueNameCase => "input-value-name-case",
+    /// Directive names should be `camelCase`.
+    DirectiveNameCase => "directive-name-case",
+    /// Enum values should be `SCREAMING_SNAKE_CASE`.
+    EnumValueNameCase => "enum-value-name-case",
+    /// Type names should not be prefixed or suffixed with their kind, such as `Type` or `Enum`.
+    TypeNameAffixes => "type-name-affixes",
+    /// Root fields should not be prefixed or suffixed with their operation type or with verbs
+    /// such as `get` or `put`.
+    RootFieldAffixes => "root-field-affixes",
+    /// `@deprecated` should always have a `reason`.
+    DeprecatedWithoutReason => "deprecated-without-reason",
+    /// Required arguments and input fields cannot be deprecated, as clients can't stop using them.
+    DeprecatedRequiredArgument => "deprecated-required-argument",
+    /// Types and their fields should have a description.
+    MissingDescription => "missing-description",
+    /// List items should be non-null.
+    NullableListItems => "nullable-list-items",
+    /// Mutation fields should take a single non-null input object argument named `input`.
+    MutationSingleInput => "mutation-single-input",
+    /// Connection and edge types should follow the Relay cursor connections specification.
+    RelayConnection => "relay-connection",
+    /// Types should be used somewhere in the schema.
+    UnusedTypes => "unused-types",
+    /// Fields in a federation `@key` should be non-null.
+    FederationNullableKey => "federation-nullable-key",
+    /// `@external` fields should be used by a `@requires`, `@provides` or `@key`.
+    FederationUnusedExternal => "federation-unused-external",
+}
+
+impl Rule {
+    pub fn description(self) -> &'static str {
+        match self {
+            Rule::TypeNameCase => "Object and enum names should be PascalCase",
+            Rule::FieldNameCase => "Field names should be camelCase",
+            Rule::ArgumentNameCase => "Argument names should be camelCase",
+            Rule::InputValueNameCase => "Input value names should be camelCase",
+            Rule::DirectiveNameCase => "Directive names should be camelCase",
+            Rule::EnumValueNameCase => "Enum values should be SCREAMING_SNAKE_CASE",
+            Rule::TypeNameAffixes => "Type names should not be prefixed or suffixed with their kind",
+            Rule::RootFieldAffixes => "Root fields should not use forbidden prefixes or suffixes",
+            Rule::DeprecatedWithoutReason => "Usages of @deprecated should populate the 'reason' argument",
+            Rule::DeprecatedRequiredArgument => "Required arguments and input fields cannot be deprecated",
+            Rule::MissingDescription => "Types and fields should have a description",
+            Rule::NullableListItems => "List items should be non-null",
+            Rule::MutationSingleInput => {
+                "Mutation fields should take a single non-null input object argument named 'input'"
+            }
+            Rule::RelayConnection => {
+                "Connection and edge types should follow the Relay cursor connections specification"
+            }
+            Rule::UnusedTypes => "Types should be used in the schema",
+            Rule::FederationNullableKey => "Fields in a @key should be non-null",
+            Rule::FederationUnusedExternal => "@external fields should be used by a @requires, @provides or @key",
+        }
+    }
+
+    /// The level of the rule if it isn't overridden by the configuration. Rules enforcing a
+    /// particular API design are opt-in.
+    pub fn default_level(self) -> RuleLevel {
+        match self {
+            Rule::MissingDescription
+            | Rule::NullableListItems
+            | Rule::MutationSingleInput
+            | Rule::RelayConnection
+            | Rule::UnusedTypes => RuleLevel::Off,
+            _ => RuleLevel::Warning,
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Rule> {
+        Rule::ALL.iter().copied().find(|rule| rule.id() == id)
+    }
+}
+
+impl serde::Serialize for Rule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Rule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        Rule::from_id(&id).ok_or_else(|| serde::de::Error::custom(format!("unknown rule '{id}'")))
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.id())
+    }
+}